@@ -43,6 +43,7 @@ data class NavigationState(
 
 fun NavigationState.isNavigating(): Boolean =
     when (tripState) {
+      is TripState.Paused,
       is TripState.Complete,
       is TripState.Idle -> false
 
@@ -5,13 +5,14 @@ import uniffi.ferrostar.SpokenInstruction
 import uniffi.ferrostar.TripState
 
 /**
- * Get the progress of the trip while navigating.
+ * Get the progress of the trip while navigating (or paused).
  *
- * @return The progress of the trip, or null if the trip is not navigating.
+ * @return The progress of the trip, or null if the trip is neither navigating nor paused.
  */
 fun TripState.progress() =
     when (this) {
       is TripState.Navigating -> this.progress
+      is TripState.Paused -> this.progress
       is TripState.Complete,
       is TripState.Idle -> null
     }
@@ -25,6 +26,7 @@ fun TripState.visualInstruction() =
     try {
       when (this) {
         is TripState.Navigating -> this.visualInstruction
+        is TripState.Paused,
         is TripState.Complete,
         is TripState.Idle -> null
       }
@@ -41,6 +43,7 @@ fun TripState.visualInstruction() =
 fun TripState.spokenInstruction(): SpokenInstruction? =
     when (this) {
       is TripState.Navigating -> this.spokenInstruction
+      is TripState.Paused,
       is TripState.Complete,
       is TripState.Idle -> null
     }
@@ -53,6 +56,7 @@ fun TripState.spokenInstruction(): SpokenInstruction? =
 fun TripState.deviation() =
     when (this) {
       is TripState.Navigating -> this.deviation
+      is TripState.Paused,
       is TripState.Complete,
       is TripState.Idle -> null
     }
@@ -64,8 +68,9 @@ fun TripState.deviation() =
  */
 fun TripState.currentRoadName() =
     when (this) {
-      is TripState.Navigating ->
-          this.remainingSteps.firstOrNull()?.roadName.let {
+      is TripState.Navigating,
+      is TripState.Paused ->
+          remainingSteps()?.firstOrNull()?.roadName.let {
             if (it.isNullOrBlank()) {
               null
             } else {
@@ -85,6 +90,7 @@ fun TripState.currentRoadName() =
 fun TripState.currentStepGeometryIndex() =
     when (this) {
       is TripState.Navigating -> this.currentStepGeometryIndex?.toInt()
+      is TripState.Paused,
       is TripState.Complete,
       is TripState.Idle -> null
     }
@@ -97,6 +103,7 @@ fun TripState.currentStepGeometryIndex() =
 fun TripState.remainingSteps() =
     when (this) {
       is TripState.Navigating -> this.remainingSteps
+      is TripState.Paused -> this.remainingSteps
       is TripState.Complete,
       is TripState.Idle -> null
     }
@@ -112,6 +119,7 @@ fun TripState.currentStep() = remainingSteps()?.first()
 fun TripState.remainingWaypoints() =
     when (this) {
       is TripState.Navigating -> this.remainingWaypoints
+      is TripState.Paused -> this.remainingWaypoints
       is TripState.Complete,
       is TripState.Idle -> null
     }
@@ -133,6 +141,7 @@ fun TripState.preferredUserLocation() =
           is RouteDeviation.Deviation -> this.userLocation
         }
       }
+      is TripState.Paused -> this.snappedUserLocation
       is TripState.Idle -> this.userLocation
       is TripState.Complete -> this.userLocation
     }
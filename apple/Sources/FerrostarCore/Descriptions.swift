@@ -24,9 +24,11 @@ extension TripState: CustomStringConvertible {
         switch self {
         case let .idle(userLocation):
             "idle: \(userLocation != nil ? "\(userLocation!.coordinates)" : "none")"
-        case let .navigating(_, _, snappedUserLocation, _, _, _, _, _, visualInstruction, _, _):
-            "navigating: \(snappedUserLocation.coordinates) instruction: \(visualInstruction != nil ? visualInstruction!.primaryContent.text : "none")"
-        case let .complete(userLocation, _):
+        case .navigating:
+            "navigating: \(navigating!.snappedUserLocation.coordinates) instruction: \(navigating!.visualInstruction != nil ? navigating!.visualInstruction!.primaryContent.text : "none")"
+        case let .paused(_, snappedUserLocation, _, _, _, _, _):
+            "paused: \(snappedUserLocation.coordinates)"
+        case let .complete(userLocation, _, _):
             "complete: \(userLocation.coordinates)"
         }
     }
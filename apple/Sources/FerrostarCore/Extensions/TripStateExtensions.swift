@@ -0,0 +1,83 @@
+import FerrostarCoreFFI
+import Foundation
+
+public extension TripState {
+    /// The values associated with a ``TripState/navigating`` trip state.
+    ///
+    /// The core adds fields to the navigating state over time,
+    /// so this is the only place that matches all of them;
+    /// everything else should read the fields it needs from here.
+    struct Navigating: Hashable {
+        public let currentStepGeometryIndex: UInt64?
+        public let userLocation: UserLocation
+        public let snappedUserLocation: UserLocation
+        public let remainingSteps: [RouteStep]
+        public let remainingWaypoints: [Waypoint]
+        public let progress: TripProgress
+        public let summary: TripSummary
+        public let deviation: RouteDeviation
+        public let visualInstruction: VisualInstruction?
+        public let spokenInstruction: SpokenInstruction?
+        public let annotationJson: String?
+        public let segmentAnnotation: SegmentAnnotation?
+        public let speedLimit: SegmentMaxSpeed?
+        public let nextSpeedLimitChange: SpeedLimitChange?
+        public let isSpeeding: Bool
+        public let upcomingManeuvers: [UpcomingManeuver]
+        public let upcomingIncident: UpcomingIncident?
+        public let locationEstimated: Bool
+        public let rawUserLocation: UserLocation?
+        public let heading: Heading?
+    }
+
+    /// The navigating state's values, or `nil` if the trip is not navigating.
+    var navigating: Navigating? {
+        guard case let .navigating(
+            currentStepGeometryIndex: currentStepGeometryIndex,
+            userLocation: userLocation,
+            snappedUserLocation: snappedUserLocation,
+            remainingSteps: remainingSteps,
+            remainingWaypoints: remainingWaypoints,
+            progress: progress,
+            summary: summary,
+            deviation: deviation,
+            visualInstruction: visualInstruction,
+            spokenInstruction: spokenInstruction,
+            annotationJson: annotationJson,
+            segmentAnnotation: segmentAnnotation,
+            speedLimit: speedLimit,
+            nextSpeedLimitChange: nextSpeedLimitChange,
+            isSpeeding: isSpeeding,
+            upcomingManeuvers: upcomingManeuvers,
+            upcomingIncident: upcomingIncident,
+            locationEstimated: locationEstimated,
+            rawUserLocation: rawUserLocation,
+            heading: heading
+        ) = self else {
+            return nil
+        }
+
+        return Navigating(
+            currentStepGeometryIndex: currentStepGeometryIndex,
+            userLocation: userLocation,
+            snappedUserLocation: snappedUserLocation,
+            remainingSteps: remainingSteps,
+            remainingWaypoints: remainingWaypoints,
+            progress: progress,
+            summary: summary,
+            deviation: deviation,
+            visualInstruction: visualInstruction,
+            spokenInstruction: spokenInstruction,
+            annotationJson: annotationJson,
+            segmentAnnotation: segmentAnnotation,
+            speedLimit: speedLimit,
+            nextSpeedLimitChange: nextSpeedLimitChange,
+            isSpeeding: isSpeeding,
+            upcomingManeuvers: upcomingManeuvers,
+            upcomingIncident: upcomingIncident,
+            locationEstimated: locationEstimated,
+            rawUserLocation: rawUserLocation,
+            heading: heading
+        )
+    }
+}
@@ -429,22 +429,18 @@ public protocol FerrostarCoreDelegate: AnyObject {
             self.state?.tripState = state.tripState
 
             switch state.tripState {
-            case .idle(userLocation: _):
+            case .idle(userLocation: _), .paused:
                 break
-            case .navigating(
-                currentStepGeometryIndex: _,
-                userLocation: _,
-                snappedUserLocation: _,
-                remainingSteps: _,
-                remainingWaypoints: let remainingWaypoints,
-                progress: let tripProgress,
-                summary: _,
-                deviation: let deviation,
-                visualInstruction: let visualInstruction,
-                spokenInstruction: let spokenInstruction,
-                annotationJson: _
-            ):
-                switch deviation {
+            case .navigating:
+                guard let navigating = state.tripState.navigating else {
+                    break
+                }
+                let remainingWaypoints = navigating.remainingWaypoints
+                let tripProgress = navigating.progress
+                let visualInstruction = navigating.visualInstruction
+                let spokenInstruction = navigating.spokenInstruction
+
+                switch navigating.deviation {
                 case .noDeviation:
                     // No action
                     break
@@ -524,7 +520,7 @@ public protocol FerrostarCoreDelegate: AnyObject {
                         tripProgress: tripProgress
                     )
                 }
-            case .complete(userLocation: _, summary: _):
+            case .complete(userLocation: _, summary: _, cancellationReason: _):
                 // End the widget session if the route is completed, regardless of whether stop is called.
                 // This avoids a dangling LiveActivity the user must close.
                 self.widgetProvider?.terminate()
@@ -28,21 +28,26 @@ public struct NavigationState: Hashable {
     }
 
     /// The current progress stats of the trip and current step.
+    ///
+    /// While the trip is paused, this is the progress at the time it was paused.
     public var currentProgress: TripProgress? {
-        guard case let .navigating(_, _, _, _, _, progress, _, _, _,
-                                   _, _) = tripState
-        else {
-            return nil
+        switch tripState {
+        case .navigating:
+            tripState.navigating?.progress
+        case let .paused(_, _, _, _, progress, _, _):
+            progress
+        case .complete, .idle:
+            nil
         }
-
-        return progress
     }
 
     /// An aggregated summary of the trip so far.
     public var currentSummary: TripSummary? {
         switch tripState {
-        case let .navigating(_, _, _, _, _, _, summary, _, _, _, _),
-             let .complete(_, summary):
+        case .navigating:
+            tripState.navigating?.summary
+        case let .paused(_, _, _, _, _, summary, _),
+             let .complete(_, summary, _):
             summary
         case .idle:
             nil
@@ -53,20 +58,26 @@ public struct NavigationState: Hashable {
     ///
     /// These are steps from the route that have not yet been travelled.
     public var remainingSteps: [RouteStep]? {
-        guard case let .navigating(_, _, _, remainingSteps, _, _, _, _, _, _, _) = tripState else {
-            return nil
+        switch tripState {
+        case .navigating:
+            tripState.navigating?.remainingSteps
+        case let .paused(_, _, remainingSteps, _, _, _, _):
+            remainingSteps
+        case .complete, .idle:
+            nil
         }
-
-        return remainingSteps
     }
 
     /// The remaining waypoints on the navigation trip.
     public var remainingWaypoints: [Waypoint]? {
-        guard case let .navigating(_, _, _, _, remainingWaypoints, _, _, _, _, _, _) = tripState else {
-            return nil
+        switch tripState {
+        case .navigating:
+            tripState.navigating?.remainingWaypoints
+        case let .paused(_, _, _, remainingWaypoints, _, _, _):
+            remainingWaypoints
+        case .complete, .idle:
+            nil
         }
-
-        return remainingWaypoints
     }
 
     /// The current route step.
@@ -76,31 +87,19 @@ public struct NavigationState: Hashable {
 
     /// The current visual instruction.
     public var currentVisualInstruction: VisualInstruction? {
-        guard case let .navigating(_, _, _, _, _, _, _, _, visualInstruction, _, _) = tripState else {
-            return nil
-        }
-
-        return visualInstruction
+        tripState.navigating?.visualInstruction
     }
 
     /// The current route deviation state.
     public var currentDeviation: RouteDeviation? {
-        guard case let .navigating(_, _, _, _, _, _, _, routeDeviation, _, _, _) = tripState else {
-            return nil
-        }
-
-        return routeDeviation
+        tripState.navigating?.deviation
     }
 
     /// The current geometry segment's annotations in a JSON string.
     ///
     /// A segment is the line between two coordinates on the geometry.
     public var currentAnnotationJSON: String? {
-        guard case let .navigating(_, _, _, _, _, _, _, _, _, _, annotationJson) = tripState else {
-            return nil
-        }
-
-        return annotationJson
+        tripState.navigating?.annotationJson
     }
 
     /// Is ferrostar currently navigating.
@@ -110,18 +109,25 @@ public struct NavigationState: Hashable {
         switch tripState {
         case .navigating:
             true
-        case .complete, .idle:
+        case .paused, .complete, .idle:
             false
         }
     }
 
-    /// The road name of the current step if one is specified.
-    public var currentRoadName: String? {
-        guard case let .navigating(_, _, _, remainingSteps, _, _, _, _, _, _, _) = tripState else {
-            return nil
+    /// Is the trip currently paused.
+    ///
+    /// This is equivalent to a ``tripState`` of `.paused`.
+    public var isPaused: Bool {
+        if case .paused = tripState {
+            true
+        } else {
+            false
         }
+    }
 
-        let roadName = remainingSteps.first?.roadName?.trimmingCharacters(in: .whitespacesAndNewlines)
+    /// The road name of the current step if one is specified.
+    public var currentRoadName: String? {
+        let roadName = currentStep?.roadName?.trimmingCharacters(in: .whitespacesAndNewlines)
 
         if roadName?.isEmpty == true {
             return nil
@@ -132,20 +138,24 @@ public struct NavigationState: Hashable {
 
     /// Get the UI's preferred representation of User's location from the trip state.
     ///
-    /// This will return the users snapped if the user is navigating and on route. Otherwise it will return the user's
-    /// raw location.
+    /// This will return the users snapped location if the user is navigating and on route, or if the trip is paused.
+    /// Otherwise it will return the user's raw location.
     public var preferredUserLocation: UserLocation? {
         switch tripState {
         case let .idle(userLocation):
             userLocation
-        case let .complete(userLocation, _):
+        case let .complete(userLocation, _, _):
             userLocation
-        case let .navigating(_, userLocation, snappedUserLocation, _, _, _, _, deviation, _, _, _):
-            switch deviation {
-            case .noDeviation:
-                snappedUserLocation
-            case .deviation:
-                userLocation
+        case let .paused(_, snappedUserLocation, _, _, _, _, _):
+            snappedUserLocation
+        case .navigating:
+            tripState.navigating.map { navigating in
+                switch navigating.deviation {
+                case .noDeviation:
+                    navigating.snappedUserLocation
+                case .deviation:
+                    navigating.userLocation
+                }
             }
         }
     }
@@ -170,7 +170,7 @@ public struct DynamicallyOrientingNavigationView: View {
     formatter.locale = Locale(identifier: "en-US")
     formatter.units = .imperial
 
-    guard case let .navigating(_, _, snappedUserLocation: userLocation, _, _, _, _, _, _, _, _) = state.tripState else {
+    guard let userLocation = state.tripState.navigating?.snappedUserLocation else {
         return EmptyView()
     }
 
@@ -191,7 +191,7 @@ public struct DynamicallyOrientingNavigationView: View {
     formatter.locale = Locale(identifier: "en-US")
     formatter.units = .metric
 
-    guard case let .navigating(_, _, snappedUserLocation: userLocation, _, _, _, _, _, _, _, _) = state.tripState else {
+    guard let userLocation = state.tripState.navigating?.snappedUserLocation else {
         return EmptyView()
     }
 
@@ -142,7 +142,7 @@ public struct LandscapeNavigationView: View {
     formatter.locale = Locale(identifier: "en-US")
     formatter.units = .imperial
 
-    guard case let .navigating(_, _, snappedUserLocation: userLocation, _, _, _, _, _, _, _, _) = state.tripState else {
+    guard let userLocation = state.tripState.navigating?.snappedUserLocation else {
         return EmptyView()
     }
 
@@ -165,7 +165,7 @@ public struct LandscapeNavigationView: View {
     formatter.locale = Locale(identifier: "en-US")
     formatter.units = .metric
 
-    guard case let .navigating(_, _, snappedUserLocation: userLocation, _, _, _, _, _, _, _, _) = state.tripState else {
+    guard let userLocation = state.tripState.navigating?.snappedUserLocation else {
         return EmptyView()
     }
 
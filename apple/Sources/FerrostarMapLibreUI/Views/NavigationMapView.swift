@@ -145,7 +145,7 @@ public struct NavigationMapView: View {
     // TODO: Make map URL configurable but gitignored
     let state = NavigationState.modifiedPedestrianExample(droppingNWaypoints: 4)
 
-    guard case let .navigating(_, _, snappedUserLocation: userLocation, _, _, _, _, _, _, _, _) = state.tripState else {
+    guard let userLocation = state.tripState.navigating?.snappedUserLocation else {
         return EmptyView()
     }
 
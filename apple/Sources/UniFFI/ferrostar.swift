@@ -1633,8 +1633,10 @@ public func FfiConverterTypeNavigationCache_lower(_ value: NavigationCache) -> U
  * returning an updated state given inputs like user location.
  *
  * Notes for implementing a new platform:
- * - A controller is bound to a single route; if you want recalculation, create a new instance.
- * - This is a pure type (no interior mutability), so a core function of your platform code is responsibly managing mutable state.
+ * - A controller navigates a single route at a time; use [`Navigator::replace_route`] after recalculation.
+ * - The only interior mutability is the current route.
+ * Everything else about the trip lives in [`NavState`],
+ * so a core function of your platform code is responsibly managing mutable state.
  */
 public protocol NavigationControllerProtocol: AnyObject, Sendable {
     
@@ -1644,8 +1646,10 @@ public protocol NavigationControllerProtocol: AnyObject, Sendable {
  * returning an updated state given inputs like user location.
  *
  * Notes for implementing a new platform:
- * - A controller is bound to a single route; if you want recalculation, create a new instance.
- * - This is a pure type (no interior mutability), so a core function of your platform code is responsibly managing mutable state.
+ * - A controller navigates a single route at a time; use [`Navigator::replace_route`] after recalculation.
+ * - The only interior mutability is the current route.
+ * Everything else about the trip lives in [`NavState`],
+ * so a core function of your platform code is responsibly managing mutable state.
  */
 open class NavigationController: NavigationControllerProtocol, @unchecked Sendable {
     fileprivate let handle: UInt64
@@ -1768,10 +1772,27 @@ public protocol NavigationObserver: AnyObject, Sendable {
     
     func onUserLocationUpdate(location: UserLocation, state: NavState) 
     
+    func onHeadingUpdate(heading: Heading, state: NavState) 
+    
     func onAdvanceToNextStep(state: NavState) 
     
     func onRouteAvailable(route: Route) 
     
+    func onRouteReplaced(route: Route, state: NavState) 
+    
+    func onPause(state: NavState) 
+    
+    func onResume(state: NavState) 
+    
+    func onCancel(state: NavState) 
+    
+    /**
+     * Called with the events produced by a navigation state update (if there were any).
+     *
+     * This is called after the observer method for the update itself.
+     */
+    func onNavigationEvents(events: [NavigationEvent]) 
+    
 }
 open class NavigationObserverImpl: NavigationObserver, @unchecked Sendable {
     fileprivate let handle: UInt64
@@ -1843,6 +1864,15 @@ open func onUserLocationUpdate(location: UserLocation, state: NavState)  {try! r
 }
 }
     
+open func onHeadingUpdate(heading: Heading, state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationobserver_on_heading_update(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeHeading_lower(heading),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
 open func onAdvanceToNextStep(state: NavState)  {try! rustCall() {
     uniffi_ferrostar_fn_method_navigationobserver_on_advance_to_next_step(
             self.uniffiCloneHandle(),
@@ -1859,6 +1889,52 @@ open func onRouteAvailable(route: Route)  {try! rustCall() {
 }
 }
     
+open func onRouteReplaced(route: Route, state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationobserver_on_route_replaced(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeRoute_lower(route),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
+open func onPause(state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationobserver_on_pause(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
+open func onResume(state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationobserver_on_resume(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
+open func onCancel(state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationobserver_on_cancel(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
+    /**
+     * Called with the events produced by a navigation state update (if there were any).
+     *
+     * This is called after the observer method for the update itself.
+     */
+open func onNavigationEvents(events: [NavigationEvent])  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationobserver_on_navigation_events(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceTypeNavigationEvent.lower(events),$0
+    )
+}
+}
+    
 
     
 }
@@ -1930,6 +2006,32 @@ fileprivate struct UniffiCallbackInterfaceNavigationObserver {
             }
 
             
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onHeadingUpdate: { (
+            uniffiHandle: UInt64,
+            heading: RustBuffer,
+            state: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeNavigationObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onHeadingUpdate(
+                     heading: try FfiConverterTypeHeading_lift(heading),
+                     state: try FfiConverterTypeNavState_lift(state)
+                )
+            }
+
+            
             let writeReturn = { () }
             uniffiTraitInterfaceCall(
                 callStatus: uniffiCallStatus,
@@ -1978,6 +2080,128 @@ fileprivate struct UniffiCallbackInterfaceNavigationObserver {
             }
 
             
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onRouteReplaced: { (
+            uniffiHandle: UInt64,
+            route: RustBuffer,
+            state: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeNavigationObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onRouteReplaced(
+                     route: try FfiConverterTypeRoute_lift(route),
+                     state: try FfiConverterTypeNavState_lift(state)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onPause: { (
+            uniffiHandle: UInt64,
+            state: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeNavigationObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onPause(
+                     state: try FfiConverterTypeNavState_lift(state)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onResume: { (
+            uniffiHandle: UInt64,
+            state: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeNavigationObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onResume(
+                     state: try FfiConverterTypeNavState_lift(state)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onCancel: { (
+            uniffiHandle: UInt64,
+            state: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeNavigationObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onCancel(
+                     state: try FfiConverterTypeNavState_lift(state)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onNavigationEvents: { (
+            uniffiHandle: UInt64,
+            events: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeNavigationObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onNavigationEvents(
+                     events: try FfiConverterSequenceTypeNavigationEvent.lift(events)
+                )
+            }
+
+            
             let writeReturn = { () }
             uniffiTraitInterfaceCall(
                 callStatus: uniffiCallStatus,
@@ -2068,10 +2292,22 @@ public protocol NavigationRecorderProtocol: AnyObject, Sendable {
     
     func onAdvanceToNextStep(state: NavState) 
     
+    func onCancel(state: NavState) 
+    
     func onGetInitialState(state: NavState) 
     
+    func onHeadingUpdate(heading: Heading, state: NavState) 
+    
+    func onNavigationEvents(events: [NavigationEvent]) 
+    
+    func onPause(state: NavState) 
+    
+    func onResume(state: NavState) 
+    
     func onRouteAvailable(route: Route) 
     
+    func onRouteReplaced(route: Route, state: NavState) 
+    
     func onUserLocationUpdate(location: UserLocation, state: NavState) 
     
 }
@@ -2161,6 +2397,14 @@ open func onAdvanceToNextStep(state: NavState)  {try! rustCall() {
 }
 }
     
+open func onCancel(state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationrecorder_on_cancel(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
 open func onGetInitialState(state: NavState)  {try! rustCall() {
     uniffi_ferrostar_fn_method_navigationrecorder_on_get_initial_state(
             self.uniffiCloneHandle(),
@@ -2169,6 +2413,39 @@ open func onGetInitialState(state: NavState)  {try! rustCall() {
 }
 }
     
+open func onHeadingUpdate(heading: Heading, state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationrecorder_on_heading_update(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeHeading_lower(heading),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
+open func onNavigationEvents(events: [NavigationEvent])  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationrecorder_on_navigation_events(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceTypeNavigationEvent.lower(events),$0
+    )
+}
+}
+    
+open func onPause(state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationrecorder_on_pause(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
+open func onResume(state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationrecorder_on_resume(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
 open func onRouteAvailable(route: Route)  {try! rustCall() {
     uniffi_ferrostar_fn_method_navigationrecorder_on_route_available(
             self.uniffiCloneHandle(),
@@ -2177,6 +2454,15 @@ open func onRouteAvailable(route: Route)  {try! rustCall() {
 }
 }
     
+open func onRouteReplaced(route: Route, state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationrecorder_on_route_replaced(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeRoute_lower(route),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
 open func onUserLocationUpdate(location: UserLocation, state: NavState)  {try! rustCall() {
     uniffi_ferrostar_fn_method_navigationrecorder_on_user_location_update(
             self.uniffiCloneHandle(),
@@ -2354,10 +2640,22 @@ public protocol NavigationSessionProtocol: AnyObject, Sendable {
     
     func advanceToNextStep(state: NavState)  -> NavState
     
+    func cancel(state: NavState, reason: CancellationReason)  -> NavState
+    
+    func estimateLocation(timestamp: Date, state: NavState)  -> NavState
+    
     func getInitialState(location: UserLocation)  -> NavState
     
+    func pause(state: NavState)  -> NavState
+    
+    func replaceRoute(route: Route, state: NavState)  -> NavState
+    
+    func resume(state: NavState)  -> NavState
+    
     func route()  -> Route
     
+    func updateHeading(heading: Heading, state: NavState)  -> NavState
+    
     func updateUserLocation(location: UserLocation, state: NavState)  -> NavState
     
 }
@@ -2432,6 +2730,26 @@ open func advanceToNextStep(state: NavState) -> NavState  {
 })
 }
     
+open func cancel(state: NavState, reason: CancellationReason) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsession_cancel(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),
+        FfiConverterTypeCancellationReason_lower(reason),$0
+    )
+})
+}
+    
+open func estimateLocation(timestamp: Date, state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsession_estimate_location(
+            self.uniffiCloneHandle(),
+        FfiConverterTimestamp.lower(timestamp),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+})
+}
+    
 open func getInitialState(location: UserLocation) -> NavState  {
     return try!  FfiConverterTypeNavState_lift(try! rustCall() {
     uniffi_ferrostar_fn_method_navigationsession_get_initial_state(
@@ -2441,29 +2759,67 @@ open func getInitialState(location: UserLocation) -> NavState  {
 })
 }
     
-open func route() -> Route  {
-    return try!  FfiConverterTypeRoute_lift(try! rustCall() {
-    uniffi_ferrostar_fn_method_navigationsession_route(
-            self.uniffiCloneHandle(),$0
+open func pause(state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsession_pause(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
     )
 })
 }
     
-open func updateUserLocation(location: UserLocation, state: NavState) -> NavState  {
+open func replaceRoute(route: Route, state: NavState) -> NavState  {
     return try!  FfiConverterTypeNavState_lift(try! rustCall() {
-    uniffi_ferrostar_fn_method_navigationsession_update_user_location(
+    uniffi_ferrostar_fn_method_navigationsession_replace_route(
             self.uniffiCloneHandle(),
-        FfiConverterTypeUserLocation_lower(location),
+        FfiConverterTypeRoute_lower(route),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+})
+}
+    
+open func resume(state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsession_resume(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+})
+}
+    
+open func route() -> Route  {
+    return try!  FfiConverterTypeRoute_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsession_route(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func updateHeading(heading: Heading, state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsession_update_heading(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeHeading_lower(heading),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+})
+}
+    
+open func updateUserLocation(location: UserLocation, state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsession_update_user_location(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeUserLocation_lower(location),
         FfiConverterTypeNavState_lower(state),$0
     )
 })
 }
     
 
-    
-}
-
-
+    
+}
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -2525,10 +2881,22 @@ public protocol NavigationSessionCacheProtocol: AnyObject, Sendable {
     
     func onAdvanceToNextStep(state: NavState) 
     
+    func onCancel(state: NavState) 
+    
     func onGetInitialState(state: NavState) 
     
+    func onHeadingUpdate(heading: Heading, state: NavState) 
+    
+    func onNavigationEvents(events: [NavigationEvent]) 
+    
+    func onPause(state: NavState) 
+    
+    func onResume(state: NavState) 
+    
     func onRouteAvailable(route: Route) 
     
+    func onRouteReplaced(route: Route, state: NavState) 
+    
     func onUserLocationUpdate(location: UserLocation, state: NavState) 
     
 }
@@ -2624,6 +2992,14 @@ open func onAdvanceToNextStep(state: NavState)  {try! rustCall() {
 }
 }
     
+open func onCancel(state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsessioncache_on_cancel(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
 open func onGetInitialState(state: NavState)  {try! rustCall() {
     uniffi_ferrostar_fn_method_navigationsessioncache_on_get_initial_state(
             self.uniffiCloneHandle(),
@@ -2632,6 +3008,39 @@ open func onGetInitialState(state: NavState)  {try! rustCall() {
 }
 }
     
+open func onHeadingUpdate(heading: Heading, state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsessioncache_on_heading_update(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeHeading_lower(heading),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
+open func onNavigationEvents(events: [NavigationEvent])  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsessioncache_on_navigation_events(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceTypeNavigationEvent.lower(events),$0
+    )
+}
+}
+    
+open func onPause(state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsessioncache_on_pause(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
+open func onResume(state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsessioncache_on_resume(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
 open func onRouteAvailable(route: Route)  {try! rustCall() {
     uniffi_ferrostar_fn_method_navigationsessioncache_on_route_available(
             self.uniffiCloneHandle(),
@@ -2640,6 +3049,15 @@ open func onRouteAvailable(route: Route)  {try! rustCall() {
 }
 }
     
+open func onRouteReplaced(route: Route, state: NavState)  {try! rustCall() {
+    uniffi_ferrostar_fn_method_navigationsessioncache_on_route_replaced(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeRoute_lower(route),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+}
+}
+    
 open func onUserLocationUpdate(location: UserLocation, state: NavState)  {try! rustCall() {
     uniffi_ferrostar_fn_method_navigationsessioncache_on_user_location_update(
             self.uniffiCloneHandle(),
@@ -2718,6 +3136,18 @@ public protocol NavigatorProtocol: AnyObject, Sendable {
     
     func updateUserLocation(location: UserLocation, state: NavState)  -> NavState
     
+    func replaceRoute(route: Route, state: NavState)  -> NavState
+    
+    func pause(state: NavState)  -> NavState
+    
+    func resume(state: NavState)  -> NavState
+    
+    func cancel(state: NavState, reason: CancellationReason)  -> NavState
+    
+    func estimateLocation(timestamp: Date, state: NavState)  -> NavState
+    
+    func updateHeading(heading: Heading, state: NavState)  -> NavState
+    
 }
 /**
  * Core interface for navigation functionalities.
@@ -2815,6 +3245,64 @@ open func updateUserLocation(location: UserLocation, state: NavState) -> NavStat
 })
 }
     
+open func replaceRoute(route: Route, state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigator_replace_route(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeRoute_lower(route),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+})
+}
+    
+open func pause(state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigator_pause(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+})
+}
+    
+open func resume(state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigator_resume(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+})
+}
+    
+open func cancel(state: NavState, reason: CancellationReason) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigator_cancel(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeNavState_lower(state),
+        FfiConverterTypeCancellationReason_lower(reason),$0
+    )
+})
+}
+    
+open func estimateLocation(timestamp: Date, state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigator_estimate_location(
+            self.uniffiCloneHandle(),
+        FfiConverterTimestamp.lower(timestamp),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+})
+}
+    
+open func updateHeading(heading: Heading, state: NavState) -> NavState  {
+    return try!  FfiConverterTypeNavState_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_navigator_update_heading(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeHeading_lower(heading),
+        FfiConverterTypeNavState_lower(state),$0
+    )
+})
+}
+    
 
     
 }
@@ -2866,27 +3354,47 @@ public func FfiConverterTypeNavigator_lower(_ value: Navigator) -> UInt64 {
 
 
 /**
- * Advance if any of the conditions are met (OR).
+ * Advances once the user's course aligns with the initial bearing of the next step.
  *
- * This is ideal for short circuit type advance conditions.
+ * This confirms that the user has actually taken the maneuver,
+ * which avoids premature advances at complex junctions where the user is close to
+ * the maneuver point but has not turned yet.
+ * On its own, this advances as soon as the user is heading the right way,
+ * so it is intended to be composed with a distance-based condition
+ * using [`AndAdvanceConditions`].
  *
- * E.g. you may have:
- * 1. A short circuit detecting if the user has exceeded a large distance from the current step.
- * 2. A default advance behavior.
+ * If there is no next step (i.e. on the last step of the route),
+ * or the next step has no bearing (e.g. a zero-length arrival step),
+ * there is nothing to confirm and the condition advances.
+ * This lets the trip complete when composed with [`AndAdvanceConditions`].
+ *
+ * This always checks the course of the raw user location.
+ * The snapped location is snapped to the current step,
+ * so its course cannot confirm a turn onto the next step.
  */
-public protocol OrAdvanceConditionsProtocol: AnyObject, Sendable {
+public protocol NextStepBearingConditionProtocol: AnyObject, Sendable {
     
 }
 /**
- * Advance if any of the conditions are met (OR).
+ * Advances once the user's course aligns with the initial bearing of the next step.
  *
- * This is ideal for short circuit type advance conditions.
+ * This confirms that the user has actually taken the maneuver,
+ * which avoids premature advances at complex junctions where the user is close to
+ * the maneuver point but has not turned yet.
+ * On its own, this advances as soon as the user is heading the right way,
+ * so it is intended to be composed with a distance-based condition
+ * using [`AndAdvanceConditions`].
  *
- * E.g. you may have:
- * 1. A short circuit detecting if the user has exceeded a large distance from the current step.
- * 2. A default advance behavior.
+ * If there is no next step (i.e. on the last step of the route),
+ * or the next step has no bearing (e.g. a zero-length arrival step),
+ * there is nothing to confirm and the condition advances.
+ * This lets the trip complete when composed with [`AndAdvanceConditions`].
+ *
+ * This always checks the course of the raw user location.
+ * The snapped location is snapped to the current step,
+ * so its course cannot confirm a turn onto the next step.
  */
-open class OrAdvanceConditions: OrAdvanceConditionsProtocol, @unchecked Sendable {
+open class NextStepBearingCondition: NextStepBearingConditionProtocol, @unchecked Sendable {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -2923,7 +3431,7 @@ open class OrAdvanceConditions: OrAdvanceConditionsProtocol, @unchecked Sendable
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { uniffi_ferrostar_fn_clone_oradvanceconditions(self.handle, $0) }
+        return try! rustCall { uniffi_ferrostar_fn_clone_nextstepbearingcondition(self.handle, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -2933,7 +3441,7 @@ open class OrAdvanceConditions: OrAdvanceConditionsProtocol, @unchecked Sendable
             return
         }
 
-        try! rustCall { uniffi_ferrostar_fn_free_oradvanceconditions(handle, $0) }
+        try! rustCall { uniffi_ferrostar_fn_free_nextstepbearingcondition(handle, $0) }
     }
 
     
@@ -2947,24 +3455,24 @@ open class OrAdvanceConditions: OrAdvanceConditionsProtocol, @unchecked Sendable
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeOrAdvanceConditions: FfiConverter {
+public struct FfiConverterTypeNextStepBearingCondition: FfiConverter {
     typealias FfiType = UInt64
-    typealias SwiftType = OrAdvanceConditions
+    typealias SwiftType = NextStepBearingCondition
 
-    public static func lift(_ handle: UInt64) throws -> OrAdvanceConditions {
-        return OrAdvanceConditions(unsafeFromHandle: handle)
+    public static func lift(_ handle: UInt64) throws -> NextStepBearingCondition {
+        return NextStepBearingCondition(unsafeFromHandle: handle)
     }
 
-    public static func lower(_ value: OrAdvanceConditions) -> UInt64 {
+    public static func lower(_ value: NextStepBearingCondition) -> UInt64 {
         return value.uniffiCloneHandle()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrAdvanceConditions {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NextStepBearingCondition {
         let handle: UInt64 = try readInt(&buf)
         return try lift(handle)
     }
 
-    public static func write(_ value: OrAdvanceConditions, into buf: inout [UInt8]) {
+    public static func write(_ value: NextStepBearingCondition, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
@@ -2973,15 +3481,15 @@ public struct FfiConverterTypeOrAdvanceConditions: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeOrAdvanceConditions_lift(_ handle: UInt64) throws -> OrAdvanceConditions {
-    return try FfiConverterTypeOrAdvanceConditions.lift(handle)
+public func FfiConverterTypeNextStepBearingCondition_lift(_ handle: UInt64) throws -> NextStepBearingCondition {
+    return try FfiConverterTypeNextStepBearingCondition.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeOrAdvanceConditions_lower(_ value: OrAdvanceConditions) -> UInt64 {
-    return FfiConverterTypeOrAdvanceConditions.lower(value)
+public func FfiConverterTypeNextStepBearingCondition_lower(_ value: NextStepBearingCondition) -> UInt64 {
+    return FfiConverterTypeNextStepBearingCondition.lower(value)
 }
 
 
@@ -2990,57 +3498,27 @@ public func FfiConverterTypeOrAdvanceConditions_lower(_ value: OrAdvanceConditio
 
 
 /**
- * The route adapter bridges between the common core and a routing backend where interaction takes place
- * over a generic request/response flow (typically over a network;
- * local/offline routers **do not use this object** as the interaction patterns are different).
- *
- * This is essentially the composite of the [`RouteRequestGenerator`] and [`RouteResponseParser`]
- * traits, but it provides one further level of abstraction which is helpful to consumers.
- * As there is no way to signal compatibility between request generators and response parsers,
- * the [`RouteAdapter`] provides convenience constructors which take the guesswork out of it,
- * while still leaving consumers free to implement one or both halves.
+ * Advance if any of the conditions are met (OR).
  *
- * In the future, we may provide additional methods or conveniences, and this
- * indirection leaves the design open to such changes without necessarily breaking source
- * compatibility.
- * One such possible extension would be the ability to fetch more detailed attributes in real time.
- * This is supported by the Valhalla stack, among others.
+ * This is ideal for short circuit type advance conditions.
  *
- * Ideas  welcome re: how to signal compatibility between request generators and response parsers.
- * I don't think we can do this in the type system, since one of the reasons for the split design
- * is modularity, including the possibility of user-provided implementations, and these will not
- * always be of a "known" type to the Rust side.
+ * E.g. you may have:
+ * 1. A short circuit detecting if the user has exceeded a large distance from the current step.
+ * 2. A default advance behavior.
  */
-public protocol RouteAdapterProtocol: AnyObject, Sendable {
-    
-    func generateRequest(userLocation: UserLocation, waypoints: [Waypoint]) throws  -> RouteRequest
-    
-    func parseResponse(response: Data) throws  -> [Route]
+public protocol OrAdvanceConditionsProtocol: AnyObject, Sendable {
     
 }
 /**
- * The route adapter bridges between the common core and a routing backend where interaction takes place
- * over a generic request/response flow (typically over a network;
- * local/offline routers **do not use this object** as the interaction patterns are different).
- *
- * This is essentially the composite of the [`RouteRequestGenerator`] and [`RouteResponseParser`]
- * traits, but it provides one further level of abstraction which is helpful to consumers.
- * As there is no way to signal compatibility between request generators and response parsers,
- * the [`RouteAdapter`] provides convenience constructors which take the guesswork out of it,
- * while still leaving consumers free to implement one or both halves.
+ * Advance if any of the conditions are met (OR).
  *
- * In the future, we may provide additional methods or conveniences, and this
- * indirection leaves the design open to such changes without necessarily breaking source
- * compatibility.
- * One such possible extension would be the ability to fetch more detailed attributes in real time.
- * This is supported by the Valhalla stack, among others.
+ * This is ideal for short circuit type advance conditions.
  *
- * Ideas  welcome re: how to signal compatibility between request generators and response parsers.
- * I don't think we can do this in the type system, since one of the reasons for the split design
- * is modularity, including the possibility of user-provided implementations, and these will not
- * always be of a "known" type to the Rust side.
+ * E.g. you may have:
+ * 1. A short circuit detecting if the user has exceeded a large distance from the current step.
+ * 2. A default advance behavior.
  */
-open class RouteAdapter: RouteAdapterProtocol, @unchecked Sendable {
+open class OrAdvanceConditions: OrAdvanceConditionsProtocol, @unchecked Sendable {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -3077,25 +3555,9 @@ open class RouteAdapter: RouteAdapterProtocol, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { uniffi_ferrostar_fn_clone_routeadapter(self.handle, $0) }
+        return try! rustCall { uniffi_ferrostar_fn_clone_oradvanceconditions(self.handle, $0) }
     }
-    /**
-     * Creates a route adapter from any request generator and response parser.
-     *
-     * This constructor offers unlimited flexibility,
-     * but if you're using a major routing vendor API,
-     * [`RouteAdapter::from_well_known_route_provider`] may be a more convenient interface.
-     */
-public convenience init(requestGenerator: RouteRequestGenerator, responseParser: RouteResponseParser) {
-    let handle =
-        try! rustCall() {
-    uniffi_ferrostar_fn_constructor_routeadapter_new(
-        FfiConverterTypeRouteRequestGenerator_lower(requestGenerator),
-        FfiConverterTypeRouteResponseParser_lower(responseParser),$0
-    )
-}
-    self.init(unsafeFromHandle: handle)
-}
+    // No primary constructor declared for this class.
 
     deinit {
         if handle == 0 {
@@ -3103,41 +3565,11 @@ public convenience init(requestGenerator: RouteRequestGenerator, responseParser:
             return
         }
 
-        try! rustCall { uniffi_ferrostar_fn_free_routeadapter(handle, $0) }
+        try! rustCall { uniffi_ferrostar_fn_free_oradvanceconditions(handle, $0) }
     }
 
     
-    /**
-     * Creates a route adapter from a well-known provider configuration.
-     */
-public static func fromWellKnownRouteProvider(wellKnownRouteProvider: WellKnownRouteProvider)throws  -> RouteAdapter  {
-    return try  FfiConverterTypeRouteAdapter_lift(try rustCallWithError(FfiConverterTypeInstantiationError_lift) {
-    uniffi_ferrostar_fn_constructor_routeadapter_from_well_known_route_provider(
-        FfiConverterTypeWellKnownRouteProvider_lower(wellKnownRouteProvider),$0
-    )
-})
-}
-    
-
-    
-open func generateRequest(userLocation: UserLocation, waypoints: [Waypoint])throws  -> RouteRequest  {
-    return try  FfiConverterTypeRouteRequest_lift(try rustCallWithError(FfiConverterTypeRoutingRequestGenerationError_lift) {
-    uniffi_ferrostar_fn_method_routeadapter_generate_request(
-            self.uniffiCloneHandle(),
-        FfiConverterTypeUserLocation_lower(userLocation),
-        FfiConverterSequenceTypeWaypoint.lower(waypoints),$0
-    )
-})
-}
-    
-open func parseResponse(response: Data)throws  -> [Route]  {
-    return try  FfiConverterSequenceTypeRoute.lift(try rustCallWithError(FfiConverterTypeParsingError_lift) {
-    uniffi_ferrostar_fn_method_routeadapter_parse_response(
-            self.uniffiCloneHandle(),
-        FfiConverterData.lower(response),$0
-    )
-})
-}
+
     
 
     
@@ -3147,24 +3579,24 @@ open func parseResponse(response: Data)throws  -> [Route]  {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRouteAdapter: FfiConverter {
+public struct FfiConverterTypeOrAdvanceConditions: FfiConverter {
     typealias FfiType = UInt64
-    typealias SwiftType = RouteAdapter
+    typealias SwiftType = OrAdvanceConditions
 
-    public static func lift(_ handle: UInt64) throws -> RouteAdapter {
-        return RouteAdapter(unsafeFromHandle: handle)
+    public static func lift(_ handle: UInt64) throws -> OrAdvanceConditions {
+        return OrAdvanceConditions(unsafeFromHandle: handle)
     }
 
-    public static func lower(_ value: RouteAdapter) -> UInt64 {
+    public static func lower(_ value: OrAdvanceConditions) -> UInt64 {
         return value.uniffiCloneHandle()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteAdapter {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrAdvanceConditions {
         let handle: UInt64 = try readInt(&buf)
         return try lift(handle)
     }
 
-    public static func write(_ value: RouteAdapter, into buf: inout [UInt8]) {
+    public static func write(_ value: OrAdvanceConditions, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
@@ -3173,15 +3605,15 @@ public struct FfiConverterTypeRouteAdapter: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteAdapter_lift(_ handle: UInt64) throws -> RouteAdapter {
-    return try FfiConverterTypeRouteAdapter.lift(handle)
+public func FfiConverterTypeOrAdvanceConditions_lift(_ handle: UInt64) throws -> OrAdvanceConditions {
+    return try FfiConverterTypeOrAdvanceConditions.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteAdapter_lower(_ value: RouteAdapter) -> UInt64 {
-    return FfiConverterTypeRouteAdapter.lower(value)
+public func FfiConverterTypeOrAdvanceConditions_lower(_ value: OrAdvanceConditions) -> UInt64 {
+    return FfiConverterTypeOrAdvanceConditions.lower(value)
 }
 
 
@@ -3190,36 +3622,43 @@ public func FfiConverterTypeRouteAdapter_lower(_ value: RouteAdapter) -> UInt64
 
 
 /**
- * A custom deviation detector (for extending the behavior of [`RouteDeviationTracking`]).
+ * Decides when to reroute, and builds the navigation state for the new route.
  *
- * This allows for arbitrarily complex implementations when the provided ones are not enough.
- * For example, detecting that the user is proceeding the wrong direction by keeping a ring buffer
- * of recent locations, or perform local map matching.
+ * Feed every state produced by your [`Navigator`] into [`RerouteController::check_for_reroute`].
+ * Once the user has been completely off the route (or traveling the wrong direction along it)
+ * for [`RerouteConfig::debounce_seconds`]
+ * (and at least [`RerouteConfig::minimum_interval_seconds`] have passed since the last attempt),
+ * the controller requests a route to the remaining waypoints
+ * and returns a state positioned on the new route
+ * (see [`Navigator::replace_route`] for what is carried over).
  */
-public protocol RouteDeviationDetector: AnyObject, Sendable {
+public protocol RerouteControllerProtocol: AnyObject, Sendable {
     
     /**
-     * Determines whether the user is following the route correctly or not.
-     *
-     * NOTE: This function has a single responsibility.
-     * Side-effects like whether to recalculate a route are left to higher levels,
-     * and implementations should only be concerned with determining the facts.
+     * Checks whether a reroute is due for the given state, and performs it if so.
      *
-     * IMPORTANT: If you are short circuiting [`StepAdvanceCondition`]'s to allow
-     * skipping steps, you must always fall back to checking the deviation from the
-     * full route line.
+     * A failed attempt still counts towards the minimum interval between reroutes.
      */
-    func checkRouteDeviation(route: Route, tripState: TripState)  -> RouteDeviation
+    func checkForReroute(state: NavState) throws  -> RerouteStatus
+    
+    /**
+     * Clears the debounce state (e.g. after the platform switched routes by other means).
+     */
+    func reset() 
     
 }
 /**
- * A custom deviation detector (for extending the behavior of [`RouteDeviationTracking`]).
+ * Decides when to reroute, and builds the navigation state for the new route.
  *
- * This allows for arbitrarily complex implementations when the provided ones are not enough.
- * For example, detecting that the user is proceeding the wrong direction by keeping a ring buffer
- * of recent locations, or perform local map matching.
+ * Feed every state produced by your [`Navigator`] into [`RerouteController::check_for_reroute`].
+ * Once the user has been completely off the route (or traveling the wrong direction along it)
+ * for [`RerouteConfig::debounce_seconds`]
+ * (and at least [`RerouteConfig::minimum_interval_seconds`] have passed since the last attempt),
+ * the controller requests a route to the remaining waypoints
+ * and returns a state positioned on the new route
+ * (see [`Navigator::replace_route`] for what is carried over).
  */
-open class RouteDeviationDetectorImpl: RouteDeviationDetector, @unchecked Sendable {
+open class RerouteController: RerouteControllerProtocol, @unchecked Sendable {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -3256,9 +3695,26 @@ open class RouteDeviationDetectorImpl: RouteDeviationDetector, @unchecked Sendab
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { uniffi_ferrostar_fn_clone_routedeviationdetector(self.handle, $0) }
+        return try! rustCall { uniffi_ferrostar_fn_clone_reroutecontroller(self.handle, $0) }
     }
-    // No primary constructor declared for this class.
+    /**
+     * Creates a reroute controller.
+     *
+     * The `navigation_config` is used to compute the navigation state on new routes,
+     * and should be the same one that your [`Navigator`] uses.
+     */
+public convenience init(config: RerouteConfig, navigationConfig: NavigationControllerConfig, routeAdapter: RouteAdapter, routeFetcher: RouteFetcher) {
+    let handle =
+        try! rustCall() {
+    uniffi_ferrostar_fn_constructor_reroutecontroller_new(
+        FfiConverterTypeRerouteConfig_lower(config),
+        FfiConverterTypeNavigationControllerConfig_lower(navigationConfig),
+        FfiConverterTypeRouteAdapter_lower(routeAdapter),
+        FfiConverterTypeRouteFetcher_lower(routeFetcher),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
 
     deinit {
         if handle == 0 {
@@ -3266,138 +3722,62 @@ open class RouteDeviationDetectorImpl: RouteDeviationDetector, @unchecked Sendab
             return
         }
 
-        try! rustCall { uniffi_ferrostar_fn_free_routedeviationdetector(handle, $0) }
+        try! rustCall { uniffi_ferrostar_fn_free_reroutecontroller(handle, $0) }
     }
 
     
 
     
     /**
-     * Determines whether the user is following the route correctly or not.
-     *
-     * NOTE: This function has a single responsibility.
-     * Side-effects like whether to recalculate a route are left to higher levels,
-     * and implementations should only be concerned with determining the facts.
+     * Checks whether a reroute is due for the given state, and performs it if so.
      *
-     * IMPORTANT: If you are short circuiting [`StepAdvanceCondition`]'s to allow
-     * skipping steps, you must always fall back to checking the deviation from the
-     * full route line.
+     * A failed attempt still counts towards the minimum interval between reroutes.
      */
-open func checkRouteDeviation(route: Route, tripState: TripState) -> RouteDeviation  {
-    return try!  FfiConverterTypeRouteDeviation_lift(try! rustCall() {
-    uniffi_ferrostar_fn_method_routedeviationdetector_check_route_deviation(
+open func checkForReroute(state: NavState)throws  -> RerouteStatus  {
+    return try  FfiConverterTypeRerouteStatus_lift(try rustCallWithError(FfiConverterTypeRerouteError_lift) {
+    uniffi_ferrostar_fn_method_reroutecontroller_check_for_reroute(
             self.uniffiCloneHandle(),
-        FfiConverterTypeRoute_lower(route),
-        FfiConverterTypeTripState_lower(tripState),$0
+        FfiConverterTypeNavState_lower(state),$0
     )
 })
 }
     
-
-    
-}
-
-
-
-// Put the implementation in a struct so we don't pollute the top-level namespace
-fileprivate struct UniffiCallbackInterfaceRouteDeviationDetector {
-
-    // Create the VTable using a series of closures.
-    // Swift automatically converts these into C callback functions.
-    //
-    // Store the vtable directly.
-    static let vtable: UniffiVTableCallbackInterfaceRouteDeviationDetector = UniffiVTableCallbackInterfaceRouteDeviationDetector(
-        uniffiFree: { (uniffiHandle: UInt64) -> () in
-            do {
-                try FfiConverterTypeRouteDeviationDetector.handleMap.remove(handle: uniffiHandle)
-            } catch {
-                print("Uniffi callback interface RouteDeviationDetector: handle missing in uniffiFree")
-            }
-        },
-        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
-            do {
-                return try FfiConverterTypeRouteDeviationDetector.handleMap.clone(handle: uniffiHandle)
-            } catch {
-                fatalError("Uniffi callback interface RouteDeviationDetector: handle missing in uniffiClone")
-            }
-        },
-        checkRouteDeviation: { (
-            uniffiHandle: UInt64,
-            route: RustBuffer,
-            tripState: RustBuffer,
-            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
-            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
-        ) in
-            let makeCall = {
-                () throws -> RouteDeviation in
-                guard let uniffiObj = try? FfiConverterTypeRouteDeviationDetector.handleMap.get(handle: uniffiHandle) else {
-                    throw UniffiInternalError.unexpectedStaleHandle
-                }
-                return uniffiObj.checkRouteDeviation(
-                     route: try FfiConverterTypeRoute_lift(route),
-                     tripState: try FfiConverterTypeTripState_lift(tripState)
-                )
-            }
-
-            
-            let writeReturn = { uniffiOutReturn.pointee = FfiConverterTypeRouteDeviation_lower($0) }
-            uniffiTraitInterfaceCall(
-                callStatus: uniffiCallStatus,
-                makeCall: makeCall,
-                writeReturn: writeReturn
-            )
-        }
+    /**
+     * Clears the debounce state (e.g. after the platform switched routes by other means).
+     */
+open func reset()  {try! rustCall() {
+    uniffi_ferrostar_fn_method_reroutecontroller_reset(
+            self.uniffiCloneHandle(),$0
     )
-
-    // Rust stores this pointer for future callback invocations, so it must live
-    // for the process lifetime (not just for the init function call).
-    static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceRouteDeviationDetector> = {
-        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceRouteDeviationDetector>.allocate(capacity: 1)
-        ptr.initialize(to: vtable)
-        return UnsafePointer(ptr)
-    }()
 }
+}
+    
 
-private func uniffiCallbackInitRouteDeviationDetector() {
-    uniffi_ferrostar_fn_init_callback_vtable_routedeviationdetector(UniffiCallbackInterfaceRouteDeviationDetector.vtablePtr)
+    
 }
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRouteDeviationDetector: FfiConverter {
-    fileprivate static let handleMap = UniffiHandleMap<RouteDeviationDetector>()
-
+public struct FfiConverterTypeRerouteController: FfiConverter {
     typealias FfiType = UInt64
-    typealias SwiftType = RouteDeviationDetector
+    typealias SwiftType = RerouteController
 
-    public static func lift(_ handle: UInt64) throws -> RouteDeviationDetector {
-        if ((handle & 1) == 0) {
-            // Rust-generated handle, construct a new class that uses the handle to implement the
-            // interface
-            return RouteDeviationDetectorImpl(unsafeFromHandle: handle)
-        } else {
-            // Swift-generated handle, get the object from the handle map
-            return try handleMap.remove(handle: handle)
-        }
+    public static func lift(_ handle: UInt64) throws -> RerouteController {
+        return RerouteController(unsafeFromHandle: handle)
     }
 
-    public static func lower(_ value: RouteDeviationDetector) -> UInt64 {
-         if let rustImpl = value as? RouteDeviationDetectorImpl {
-             // Rust-implemented object.  Clone the handle and return it
-            return rustImpl.uniffiCloneHandle()
-         } else {
-            // Swift object, generate a new vtable handle and return that.
-            return handleMap.insert(obj: value)
-         }
+    public static func lower(_ value: RerouteController) -> UInt64 {
+        return value.uniffiCloneHandle()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteDeviationDetector {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RerouteController {
         let handle: UInt64 = try readInt(&buf)
         return try lift(handle)
     }
 
-    public static func write(_ value: RouteDeviationDetector, into buf: inout [UInt8]) {
+    public static func write(_ value: RerouteController, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
@@ -3406,15 +3786,15 @@ public struct FfiConverterTypeRouteDeviationDetector: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteDeviationDetector_lift(_ handle: UInt64) throws -> RouteDeviationDetector {
-    return try FfiConverterTypeRouteDeviationDetector.lift(handle)
+public func FfiConverterTypeRerouteController_lift(_ handle: UInt64) throws -> RerouteController {
+    return try FfiConverterTypeRerouteController.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteDeviationDetector_lower(_ value: RouteDeviationDetector) -> UInt64 {
-    return FfiConverterTypeRouteDeviationDetector.lower(value)
+public func FfiConverterTypeRerouteController_lower(_ value: RerouteController) -> UInt64 {
+    return FfiConverterTypeRerouteController.lower(value)
 }
 
 
@@ -3423,39 +3803,57 @@ public func FfiConverterTypeRouteDeviationDetector_lower(_ value: RouteDeviation
 
 
 /**
- * A trait describing any object capable of generating [`RouteRequest`]s.
+ * The route adapter bridges between the common core and a routing backend where interaction takes place
+ * over a generic request/response flow (typically over a network;
+ * local/offline routers **do not use this object** as the interaction patterns are different).
  *
- * The interface is intentionally generic. Every routing backend has its own set of
- * parameters, including a "profile," max travel speed, units of speed and distance, and more.
- * It is assumed that these properties will be set at construction time or otherwise configured
- * before use, so that we can keep the public interface as generic as possible.
+ * This is essentially the composite of the [`RouteRequestGenerator`] and [`RouteResponseParser`]
+ * traits, but it provides one further level of abstraction which is helpful to consumers.
+ * As there is no way to signal compatibility between request generators and response parsers,
+ * the [`RouteAdapter`] provides convenience constructors which take the guesswork out of it,
+ * while still leaving consumers free to implement one or both halves.
  *
- * Implementations may be either in Rust (most popular engines should eventually have Rust
- * glue code) or foreign code.
+ * In the future, we may provide additional methods or conveniences, and this
+ * indirection leaves the design open to such changes without necessarily breaking source
+ * compatibility.
+ * One such possible extension would be the ability to fetch more detailed attributes in real time.
+ * This is supported by the Valhalla stack, among others.
+ *
+ * Ideas  welcome re: how to signal compatibility between request generators and response parsers.
+ * I don't think we can do this in the type system, since one of the reasons for the split design
+ * is modularity, including the possibility of user-provided implementations, and these will not
+ * always be of a "known" type to the Rust side.
  */
-public protocol RouteRequestGenerator: AnyObject, Sendable {
+public protocol RouteAdapterProtocol: AnyObject, Sendable {
     
-    /**
-     * Generates a routing backend request given the set of locations.
-     *
-     * While most implementations will treat the locations as an ordered sequence, this is not
-     * guaranteed (ex: an optimized router).
-     */
     func generateRequest(userLocation: UserLocation, waypoints: [Waypoint]) throws  -> RouteRequest
     
+    func parseResponse(response: Data) throws  -> [Route]
+    
 }
 /**
- * A trait describing any object capable of generating [`RouteRequest`]s.
+ * The route adapter bridges between the common core and a routing backend where interaction takes place
+ * over a generic request/response flow (typically over a network;
+ * local/offline routers **do not use this object** as the interaction patterns are different).
  *
- * The interface is intentionally generic. Every routing backend has its own set of
- * parameters, including a "profile," max travel speed, units of speed and distance, and more.
- * It is assumed that these properties will be set at construction time or otherwise configured
- * before use, so that we can keep the public interface as generic as possible.
+ * This is essentially the composite of the [`RouteRequestGenerator`] and [`RouteResponseParser`]
+ * traits, but it provides one further level of abstraction which is helpful to consumers.
+ * As there is no way to signal compatibility between request generators and response parsers,
+ * the [`RouteAdapter`] provides convenience constructors which take the guesswork out of it,
+ * while still leaving consumers free to implement one or both halves.
  *
- * Implementations may be either in Rust (most popular engines should eventually have Rust
- * glue code) or foreign code.
+ * In the future, we may provide additional methods or conveniences, and this
+ * indirection leaves the design open to such changes without necessarily breaking source
+ * compatibility.
+ * One such possible extension would be the ability to fetch more detailed attributes in real time.
+ * This is supported by the Valhalla stack, among others.
+ *
+ * Ideas  welcome re: how to signal compatibility between request generators and response parsers.
+ * I don't think we can do this in the type system, since one of the reasons for the split design
+ * is modularity, including the possibility of user-provided implementations, and these will not
+ * always be of a "known" type to the Rust side.
  */
-open class RouteRequestGeneratorImpl: RouteRequestGenerator, @unchecked Sendable {
+open class RouteAdapter: RouteAdapterProtocol, @unchecked Sendable {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -3492,9 +3890,25 @@ open class RouteRequestGeneratorImpl: RouteRequestGenerator, @unchecked Sendable
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { uniffi_ferrostar_fn_clone_routerequestgenerator(self.handle, $0) }
+        return try! rustCall { uniffi_ferrostar_fn_clone_routeadapter(self.handle, $0) }
     }
-    // No primary constructor declared for this class.
+    /**
+     * Creates a route adapter from any request generator and response parser.
+     *
+     * This constructor offers unlimited flexibility,
+     * but if you're using a major routing vendor API,
+     * [`RouteAdapter::from_well_known_route_provider`] may be a more convenient interface.
+     */
+public convenience init(requestGenerator: RouteRequestGenerator, responseParser: RouteResponseParser) {
+    let handle =
+        try! rustCall() {
+    uniffi_ferrostar_fn_constructor_routeadapter_new(
+        FfiConverterTypeRouteRequestGenerator_lower(requestGenerator),
+        FfiConverterTypeRouteResponseParser_lower(responseParser),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
 
     deinit {
         if handle == 0 {
@@ -3502,21 +3916,26 @@ open class RouteRequestGeneratorImpl: RouteRequestGenerator, @unchecked Sendable
             return
         }
 
-        try! rustCall { uniffi_ferrostar_fn_free_routerequestgenerator(handle, $0) }
+        try! rustCall { uniffi_ferrostar_fn_free_routeadapter(handle, $0) }
     }
 
     
-
-    
     /**
-     * Generates a routing backend request given the set of locations.
-     *
-     * While most implementations will treat the locations as an ordered sequence, this is not
-     * guaranteed (ex: an optimized router).
+     * Creates a route adapter from a well-known provider configuration.
      */
+public static func fromWellKnownRouteProvider(wellKnownRouteProvider: WellKnownRouteProvider)throws  -> RouteAdapter  {
+    return try  FfiConverterTypeRouteAdapter_lift(try rustCallWithError(FfiConverterTypeInstantiationError_lift) {
+    uniffi_ferrostar_fn_constructor_routeadapter_from_well_known_route_provider(
+        FfiConverterTypeWellKnownRouteProvider_lower(wellKnownRouteProvider),$0
+    )
+})
+}
+    
+
+    
 open func generateRequest(userLocation: UserLocation, waypoints: [Waypoint])throws  -> RouteRequest  {
     return try  FfiConverterTypeRouteRequest_lift(try rustCallWithError(FfiConverterTypeRoutingRequestGenerationError_lift) {
-    uniffi_ferrostar_fn_method_routerequestgenerator_generate_request(
+    uniffi_ferrostar_fn_method_routeadapter_generate_request(
             self.uniffiCloneHandle(),
         FfiConverterTypeUserLocation_lower(userLocation),
         FfiConverterSequenceTypeWaypoint.lower(waypoints),$0
@@ -3524,112 +3943,41 @@ open func generateRequest(userLocation: UserLocation, waypoints: [Waypoint])thro
 })
 }
     
-
-    
-}
-
-
-
-// Put the implementation in a struct so we don't pollute the top-level namespace
-fileprivate struct UniffiCallbackInterfaceRouteRequestGenerator {
-
-    // Create the VTable using a series of closures.
-    // Swift automatically converts these into C callback functions.
-    //
-    // Store the vtable directly.
-    static let vtable: UniffiVTableCallbackInterfaceRouteRequestGenerator = UniffiVTableCallbackInterfaceRouteRequestGenerator(
-        uniffiFree: { (uniffiHandle: UInt64) -> () in
-            do {
-                try FfiConverterTypeRouteRequestGenerator.handleMap.remove(handle: uniffiHandle)
-            } catch {
-                print("Uniffi callback interface RouteRequestGenerator: handle missing in uniffiFree")
-            }
-        },
-        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
-            do {
-                return try FfiConverterTypeRouteRequestGenerator.handleMap.clone(handle: uniffiHandle)
-            } catch {
-                fatalError("Uniffi callback interface RouteRequestGenerator: handle missing in uniffiClone")
-            }
-        },
-        generateRequest: { (
-            uniffiHandle: UInt64,
-            userLocation: RustBuffer,
-            waypoints: RustBuffer,
-            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
-            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
-        ) in
-            let makeCall = {
-                () throws -> RouteRequest in
-                guard let uniffiObj = try? FfiConverterTypeRouteRequestGenerator.handleMap.get(handle: uniffiHandle) else {
-                    throw UniffiInternalError.unexpectedStaleHandle
-                }
-                return try uniffiObj.generateRequest(
-                     userLocation: try FfiConverterTypeUserLocation_lift(userLocation),
-                     waypoints: try FfiConverterSequenceTypeWaypoint.lift(waypoints)
-                )
-            }
-
-            
-            let writeReturn = { uniffiOutReturn.pointee = FfiConverterTypeRouteRequest_lower($0) }
-            uniffiTraitInterfaceCallWithError(
-                callStatus: uniffiCallStatus,
-                makeCall: makeCall,
-                writeReturn: writeReturn,
-                lowerError: FfiConverterTypeRoutingRequestGenerationError_lower
-            )
-        }
+open func parseResponse(response: Data)throws  -> [Route]  {
+    return try  FfiConverterSequenceTypeRoute.lift(try rustCallWithError(FfiConverterTypeParsingError_lift) {
+    uniffi_ferrostar_fn_method_routeadapter_parse_response(
+            self.uniffiCloneHandle(),
+        FfiConverterData.lower(response),$0
     )
-
-    // Rust stores this pointer for future callback invocations, so it must live
-    // for the process lifetime (not just for the init function call).
-    static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceRouteRequestGenerator> = {
-        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceRouteRequestGenerator>.allocate(capacity: 1)
-        ptr.initialize(to: vtable)
-        return UnsafePointer(ptr)
-    }()
+})
 }
+    
 
-private func uniffiCallbackInitRouteRequestGenerator() {
-    uniffi_ferrostar_fn_init_callback_vtable_routerequestgenerator(UniffiCallbackInterfaceRouteRequestGenerator.vtablePtr)
+    
 }
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRouteRequestGenerator: FfiConverter {
-    fileprivate static let handleMap = UniffiHandleMap<RouteRequestGenerator>()
-
+public struct FfiConverterTypeRouteAdapter: FfiConverter {
     typealias FfiType = UInt64
-    typealias SwiftType = RouteRequestGenerator
+    typealias SwiftType = RouteAdapter
 
-    public static func lift(_ handle: UInt64) throws -> RouteRequestGenerator {
-        if ((handle & 1) == 0) {
-            // Rust-generated handle, construct a new class that uses the handle to implement the
-            // interface
-            return RouteRequestGeneratorImpl(unsafeFromHandle: handle)
-        } else {
-            // Swift-generated handle, get the object from the handle map
-            return try handleMap.remove(handle: handle)
-        }
+    public static func lift(_ handle: UInt64) throws -> RouteAdapter {
+        return RouteAdapter(unsafeFromHandle: handle)
     }
 
-    public static func lower(_ value: RouteRequestGenerator) -> UInt64 {
-         if let rustImpl = value as? RouteRequestGeneratorImpl {
-             // Rust-implemented object.  Clone the handle and return it
-            return rustImpl.uniffiCloneHandle()
-         } else {
-            // Swift object, generate a new vtable handle and return that.
-            return handleMap.insert(obj: value)
-         }
+    public static func lower(_ value: RouteAdapter) -> UInt64 {
+        return value.uniffiCloneHandle()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteRequestGenerator {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteAdapter {
         let handle: UInt64 = try readInt(&buf)
         return try lift(handle)
     }
 
-    public static func write(_ value: RouteRequestGenerator, into buf: inout [UInt8]) {
+    public static func write(_ value: RouteAdapter, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
@@ -3638,15 +3986,15 @@ public struct FfiConverterTypeRouteRequestGenerator: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteRequestGenerator_lift(_ handle: UInt64) throws -> RouteRequestGenerator {
-    return try FfiConverterTypeRouteRequestGenerator.lift(handle)
+public func FfiConverterTypeRouteAdapter_lift(_ handle: UInt64) throws -> RouteAdapter {
+    return try FfiConverterTypeRouteAdapter.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteRequestGenerator_lower(_ value: RouteRequestGenerator) -> UInt64 {
-    return FfiConverterTypeRouteRequestGenerator.lower(value)
+public func FfiConverterTypeRouteAdapter_lower(_ value: RouteAdapter) -> UInt64 {
+    return FfiConverterTypeRouteAdapter.lower(value)
 }
 
 
@@ -3655,25 +4003,36 @@ public func FfiConverterTypeRouteRequestGenerator_lower(_ value: RouteRequestGen
 
 
 /**
- * A generic interface describing any object capable of parsing a response from a routing
- * backend into one or more [`Route`]s.
+ * A custom deviation detector (for extending the behavior of [`RouteDeviationTracking`]).
+ *
+ * This allows for arbitrarily complex implementations when the provided ones are not enough.
+ * For example, detecting that the user is proceeding the wrong direction by keeping a ring buffer
+ * of recent locations, or perform local map matching.
  */
-public protocol RouteResponseParser: AnyObject, Sendable {
+public protocol RouteDeviationDetector: AnyObject, Sendable {
     
     /**
-     * Parses a raw response from the routing backend into a route.
+     * Determines whether the user is following the route correctly or not.
      *
-     * We use a sequence of octets as a common interchange format.
-     * as this works for all currently conceivable formats (JSON, PBF, etc.).
+     * NOTE: This function has a single responsibility.
+     * Side-effects like whether to recalculate a route are left to higher levels,
+     * and implementations should only be concerned with determining the facts.
+     *
+     * IMPORTANT: If you are short circuiting [`StepAdvanceCondition`]'s to allow
+     * skipping steps, you must always fall back to checking the deviation from the
+     * full route line.
      */
-    func parseResponse(response: Data) throws  -> [Route]
+    func checkRouteDeviation(route: Route, tripState: TripState)  -> RouteDeviation
     
 }
 /**
- * A generic interface describing any object capable of parsing a response from a routing
- * backend into one or more [`Route`]s.
+ * A custom deviation detector (for extending the behavior of [`RouteDeviationTracking`]).
+ *
+ * This allows for arbitrarily complex implementations when the provided ones are not enough.
+ * For example, detecting that the user is proceeding the wrong direction by keeping a ring buffer
+ * of recent locations, or perform local map matching.
  */
-open class RouteResponseParserImpl: RouteResponseParser, @unchecked Sendable {
+open class RouteDeviationDetectorImpl: RouteDeviationDetector, @unchecked Sendable {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -3710,7 +4069,7 @@ open class RouteResponseParserImpl: RouteResponseParser, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { uniffi_ferrostar_fn_clone_routeresponseparser(self.handle, $0) }
+        return try! rustCall { uniffi_ferrostar_fn_clone_routedeviationdetector(self.handle, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -3720,23 +4079,29 @@ open class RouteResponseParserImpl: RouteResponseParser, @unchecked Sendable {
             return
         }
 
-        try! rustCall { uniffi_ferrostar_fn_free_routeresponseparser(handle, $0) }
+        try! rustCall { uniffi_ferrostar_fn_free_routedeviationdetector(handle, $0) }
     }
 
     
 
     
     /**
-     * Parses a raw response from the routing backend into a route.
+     * Determines whether the user is following the route correctly or not.
      *
-     * We use a sequence of octets as a common interchange format.
-     * as this works for all currently conceivable formats (JSON, PBF, etc.).
+     * NOTE: This function has a single responsibility.
+     * Side-effects like whether to recalculate a route are left to higher levels,
+     * and implementations should only be concerned with determining the facts.
+     *
+     * IMPORTANT: If you are short circuiting [`StepAdvanceCondition`]'s to allow
+     * skipping steps, you must always fall back to checking the deviation from the
+     * full route line.
      */
-open func parseResponse(response: Data)throws  -> [Route]  {
-    return try  FfiConverterSequenceTypeRoute.lift(try rustCallWithError(FfiConverterTypeParsingError_lift) {
-    uniffi_ferrostar_fn_method_routeresponseparser_parse_response(
+open func checkRouteDeviation(route: Route, tripState: TripState) -> RouteDeviation  {
+    return try!  FfiConverterTypeRouteDeviation_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_routedeviationdetector_check_route_deviation(
             self.uniffiCloneHandle(),
-        FfiConverterData.lower(response),$0
+        FfiConverterTypeRoute_lower(route),
+        FfiConverterTypeTripState_lower(tripState),$0
     )
 })
 }
@@ -3748,89 +4113,90 @@ open func parseResponse(response: Data)throws  -> [Route]  {
 
 
 // Put the implementation in a struct so we don't pollute the top-level namespace
-fileprivate struct UniffiCallbackInterfaceRouteResponseParser {
+fileprivate struct UniffiCallbackInterfaceRouteDeviationDetector {
 
     // Create the VTable using a series of closures.
     // Swift automatically converts these into C callback functions.
     //
     // Store the vtable directly.
-    static let vtable: UniffiVTableCallbackInterfaceRouteResponseParser = UniffiVTableCallbackInterfaceRouteResponseParser(
+    static let vtable: UniffiVTableCallbackInterfaceRouteDeviationDetector = UniffiVTableCallbackInterfaceRouteDeviationDetector(
         uniffiFree: { (uniffiHandle: UInt64) -> () in
             do {
-                try FfiConverterTypeRouteResponseParser.handleMap.remove(handle: uniffiHandle)
+                try FfiConverterTypeRouteDeviationDetector.handleMap.remove(handle: uniffiHandle)
             } catch {
-                print("Uniffi callback interface RouteResponseParser: handle missing in uniffiFree")
+                print("Uniffi callback interface RouteDeviationDetector: handle missing in uniffiFree")
             }
         },
         uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
             do {
-                return try FfiConverterTypeRouteResponseParser.handleMap.clone(handle: uniffiHandle)
+                return try FfiConverterTypeRouteDeviationDetector.handleMap.clone(handle: uniffiHandle)
             } catch {
-                fatalError("Uniffi callback interface RouteResponseParser: handle missing in uniffiClone")
+                fatalError("Uniffi callback interface RouteDeviationDetector: handle missing in uniffiClone")
             }
         },
-        parseResponse: { (
+        checkRouteDeviation: { (
             uniffiHandle: UInt64,
-            response: RustBuffer,
+            route: RustBuffer,
+            tripState: RustBuffer,
             uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
             uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
         ) in
             let makeCall = {
-                () throws -> [Route] in
-                guard let uniffiObj = try? FfiConverterTypeRouteResponseParser.handleMap.get(handle: uniffiHandle) else {
+                () throws -> RouteDeviation in
+                guard let uniffiObj = try? FfiConverterTypeRouteDeviationDetector.handleMap.get(handle: uniffiHandle) else {
                     throw UniffiInternalError.unexpectedStaleHandle
                 }
-                return try uniffiObj.parseResponse(
-                     response: try FfiConverterData.lift(response)
+                return uniffiObj.checkRouteDeviation(
+                     route: try FfiConverterTypeRoute_lift(route),
+                     tripState: try FfiConverterTypeTripState_lift(tripState)
                 )
             }
 
             
-            let writeReturn = { uniffiOutReturn.pointee = FfiConverterSequenceTypeRoute.lower($0) }
-            uniffiTraitInterfaceCallWithError(
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterTypeRouteDeviation_lower($0) }
+            uniffiTraitInterfaceCall(
                 callStatus: uniffiCallStatus,
                 makeCall: makeCall,
-                writeReturn: writeReturn,
-                lowerError: FfiConverterTypeParsingError_lower
+                writeReturn: writeReturn
             )
         }
     )
 
     // Rust stores this pointer for future callback invocations, so it must live
     // for the process lifetime (not just for the init function call).
-    static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceRouteResponseParser> = {
-        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceRouteResponseParser>.allocate(capacity: 1)
+    static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceRouteDeviationDetector> = {
+        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceRouteDeviationDetector>.allocate(capacity: 1)
         ptr.initialize(to: vtable)
         return UnsafePointer(ptr)
     }()
 }
 
-private func uniffiCallbackInitRouteResponseParser() {
-    uniffi_ferrostar_fn_init_callback_vtable_routeresponseparser(UniffiCallbackInterfaceRouteResponseParser.vtablePtr)
+private func uniffiCallbackInitRouteDeviationDetector() {
+    uniffi_ferrostar_fn_init_callback_vtable_routedeviationdetector(UniffiCallbackInterfaceRouteDeviationDetector.vtablePtr)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRouteResponseParser: FfiConverter {
-    fileprivate static let handleMap = UniffiHandleMap<RouteResponseParser>()
+public struct FfiConverterTypeRouteDeviationDetector: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<RouteDeviationDetector>()
 
     typealias FfiType = UInt64
-    typealias SwiftType = RouteResponseParser
+    typealias SwiftType = RouteDeviationDetector
 
-    public static func lift(_ handle: UInt64) throws -> RouteResponseParser {
+    public static func lift(_ handle: UInt64) throws -> RouteDeviationDetector {
         if ((handle & 1) == 0) {
             // Rust-generated handle, construct a new class that uses the handle to implement the
             // interface
-            return RouteResponseParserImpl(unsafeFromHandle: handle)
+            return RouteDeviationDetectorImpl(unsafeFromHandle: handle)
         } else {
             // Swift-generated handle, get the object from the handle map
             return try handleMap.remove(handle: handle)
         }
     }
 
-    public static func lower(_ value: RouteResponseParser) -> UInt64 {
-         if let rustImpl = value as? RouteResponseParserImpl {
+    public static func lower(_ value: RouteDeviationDetector) -> UInt64 {
+         if let rustImpl = value as? RouteDeviationDetectorImpl {
              // Rust-implemented object.  Clone the handle and return it
             return rustImpl.uniffiCloneHandle()
          } else {
@@ -3839,12 +4205,12 @@ public struct FfiConverterTypeRouteResponseParser: FfiConverter {
          }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteResponseParser {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteDeviationDetector {
         let handle: UInt64 = try readInt(&buf)
         return try lift(handle)
     }
 
-    public static func write(_ value: RouteResponseParser, into buf: inout [UInt8]) {
+    public static func write(_ value: RouteDeviationDetector, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
@@ -3853,15 +4219,15 @@ public struct FfiConverterTypeRouteResponseParser: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteResponseParser_lift(_ handle: UInt64) throws -> RouteResponseParser {
-    return try FfiConverterTypeRouteResponseParser.lift(handle)
+public func FfiConverterTypeRouteDeviationDetector_lift(_ handle: UInt64) throws -> RouteDeviationDetector {
+    return try FfiConverterTypeRouteDeviationDetector.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteResponseParser_lower(_ value: RouteResponseParser) -> UInt64 {
-    return FfiConverterTypeRouteResponseParser.lower(value)
+public func FfiConverterTypeRouteDeviationDetector_lower(_ value: RouteDeviationDetector) -> UInt64 {
+    return FfiConverterTypeRouteDeviationDetector.lower(value)
 }
 
 
@@ -3870,43 +4236,25 @@ public func FfiConverterTypeRouteResponseParser_lower(_ value: RouteResponsePars
 
 
 /**
- * When implementing custom step advance logic, this trait allows you to define
- * whether the condition should advance to the next condition, the next step or not.
+ * Performs the network request for a reroute.
  *
- * At the moment, these must be implemented in Rust.
+ * Implementations execute the [`RouteRequest`] (typically over HTTP)
+ * and return the raw response body,
+ * which is then parsed by the [`RouteAdapter`] of the [`RerouteController`].
  */
-public protocol StepAdvanceConditionProtocol: AnyObject, Sendable {
-    
-    /**
-     * This callback method is used by a step advance condition to receive step updates.
-     * The step advance condition can choose based on its outcome and internal state
-     * whether to advance to the next step or not.
-     */
-    func shouldAdvanceStep(tripState: TripState)  -> StepAdvanceResult
+public protocol RouteFetcher: AnyObject, Sendable {
     
-    /**
-     * Creates a clean instance of this condition with the same configuration but reset state.
-     * This is used by composite conditions (Or/And) to ensure proper state isolation
-     * when any condition triggers advancement.
-     *
-     * **Implementation Requirements:**
-     * - **Stateless conditions**: Return a copy/clone of self
-     * - **Stateful conditions**: Return a new instance with initial state but preserve configuration parameters
-     * - **Composite conditions**: Recursively create fresh instances of all nested conditions
-     *
-     * This method prevents the state leakage bugs that can cause rapid step advancement
-     * and jumping behavior in navigation.
-     */
-    func newInstance()  -> StepAdvanceCondition
+    func fetchRoute(request: RouteRequest) throws  -> Data
     
 }
 /**
- * When implementing custom step advance logic, this trait allows you to define
- * whether the condition should advance to the next condition, the next step or not.
+ * Performs the network request for a reroute.
  *
- * At the moment, these must be implemented in Rust.
+ * Implementations execute the [`RouteRequest`] (typically over HTTP)
+ * and return the raw response body,
+ * which is then parsed by the [`RouteAdapter`] of the [`RerouteController`].
  */
-open class StepAdvanceCondition: StepAdvanceConditionProtocol, @unchecked Sendable {
+open class RouteFetcherImpl: RouteFetcher, @unchecked Sendable {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -3943,7 +4291,7 @@ open class StepAdvanceCondition: StepAdvanceConditionProtocol, @unchecked Sendab
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { uniffi_ferrostar_fn_clone_stepadvancecondition(self.handle, $0) }
+        return try! rustCall { uniffi_ferrostar_fn_clone_routefetcher(self.handle, $0) }
     }
     // No primary constructor declared for this class.
 
@@ -3953,43 +4301,17 @@ open class StepAdvanceCondition: StepAdvanceConditionProtocol, @unchecked Sendab
             return
         }
 
-        try! rustCall { uniffi_ferrostar_fn_free_stepadvancecondition(handle, $0) }
+        try! rustCall { uniffi_ferrostar_fn_free_routefetcher(handle, $0) }
     }
 
     
 
     
-    /**
-     * This callback method is used by a step advance condition to receive step updates.
-     * The step advance condition can choose based on its outcome and internal state
-     * whether to advance to the next step or not.
-     */
-open func shouldAdvanceStep(tripState: TripState) -> StepAdvanceResult  {
-    return try!  FfiConverterTypeStepAdvanceResult_lift(try! rustCall() {
-    uniffi_ferrostar_fn_method_stepadvancecondition_should_advance_step(
+open func fetchRoute(request: RouteRequest)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRerouteError_lift) {
+    uniffi_ferrostar_fn_method_routefetcher_fetch_route(
             self.uniffiCloneHandle(),
-        FfiConverterTypeTripState_lower(tripState),$0
-    )
-})
-}
-    
-    /**
-     * Creates a clean instance of this condition with the same configuration but reset state.
-     * This is used by composite conditions (Or/And) to ensure proper state isolation
-     * when any condition triggers advancement.
-     *
-     * **Implementation Requirements:**
-     * - **Stateless conditions**: Return a copy/clone of self
-     * - **Stateful conditions**: Return a new instance with initial state but preserve configuration parameters
-     * - **Composite conditions**: Recursively create fresh instances of all nested conditions
-     *
-     * This method prevents the state leakage bugs that can cause rapid step advancement
-     * and jumping behavior in navigation.
-     */
-open func newInstance() -> StepAdvanceCondition  {
-    return try!  FfiConverterTypeStepAdvanceCondition_lift(try! rustCall() {
-    uniffi_ferrostar_fn_method_stepadvancecondition_new_instance(
-            self.uniffiCloneHandle(),$0
+        FfiConverterTypeRouteRequest_lower(request),$0
     )
 })
 }
@@ -3999,27 +4321,105 @@ open func newInstance() -> StepAdvanceCondition  {
 }
 
 
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceRouteFetcher {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // Store the vtable directly.
+    static let vtable: UniffiVTableCallbackInterfaceRouteFetcher = UniffiVTableCallbackInterfaceRouteFetcher(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeRouteFetcher.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface RouteFetcher: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeRouteFetcher.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface RouteFetcher: handle missing in uniffiClone")
+            }
+        },
+        fetchRoute: { (
+            uniffiHandle: UInt64,
+            request: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> Data in
+                guard let uniffiObj = try? FfiConverterTypeRouteFetcher.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try uniffiObj.fetchRoute(
+                     request: try FfiConverterTypeRouteRequest_lift(request)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterData.lower($0) }
+            uniffiTraitInterfaceCallWithError(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn,
+                lowerError: FfiConverterTypeRerouteError_lower
+            )
+        }
+    )
+
+    // Rust stores this pointer for future callback invocations, so it must live
+    // for the process lifetime (not just for the init function call).
+    static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceRouteFetcher> = {
+        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceRouteFetcher>.allocate(capacity: 1)
+        ptr.initialize(to: vtable)
+        return UnsafePointer(ptr)
+    }()
+}
+
+private func uniffiCallbackInitRouteFetcher() {
+    uniffi_ferrostar_fn_init_callback_vtable_routefetcher(UniffiCallbackInterfaceRouteFetcher.vtablePtr)
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeStepAdvanceCondition: FfiConverter {
+public struct FfiConverterTypeRouteFetcher: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<RouteFetcher>()
+
     typealias FfiType = UInt64
-    typealias SwiftType = StepAdvanceCondition
+    typealias SwiftType = RouteFetcher
 
-    public static func lift(_ handle: UInt64) throws -> StepAdvanceCondition {
-        return StepAdvanceCondition(unsafeFromHandle: handle)
+    public static func lift(_ handle: UInt64) throws -> RouteFetcher {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return RouteFetcherImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
     }
 
-    public static func lower(_ value: StepAdvanceCondition) -> UInt64 {
-        return value.uniffiCloneHandle()
+    public static func lower(_ value: RouteFetcher) -> UInt64 {
+         if let rustImpl = value as? RouteFetcherImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StepAdvanceCondition {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteFetcher {
         let handle: UInt64 = try readInt(&buf)
         return try lift(handle)
     }
 
-    public static func write(_ value: StepAdvanceCondition, into buf: inout [UInt8]) {
+    public static func write(_ value: RouteFetcher, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
@@ -4028,212 +4428,231 @@ public struct FfiConverterTypeStepAdvanceCondition: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeStepAdvanceCondition_lift(_ handle: UInt64) throws -> StepAdvanceCondition {
-    return try FfiConverterTypeStepAdvanceCondition.lift(handle)
+public func FfiConverterTypeRouteFetcher_lift(_ handle: UInt64) throws -> RouteFetcher {
+    return try FfiConverterTypeRouteFetcher.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeStepAdvanceCondition_lower(_ value: StepAdvanceCondition) -> UInt64 {
-    return FfiConverterTypeStepAdvanceCondition.lower(value)
+public func FfiConverterTypeRouteFetcher_lower(_ value: RouteFetcher) -> UInt64 {
+    return FfiConverterTypeRouteFetcher.lower(value)
 }
 
 
 
 
+
+
 /**
- * A geographic bounding box defined by its corners.
+ * A trait describing any object capable of generating [`RouteRequest`]s.
+ *
+ * The interface is intentionally generic. Every routing backend has its own set of
+ * parameters, including a "profile," max travel speed, units of speed and distance, and more.
+ * It is assumed that these properties will be set at construction time or otherwise configured
+ * before use, so that we can keep the public interface as generic as possible.
+ *
+ * Implementations may be either in Rust (most popular engines should eventually have Rust
+ * glue code) or foreign code.
  */
-public struct BoundingBox: Equatable, Hashable, Codable {
-    /**
-     * The southwest corner of the bounding box.
-     */
-    public var sw: GeographicCoordinate
+public protocol RouteRequestGenerator: AnyObject, Sendable {
+    
     /**
-     * The northeast corner of the bounding box.
+     * Generates a routing backend request given the set of locations.
+     *
+     * While most implementations will treat the locations as an ordered sequence, this is not
+     * guaranteed (ex: an optimized router).
      */
-    public var ne: GeographicCoordinate
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The southwest corner of the bounding box.
-         */sw: GeographicCoordinate, 
-        /**
-         * The northeast corner of the bounding box.
-         */ne: GeographicCoordinate) {
-        self.sw = sw
-        self.ne = ne
-    }
-
-    
-
+    func generateRequest(userLocation: UserLocation, waypoints: [Waypoint]) throws  -> RouteRequest
     
 }
+/**
+ * A trait describing any object capable of generating [`RouteRequest`]s.
+ *
+ * The interface is intentionally generic. Every routing backend has its own set of
+ * parameters, including a "profile," max travel speed, units of speed and distance, and more.
+ * It is assumed that these properties will be set at construction time or otherwise configured
+ * before use, so that we can keep the public interface as generic as possible.
+ *
+ * Implementations may be either in Rust (most popular engines should eventually have Rust
+ * glue code) or foreign code.
+ */
+open class RouteRequestGeneratorImpl: RouteRequestGenerator, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
-#if compiler(>=6)
-extension BoundingBox: Sendable {}
-#endif
-
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeBoundingBox: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BoundingBox {
-        return
-            try BoundingBox(
-                sw: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
-                ne: FfiConverterTypeGeographicCoordinate.read(from: &buf)
-        )
+    public struct NoHandle {
+        public init() {}
     }
 
-    public static func write(_ value: BoundingBox, into buf: inout [UInt8]) {
-        FfiConverterTypeGeographicCoordinate.write(value.sw, into: &buf)
-        FfiConverterTypeGeographicCoordinate.write(value.ne, into: &buf)
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeBoundingBox_lift(_ buf: RustBuffer) throws -> BoundingBox {
-    return try FfiConverterTypeBoundingBox.lift(buf)
-}
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeBoundingBox_lower(_ value: BoundingBox) -> RustBuffer {
-    return FfiConverterTypeBoundingBox.lower(value)
-}
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_ferrostar_fn_clone_routerequestgenerator(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
 
+        try! rustCall { uniffi_ferrostar_fn_free_routerequestgenerator(handle, $0) }
+    }
 
-/**
- * Details about congestion for an incident.
- */
-public struct Congestion: Equatable, Hashable, Codable {
+    
+
+    
     /**
-     * The level of congestion caused by the incident.
-     *
-     * 0 = no congestion
+     * Generates a routing backend request given the set of locations.
      *
-     * 100 = road closed
-     *
-     * Other values mean no congestion was calculated
+     * While most implementations will treat the locations as an ordered sequence, this is not
+     * guaranteed (ex: an optimized router).
      */
-    public var value: UInt8
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The level of congestion caused by the incident.
-         *
-         * 0 = no congestion
-         *
-         * 100 = road closed
-         *
-         * Other values mean no congestion was calculated
-         */value: UInt8) {
-        self.value = value
-    }
-
+open func generateRequest(userLocation: UserLocation, waypoints: [Waypoint])throws  -> RouteRequest  {
+    return try  FfiConverterTypeRouteRequest_lift(try rustCallWithError(FfiConverterTypeRoutingRequestGenerationError_lift) {
+    uniffi_ferrostar_fn_method_routerequestgenerator_generate_request(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeUserLocation_lower(userLocation),
+        FfiConverterSequenceTypeWaypoint.lower(waypoints),$0
+    )
+})
+}
     
 
     
 }
 
-#if compiler(>=6)
-extension Congestion: Sendable {}
-#endif
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeCongestion: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Congestion {
-        return
-            try Congestion(
-                value: FfiConverterUInt8.read(from: &buf)
-        )
-    }
 
-    public static func write(_ value: Congestion, into buf: inout [UInt8]) {
-        FfiConverterUInt8.write(value.value, into: &buf)
-    }
-}
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceRouteRequestGenerator {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // Store the vtable directly.
+    static let vtable: UniffiVTableCallbackInterfaceRouteRequestGenerator = UniffiVTableCallbackInterfaceRouteRequestGenerator(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeRouteRequestGenerator.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface RouteRequestGenerator: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeRouteRequestGenerator.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface RouteRequestGenerator: handle missing in uniffiClone")
+            }
+        },
+        generateRequest: { (
+            uniffiHandle: UInt64,
+            userLocation: RustBuffer,
+            waypoints: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> RouteRequest in
+                guard let uniffiObj = try? FfiConverterTypeRouteRequestGenerator.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try uniffiObj.generateRequest(
+                     userLocation: try FfiConverterTypeUserLocation_lift(userLocation),
+                     waypoints: try FfiConverterSequenceTypeWaypoint.lift(waypoints)
+                )
+            }
 
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterTypeRouteRequest_lower($0) }
+            uniffiTraitInterfaceCallWithError(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn,
+                lowerError: FfiConverterTypeRoutingRequestGenerationError_lower
+            )
+        }
+    )
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeCongestion_lift(_ buf: RustBuffer) throws -> Congestion {
-    return try FfiConverterTypeCongestion.lift(buf)
+    // Rust stores this pointer for future callback invocations, so it must live
+    // for the process lifetime (not just for the init function call).
+    static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceRouteRequestGenerator> = {
+        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceRouteRequestGenerator>.allocate(capacity: 1)
+        ptr.initialize(to: vtable)
+        return UnsafePointer(ptr)
+    }()
+}
+
+private func uniffiCallbackInitRouteRequestGenerator() {
+    uniffi_ferrostar_fn_init_callback_vtable_routerequestgenerator(UniffiCallbackInterfaceRouteRequestGenerator.vtablePtr)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCongestion_lower(_ value: Congestion) -> RustBuffer {
-    return FfiConverterTypeCongestion.lower(value)
-}
-
+public struct FfiConverterTypeRouteRequestGenerator: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<RouteRequestGenerator>()
 
-/**
- * The direction in which the user/device is observed to be traveling.
- */
-public struct CourseOverGround: Equatable, Hashable, Codable {
-    /**
-     * The direction in which the user's device is traveling, measured in clockwise degrees from
-     * true north (N = 0, E = 90, S = 180, W = 270).
-     */
-    public var degrees: UInt16
-    /**
-     * The accuracy of the course value, measured in degrees.
-     */
-    public var accuracy: UInt16?
+    typealias FfiType = UInt64
+    typealias SwiftType = RouteRequestGenerator
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The direction in which the user's device is traveling, measured in clockwise degrees from
-         * true north (N = 0, E = 90, S = 180, W = 270).
-         */degrees: UInt16, 
-        /**
-         * The accuracy of the course value, measured in degrees.
-         */accuracy: UInt16?) {
-        self.degrees = degrees
-        self.accuracy = accuracy
+    public static func lift(_ handle: UInt64) throws -> RouteRequestGenerator {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return RouteRequestGeneratorImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
     }
 
-    
-
-    
-}
-
-#if compiler(>=6)
-extension CourseOverGround: Sendable {}
-#endif
+    public static func lower(_ value: RouteRequestGenerator) -> UInt64 {
+         if let rustImpl = value as? RouteRequestGeneratorImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeCourseOverGround: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CourseOverGround {
-        return
-            try CourseOverGround(
-                degrees: FfiConverterUInt16.read(from: &buf), 
-                accuracy: FfiConverterOptionUInt16.read(from: &buf)
-        )
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteRequestGenerator {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
     }
 
-    public static func write(_ value: CourseOverGround, into buf: inout [UInt8]) {
-        FfiConverterUInt16.write(value.degrees, into: &buf)
-        FfiConverterOptionUInt16.write(value.accuracy, into: &buf)
+    public static func write(_ value: RouteRequestGenerator, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
 }
 
@@ -4241,147 +4660,214 @@ public struct FfiConverterTypeCourseOverGround: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCourseOverGround_lift(_ buf: RustBuffer) throws -> CourseOverGround {
-    return try FfiConverterTypeCourseOverGround.lift(buf)
+public func FfiConverterTypeRouteRequestGenerator_lift(_ handle: UInt64) throws -> RouteRequestGenerator {
+    return try FfiConverterTypeRouteRequestGenerator.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCourseOverGround_lower(_ value: CourseOverGround) -> RustBuffer {
-    return FfiConverterTypeCourseOverGround.lower(value)
+public func FfiConverterTypeRouteRequestGenerator_lower(_ value: RouteRequestGenerator) -> UInt64 {
+    return FfiConverterTypeRouteRequestGenerator.lower(value)
 }
 
 
+
+
+
+
 /**
- * A geographic coordinate in WGS84.
+ * A generic interface describing any object capable of parsing a response from a routing
+ * backend into one or more [`Route`]s.
  */
-public struct GeographicCoordinate: Equatable, Hashable, Codable {
-    /**
-     * The latitude (in degrees).
-     */
-    public var lat: Double
+public protocol RouteResponseParser: AnyObject, Sendable {
+    
     /**
-     * The Longitude (in degrees).
+     * Parses a raw response from the routing backend into a route.
+     *
+     * We use a sequence of octets as a common interchange format.
+     * as this works for all currently conceivable formats (JSON, PBF, etc.).
      */
-    public var lng: Double
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The latitude (in degrees).
-         */lat: Double, 
-        /**
-         * The Longitude (in degrees).
-         */lng: Double) {
-        self.lat = lat
-        self.lng = lng
-    }
-
-    
-
+    func parseResponse(response: Data) throws  -> [Route]
     
 }
+/**
+ * A generic interface describing any object capable of parsing a response from a routing
+ * backend into one or more [`Route`]s.
+ */
+open class RouteResponseParserImpl: RouteResponseParser, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
-#if compiler(>=6)
-extension GeographicCoordinate: Sendable {}
-#endif
-
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeGeographicCoordinate: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GeographicCoordinate {
-        return
-            try GeographicCoordinate(
-                lat: FfiConverterDouble.read(from: &buf), 
-                lng: FfiConverterDouble.read(from: &buf)
-        )
+    public struct NoHandle {
+        public init() {}
     }
 
-    public static func write(_ value: GeographicCoordinate, into buf: inout [UInt8]) {
-        FfiConverterDouble.write(value.lat, into: &buf)
-        FfiConverterDouble.write(value.lng, into: &buf)
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeGeographicCoordinate_lift(_ buf: RustBuffer) throws -> GeographicCoordinate {
-    return try FfiConverterTypeGeographicCoordinate.lift(buf)
-}
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeGeographicCoordinate_lower(_ value: GeographicCoordinate) -> RustBuffer {
-    return FfiConverterTypeGeographicCoordinate.lower(value)
-}
-
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_ferrostar_fn_clone_routeresponseparser(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
 
-/**
- * The heading of the user/device.
- */
-public struct Heading: Equatable, Hashable, Codable {
-    /**
-     * The heading in degrees relative to true north.
-     */
-    public var trueHeading: UInt16
-    /**
-     * The platform specific accuracy of the heading value.
-     */
-    public var accuracy: UInt16
-    /**
-     * The time at which the heading was recorded.
-     */
-    public var timestamp: Date
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The heading in degrees relative to true north.
-         */trueHeading: UInt16, 
-        /**
-         * The platform specific accuracy of the heading value.
-         */accuracy: UInt16, 
-        /**
-         * The time at which the heading was recorded.
-         */timestamp: Date) {
-        self.trueHeading = trueHeading
-        self.accuracy = accuracy
-        self.timestamp = timestamp
+        try! rustCall { uniffi_ferrostar_fn_free_routeresponseparser(handle, $0) }
     }
 
     
 
     
+    /**
+     * Parses a raw response from the routing backend into a route.
+     *
+     * We use a sequence of octets as a common interchange format.
+     * as this works for all currently conceivable formats (JSON, PBF, etc.).
+     */
+open func parseResponse(response: Data)throws  -> [Route]  {
+    return try  FfiConverterSequenceTypeRoute.lift(try rustCallWithError(FfiConverterTypeParsingError_lift) {
+    uniffi_ferrostar_fn_method_routeresponseparser_parse_response(
+            self.uniffiCloneHandle(),
+        FfiConverterData.lower(response),$0
+    )
+})
+}
+    
+
+    
 }
 
-#if compiler(>=6)
-extension Heading: Sendable {}
-#endif
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceRouteResponseParser {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // Store the vtable directly.
+    static let vtable: UniffiVTableCallbackInterfaceRouteResponseParser = UniffiVTableCallbackInterfaceRouteResponseParser(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeRouteResponseParser.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface RouteResponseParser: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeRouteResponseParser.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface RouteResponseParser: handle missing in uniffiClone")
+            }
+        },
+        parseResponse: { (
+            uniffiHandle: UInt64,
+            response: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> [Route] in
+                guard let uniffiObj = try? FfiConverterTypeRouteResponseParser.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return try uniffiObj.parseResponse(
+                     response: try FfiConverterData.lift(response)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterSequenceTypeRoute.lower($0) }
+            uniffiTraitInterfaceCallWithError(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn,
+                lowerError: FfiConverterTypeParsingError_lower
+            )
+        }
+    )
+
+    // Rust stores this pointer for future callback invocations, so it must live
+    // for the process lifetime (not just for the init function call).
+    static let vtablePtr: UnsafePointer<UniffiVTableCallbackInterfaceRouteResponseParser> = {
+        let ptr = UnsafeMutablePointer<UniffiVTableCallbackInterfaceRouteResponseParser>.allocate(capacity: 1)
+        ptr.initialize(to: vtable)
+        return UnsafePointer(ptr)
+    }()
+}
+
+private func uniffiCallbackInitRouteResponseParser() {
+    uniffi_ferrostar_fn_init_callback_vtable_routeresponseparser(UniffiCallbackInterfaceRouteResponseParser.vtablePtr)
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHeading: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Heading {
-        return
-            try Heading(
-                trueHeading: FfiConverterUInt16.read(from: &buf), 
-                accuracy: FfiConverterUInt16.read(from: &buf), 
-                timestamp: FfiConverterTimestamp.read(from: &buf)
-        )
+public struct FfiConverterTypeRouteResponseParser: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<RouteResponseParser>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = RouteResponseParser
+
+    public static func lift(_ handle: UInt64) throws -> RouteResponseParser {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return RouteResponseParserImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
     }
 
-    public static func write(_ value: Heading, into buf: inout [UInt8]) {
-        FfiConverterUInt16.write(value.trueHeading, into: &buf)
-        FfiConverterUInt16.write(value.accuracy, into: &buf)
-        FfiConverterTimestamp.write(value.timestamp, into: &buf)
+    public static func lower(_ value: RouteResponseParser) -> UInt64 {
+         if let rustImpl = value as? RouteResponseParserImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteResponseParser {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: RouteResponseParser, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
 }
 
@@ -4389,243 +4875,121 @@ public struct FfiConverterTypeHeading: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHeading_lift(_ buf: RustBuffer) throws -> Heading {
-    return try FfiConverterTypeHeading.lift(buf)
+public func FfiConverterTypeRouteResponseParser_lift(_ handle: UInt64) throws -> RouteResponseParser {
+    return try FfiConverterTypeRouteResponseParser.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHeading_lower(_ value: Heading) -> RustBuffer {
-    return FfiConverterTypeHeading.lower(value)
+public func FfiConverterTypeRouteResponseParser_lower(_ value: RouteResponseParser) -> UInt64 {
+    return FfiConverterTypeRouteResponseParser.lower(value)
 }
 
 
+
+
+
+
 /**
- * An incident affecting the free flow of traffic,
- * such as constructions, accidents, and congestion.
+ * Automatically advances when the user is expected to reach the end of the step within a given time.
+ *
+ * The distance threshold scales with the user's reported speed,
+ * so it is wider at highway speeds and tighter on foot.
+ * It is clamped between `minimum_distance` and `maximum_distance`,
+ * and falls back to `minimum_distance` when the location has no speed.
  */
-public struct Incident: Equatable, Hashable, Codable {
-    /**
-     * A unique identifier for the incident.
-     */
-    public var id: String
-    /**
-     * The type of incident.
-     */
-    public var incidentType: IncidentType
-    /**
-     * A short description of the incident.
-     */
-    public var description: String?
-    /**
-     * A longer description of the incident.
-     */
-    public var longDescription: String?
-    /**
-     * The time at which the incident was *last* created.
-     *
-     * NB: This can change throughout the life of the incident.
-     */
-    public var creationTime: UtcDateTime?
-    /**
-     * The time at which the incident started or is expected to start (ex: planned closure).
-     */
-    public var startTime: UtcDateTime?
-    /**
-     * The time at which the incident ended or is expected to end.
-     */
-    public var endTime: UtcDateTime?
-    /**
-     * The level of impact to traffic.
-     */
-    public var impact: Impact?
-    /**
-     * Lanes which are blocked by the incident.
-     */
-    public var lanesBlocked: [BlockedLane]
-    /**
-     * Info about the amount of congestion on the road around the incident.
-     */
-    public var congestion: Congestion?
-    /**
-     * Is the road completely closed?
-     */
-    public var closed: Bool?
-    /**
-     * The index into the [`RouteStep`] geometry where the incident starts.
-     */
-    public var geometryIndexStart: UInt64
-    /**
-     * The index into the [`RouteStep`] geometry where the incident ends.
-     */
-    public var geometryIndexEnd: UInt64?
-    /**
-     * Optional additional information about the type of incident (free-form text).
-     */
-    public var subType: String?
-    /**
-     * Optional descriptions about the type of incident (free-form text).
-     */
-    public var subTypeDescription: String?
-    /**
-     * The ISO 3166-1 alpha-2 code of the country in which the incident occurs.
-     */
-    public var iso31661Alpha2: String?
-    /**
-     * The ISO 3166-1 alpha-3 code of the country in which the incident occurs.
-     */
-    public var iso31661Alpha3: String?
-    /**
-     * A list of road names affected by the incident.
-     */
-    public var affectedRoadNames: [String]
-    /**
-     * The bounding box over which the incident occurs.
-     */
-    public var bbox: BoundingBox?
+public protocol SpeedScaledDistanceToEndOfStepConditionProtocol: AnyObject, Sendable {
+    
+}
+/**
+ * Automatically advances when the user is expected to reach the end of the step within a given time.
+ *
+ * The distance threshold scales with the user's reported speed,
+ * so it is wider at highway speeds and tighter on foot.
+ * It is clamped between `minimum_distance` and `maximum_distance`,
+ * and falls back to `minimum_distance` when the location has no speed.
+ */
+open class SpeedScaledDistanceToEndOfStepCondition: SpeedScaledDistanceToEndOfStepConditionProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * A unique identifier for the incident.
-         */id: String, 
-        /**
-         * The type of incident.
-         */incidentType: IncidentType, 
-        /**
-         * A short description of the incident.
-         */description: String?, 
-        /**
-         * A longer description of the incident.
-         */longDescription: String?, 
-        /**
-         * The time at which the incident was *last* created.
-         *
-         * NB: This can change throughout the life of the incident.
-         */creationTime: UtcDateTime?, 
-        /**
-         * The time at which the incident started or is expected to start (ex: planned closure).
-         */startTime: UtcDateTime?, 
-        /**
-         * The time at which the incident ended or is expected to end.
-         */endTime: UtcDateTime?, 
-        /**
-         * The level of impact to traffic.
-         */impact: Impact?, 
-        /**
-         * Lanes which are blocked by the incident.
-         */lanesBlocked: [BlockedLane], 
-        /**
-         * Info about the amount of congestion on the road around the incident.
-         */congestion: Congestion?, 
-        /**
-         * Is the road completely closed?
-         */closed: Bool?, 
-        /**
-         * The index into the [`RouteStep`] geometry where the incident starts.
-         */geometryIndexStart: UInt64, 
-        /**
-         * The index into the [`RouteStep`] geometry where the incident ends.
-         */geometryIndexEnd: UInt64?, 
-        /**
-         * Optional additional information about the type of incident (free-form text).
-         */subType: String?, 
-        /**
-         * Optional descriptions about the type of incident (free-form text).
-         */subTypeDescription: String?, 
-        /**
-         * The ISO 3166-1 alpha-2 code of the country in which the incident occurs.
-         */iso31661Alpha2: String?, 
-        /**
-         * The ISO 3166-1 alpha-3 code of the country in which the incident occurs.
-         */iso31661Alpha3: String?, 
-        /**
-         * A list of road names affected by the incident.
-         */affectedRoadNames: [String], 
-        /**
-         * The bounding box over which the incident occurs.
-         */bbox: BoundingBox?) {
-        self.id = id
-        self.incidentType = incidentType
-        self.description = description
-        self.longDescription = longDescription
-        self.creationTime = creationTime
-        self.startTime = startTime
-        self.endTime = endTime
-        self.impact = impact
-        self.lanesBlocked = lanesBlocked
-        self.congestion = congestion
-        self.closed = closed
-        self.geometryIndexStart = geometryIndexStart
-        self.geometryIndexEnd = geometryIndexEnd
-        self.subType = subType
-        self.subTypeDescription = subTypeDescription
-        self.iso31661Alpha2 = iso31661Alpha2
-        self.iso31661Alpha3 = iso31661Alpha3
-        self.affectedRoadNames = affectedRoadNames
-        self.bbox = bbox
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_ferrostar_fn_clone_speedscaleddistancetoendofstepcondition(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_ferrostar_fn_free_speedscaleddistancetoendofstepcondition(handle, $0) }
     }
 
     
 
     
+
+    
 }
 
-#if compiler(>=6)
-extension Incident: Sendable {}
-#endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeIncident: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Incident {
-        return
-            try Incident(
-                id: FfiConverterString.read(from: &buf), 
-                incidentType: FfiConverterTypeIncidentType.read(from: &buf), 
-                description: FfiConverterOptionString.read(from: &buf), 
-                longDescription: FfiConverterOptionString.read(from: &buf), 
-                creationTime: FfiConverterOptionTypeUtcDateTime.read(from: &buf), 
-                startTime: FfiConverterOptionTypeUtcDateTime.read(from: &buf), 
-                endTime: FfiConverterOptionTypeUtcDateTime.read(from: &buf), 
-                impact: FfiConverterOptionTypeImpact.read(from: &buf), 
-                lanesBlocked: FfiConverterSequenceTypeBlockedLane.read(from: &buf), 
-                congestion: FfiConverterOptionTypeCongestion.read(from: &buf), 
-                closed: FfiConverterOptionBool.read(from: &buf), 
-                geometryIndexStart: FfiConverterUInt64.read(from: &buf), 
-                geometryIndexEnd: FfiConverterOptionUInt64.read(from: &buf), 
-                subType: FfiConverterOptionString.read(from: &buf), 
-                subTypeDescription: FfiConverterOptionString.read(from: &buf), 
-                iso31661Alpha2: FfiConverterOptionString.read(from: &buf), 
-                iso31661Alpha3: FfiConverterOptionString.read(from: &buf), 
-                affectedRoadNames: FfiConverterSequenceString.read(from: &buf), 
-                bbox: FfiConverterOptionTypeBoundingBox.read(from: &buf)
-        )
+public struct FfiConverterTypeSpeedScaledDistanceToEndOfStepCondition: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = SpeedScaledDistanceToEndOfStepCondition
+
+    public static func lift(_ handle: UInt64) throws -> SpeedScaledDistanceToEndOfStepCondition {
+        return SpeedScaledDistanceToEndOfStepCondition(unsafeFromHandle: handle)
     }
 
-    public static func write(_ value: Incident, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.id, into: &buf)
-        FfiConverterTypeIncidentType.write(value.incidentType, into: &buf)
-        FfiConverterOptionString.write(value.description, into: &buf)
-        FfiConverterOptionString.write(value.longDescription, into: &buf)
-        FfiConverterOptionTypeUtcDateTime.write(value.creationTime, into: &buf)
-        FfiConverterOptionTypeUtcDateTime.write(value.startTime, into: &buf)
-        FfiConverterOptionTypeUtcDateTime.write(value.endTime, into: &buf)
-        FfiConverterOptionTypeImpact.write(value.impact, into: &buf)
-        FfiConverterSequenceTypeBlockedLane.write(value.lanesBlocked, into: &buf)
-        FfiConverterOptionTypeCongestion.write(value.congestion, into: &buf)
-        FfiConverterOptionBool.write(value.closed, into: &buf)
-        FfiConverterUInt64.write(value.geometryIndexStart, into: &buf)
-        FfiConverterOptionUInt64.write(value.geometryIndexEnd, into: &buf)
-        FfiConverterOptionString.write(value.subType, into: &buf)
-        FfiConverterOptionString.write(value.subTypeDescription, into: &buf)
-        FfiConverterOptionString.write(value.iso31661Alpha2, into: &buf)
-        FfiConverterOptionString.write(value.iso31661Alpha3, into: &buf)
-        FfiConverterSequenceString.write(value.affectedRoadNames, into: &buf)
-        FfiConverterOptionTypeBoundingBox.write(value.bbox, into: &buf)
+    public static func lower(_ value: SpeedScaledDistanceToEndOfStepCondition) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpeedScaledDistanceToEndOfStepCondition {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: SpeedScaledDistanceToEndOfStepCondition, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
 }
 
@@ -4633,121 +4997,174 @@ public struct FfiConverterTypeIncident: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIncident_lift(_ buf: RustBuffer) throws -> Incident {
-    return try FfiConverterTypeIncident.lift(buf)
+public func FfiConverterTypeSpeedScaledDistanceToEndOfStepCondition_lift(_ handle: UInt64) throws -> SpeedScaledDistanceToEndOfStepCondition {
+    return try FfiConverterTypeSpeedScaledDistanceToEndOfStepCondition.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIncident_lower(_ value: Incident) -> RustBuffer {
-    return FfiConverterTypeIncident.lower(value)
+public func FfiConverterTypeSpeedScaledDistanceToEndOfStepCondition_lower(_ value: SpeedScaledDistanceToEndOfStepCondition) -> UInt64 {
+    return FfiConverterTypeSpeedScaledDistanceToEndOfStepCondition.lower(value)
 }
 
 
-/**
- * The content of a visual instruction.
- */
-public struct LaneInfo: Equatable, Hashable, Codable {
-    public var active: Bool
-    public var directions: [String]
-    public var activeDirection: String?
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(active: Bool, directions: [String], activeDirection: String?) {
-        self.active = active
-        self.directions = directions
-        self.activeDirection = activeDirection
-    }
 
-    
 
-    
-}
 
-#if compiler(>=6)
-extension LaneInfo: Sendable {}
-#endif
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeLaneInfo: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LaneInfo {
-        return
-            try LaneInfo(
-                active: FfiConverterBool.read(from: &buf), 
-                directions: FfiConverterSequenceString.read(from: &buf), 
-                activeDirection: FfiConverterOptionString.read(from: &buf)
-        )
-    }
-
-    public static func write(_ value: LaneInfo, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.active, into: &buf)
-        FfiConverterSequenceString.write(value.directions, into: &buf)
-        FfiConverterOptionString.write(value.activeDirection, into: &buf)
-    }
+/**
+ * When implementing custom step advance logic, this trait allows you to define
+ * whether the condition should advance to the next condition, the next step or not.
+ *
+ * At the moment, these must be implemented in Rust.
+ */
+public protocol StepAdvanceConditionProtocol: AnyObject, Sendable {
+    
+    /**
+     * This callback method is used by a step advance condition to receive step updates.
+     * The step advance condition can choose based on its outcome and internal state
+     * whether to advance to the next step or not.
+     */
+    func shouldAdvanceStep(tripState: TripState)  -> StepAdvanceResult
+    
+    /**
+     * Creates a clean instance of this condition with the same configuration but reset state.
+     * This is used by composite conditions (Or/And) to ensure proper state isolation
+     * when any condition triggers advancement.
+     *
+     * **Implementation Requirements:**
+     * - **Stateless conditions**: Return a copy/clone of self
+     * - **Stateful conditions**: Return a new instance with initial state but preserve configuration parameters
+     * - **Composite conditions**: Recursively create fresh instances of all nested conditions
+     *
+     * This method prevents the state leakage bugs that can cause rapid step advancement
+     * and jumping behavior in navigation.
+     */
+    func newInstance()  -> StepAdvanceCondition
+    
 }
+/**
+ * When implementing custom step advance logic, this trait allows you to define
+ * whether the condition should advance to the next condition, the next step or not.
+ *
+ * At the moment, these must be implemented in Rust.
+ */
+open class StepAdvanceCondition: StepAdvanceConditionProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
 
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLaneInfo_lift(_ buf: RustBuffer) throws -> LaneInfo {
-    return try FfiConverterTypeLaneInfo.lift(buf)
-}
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLaneInfo_lower(_ value: LaneInfo) -> RustBuffer {
-    return FfiConverterTypeLaneInfo.lower(value)
-}
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
 
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_ferrostar_fn_clone_stepadvancecondition(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
 
-/**
- * The current state of the simulation.
- */
-public struct LocationSimulationState: Equatable, Hashable, Codable {
-    public var currentLocation: UserLocation
-    public var remainingLocations: [GeographicCoordinate]
-    public var bias: LocationBias
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(currentLocation: UserLocation, remainingLocations: [GeographicCoordinate], bias: LocationBias) {
-        self.currentLocation = currentLocation
-        self.remainingLocations = remainingLocations
-        self.bias = bias
+        try! rustCall { uniffi_ferrostar_fn_free_stepadvancecondition(handle, $0) }
     }
 
     
 
     
+    /**
+     * This callback method is used by a step advance condition to receive step updates.
+     * The step advance condition can choose based on its outcome and internal state
+     * whether to advance to the next step or not.
+     */
+open func shouldAdvanceStep(tripState: TripState) -> StepAdvanceResult  {
+    return try!  FfiConverterTypeStepAdvanceResult_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_stepadvancecondition_should_advance_step(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeTripState_lower(tripState),$0
+    )
+})
+}
+    
+    /**
+     * Creates a clean instance of this condition with the same configuration but reset state.
+     * This is used by composite conditions (Or/And) to ensure proper state isolation
+     * when any condition triggers advancement.
+     *
+     * **Implementation Requirements:**
+     * - **Stateless conditions**: Return a copy/clone of self
+     * - **Stateful conditions**: Return a new instance with initial state but preserve configuration parameters
+     * - **Composite conditions**: Recursively create fresh instances of all nested conditions
+     *
+     * This method prevents the state leakage bugs that can cause rapid step advancement
+     * and jumping behavior in navigation.
+     */
+open func newInstance() -> StepAdvanceCondition  {
+    return try!  FfiConverterTypeStepAdvanceCondition_lift(try! rustCall() {
+    uniffi_ferrostar_fn_method_stepadvancecondition_new_instance(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+
+    
 }
 
-#if compiler(>=6)
-extension LocationSimulationState: Sendable {}
-#endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLocationSimulationState: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationSimulationState {
-        return
-            try LocationSimulationState(
-                currentLocation: FfiConverterTypeUserLocation.read(from: &buf), 
-                remainingLocations: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
-                bias: FfiConverterTypeLocationBias.read(from: &buf)
-        )
+public struct FfiConverterTypeStepAdvanceCondition: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = StepAdvanceCondition
+
+    public static func lift(_ handle: UInt64) throws -> StepAdvanceCondition {
+        return StepAdvanceCondition(unsafeFromHandle: handle)
     }
 
-    public static func write(_ value: LocationSimulationState, into buf: inout [UInt8]) {
-        FfiConverterTypeUserLocation.write(value.currentLocation, into: &buf)
-        FfiConverterSequenceTypeGeographicCoordinate.write(value.remainingLocations, into: &buf)
-        FfiConverterTypeLocationBias.write(value.bias, into: &buf)
+    public static func lower(_ value: StepAdvanceCondition) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StepAdvanceCondition {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: StepAdvanceCondition, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
 }
 
@@ -4755,35 +5172,57 @@ public struct FfiConverterTypeLocationSimulationState: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLocationSimulationState_lift(_ buf: RustBuffer) throws -> LocationSimulationState {
-    return try FfiConverterTypeLocationSimulationState.lift(buf)
+public func FfiConverterTypeStepAdvanceCondition_lift(_ handle: UInt64) throws -> StepAdvanceCondition {
+    return try FfiConverterTypeStepAdvanceCondition.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLocationSimulationState_lower(_ value: LocationSimulationState) -> RustBuffer {
-    return FfiConverterTypeLocationSimulationState.lower(value)
+public func FfiConverterTypeStepAdvanceCondition_lower(_ value: StepAdvanceCondition) -> UInt64 {
+    return FfiConverterTypeStepAdvanceCondition.lower(value)
 }
 
 
+
+
 /**
- * The navigation state.
+ * Configures the adaptive ETA reported in [`TripProgress::adaptive_duration_remaining`].
  *
- * This is typically created from an initial trip state
- * and conditions for advancing navigation to the next step.
- * Any internal navigation state is packed in here so that
- * the navigation controller can remain functionally pure.
+ * The user's speed is exponentially smoothed over the current step,
+ * starting from the speed planned for the step.
+ * The time to the next maneuver is then derived from the smoothed speed,
+ * while the planned durations are used for all later steps.
  */
-public struct NavState {
-    public var tripState: TripState
-    public var stepAdvanceCondition: StepAdvanceCondition
+public struct AdaptiveEtaConfig: Equatable, Hashable, Codable {
+    /**
+     * The weight (between 0 and 1) given to each new speed observation.
+     *
+     * Larger values react faster to changes in speed, but are noisier.
+     */
+    public var smoothingFactor: Double
+    /**
+     * The minimum speed (in meters per second) assumed when estimating the time remaining.
+     *
+     * This keeps the estimate finite while the user is stopped (ex: at a traffic light).
+     */
+    public var minimumSpeed: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(tripState: TripState, stepAdvanceCondition: StepAdvanceCondition) {
-        self.tripState = tripState
-        self.stepAdvanceCondition = stepAdvanceCondition
+    public init(
+        /**
+         * The weight (between 0 and 1) given to each new speed observation.
+         *
+         * Larger values react faster to changes in speed, but are noisier.
+         */smoothingFactor: Double, 
+        /**
+         * The minimum speed (in meters per second) assumed when estimating the time remaining.
+         *
+         * This keeps the estimate finite while the user is stopped (ex: at a traffic light).
+         */minimumSpeed: Double) {
+        self.smoothingFactor = smoothingFactor
+        self.minimumSpeed = minimumSpeed
     }
 
     
@@ -4792,24 +5231,24 @@ public struct NavState {
 }
 
 #if compiler(>=6)
-extension NavState: Sendable {}
+extension AdaptiveEtaConfig: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNavState: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavState {
+public struct FfiConverterTypeAdaptiveEtaConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AdaptiveEtaConfig {
         return
-            try NavState(
-                tripState: FfiConverterTypeTripState.read(from: &buf), 
-                stepAdvanceCondition: FfiConverterTypeStepAdvanceCondition.read(from: &buf)
+            try AdaptiveEtaConfig(
+                smoothingFactor: FfiConverterDouble.read(from: &buf), 
+                minimumSpeed: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: NavState, into buf: inout [UInt8]) {
-        FfiConverterTypeTripState.write(value.tripState, into: &buf)
-        FfiConverterTypeStepAdvanceCondition.write(value.stepAdvanceCondition, into: &buf)
+    public static func write(_ value: AdaptiveEtaConfig, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.smoothingFactor, into: &buf)
+        FfiConverterDouble.write(value.minimumSpeed, into: &buf)
     }
 }
 
@@ -4817,27 +5256,61 @@ public struct FfiConverterTypeNavState: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavState_lift(_ buf: RustBuffer) throws -> NavState {
-    return try FfiConverterTypeNavState.lift(buf)
+public func FfiConverterTypeAdaptiveEtaConfig_lift(_ buf: RustBuffer) throws -> AdaptiveEtaConfig {
+    return try FfiConverterTypeAdaptiveEtaConfig.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavState_lower(_ value: NavState) -> RustBuffer {
-    return FfiConverterTypeNavState.lower(value)
+public func FfiConverterTypeAdaptiveEtaConfig_lower(_ value: AdaptiveEtaConfig) -> RustBuffer {
+    return FfiConverterTypeAdaptiveEtaConfig.lower(value)
 }
 
 
-public struct NavigationCachingConfig: Equatable, Hashable, Codable {
-    public var cacheIntervalSeconds: Int64
-    public var maxAgeSeconds: Int64
+/**
+ * The filter state along one axis (east or north) of the local tangent plane.
+ *
+ * The position is always zero (the plane is centered on the last smoothed location),
+ * so only its variance is tracked.
+ */
+public struct AxisEstimate: Equatable, Hashable, Codable {
+    /**
+     * The velocity along the axis, in meters per second.
+     */
+    public var velocity: Double
+    /**
+     * The variance of the position, in square meters.
+     */
+    public var positionVariance: Double
+    /**
+     * The covariance of the position and velocity.
+     */
+    public var covariance: Double
+    /**
+     * The variance of the velocity, in square meters per square second.
+     */
+    public var velocityVariance: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(cacheIntervalSeconds: Int64, maxAgeSeconds: Int64) {
-        self.cacheIntervalSeconds = cacheIntervalSeconds
-        self.maxAgeSeconds = maxAgeSeconds
+    public init(
+        /**
+         * The velocity along the axis, in meters per second.
+         */velocity: Double, 
+        /**
+         * The variance of the position, in square meters.
+         */positionVariance: Double, 
+        /**
+         * The covariance of the position and velocity.
+         */covariance: Double, 
+        /**
+         * The variance of the velocity, in square meters per square second.
+         */velocityVariance: Double) {
+        self.velocity = velocity
+        self.positionVariance = positionVariance
+        self.covariance = covariance
+        self.velocityVariance = velocityVariance
     }
 
     
@@ -4846,24 +5319,28 @@ public struct NavigationCachingConfig: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension NavigationCachingConfig: Sendable {}
+extension AxisEstimate: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNavigationCachingConfig: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationCachingConfig {
+public struct FfiConverterTypeAxisEstimate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AxisEstimate {
         return
-            try NavigationCachingConfig(
-                cacheIntervalSeconds: FfiConverterInt64.read(from: &buf), 
-                maxAgeSeconds: FfiConverterInt64.read(from: &buf)
+            try AxisEstimate(
+                velocity: FfiConverterDouble.read(from: &buf), 
+                positionVariance: FfiConverterDouble.read(from: &buf), 
+                covariance: FfiConverterDouble.read(from: &buf), 
+                velocityVariance: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: NavigationCachingConfig, into buf: inout [UInt8]) {
-        FfiConverterInt64.write(value.cacheIntervalSeconds, into: &buf)
-        FfiConverterInt64.write(value.maxAgeSeconds, into: &buf)
+    public static func write(_ value: AxisEstimate, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.velocity, into: &buf)
+        FfiConverterDouble.write(value.positionVariance, into: &buf)
+        FfiConverterDouble.write(value.covariance, into: &buf)
+        FfiConverterDouble.write(value.velocityVariance, into: &buf)
     }
 }
 
@@ -4871,77 +5348,42 @@ public struct FfiConverterTypeNavigationCachingConfig: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationCachingConfig_lift(_ buf: RustBuffer) throws -> NavigationCachingConfig {
-    return try FfiConverterTypeNavigationCachingConfig.lift(buf)
+public func FfiConverterTypeAxisEstimate_lift(_ buf: RustBuffer) throws -> AxisEstimate {
+    return try FfiConverterTypeAxisEstimate.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationCachingConfig_lower(_ value: NavigationCachingConfig) -> RustBuffer {
-    return FfiConverterTypeNavigationCachingConfig.lower(value)
+public func FfiConverterTypeAxisEstimate_lower(_ value: AxisEstimate) -> RustBuffer {
+    return FfiConverterTypeAxisEstimate.lower(value)
 }
 
 
-public struct NavigationControllerConfig {
-    /**
-     * Configures when navigation advances to the next waypoint in the route.
-     */
-    public var waypointAdvance: WaypointAdvanceMode
-    /**
-     * Configures when navigation advances to the next step in the route.
-     */
-    public var stepAdvanceCondition: StepAdvanceCondition
-    /**
-     * A special advance condition used for the final 2 route steps (last and arrival).
-     *
-     * This exists because several of our step advance conditions require entry and
-     * exit from a step's geometry. The end of the route/arrival doesn't always accommodate
-     * the expected location updates for the core step advance condition.
-     */
-    public var arrivalStepAdvanceCondition: StepAdvanceCondition
+/**
+ * A geographic bounding box defined by its corners.
+ */
+public struct BoundingBox: Equatable, Hashable, Codable {
     /**
-     * Configures when the user is deemed to be off course.
-     *
-     * NOTE: This is distinct from the action that is taken.
-     * It is only the determination that the user has deviated from the expected route.
+     * The southwest corner of the bounding box.
      */
-    public var routeDeviationTracking: RouteDeviationTracking
+    public var sw: GeographicCoordinate
     /**
-     * Configures how the heading component of the snapped location is reported in [`TripState`].
+     * The northeast corner of the bounding box.
      */
-    public var snappedLocationCourseFiltering: CourseFiltering
+    public var ne: GeographicCoordinate
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Configures when navigation advances to the next waypoint in the route.
-         */waypointAdvance: WaypointAdvanceMode, 
-        /**
-         * Configures when navigation advances to the next step in the route.
-         */stepAdvanceCondition: StepAdvanceCondition, 
-        /**
-         * A special advance condition used for the final 2 route steps (last and arrival).
-         *
-         * This exists because several of our step advance conditions require entry and
-         * exit from a step's geometry. The end of the route/arrival doesn't always accommodate
-         * the expected location updates for the core step advance condition.
-         */arrivalStepAdvanceCondition: StepAdvanceCondition, 
-        /**
-         * Configures when the user is deemed to be off course.
-         *
-         * NOTE: This is distinct from the action that is taken.
-         * It is only the determination that the user has deviated from the expected route.
-         */routeDeviationTracking: RouteDeviationTracking, 
+         * The southwest corner of the bounding box.
+         */sw: GeographicCoordinate, 
         /**
-         * Configures how the heading component of the snapped location is reported in [`TripState`].
-         */snappedLocationCourseFiltering: CourseFiltering) {
-        self.waypointAdvance = waypointAdvance
-        self.stepAdvanceCondition = stepAdvanceCondition
-        self.arrivalStepAdvanceCondition = arrivalStepAdvanceCondition
-        self.routeDeviationTracking = routeDeviationTracking
-        self.snappedLocationCourseFiltering = snappedLocationCourseFiltering
+         * The northeast corner of the bounding box.
+         */ne: GeographicCoordinate) {
+        self.sw = sw
+        self.ne = ne
     }
 
     
@@ -4950,30 +5392,24 @@ public struct NavigationControllerConfig {
 }
 
 #if compiler(>=6)
-extension NavigationControllerConfig: Sendable {}
+extension BoundingBox: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNavigationControllerConfig: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationControllerConfig {
+public struct FfiConverterTypeBoundingBox: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BoundingBox {
         return
-            try NavigationControllerConfig(
-                waypointAdvance: FfiConverterTypeWaypointAdvanceMode.read(from: &buf), 
-                stepAdvanceCondition: FfiConverterTypeStepAdvanceCondition.read(from: &buf), 
-                arrivalStepAdvanceCondition: FfiConverterTypeStepAdvanceCondition.read(from: &buf), 
-                routeDeviationTracking: FfiConverterTypeRouteDeviationTracking.read(from: &buf), 
-                snappedLocationCourseFiltering: FfiConverterTypeCourseFiltering.read(from: &buf)
+            try BoundingBox(
+                sw: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
+                ne: FfiConverterTypeGeographicCoordinate.read(from: &buf)
         )
     }
 
-    public static func write(_ value: NavigationControllerConfig, into buf: inout [UInt8]) {
-        FfiConverterTypeWaypointAdvanceMode.write(value.waypointAdvance, into: &buf)
-        FfiConverterTypeStepAdvanceCondition.write(value.stepAdvanceCondition, into: &buf)
-        FfiConverterTypeStepAdvanceCondition.write(value.arrivalStepAdvanceCondition, into: &buf)
-        FfiConverterTypeRouteDeviationTracking.write(value.routeDeviationTracking, into: &buf)
-        FfiConverterTypeCourseFiltering.write(value.snappedLocationCourseFiltering, into: &buf)
+    public static func write(_ value: BoundingBox, into buf: inout [UInt8]) {
+        FfiConverterTypeGeographicCoordinate.write(value.sw, into: &buf)
+        FfiConverterTypeGeographicCoordinate.write(value.ne, into: &buf)
     }
 }
 
@@ -4981,44 +5417,46 @@ public struct FfiConverterTypeNavigationControllerConfig: FfiConverterRustBuffer
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationControllerConfig_lift(_ buf: RustBuffer) throws -> NavigationControllerConfig {
-    return try FfiConverterTypeNavigationControllerConfig.lift(buf)
+public func FfiConverterTypeBoundingBox_lift(_ buf: RustBuffer) throws -> BoundingBox {
+    return try FfiConverterTypeBoundingBox.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationControllerConfig_lower(_ value: NavigationControllerConfig) -> RustBuffer {
-    return FfiConverterTypeNavigationControllerConfig.lower(value)
+public func FfiConverterTypeBoundingBox_lower(_ value: BoundingBox) -> RustBuffer {
+    return FfiConverterTypeBoundingBox.lower(value)
 }
 
 
 /**
- * An event that occurs during navigation.
- *
- * This is used for the optional session recording / telemetry.
+ * Details about congestion for an incident or a segment of the route.
  */
-public struct NavigationRecordingEvent: Equatable, Hashable, Codable {
-    /**
-     * The timestamp of the event in milliseconds since Jan 1, 1970 UTC.
-     */
-    public var timestamp: Int64
+public struct Congestion: Equatable, Hashable, Codable {
     /**
-     * Data associated with the event.
+     * The level of congestion.
+     *
+     * 0 = no congestion
+     *
+     * 100 = road closed
+     *
+     * Other values mean no congestion was calculated
      */
-    public var eventData: NavigationRecordingEventData
+    public var value: UInt8
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The timestamp of the event in milliseconds since Jan 1, 1970 UTC.
-         */timestamp: Int64, 
-        /**
-         * Data associated with the event.
-         */eventData: NavigationRecordingEventData) {
-        self.timestamp = timestamp
-        self.eventData = eventData
+         * The level of congestion.
+         *
+         * 0 = no congestion
+         *
+         * 100 = road closed
+         *
+         * Other values mean no congestion was calculated
+         */value: UInt8) {
+        self.value = value
     }
 
     
@@ -5027,24 +5465,22 @@ public struct NavigationRecordingEvent: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension NavigationRecordingEvent: Sendable {}
+extension Congestion: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNavigationRecordingEvent: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationRecordingEvent {
+public struct FfiConverterTypeCongestion: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Congestion {
         return
-            try NavigationRecordingEvent(
-                timestamp: FfiConverterInt64.read(from: &buf), 
-                eventData: FfiConverterTypeNavigationRecordingEventData.read(from: &buf)
+            try Congestion(
+                value: FfiConverterUInt8.read(from: &buf)
         )
     }
 
-    public static func write(_ value: NavigationRecordingEvent, into buf: inout [UInt8]) {
-        FfiConverterInt64.write(value.timestamp, into: &buf)
-        FfiConverterTypeNavigationRecordingEventData.write(value.eventData, into: &buf)
+    public static func write(_ value: Congestion, into buf: inout [UInt8]) {
+        FfiConverterUInt8.write(value.value, into: &buf)
     }
 }
 
@@ -5052,29 +5488,44 @@ public struct FfiConverterTypeNavigationRecordingEvent: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationRecordingEvent_lift(_ buf: RustBuffer) throws -> NavigationRecordingEvent {
-    return try FfiConverterTypeNavigationRecordingEvent.lift(buf)
+public func FfiConverterTypeCongestion_lift(_ buf: RustBuffer) throws -> Congestion {
+    return try FfiConverterTypeCongestion.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationRecordingEvent_lower(_ value: NavigationRecordingEvent) -> RustBuffer {
-    return FfiConverterTypeNavigationRecordingEvent.lower(value)
+public func FfiConverterTypeCongestion_lower(_ value: Congestion) -> RustBuffer {
+    return FfiConverterTypeCongestion.lower(value)
 }
 
 
-public struct NavigationSessionSnapshot: Equatable, Hashable, Codable {
-    public var savedAt: UtcDateTime
-    public var route: Route
-    public var tripState: TripState?
+/**
+ * The direction in which the user/device is observed to be traveling.
+ */
+public struct CourseOverGround: Equatable, Hashable, Codable {
+    /**
+     * The direction in which the user's device is traveling, measured in clockwise degrees from
+     * true north (N = 0, E = 90, S = 180, W = 270).
+     */
+    public var degrees: UInt16
+    /**
+     * The accuracy of the course value, measured in degrees.
+     */
+    public var accuracy: UInt16?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(savedAt: UtcDateTime, route: Route, tripState: TripState?) {
-        self.savedAt = savedAt
-        self.route = route
-        self.tripState = tripState
+    public init(
+        /**
+         * The direction in which the user's device is traveling, measured in clockwise degrees from
+         * true north (N = 0, E = 90, S = 180, W = 270).
+         */degrees: UInt16, 
+        /**
+         * The accuracy of the course value, measured in degrees.
+         */accuracy: UInt16?) {
+        self.degrees = degrees
+        self.accuracy = accuracy
     }
 
     
@@ -5083,26 +5534,24 @@ public struct NavigationSessionSnapshot: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension NavigationSessionSnapshot: Sendable {}
+extension CourseOverGround: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNavigationSessionSnapshot: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationSessionSnapshot {
+public struct FfiConverterTypeCourseOverGround: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CourseOverGround {
         return
-            try NavigationSessionSnapshot(
-                savedAt: FfiConverterTypeUtcDateTime.read(from: &buf), 
-                route: FfiConverterTypeRoute.read(from: &buf), 
-                tripState: FfiConverterOptionTypeTripState.read(from: &buf)
+            try CourseOverGround(
+                degrees: FfiConverterUInt16.read(from: &buf), 
+                accuracy: FfiConverterOptionUInt16.read(from: &buf)
         )
     }
 
-    public static func write(_ value: NavigationSessionSnapshot, into buf: inout [UInt8]) {
-        FfiConverterTypeUtcDateTime.write(value.savedAt, into: &buf)
-        FfiConverterTypeRoute.write(value.route, into: &buf)
-        FfiConverterOptionTypeTripState.write(value.tripState, into: &buf)
+    public static func write(_ value: CourseOverGround, into buf: inout [UInt8]) {
+        FfiConverterUInt16.write(value.degrees, into: &buf)
+        FfiConverterOptionUInt16.write(value.accuracy, into: &buf)
     }
 }
 
@@ -5110,48 +5559,57 @@ public struct FfiConverterTypeNavigationSessionSnapshot: FfiConverterRustBuffer
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationSessionSnapshot_lift(_ buf: RustBuffer) throws -> NavigationSessionSnapshot {
-    return try FfiConverterTypeNavigationSessionSnapshot.lift(buf)
+public func FfiConverterTypeCourseOverGround_lift(_ buf: RustBuffer) throws -> CourseOverGround {
+    return try FfiConverterTypeCourseOverGround.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationSessionSnapshot_lower(_ value: NavigationSessionSnapshot) -> RustBuffer {
-    return FfiConverterTypeNavigationSessionSnapshot.lower(value)
+public func FfiConverterTypeCourseOverGround_lower(_ value: CourseOverGround) -> RustBuffer {
+    return FfiConverterTypeCourseOverGround.lower(value)
 }
 
 
 /**
- * Waypoint properties parsed from an OSRM-compatible server response.
+ * Configures dead reckoning along the route during GPS outages (ex: in tunnels).
  *
- * NOTE: Some servers (such as Valhalla) may support additional parameters at request time
- * which are _not_ echoed back in the response time.
- * This is unfortunate; PRs upstream would likely be welcomed!
- * Similarly, if your server is OSRM-compatible and returns additional attributes,
- * feel free to open a PR to include these as optional properties.
+ * When location fixes are too inaccurate to be trusted,
+ * or stop arriving altogether (see [`Navigator::estimate_location`](super::Navigator::estimate_location)),
+ * the navigation controller instead advances the user along the route geometry.
+ * The speed is taken from the last trustworthy fix,
+ * falling back to the speed annotations (or the planned speed) of the current step.
+ *
+ * Estimates never run past the end of the current step,
+ * so navigation continues smoothly from the next trustworthy fix.
  */
-public struct OsrmWaypointProperties: Equatable, Hashable, Codable {
+public struct DeadReckoningConfig: Equatable, Hashable, Codable {
     /**
-     * The name of the street that the waypoint snapped to.
+     * Location fixes with a horizontal accuracy worse than this (in meters) are replaced by an estimate.
      */
-    public var name: String?
+    public var maximumHorizontalAccuracy: Double
     /**
-     * The distance (in meters) between the snapped point and the input coordinate.
+     * The longest time (in seconds) since the last trustworthy fix that the location is estimated for.
+     *
+     * After this, location fixes are used as reported again,
+     * as the estimate is increasingly likely to be wrong.
      */
-    public var distance: Double?
+    public var maximumDuration: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The name of the street that the waypoint snapped to.
-         */name: String?, 
+         * Location fixes with a horizontal accuracy worse than this (in meters) are replaced by an estimate.
+         */maximumHorizontalAccuracy: Double, 
         /**
-         * The distance (in meters) between the snapped point and the input coordinate.
-         */distance: Double?) {
-        self.name = name
-        self.distance = distance
+         * The longest time (in seconds) since the last trustworthy fix that the location is estimated for.
+         *
+         * After this, location fixes are used as reported again,
+         * as the estimate is increasingly likely to be wrong.
+         */maximumDuration: Double) {
+        self.maximumHorizontalAccuracy = maximumHorizontalAccuracy
+        self.maximumDuration = maximumDuration
     }
 
     
@@ -5160,24 +5618,24 @@ public struct OsrmWaypointProperties: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension OsrmWaypointProperties: Sendable {}
+extension DeadReckoningConfig: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeOsrmWaypointProperties: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OsrmWaypointProperties {
+public struct FfiConverterTypeDeadReckoningConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeadReckoningConfig {
         return
-            try OsrmWaypointProperties(
-                name: FfiConverterOptionString.read(from: &buf), 
-                distance: FfiConverterOptionDouble.read(from: &buf)
+            try DeadReckoningConfig(
+                maximumHorizontalAccuracy: FfiConverterDouble.read(from: &buf), 
+                maximumDuration: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: OsrmWaypointProperties, into buf: inout [UInt8]) {
-        FfiConverterOptionString.write(value.name, into: &buf)
-        FfiConverterOptionDouble.write(value.distance, into: &buf)
+    public static func write(_ value: DeadReckoningConfig, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.maximumHorizontalAccuracy, into: &buf)
+        FfiConverterDouble.write(value.maximumDuration, into: &buf)
     }
 }
 
@@ -5185,55 +5643,42 @@ public struct FfiConverterTypeOsrmWaypointProperties: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeOsrmWaypointProperties_lift(_ buf: RustBuffer) throws -> OsrmWaypointProperties {
-    return try FfiConverterTypeOsrmWaypointProperties.lift(buf)
+public func FfiConverterTypeDeadReckoningConfig_lift(_ buf: RustBuffer) throws -> DeadReckoningConfig {
+    return try FfiConverterTypeDeadReckoningConfig.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeOsrmWaypointProperties_lower(_ value: OsrmWaypointProperties) -> RustBuffer {
-    return FfiConverterTypeOsrmWaypointProperties.lower(value)
+public func FfiConverterTypeDeadReckoningConfig_lower(_ value: DeadReckoningConfig) -> RustBuffer {
+    return FfiConverterTypeDeadReckoningConfig.lower(value)
 }
 
 
 /**
- * Information describing the series of steps needed to travel between two or more points.
- *
- * NOTE: This type is unstable and is still under active development and should be
- * considered unstable.
+ * A geographic coordinate in WGS84.
  */
-public struct Route: Equatable, Hashable, Codable {
-    public var geometry: [GeographicCoordinate]
-    public var bbox: BoundingBox
+public struct GeographicCoordinate: Equatable, Hashable, Codable {
     /**
-     * The total route distance, in meters.
+     * The latitude (in degrees).
      */
-    public var distance: Double
+    public var lat: Double
     /**
-     * The ordered list of waypoints to visit, including the starting point.
-     * Note that this is distinct from the *geometry* which includes all points visited.
-     * A waypoint represents a start/end point for a route leg.
+     * The Longitude (in degrees).
      */
-    public var waypoints: [Waypoint]
-    public var steps: [RouteStep]
+    public var lng: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(geometry: [GeographicCoordinate], bbox: BoundingBox, 
+    public init(
         /**
-         * The total route distance, in meters.
-         */distance: Double, 
+         * The latitude (in degrees).
+         */lat: Double, 
         /**
-         * The ordered list of waypoints to visit, including the starting point.
-         * Note that this is distinct from the *geometry* which includes all points visited.
-         * A waypoint represents a start/end point for a route leg.
-         */waypoints: [Waypoint], steps: [RouteStep]) {
-        self.geometry = geometry
-        self.bbox = bbox
-        self.distance = distance
-        self.waypoints = waypoints
-        self.steps = steps
+         * The Longitude (in degrees).
+         */lng: Double) {
+        self.lat = lat
+        self.lng = lng
     }
 
     
@@ -5242,30 +5687,24 @@ public struct Route: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension Route: Sendable {}
+extension GeographicCoordinate: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRoute: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Route {
+public struct FfiConverterTypeGeographicCoordinate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GeographicCoordinate {
         return
-            try Route(
-                geometry: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
-                bbox: FfiConverterTypeBoundingBox.read(from: &buf), 
-                distance: FfiConverterDouble.read(from: &buf), 
-                waypoints: FfiConverterSequenceTypeWaypoint.read(from: &buf), 
-                steps: FfiConverterSequenceTypeRouteStep.read(from: &buf)
+            try GeographicCoordinate(
+                lat: FfiConverterDouble.read(from: &buf), 
+                lng: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: Route, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypeGeographicCoordinate.write(value.geometry, into: &buf)
-        FfiConverterTypeBoundingBox.write(value.bbox, into: &buf)
-        FfiConverterDouble.write(value.distance, into: &buf)
-        FfiConverterSequenceTypeWaypoint.write(value.waypoints, into: &buf)
-        FfiConverterSequenceTypeRouteStep.write(value.steps, into: &buf)
+    public static func write(_ value: GeographicCoordinate, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.lat, into: &buf)
+        FfiConverterDouble.write(value.lng, into: &buf)
     }
 }
 
@@ -5273,137 +5712,50 @@ public struct FfiConverterTypeRoute: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRoute_lift(_ buf: RustBuffer) throws -> Route {
-    return try FfiConverterTypeRoute.lift(buf)
+public func FfiConverterTypeGeographicCoordinate_lift(_ buf: RustBuffer) throws -> GeographicCoordinate {
+    return try FfiConverterTypeGeographicCoordinate.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRoute_lower(_ value: Route) -> RustBuffer {
-    return FfiConverterTypeRoute.lower(value)
+public func FfiConverterTypeGeographicCoordinate_lower(_ value: GeographicCoordinate) -> RustBuffer {
+    return FfiConverterTypeGeographicCoordinate.lower(value)
 }
 
 
 /**
- * A maneuver (such as a turn or merge) followed by travel of a certain distance until reaching
- * the next step.
+ * The heading of the user/device.
  */
-public struct RouteStep: Equatable, Hashable, Codable {
-    /**
-     * The full route geometry for this step.
-     */
-    public var geometry: [GeographicCoordinate]
-    /**
-     * The distance, in meters, to travel along the route after the maneuver to reach the next step.
-     */
-    public var distance: Double
-    /**
-     * The estimated duration, in seconds, that it will take to complete this step.
-     */
-    public var duration: Double
-    /**
-     * The name of the road being traveled on (useful for certain UI styles).
-     */
-    public var roadName: String?
-    /**
-     * A list of exits (name or number).
-     */
-    public var exits: [String]
-    /**
-     * A description of the maneuver (ex: "Turn wright onto main street").
-     *
-     * Note for UI implementers: the context this appears in (or doesn't)
-     * depends somewhat on your use case and routing engine.
-     * For example, this field is useful as a written instruction in Valhalla.
-     */
-    public var instruction: String
-    /**
-     * A list of instructions for visual display (usually as banners) at specific points along the step.
-     */
-    public var visualInstructions: [VisualInstruction]
-    /**
-     * A list of prompts to announce (via speech synthesis) at specific points along the step.
-     */
-    public var spokenInstructions: [SpokenInstruction]
-    /**
-     * A list of json encoded strings representing annotations between each coordinate along the step.
-     */
-    public var annotations: [String]?
+public struct Heading: Equatable, Hashable, Codable {
     /**
-     * A list of incidents that occur along the step.
+     * The heading in degrees relative to true north.
      */
-    public var incidents: [Incident]
+    public var trueHeading: UInt16
     /**
-     * Which side of the road traffic drives on for this step.
-     *
-     * This is relevant for roundabouts: left-hand traffic (e.g. UK) uses clockwise roundabouts,
-     * while right-hand traffic uses counterclockwise roundabouts.
+     * The platform specific accuracy of the heading value.
      */
-    public var drivingSide: DrivingSide?
+    public var accuracy: UInt16
     /**
-     * The exit number when entering a roundabout (1 = first exit, 2 = second, etc.).
+     * The time at which the heading was recorded.
      */
-    public var roundaboutExitNumber: UInt8?
+    public var timestamp: Date
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The full route geometry for this step.
-         */geometry: [GeographicCoordinate], 
-        /**
-         * The distance, in meters, to travel along the route after the maneuver to reach the next step.
-         */distance: Double, 
-        /**
-         * The estimated duration, in seconds, that it will take to complete this step.
-         */duration: Double, 
-        /**
-         * The name of the road being traveled on (useful for certain UI styles).
-         */roadName: String?, 
-        /**
-         * A list of exits (name or number).
-         */exits: [String], 
-        /**
-         * A description of the maneuver (ex: "Turn wright onto main street").
-         *
-         * Note for UI implementers: the context this appears in (or doesn't)
-         * depends somewhat on your use case and routing engine.
-         * For example, this field is useful as a written instruction in Valhalla.
-         */instruction: String, 
-        /**
-         * A list of instructions for visual display (usually as banners) at specific points along the step.
-         */visualInstructions: [VisualInstruction], 
-        /**
-         * A list of prompts to announce (via speech synthesis) at specific points along the step.
-         */spokenInstructions: [SpokenInstruction], 
-        /**
-         * A list of json encoded strings representing annotations between each coordinate along the step.
-         */annotations: [String]?, 
-        /**
-         * A list of incidents that occur along the step.
-         */incidents: [Incident], 
+         * The heading in degrees relative to true north.
+         */trueHeading: UInt16, 
         /**
-         * Which side of the road traffic drives on for this step.
-         *
-         * This is relevant for roundabouts: left-hand traffic (e.g. UK) uses clockwise roundabouts,
-         * while right-hand traffic uses counterclockwise roundabouts.
-         */drivingSide: DrivingSide?, 
+         * The platform specific accuracy of the heading value.
+         */accuracy: UInt16, 
         /**
-         * The exit number when entering a roundabout (1 = first exit, 2 = second, etc.).
-         */roundaboutExitNumber: UInt8?) {
-        self.geometry = geometry
-        self.distance = distance
-        self.duration = duration
-        self.roadName = roadName
-        self.exits = exits
-        self.instruction = instruction
-        self.visualInstructions = visualInstructions
-        self.spokenInstructions = spokenInstructions
-        self.annotations = annotations
-        self.incidents = incidents
-        self.drivingSide = drivingSide
-        self.roundaboutExitNumber = roundaboutExitNumber
+         * The time at which the heading was recorded.
+         */timestamp: Date) {
+        self.trueHeading = trueHeading
+        self.accuracy = accuracy
+        self.timestamp = timestamp
     }
 
     
@@ -5412,44 +5764,26 @@ public struct RouteStep: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension RouteStep: Sendable {}
+extension Heading: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRouteStep: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteStep {
+public struct FfiConverterTypeHeading: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Heading {
         return
-            try RouteStep(
-                geometry: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
-                distance: FfiConverterDouble.read(from: &buf), 
-                duration: FfiConverterDouble.read(from: &buf), 
-                roadName: FfiConverterOptionString.read(from: &buf), 
-                exits: FfiConverterSequenceString.read(from: &buf), 
-                instruction: FfiConverterString.read(from: &buf), 
-                visualInstructions: FfiConverterSequenceTypeVisualInstruction.read(from: &buf), 
-                spokenInstructions: FfiConverterSequenceTypeSpokenInstruction.read(from: &buf), 
-                annotations: FfiConverterOptionSequenceString.read(from: &buf), 
-                incidents: FfiConverterSequenceTypeIncident.read(from: &buf), 
-                drivingSide: FfiConverterOptionTypeDrivingSide.read(from: &buf), 
-                roundaboutExitNumber: FfiConverterOptionUInt8.read(from: &buf)
+            try Heading(
+                trueHeading: FfiConverterUInt16.read(from: &buf), 
+                accuracy: FfiConverterUInt16.read(from: &buf), 
+                timestamp: FfiConverterTimestamp.read(from: &buf)
         )
     }
 
-    public static func write(_ value: RouteStep, into buf: inout [UInt8]) {
-        FfiConverterSequenceTypeGeographicCoordinate.write(value.geometry, into: &buf)
-        FfiConverterDouble.write(value.distance, into: &buf)
-        FfiConverterDouble.write(value.duration, into: &buf)
-        FfiConverterOptionString.write(value.roadName, into: &buf)
-        FfiConverterSequenceString.write(value.exits, into: &buf)
-        FfiConverterString.write(value.instruction, into: &buf)
-        FfiConverterSequenceTypeVisualInstruction.write(value.visualInstructions, into: &buf)
-        FfiConverterSequenceTypeSpokenInstruction.write(value.spokenInstructions, into: &buf)
-        FfiConverterOptionSequenceString.write(value.annotations, into: &buf)
-        FfiConverterSequenceTypeIncident.write(value.incidents, into: &buf)
-        FfiConverterOptionTypeDrivingSide.write(value.drivingSide, into: &buf)
-        FfiConverterOptionUInt8.write(value.roundaboutExitNumber, into: &buf)
+    public static func write(_ value: Heading, into buf: inout [UInt8]) {
+        FfiConverterUInt16.write(value.trueHeading, into: &buf)
+        FfiConverterUInt16.write(value.accuracy, into: &buf)
+        FfiConverterTimestamp.write(value.timestamp, into: &buf)
     }
 }
 
@@ -5457,96 +5791,183 @@ public struct FfiConverterTypeRouteStep: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteStep_lift(_ buf: RustBuffer) throws -> RouteStep {
-    return try FfiConverterTypeRouteStep.lift(buf)
+public func FfiConverterTypeHeading_lift(_ buf: RustBuffer) throws -> Heading {
+    return try FfiConverterTypeHeading.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRouteStep_lower(_ value: RouteStep) -> RustBuffer {
-    return FfiConverterTypeRouteStep.lower(value)
-}
-
-
-public struct SerializableNavState: Equatable, Hashable, Codable {
-    public var tripState: TripState
-    public var stepAdvanceCondition: SerializableStepAdvanceCondition
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(tripState: TripState, stepAdvanceCondition: SerializableStepAdvanceCondition) {
-        self.tripState = tripState
-        self.stepAdvanceCondition = stepAdvanceCondition
-    }
-
-    
-
-    
-}
-
-#if compiler(>=6)
-extension SerializableNavState: Sendable {}
-#endif
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeSerializableNavState: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SerializableNavState {
-        return
-            try SerializableNavState(
-                tripState: FfiConverterTypeTripState.read(from: &buf), 
-                stepAdvanceCondition: FfiConverterTypeSerializableStepAdvanceCondition.read(from: &buf)
-        )
-    }
-
-    public static func write(_ value: SerializableNavState, into buf: inout [UInt8]) {
-        FfiConverterTypeTripState.write(value.tripState, into: &buf)
-        FfiConverterTypeSerializableStepAdvanceCondition.write(value.stepAdvanceCondition, into: &buf)
-    }
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeSerializableNavState_lift(_ buf: RustBuffer) throws -> SerializableNavState {
-    return try FfiConverterTypeSerializableNavState.lift(buf)
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeSerializableNavState_lower(_ value: SerializableNavState) -> RustBuffer {
-    return FfiConverterTypeSerializableNavState.lower(value)
+public func FfiConverterTypeHeading_lower(_ value: Heading) -> RustBuffer {
+    return FfiConverterTypeHeading.lower(value)
 }
 
 
 /**
- * The speed of the user from the location provider.
+ * An incident affecting the free flow of traffic,
+ * such as constructions, accidents, and congestion.
  */
-public struct Speed: Equatable, Hashable, Codable {
+public struct Incident: Equatable, Hashable, Codable {
     /**
-     * The user's speed in meters per second.
+     * A unique identifier for the incident.
      */
-    public var value: Double
+    public var id: String
     /**
-     * The accuracy of the speed value, measured in meters per second.
+     * The type of incident.
      */
-    public var accuracy: Double?
+    public var incidentType: IncidentType
+    /**
+     * A short description of the incident.
+     */
+    public var description: String?
+    /**
+     * A longer description of the incident.
+     */
+    public var longDescription: String?
+    /**
+     * The time at which the incident was *last* created.
+     *
+     * NB: This can change throughout the life of the incident.
+     */
+    public var creationTime: UtcDateTime?
+    /**
+     * The time at which the incident started or is expected to start (ex: planned closure).
+     */
+    public var startTime: UtcDateTime?
+    /**
+     * The time at which the incident ended or is expected to end.
+     */
+    public var endTime: UtcDateTime?
+    /**
+     * The level of impact to traffic.
+     */
+    public var impact: Impact?
+    /**
+     * Lanes which are blocked by the incident.
+     */
+    public var lanesBlocked: [BlockedLane]
+    /**
+     * Info about the amount of congestion on the road around the incident.
+     */
+    public var congestion: Congestion?
+    /**
+     * Is the road completely closed?
+     */
+    public var closed: Bool?
+    /**
+     * The index into the [`RouteStep`] geometry where the incident starts.
+     */
+    public var geometryIndexStart: UInt64
+    /**
+     * The index into the [`RouteStep`] geometry where the incident ends.
+     */
+    public var geometryIndexEnd: UInt64?
+    /**
+     * Optional additional information about the type of incident (free-form text).
+     */
+    public var subType: String?
+    /**
+     * Optional descriptions about the type of incident (free-form text).
+     */
+    public var subTypeDescription: String?
+    /**
+     * The ISO 3166-1 alpha-2 code of the country in which the incident occurs.
+     */
+    public var iso31661Alpha2: String?
+    /**
+     * The ISO 3166-1 alpha-3 code of the country in which the incident occurs.
+     */
+    public var iso31661Alpha3: String?
+    /**
+     * A list of road names affected by the incident.
+     */
+    public var affectedRoadNames: [String]
+    /**
+     * The bounding box over which the incident occurs.
+     */
+    public var bbox: BoundingBox?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The user's speed in meters per second.
-         */value: Double, 
+         * A unique identifier for the incident.
+         */id: String, 
         /**
-         * The accuracy of the speed value, measured in meters per second.
-         */accuracy: Double?) {
-        self.value = value
-        self.accuracy = accuracy
+         * The type of incident.
+         */incidentType: IncidentType, 
+        /**
+         * A short description of the incident.
+         */description: String?, 
+        /**
+         * A longer description of the incident.
+         */longDescription: String?, 
+        /**
+         * The time at which the incident was *last* created.
+         *
+         * NB: This can change throughout the life of the incident.
+         */creationTime: UtcDateTime?, 
+        /**
+         * The time at which the incident started or is expected to start (ex: planned closure).
+         */startTime: UtcDateTime?, 
+        /**
+         * The time at which the incident ended or is expected to end.
+         */endTime: UtcDateTime?, 
+        /**
+         * The level of impact to traffic.
+         */impact: Impact?, 
+        /**
+         * Lanes which are blocked by the incident.
+         */lanesBlocked: [BlockedLane], 
+        /**
+         * Info about the amount of congestion on the road around the incident.
+         */congestion: Congestion?, 
+        /**
+         * Is the road completely closed?
+         */closed: Bool?, 
+        /**
+         * The index into the [`RouteStep`] geometry where the incident starts.
+         */geometryIndexStart: UInt64, 
+        /**
+         * The index into the [`RouteStep`] geometry where the incident ends.
+         */geometryIndexEnd: UInt64?, 
+        /**
+         * Optional additional information about the type of incident (free-form text).
+         */subType: String?, 
+        /**
+         * Optional descriptions about the type of incident (free-form text).
+         */subTypeDescription: String?, 
+        /**
+         * The ISO 3166-1 alpha-2 code of the country in which the incident occurs.
+         */iso31661Alpha2: String?, 
+        /**
+         * The ISO 3166-1 alpha-3 code of the country in which the incident occurs.
+         */iso31661Alpha3: String?, 
+        /**
+         * A list of road names affected by the incident.
+         */affectedRoadNames: [String], 
+        /**
+         * The bounding box over which the incident occurs.
+         */bbox: BoundingBox?) {
+        self.id = id
+        self.incidentType = incidentType
+        self.description = description
+        self.longDescription = longDescription
+        self.creationTime = creationTime
+        self.startTime = startTime
+        self.endTime = endTime
+        self.impact = impact
+        self.lanesBlocked = lanesBlocked
+        self.congestion = congestion
+        self.closed = closed
+        self.geometryIndexStart = geometryIndexStart
+        self.geometryIndexEnd = geometryIndexEnd
+        self.subType = subType
+        self.subTypeDescription = subTypeDescription
+        self.iso31661Alpha2 = iso31661Alpha2
+        self.iso31661Alpha3 = iso31661Alpha3
+        self.affectedRoadNames = affectedRoadNames
+        self.bbox = bbox
     }
 
     
@@ -5555,24 +5976,58 @@ public struct Speed: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension Speed: Sendable {}
+extension Incident: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSpeed: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Speed {
+public struct FfiConverterTypeIncident: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Incident {
         return
-            try Speed(
-                value: FfiConverterDouble.read(from: &buf), 
-                accuracy: FfiConverterOptionDouble.read(from: &buf)
+            try Incident(
+                id: FfiConverterString.read(from: &buf), 
+                incidentType: FfiConverterTypeIncidentType.read(from: &buf), 
+                description: FfiConverterOptionString.read(from: &buf), 
+                longDescription: FfiConverterOptionString.read(from: &buf), 
+                creationTime: FfiConverterOptionTypeUtcDateTime.read(from: &buf), 
+                startTime: FfiConverterOptionTypeUtcDateTime.read(from: &buf), 
+                endTime: FfiConverterOptionTypeUtcDateTime.read(from: &buf), 
+                impact: FfiConverterOptionTypeImpact.read(from: &buf), 
+                lanesBlocked: FfiConverterSequenceTypeBlockedLane.read(from: &buf), 
+                congestion: FfiConverterOptionTypeCongestion.read(from: &buf), 
+                closed: FfiConverterOptionBool.read(from: &buf), 
+                geometryIndexStart: FfiConverterUInt64.read(from: &buf), 
+                geometryIndexEnd: FfiConverterOptionUInt64.read(from: &buf), 
+                subType: FfiConverterOptionString.read(from: &buf), 
+                subTypeDescription: FfiConverterOptionString.read(from: &buf), 
+                iso31661Alpha2: FfiConverterOptionString.read(from: &buf), 
+                iso31661Alpha3: FfiConverterOptionString.read(from: &buf), 
+                affectedRoadNames: FfiConverterSequenceString.read(from: &buf), 
+                bbox: FfiConverterOptionTypeBoundingBox.read(from: &buf)
         )
     }
 
-    public static func write(_ value: Speed, into buf: inout [UInt8]) {
-        FfiConverterDouble.write(value.value, into: &buf)
-        FfiConverterOptionDouble.write(value.accuracy, into: &buf)
+    public static func write(_ value: Incident, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterTypeIncidentType.write(value.incidentType, into: &buf)
+        FfiConverterOptionString.write(value.description, into: &buf)
+        FfiConverterOptionString.write(value.longDescription, into: &buf)
+        FfiConverterOptionTypeUtcDateTime.write(value.creationTime, into: &buf)
+        FfiConverterOptionTypeUtcDateTime.write(value.startTime, into: &buf)
+        FfiConverterOptionTypeUtcDateTime.write(value.endTime, into: &buf)
+        FfiConverterOptionTypeImpact.write(value.impact, into: &buf)
+        FfiConverterSequenceTypeBlockedLane.write(value.lanesBlocked, into: &buf)
+        FfiConverterOptionTypeCongestion.write(value.congestion, into: &buf)
+        FfiConverterOptionBool.write(value.closed, into: &buf)
+        FfiConverterUInt64.write(value.geometryIndexStart, into: &buf)
+        FfiConverterOptionUInt64.write(value.geometryIndexEnd, into: &buf)
+        FfiConverterOptionString.write(value.subType, into: &buf)
+        FfiConverterOptionString.write(value.subTypeDescription, into: &buf)
+        FfiConverterOptionString.write(value.iso31661Alpha2, into: &buf)
+        FfiConverterOptionString.write(value.iso31661Alpha3, into: &buf)
+        FfiConverterSequenceString.write(value.affectedRoadNames, into: &buf)
+        FfiConverterOptionTypeBoundingBox.write(value.bbox, into: &buf)
     }
 }
 
@@ -5580,74 +6035,34 @@ public struct FfiConverterTypeSpeed: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpeed_lift(_ buf: RustBuffer) throws -> Speed {
-    return try FfiConverterTypeSpeed.lift(buf)
+public func FfiConverterTypeIncident_lift(_ buf: RustBuffer) throws -> Incident {
+    return try FfiConverterTypeIncident.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpeed_lower(_ value: Speed) -> RustBuffer {
-    return FfiConverterTypeSpeed.lower(value)
+public func FfiConverterTypeIncident_lower(_ value: Incident) -> RustBuffer {
+    return FfiConverterTypeIncident.lower(value)
 }
 
 
 /**
- * An instruction that can be synthesized using a TTS engine to announce an upcoming maneuver.
- *
- * Note that these do not have any locale information attached.
+ * Configures the reporting of incidents along the route (see `upcoming_incident` in [`TripState::Navigating`]).
  */
-public struct SpokenInstruction: Equatable, Hashable, Codable {
+public struct IncidentsConfig: Equatable, Hashable, Codable {
     /**
-     * Plain-text instruction which can be synthesized with a TTS engine.
-     */
-    public var text: String
-    /**
-     * Speech Synthesis Markup Language, which should be preferred by clients capable of understanding it.
+     * Incidents farther than this distance (in meters) along the route are not reported.
      */
-    public var ssml: String?
-    /**
-     * How far (in meters) from the upcoming maneuver the instruction should start being spoken.
-     */
-    public var triggerDistanceBeforeManeuver: Double
-    /**
-     * A unique identifier for this instruction.
-     *
-     * This is provided so that platform-layer integrations can easily disambiguate between distinct utterances,
-     * which may have the same textual content.
-     * UUIDs conveniently fill this purpose.
-     *
-     * NOTE: While it is possible to deterministically create UUIDs, we do not do so at this time.
-     * This should be theoretically possible though if someone cares to write up a proposal and a PR.
-     */
-    public var utteranceId: Uuid
+    public var maximumDistance: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Plain-text instruction which can be synthesized with a TTS engine.
-         */text: String, 
-        /**
-         * Speech Synthesis Markup Language, which should be preferred by clients capable of understanding it.
-         */ssml: String?, 
-        /**
-         * How far (in meters) from the upcoming maneuver the instruction should start being spoken.
-         */triggerDistanceBeforeManeuver: Double, 
-        /**
-         * A unique identifier for this instruction.
-         *
-         * This is provided so that platform-layer integrations can easily disambiguate between distinct utterances,
-         * which may have the same textual content.
-         * UUIDs conveniently fill this purpose.
-         *
-         * NOTE: While it is possible to deterministically create UUIDs, we do not do so at this time.
-         * This should be theoretically possible though if someone cares to write up a proposal and a PR.
-         */utteranceId: Uuid) {
-        self.text = text
-        self.ssml = ssml
-        self.triggerDistanceBeforeManeuver = triggerDistanceBeforeManeuver
-        self.utteranceId = utteranceId
+         * Incidents farther than this distance (in meters) along the route are not reported.
+         */maximumDistance: Double) {
+        self.maximumDistance = maximumDistance
     }
 
     
@@ -5656,28 +6071,22 @@ public struct SpokenInstruction: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension SpokenInstruction: Sendable {}
+extension IncidentsConfig: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSpokenInstruction: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpokenInstruction {
+public struct FfiConverterTypeIncidentsConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IncidentsConfig {
         return
-            try SpokenInstruction(
-                text: FfiConverterString.read(from: &buf), 
-                ssml: FfiConverterOptionString.read(from: &buf), 
-                triggerDistanceBeforeManeuver: FfiConverterDouble.read(from: &buf), 
-                utteranceId: FfiConverterTypeUuid.read(from: &buf)
+            try IncidentsConfig(
+                maximumDistance: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: SpokenInstruction, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.text, into: &buf)
-        FfiConverterOptionString.write(value.ssml, into: &buf)
-        FfiConverterDouble.write(value.triggerDistanceBeforeManeuver, into: &buf)
-        FfiConverterTypeUuid.write(value.utteranceId, into: &buf)
+    public static func write(_ value: IncidentsConfig, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.maximumDistance, into: &buf)
     }
 }
 
@@ -5685,67 +6094,32 @@ public struct FfiConverterTypeSpokenInstruction: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpokenInstruction_lift(_ buf: RustBuffer) throws -> SpokenInstruction {
-    return try FfiConverterTypeSpokenInstruction.lift(buf)
+public func FfiConverterTypeIncidentsConfig_lift(_ buf: RustBuffer) throws -> IncidentsConfig {
+    return try FfiConverterTypeIncidentsConfig.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSpokenInstruction_lower(_ value: SpokenInstruction) -> RustBuffer {
-    return FfiConverterTypeSpokenInstruction.lower(value)
+public func FfiConverterTypeIncidentsConfig_lower(_ value: IncidentsConfig) -> RustBuffer {
+    return FfiConverterTypeIncidentsConfig.lower(value)
 }
 
 
 /**
- * The step advance result is produced on every iteration of the navigation state machine and
- * used by the navigation to build a new [`NavState`](super::NavState) instance for that update.
+ * The content of a visual instruction.
  */
-public struct StepAdvanceResult {
-    /**
-     * The step should be advanced.
-     */
-    public var shouldAdvance: Bool
-    /**
-     * The next iteration of the step advance condition.
-     *
-     * This is what the navigation controller passes to the next instance of [`NavState`](super::NavState) on the completion of
-     * an update (e.g. a user location update). Use the helper methods on [`StepAdvanceResult`] to ensure proper state handling:
-     *
-     * - [`StepAdvanceResult::next()`] - Unified method that takes a boolean and handles state appropriately
-     * - [`StepAdvanceResult::advance()`] - Creates an advancing result with automatically reset state
-     * - [`StepAdvanceResult::no_advance()`] - Creates a non-advancing result preserving current state
-     *
-     * The trait method [`StepAdvanceCondition::new_instance()`] ensures that composite conditions
-     * (Or/And) properly reset all nested conditions when any condition triggers advancement.
-     *
-     * **CRITICAL**: When advancing, this must be a clean/reset state to prevent state leakage between steps.
-     */
-    public var nextIteration: StepAdvanceCondition
+public struct LaneInfo: Equatable, Hashable, Codable {
+    public var active: Bool
+    public var directions: [String]
+    public var activeDirection: String?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(
-        /**
-         * The step should be advanced.
-         */shouldAdvance: Bool, 
-        /**
-         * The next iteration of the step advance condition.
-         *
-         * This is what the navigation controller passes to the next instance of [`NavState`](super::NavState) on the completion of
-         * an update (e.g. a user location update). Use the helper methods on [`StepAdvanceResult`] to ensure proper state handling:
-         *
-         * - [`StepAdvanceResult::next()`] - Unified method that takes a boolean and handles state appropriately
-         * - [`StepAdvanceResult::advance()`] - Creates an advancing result with automatically reset state
-         * - [`StepAdvanceResult::no_advance()`] - Creates a non-advancing result preserving current state
-         *
-         * The trait method [`StepAdvanceCondition::new_instance()`] ensures that composite conditions
-         * (Or/And) properly reset all nested conditions when any condition triggers advancement.
-         *
-         * **CRITICAL**: When advancing, this must be a clean/reset state to prevent state leakage between steps.
-         */nextIteration: StepAdvanceCondition) {
-        self.shouldAdvance = shouldAdvance
-        self.nextIteration = nextIteration
+    public init(active: Bool, directions: [String], activeDirection: String?) {
+        self.active = active
+        self.directions = directions
+        self.activeDirection = activeDirection
     }
 
     
@@ -5754,24 +6128,26 @@ public struct StepAdvanceResult {
 }
 
 #if compiler(>=6)
-extension StepAdvanceResult: Sendable {}
+extension LaneInfo: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeStepAdvanceResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StepAdvanceResult {
+public struct FfiConverterTypeLaneInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LaneInfo {
         return
-            try StepAdvanceResult(
-                shouldAdvance: FfiConverterBool.read(from: &buf), 
-                nextIteration: FfiConverterTypeStepAdvanceCondition.read(from: &buf)
+            try LaneInfo(
+                active: FfiConverterBool.read(from: &buf), 
+                directions: FfiConverterSequenceString.read(from: &buf), 
+                activeDirection: FfiConverterOptionString.read(from: &buf)
         )
     }
 
-    public static func write(_ value: StepAdvanceResult, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.shouldAdvance, into: &buf)
-        FfiConverterTypeStepAdvanceCondition.write(value.nextIteration, into: &buf)
+    public static func write(_ value: LaneInfo, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.active, into: &buf)
+        FfiConverterSequenceString.write(value.directions, into: &buf)
+        FfiConverterOptionString.write(value.activeDirection, into: &buf)
     }
 }
 
@@ -5779,34 +6155,32 @@ public struct FfiConverterTypeStepAdvanceResult: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeStepAdvanceResult_lift(_ buf: RustBuffer) throws -> StepAdvanceResult {
-    return try FfiConverterTypeStepAdvanceResult.lift(buf)
+public func FfiConverterTypeLaneInfo_lift(_ buf: RustBuffer) throws -> LaneInfo {
+    return try FfiConverterTypeLaneInfo.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeStepAdvanceResult_lower(_ value: StepAdvanceResult) -> RustBuffer {
-    return FfiConverterTypeStepAdvanceResult.lower(value)
+public func FfiConverterTypeLaneInfo_lower(_ value: LaneInfo) -> RustBuffer {
+    return FfiConverterTypeLaneInfo.lower(value)
 }
 
 
 /**
- * High-level state describing progress through a route.
+ * Progress towards the end of a single route leg.
  */
-public struct TripProgress: Equatable, Hashable, Codable {
+public struct LegProgress: Equatable, Hashable, Codable {
     /**
-     * The distance to the next maneuver, in meters.
+     * The index of the leg within the route.
      */
-    public var distanceToNextManeuver: Double
+    public var legIndex: UInt32
     /**
-     * The total distance remaining in the trip, in meters.
-     *
-     * This is the sum of the distance remaining in the current step and the distance remaining in all subsequent steps.
+     * The distance from the user to the end of the leg, in meters.
      */
     public var distanceRemaining: Double
     /**
-     * The total duration remaining in the trip, in seconds.
+     * The estimated duration until the user reaches the end of the leg, in seconds.
      */
     public var durationRemaining: Double
 
@@ -5814,17 +6188,15 @@ public struct TripProgress: Equatable, Hashable, Codable {
     // declare one manually.
     public init(
         /**
-         * The distance to the next maneuver, in meters.
-         */distanceToNextManeuver: Double, 
+         * The index of the leg within the route.
+         */legIndex: UInt32, 
         /**
-         * The total distance remaining in the trip, in meters.
-         *
-         * This is the sum of the distance remaining in the current step and the distance remaining in all subsequent steps.
+         * The distance from the user to the end of the leg, in meters.
          */distanceRemaining: Double, 
         /**
-         * The total duration remaining in the trip, in seconds.
+         * The estimated duration until the user reaches the end of the leg, in seconds.
          */durationRemaining: Double) {
-        self.distanceToNextManeuver = distanceToNextManeuver
+        self.legIndex = legIndex
         self.distanceRemaining = distanceRemaining
         self.durationRemaining = durationRemaining
     }
@@ -5835,24 +6207,24 @@ public struct TripProgress: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension TripProgress: Sendable {}
+extension LegProgress: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTripProgress: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripProgress {
+public struct FfiConverterTypeLegProgress: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LegProgress {
         return
-            try TripProgress(
-                distanceToNextManeuver: FfiConverterDouble.read(from: &buf), 
+            try LegProgress(
+                legIndex: FfiConverterUInt32.read(from: &buf), 
                 distanceRemaining: FfiConverterDouble.read(from: &buf), 
                 durationRemaining: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TripProgress, into buf: inout [UInt8]) {
-        FfiConverterDouble.write(value.distanceToNextManeuver, into: &buf)
+    public static func write(_ value: LegProgress, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.legIndex, into: &buf)
         FfiConverterDouble.write(value.distanceRemaining, into: &buf)
         FfiConverterDouble.write(value.durationRemaining, into: &buf)
     }
@@ -5862,59 +6234,45 @@ public struct FfiConverterTypeTripProgress: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTripProgress_lift(_ buf: RustBuffer) throws -> TripProgress {
-    return try FfiConverterTypeTripProgress.lift(buf)
+public func FfiConverterTypeLegProgress_lift(_ buf: RustBuffer) throws -> LegProgress {
+    return try FfiConverterTypeLegProgress.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTripProgress_lower(_ value: TripProgress) -> RustBuffer {
-    return FfiConverterTypeTripProgress.lower(value)
+public func FfiConverterTypeLegProgress_lower(_ value: LegProgress) -> RustBuffer {
+    return FfiConverterTypeLegProgress.lower(value)
 }
 
 
 /**
- * Information pertaining to the user's full navigation trip. This includes
- * simple stats like total duration and distance.
+ * Configures the sanitation of location fixes before they are used for navigation.
+ *
+ * Fixes which are rejected leave the navigation state unchanged,
+ * and are reported via [`NavigationEvent::LocationRejected`].
+ * Fixes with invalid coordinates, and fixes which are older than (or as old as)
+ * the last accepted one are always rejected.
  */
-public struct TripSummary: Equatable, Hashable, Codable {
-    /**
-     * The total raw distance traveled in the trip, in meters.
-     */
-    public var distanceTraveled: Double
-    /**
-     * The total snapped distance traveled in the trip, in meters.
-     */
-    public var snappedDistanceTraveled: Double
-    /**
-     * When the trip was started.
-     */
-    public var startedAt: UtcDateTime
+public struct LocationFilterConfig: Equatable, Hashable, Codable {
     /**
-     * When the trip was completed or canceled.
+     * The fastest plausible speed (in meters per second) between consecutive fixes.
+     *
+     * The horizontal accuracy of both fixes is taken into account,
+     * so that jitter while moving slowly is not mistaken for a jump.
      */
-    public var endedAt: UtcDateTime?
+    public var maximumSpeed: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * The total raw distance traveled in the trip, in meters.
-         */distanceTraveled: Double, 
-        /**
-         * The total snapped distance traveled in the trip, in meters.
-         */snappedDistanceTraveled: Double, 
-        /**
-         * When the trip was started.
-         */startedAt: UtcDateTime, 
-        /**
-         * When the trip was completed or canceled.
-         */endedAt: UtcDateTime?) {
-        self.distanceTraveled = distanceTraveled
-        self.snappedDistanceTraveled = snappedDistanceTraveled
-        self.startedAt = startedAt
-        self.endedAt = endedAt
+         * The fastest plausible speed (in meters per second) between consecutive fixes.
+         *
+         * The horizontal accuracy of both fixes is taken into account,
+         * so that jitter while moving slowly is not mistaken for a jump.
+         */maximumSpeed: Double) {
+        self.maximumSpeed = maximumSpeed
     }
 
     
@@ -5923,28 +6281,22 @@ public struct TripSummary: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension TripSummary: Sendable {}
+extension LocationFilterConfig: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTripSummary: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripSummary {
+public struct FfiConverterTypeLocationFilterConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationFilterConfig {
         return
-            try TripSummary(
-                distanceTraveled: FfiConverterDouble.read(from: &buf), 
-                snappedDistanceTraveled: FfiConverterDouble.read(from: &buf), 
-                startedAt: FfiConverterTypeUtcDateTime.read(from: &buf), 
-                endedAt: FfiConverterOptionTypeUtcDateTime.read(from: &buf)
+            try LocationFilterConfig(
+                maximumSpeed: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: TripSummary, into buf: inout [UInt8]) {
-        FfiConverterDouble.write(value.distanceTraveled, into: &buf)
-        FfiConverterDouble.write(value.snappedDistanceTraveled, into: &buf)
-        FfiConverterTypeUtcDateTime.write(value.startedAt, into: &buf)
-        FfiConverterOptionTypeUtcDateTime.write(value.endedAt, into: &buf)
+    public static func write(_ value: LocationFilterConfig, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.maximumSpeed, into: &buf)
     }
 }
 
@@ -5952,48 +6304,32 @@ public struct FfiConverterTypeTripSummary: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTripSummary_lift(_ buf: RustBuffer) throws -> TripSummary {
-    return try FfiConverterTypeTripSummary.lift(buf)
+public func FfiConverterTypeLocationFilterConfig_lift(_ buf: RustBuffer) throws -> LocationFilterConfig {
+    return try FfiConverterTypeLocationFilterConfig.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTripSummary_lower(_ value: TripSummary) -> RustBuffer {
-    return FfiConverterTypeTripSummary.lower(value)
+public func FfiConverterTypeLocationFilterConfig_lower(_ value: LocationFilterConfig) -> RustBuffer {
+    return FfiConverterTypeLocationFilterConfig.lower(value)
 }
 
 
 /**
- * The location of the user that is navigating.
- *
- * In addition to coordinates, this includes estimated accuracy and course information,
- * which can influence navigation logic and UI.
- *
- * NOTE: Heading is absent on purpose.
- * Heading updates are not related to a change in the user's location.
+ * The current state of the simulation.
  */
-public struct UserLocation: Equatable, Hashable, Codable {
-    public var coordinates: GeographicCoordinate
-    /**
-     * The estimated accuracy of the coordinate (in meters)
-     */
-    public var horizontalAccuracy: Double
-    public var courseOverGround: CourseOverGround?
-    public var timestamp: Date
-    public var speed: Speed?
+public struct LocationSimulationState: Equatable, Hashable, Codable {
+    public var currentLocation: UserLocation
+    public var remainingLocations: [GeographicCoordinate]
+    public var bias: LocationBias
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(coordinates: GeographicCoordinate, 
-        /**
-         * The estimated accuracy of the coordinate (in meters)
-         */horizontalAccuracy: Double, courseOverGround: CourseOverGround?, timestamp: Date, speed: Speed?) {
-        self.coordinates = coordinates
-        self.horizontalAccuracy = horizontalAccuracy
-        self.courseOverGround = courseOverGround
-        self.timestamp = timestamp
-        self.speed = speed
+    public init(currentLocation: UserLocation, remainingLocations: [GeographicCoordinate], bias: LocationBias) {
+        self.currentLocation = currentLocation
+        self.remainingLocations = remainingLocations
+        self.bias = bias
     }
 
     
@@ -6002,30 +6338,26 @@ public struct UserLocation: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension UserLocation: Sendable {}
+extension LocationSimulationState: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeUserLocation: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserLocation {
+public struct FfiConverterTypeLocationSimulationState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationSimulationState {
         return
-            try UserLocation(
-                coordinates: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
-                horizontalAccuracy: FfiConverterDouble.read(from: &buf), 
-                courseOverGround: FfiConverterOptionTypeCourseOverGround.read(from: &buf), 
-                timestamp: FfiConverterTimestamp.read(from: &buf), 
-                speed: FfiConverterOptionTypeSpeed.read(from: &buf)
+            try LocationSimulationState(
+                currentLocation: FfiConverterTypeUserLocation.read(from: &buf), 
+                remainingLocations: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
+                bias: FfiConverterTypeLocationBias.read(from: &buf)
         )
     }
 
-    public static func write(_ value: UserLocation, into buf: inout [UInt8]) {
-        FfiConverterTypeGeographicCoordinate.write(value.coordinates, into: &buf)
-        FfiConverterDouble.write(value.horizontalAccuracy, into: &buf)
-        FfiConverterOptionTypeCourseOverGround.write(value.courseOverGround, into: &buf)
-        FfiConverterTimestamp.write(value.timestamp, into: &buf)
-        FfiConverterOptionTypeSpeed.write(value.speed, into: &buf)
+    public static func write(_ value: LocationSimulationState, into buf: inout [UInt8]) {
+        FfiConverterTypeUserLocation.write(value.currentLocation, into: &buf)
+        FfiConverterSequenceTypeGeographicCoordinate.write(value.remainingLocations, into: &buf)
+        FfiConverterTypeLocationBias.write(value.bias, into: &buf)
     }
 }
 
@@ -6033,102 +6365,227 @@ public struct FfiConverterTypeUserLocation: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeUserLocation_lift(_ buf: RustBuffer) throws -> UserLocation {
-    return try FfiConverterTypeUserLocation.lift(buf)
+public func FfiConverterTypeLocationSimulationState_lift(_ buf: RustBuffer) throws -> LocationSimulationState {
+    return try FfiConverterTypeLocationSimulationState.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeUserLocation_lower(_ value: UserLocation) -> RustBuffer {
-    return FfiConverterTypeUserLocation.lower(value)
+public func FfiConverterTypeLocationSimulationState_lower(_ value: LocationSimulationState) -> RustBuffer {
+    return FfiConverterTypeLocationSimulationState.lower(value)
 }
 
 
 /**
- * A set of optional filters to exclude candidate edges based on their attributes.
+ * State carried between location updates by [`LocationSmoothing`].
  */
-public struct ValhallaLocationSearchFilter: Equatable, Hashable, Codable {
+public struct LocationSmoothingState: Equatable, Hashable, Codable {
     /**
-     * Whether to exclude roads marked as tunnels.
+     * The last smoothed location.
      */
-    public var excludeTunnel: Bool?
+    public var location: UserLocation
     /**
-     * Whether to exclude roads marked as bridges.
+     * The filter state along the east axis.
      */
-    public var excludeBridge: Bool?
+    public var east: AxisEstimate
     /**
-     * Whether to exclude roads with tolls.
+     * The filter state along the north axis.
      */
-    public var excludeTolls: Bool?
+    public var north: AxisEstimate
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The last smoothed location.
+         */location: UserLocation, 
+        /**
+         * The filter state along the east axis.
+         */east: AxisEstimate, 
+        /**
+         * The filter state along the north axis.
+         */north: AxisEstimate) {
+        self.location = location
+        self.east = east
+        self.north = north
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension LocationSmoothingState: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLocationSmoothingState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationSmoothingState {
+        return
+            try LocationSmoothingState(
+                location: FfiConverterTypeUserLocation.read(from: &buf), 
+                east: FfiConverterTypeAxisEstimate.read(from: &buf), 
+                north: FfiConverterTypeAxisEstimate.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LocationSmoothingState, into buf: inout [UInt8]) {
+        FfiConverterTypeUserLocation.write(value.location, into: &buf)
+        FfiConverterTypeAxisEstimate.write(value.east, into: &buf)
+        FfiConverterTypeAxisEstimate.write(value.north, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocationSmoothingState_lift(_ buf: RustBuffer) throws -> LocationSmoothingState {
+    return try FfiConverterTypeLocationSmoothingState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocationSmoothingState_lower(_ value: LocationSmoothingState) -> RustBuffer {
+    return FfiConverterTypeLocationSmoothingState.lower(value)
+}
+
+
+/**
+ * Configures the preview of upcoming maneuvers (see `upcoming_maneuvers` in [`TripState::Navigating`]).
+ */
+public struct ManeuverPreviewConfig: Equatable, Hashable, Codable {
     /**
-     * Whether to exclude ferries.
+     * The maximum number of upcoming maneuvers to include.
      */
-    public var excludeFerry: Bool?
+    public var maximumManeuvers: UInt16
     /**
-     * Whether to exclude roads marked as ramps.
+     * Maneuvers at most this distance (in meters) after the previous one are flagged as closely spaced
+     * (ex: so that the UI can show "then turn left" alongside the current instruction).
      */
-    public var excludeRamp: Bool?
+    public var closelySpacedDistance: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The maximum number of upcoming maneuvers to include.
+         */maximumManeuvers: UInt16, 
+        /**
+         * Maneuvers at most this distance (in meters) after the previous one are flagged as closely spaced
+         * (ex: so that the UI can show "then turn left" alongside the current instruction).
+         */closelySpacedDistance: Double) {
+        self.maximumManeuvers = maximumManeuvers
+        self.closelySpacedDistance = closelySpacedDistance
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension ManeuverPreviewConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeManeuverPreviewConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManeuverPreviewConfig {
+        return
+            try ManeuverPreviewConfig(
+                maximumManeuvers: FfiConverterUInt16.read(from: &buf), 
+                closelySpacedDistance: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ManeuverPreviewConfig, into buf: inout [UInt8]) {
+        FfiConverterUInt16.write(value.maximumManeuvers, into: &buf)
+        FfiConverterDouble.write(value.closelySpacedDistance, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManeuverPreviewConfig_lift(_ buf: RustBuffer) throws -> ManeuverPreviewConfig {
+    return try FfiConverterTypeManeuverPreviewConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeManeuverPreviewConfig_lower(_ value: ManeuverPreviewConfig) -> RustBuffer {
+    return FfiConverterTypeManeuverPreviewConfig.lower(value)
+}
+
+
+/**
+ * Configures hidden Markov model (HMM) map matching of user locations to the route.
+ *
+ * By default, each location is snapped independently to the closest point on the current step.
+ * This is cheap, but can jump to the wrong branch when the route passes near itself
+ * (self-intersections, parallel frontage roads, cloverleaf interchanges, etc.).
+ *
+ * With map matching enabled, the navigation controller instead considers the most recent location fixes,
+ * along with their course and speed,
+ * and picks the most likely position along the remaining route near the current step.
+ * See [`crate::map_matching`] for details.
+ */
+public struct MapMatchingConfig: Equatable, Hashable, Codable {
     /**
-     * Whether to exclude roads marked as closed due to a live traffic closure.
+     * The number of recent location fixes (including the current one) to consider.
      */
-    public var excludeClosures: Bool?
+    public var historySize: UInt16
     /**
-     * The lowest road class allowed.
+     * Route positions farther than this many meters from a location fix are not considered as matches.
      */
-    public var minRoadClass: ValhallaRoadClass?
+    public var searchRadius: Double
     /**
-     * The highest road class allowed.
+     * The minimum standard deviation (in meters) assumed for location measurements.
+     *
+     * The reported horizontal accuracy is used when it is larger than this.
      */
-    public var maxRoadClass: ValhallaRoadClass?
+    public var minimumMeasurementSigma: Double
     /**
-     * If specified, will only consider edges that are on or traverse the passed floor level.
-     * It will set `search_cutoff` to a default value of 300 meters if no cutoff value is passed.
-     * Additionally, if a `search_cutoff` is passed, it will be clamped to 1000 meters.
+     * How strongly (in meters) the distance traveled along the route may differ
+     * from the distance traveled between fixes.
+     *
+     * Smaller values penalize jumps along the route (ex: to another branch) more heavily.
      */
-    public var level: Float?
+    public var transitionBeta: Double
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Whether to exclude roads marked as tunnels.
-         */excludeTunnel: Bool? = nil, 
-        /**
-         * Whether to exclude roads marked as bridges.
-         */excludeBridge: Bool? = nil, 
-        /**
-         * Whether to exclude roads with tolls.
-         */excludeTolls: Bool? = nil, 
-        /**
-         * Whether to exclude ferries.
-         */excludeFerry: Bool? = nil, 
-        /**
-         * Whether to exclude roads marked as ramps.
-         */excludeRamp: Bool? = nil, 
-        /**
-         * Whether to exclude roads marked as closed due to a live traffic closure.
-         */excludeClosures: Bool? = nil, 
+         * The number of recent location fixes (including the current one) to consider.
+         */historySize: UInt16, 
         /**
-         * The lowest road class allowed.
-         */minRoadClass: ValhallaRoadClass? = nil, 
+         * Route positions farther than this many meters from a location fix are not considered as matches.
+         */searchRadius: Double, 
         /**
-         * The highest road class allowed.
-         */maxRoadClass: ValhallaRoadClass? = nil, 
+         * The minimum standard deviation (in meters) assumed for location measurements.
+         *
+         * The reported horizontal accuracy is used when it is larger than this.
+         */minimumMeasurementSigma: Double, 
         /**
-         * If specified, will only consider edges that are on or traverse the passed floor level.
-         * It will set `search_cutoff` to a default value of 300 meters if no cutoff value is passed.
-         * Additionally, if a `search_cutoff` is passed, it will be clamped to 1000 meters.
-         */level: Float? = nil) {
-        self.excludeTunnel = excludeTunnel
-        self.excludeBridge = excludeBridge
-        self.excludeTolls = excludeTolls
-        self.excludeFerry = excludeFerry
-        self.excludeRamp = excludeRamp
-        self.excludeClosures = excludeClosures
-        self.minRoadClass = minRoadClass
-        self.maxRoadClass = maxRoadClass
-        self.level = level
+         * How strongly (in meters) the distance traveled along the route may differ
+         * from the distance traveled between fixes.
+         *
+         * Smaller values penalize jumps along the route (ex: to another branch) more heavily.
+         */transitionBeta: Double) {
+        self.historySize = historySize
+        self.searchRadius = searchRadius
+        self.minimumMeasurementSigma = minimumMeasurementSigma
+        self.transitionBeta = transitionBeta
     }
 
     
@@ -6137,38 +6594,28 @@ public struct ValhallaLocationSearchFilter: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension ValhallaLocationSearchFilter: Sendable {}
+extension MapMatchingConfig: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeValhallaLocationSearchFilter: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ValhallaLocationSearchFilter {
+public struct FfiConverterTypeMapMatchingConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MapMatchingConfig {
         return
-            try ValhallaLocationSearchFilter(
-                excludeTunnel: FfiConverterOptionBool.read(from: &buf), 
-                excludeBridge: FfiConverterOptionBool.read(from: &buf), 
-                excludeTolls: FfiConverterOptionBool.read(from: &buf), 
-                excludeFerry: FfiConverterOptionBool.read(from: &buf), 
-                excludeRamp: FfiConverterOptionBool.read(from: &buf), 
-                excludeClosures: FfiConverterOptionBool.read(from: &buf), 
-                minRoadClass: FfiConverterOptionTypeValhallaRoadClass.read(from: &buf), 
-                maxRoadClass: FfiConverterOptionTypeValhallaRoadClass.read(from: &buf), 
-                level: FfiConverterOptionFloat.read(from: &buf)
+            try MapMatchingConfig(
+                historySize: FfiConverterUInt16.read(from: &buf), 
+                searchRadius: FfiConverterDouble.read(from: &buf), 
+                minimumMeasurementSigma: FfiConverterDouble.read(from: &buf), 
+                transitionBeta: FfiConverterDouble.read(from: &buf)
         )
     }
 
-    public static func write(_ value: ValhallaLocationSearchFilter, into buf: inout [UInt8]) {
-        FfiConverterOptionBool.write(value.excludeTunnel, into: &buf)
-        FfiConverterOptionBool.write(value.excludeBridge, into: &buf)
-        FfiConverterOptionBool.write(value.excludeTolls, into: &buf)
-        FfiConverterOptionBool.write(value.excludeFerry, into: &buf)
-        FfiConverterOptionBool.write(value.excludeRamp, into: &buf)
-        FfiConverterOptionBool.write(value.excludeClosures, into: &buf)
-        FfiConverterOptionTypeValhallaRoadClass.write(value.minRoadClass, into: &buf)
-        FfiConverterOptionTypeValhallaRoadClass.write(value.maxRoadClass, into: &buf)
-        FfiConverterOptionFloat.write(value.level, into: &buf)
+    public static func write(_ value: MapMatchingConfig, into buf: inout [UInt8]) {
+        FfiConverterUInt16.write(value.historySize, into: &buf)
+        FfiConverterDouble.write(value.searchRadius, into: &buf)
+        FfiConverterDouble.write(value.minimumMeasurementSigma, into: &buf)
+        FfiConverterDouble.write(value.transitionBeta, into: &buf)
     }
 }
 
@@ -6176,297 +6623,3317 @@ public struct FfiConverterTypeValhallaLocationSearchFilter: FfiConverterRustBuff
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeValhallaLocationSearchFilter_lift(_ buf: RustBuffer) throws -> ValhallaLocationSearchFilter {
-    return try FfiConverterTypeValhallaLocationSearchFilter.lift(buf)
+public func FfiConverterTypeMapMatchingConfig_lift(_ buf: RustBuffer) throws -> MapMatchingConfig {
+    return try FfiConverterTypeMapMatchingConfig.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeValhallaLocationSearchFilter_lower(_ value: ValhallaLocationSearchFilter) -> RustBuffer {
-    return FfiConverterTypeValhallaLocationSearchFilter.lower(value)
+public func FfiConverterTypeMapMatchingConfig_lower(_ value: MapMatchingConfig) -> RustBuffer {
+    return FfiConverterTypeMapMatchingConfig.lower(value)
 }
 
 
 /**
- * Waypoint properties supported by Valhalla servers.
- *
- * Our docstrings are short here, since Valhalla is the final authority.
- * Refer to <https://valhalla.github.io/valhalla/api/turn-by-turn/api-reference/#locations>
- * for more details, including default values.
- * Other Valhalla-based APIs such as Stadia Maps or Mapbox may have slightly different defaults.
- * Refer to your vendor's documentation when in doubt.
+ * The navigation state.
  *
- * NOTE: Waypoint properties will NOT currently be echoed back in OSRM format,
- * so these are sent to the server one time.
+ * This is typically created from an initial trip state
+ * and conditions for advancing navigation to the next step.
+ * Any internal navigation state is packed in here so that
+ * the navigation controller can remain functionally pure.
  */
-public struct ValhallaWaypointProperties: Equatable, Hashable, Codable {
+public struct NavState {
+    public var tripState: TripState
+    public var stepAdvanceCondition: StepAdvanceCondition
     /**
-     * Preferred direction of travel for the start from the location.
+     * The most recent raw location fixes (oldest first) used for map matching.
      *
-     * The heading is indicated in degrees from north in a clockwise direction, where north is 0°, east is 90°, south is 180°, and west is 270°.
-     * Avoid specifying this unless you really know what you're doing.
-     * Most use cases for this are better served by `preferred_side`.
+     * This is only populated when [`NavigationControllerConfig::map_matching`] is set.
      */
-    public var heading: UInt16?
+    public var recentLocations: [UserLocation]
     /**
-     * How close in degrees a given street's angle must be
-     * in order for it to be considered as in the same direction of the heading parameter.
+     * State carried between location updates for deviation tracking with hysteresis.
      */
-    public var headingTolerance: UInt16?
+    public var deviationState: RouteDeviationState
     /**
-     * Minimum number of nodes (intersections) reachable for a given edge
-     * (road between intersections) to consider that edge as belonging to a connected region.
-     * Disconnected edges are ignored.
+     * The exponentially smoothed speed (in meters per second) over the current step.
+     *
+     * This is only populated when [`NavigationControllerConfig::adaptive_eta`] is set.
      */
-    public var minimumReachability: UInt16?
+    public var smoothedSpeed: Double?
     /**
-     * The number of meters about this input location within which edges
-     * will be considered as candidates for said location.
-     * If there are no candidates within this distance,
-     * it will return the closest candidate within reason.
-     *
-     * This allows the routing engine to match another edge which is NOT
-     * the closest to your location, but in within this range.
-     * This can be useful if you have other constraints and want to disambiguate,
-     * but beware that this can lead to very strange results,
-     * particularly if you have specified other parameters like `heading`.
-     * This is an advanced feature and should only be used with extreme care.
+     * The events produced by the update that resulted in this state.
      */
-    public var radius: UInt16?
+    public var events: [NavigationEvent]
     /**
-     * Determines whether the location should be visited from the same, opposite or either side of the road,
-     * with respect to the side of the road the given locale drives on.
-     *
-     * NOTE: If the location is not offset from the road centerline
-     * or is very close to an intersection, this option has no effect!
+     * The utterance IDs of all spoken instructions announced so far during the trip.
      */
-    public var preferredSide: ValhallaWaypointPreferredSide?
+    public var announcedUtteranceIds: [Uuid]
     /**
-     * Latitude of the map location in degrees.
+     * The last trustworthy location fix while the user's location is being estimated.
      *
-     * If provided, the waypoint location will still be used for routing,
-     * but these coordinates will determine the side of the street.
+     * This is only populated during dead reckoning
+     * (see [`NavigationControllerConfig::dead_reckoning`]).
      */
-    public var displayCoordinate: GeographicCoordinate?
+    public var deadReckoningAnchor: UserLocation?
     /**
-     * The cutoff at which we will assume the input is too far away from civilization
-     * to be worth correlating to the nearest graph elements.
+     * The location smoothing filter state.
+     *
+     * This is only populated when [`NavigationControllerConfig::location_smoothing`] is set.
      */
-    public var searchCutoff: UInt32?
+    public var locationSmoothingState: LocationSmoothingState?
     /**
-     * During edge correlation, this is the tolerance used to determine whether to snap
-     * to the intersection rather than along the street.
-     * If the snap location is within this distance from the intersection,
-     * the intersection is used instead.
+     * The number of route steps the user has already completed.
+     *
+     * The completed steps are the first steps of the route being navigated.
+     * This is only tracked when [`NavigationControllerConfig::step_regression`] is set.
      */
-    public var nodeSnapTolerance: UInt16?
+    public var completedStepCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(tripState: TripState, stepAdvanceCondition: StepAdvanceCondition, 
+        /**
+         * The most recent raw location fixes (oldest first) used for map matching.
+         *
+         * This is only populated when [`NavigationControllerConfig::map_matching`] is set.
+         */recentLocations: [UserLocation] = [], 
+        /**
+         * State carried between location updates for deviation tracking with hysteresis.
+         */deviationState: RouteDeviationState = RouteDeviationState(), 
+        /**
+         * The exponentially smoothed speed (in meters per second) over the current step.
+         *
+         * This is only populated when [`NavigationControllerConfig::adaptive_eta`] is set.
+         */smoothedSpeed: Double? = nil, 
+        /**
+         * The events produced by the update that resulted in this state.
+         */events: [NavigationEvent] = [], 
+        /**
+         * The utterance IDs of all spoken instructions announced so far during the trip.
+         */announcedUtteranceIds: [Uuid] = [], 
+        /**
+         * The last trustworthy location fix while the user's location is being estimated.
+         *
+         * This is only populated during dead reckoning
+         * (see [`NavigationControllerConfig::dead_reckoning`]).
+         */deadReckoningAnchor: UserLocation? = nil, 
+        /**
+         * The location smoothing filter state.
+         *
+         * This is only populated when [`NavigationControllerConfig::location_smoothing`] is set.
+         */locationSmoothingState: LocationSmoothingState? = nil, 
+        /**
+         * The number of route steps the user has already completed.
+         *
+         * The completed steps are the first steps of the route being navigated.
+         * This is only tracked when [`NavigationControllerConfig::step_regression`] is set.
+         */completedStepCount: UInt32 = UInt32(0)) {
+        self.tripState = tripState
+        self.stepAdvanceCondition = stepAdvanceCondition
+        self.recentLocations = recentLocations
+        self.deviationState = deviationState
+        self.smoothedSpeed = smoothedSpeed
+        self.events = events
+        self.announcedUtteranceIds = announcedUtteranceIds
+        self.deadReckoningAnchor = deadReckoningAnchor
+        self.locationSmoothingState = locationSmoothingState
+        self.completedStepCount = completedStepCount
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension NavState: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNavState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavState {
+        return
+            try NavState(
+                tripState: FfiConverterTypeTripState.read(from: &buf), 
+                stepAdvanceCondition: FfiConverterTypeStepAdvanceCondition.read(from: &buf), 
+                recentLocations: FfiConverterSequenceTypeUserLocation.read(from: &buf), 
+                deviationState: FfiConverterTypeRouteDeviationState.read(from: &buf), 
+                smoothedSpeed: FfiConverterOptionDouble.read(from: &buf), 
+                events: FfiConverterSequenceTypeNavigationEvent.read(from: &buf), 
+                announcedUtteranceIds: FfiConverterSequenceTypeUuid.read(from: &buf), 
+                deadReckoningAnchor: FfiConverterOptionTypeUserLocation.read(from: &buf), 
+                locationSmoothingState: FfiConverterOptionTypeLocationSmoothingState.read(from: &buf), 
+                completedStepCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NavState, into buf: inout [UInt8]) {
+        FfiConverterTypeTripState.write(value.tripState, into: &buf)
+        FfiConverterTypeStepAdvanceCondition.write(value.stepAdvanceCondition, into: &buf)
+        FfiConverterSequenceTypeUserLocation.write(value.recentLocations, into: &buf)
+        FfiConverterTypeRouteDeviationState.write(value.deviationState, into: &buf)
+        FfiConverterOptionDouble.write(value.smoothedSpeed, into: &buf)
+        FfiConverterSequenceTypeNavigationEvent.write(value.events, into: &buf)
+        FfiConverterSequenceTypeUuid.write(value.announcedUtteranceIds, into: &buf)
+        FfiConverterOptionTypeUserLocation.write(value.deadReckoningAnchor, into: &buf)
+        FfiConverterOptionTypeLocationSmoothingState.write(value.locationSmoothingState, into: &buf)
+        FfiConverterUInt32.write(value.completedStepCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavState_lift(_ buf: RustBuffer) throws -> NavState {
+    return try FfiConverterTypeNavState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavState_lower(_ value: NavState) -> RustBuffer {
+    return FfiConverterTypeNavState.lower(value)
+}
+
+
+public struct NavigationCachingConfig: Equatable, Hashable, Codable {
+    public var cacheIntervalSeconds: Int64
+    public var maxAgeSeconds: Int64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(cacheIntervalSeconds: Int64, maxAgeSeconds: Int64) {
+        self.cacheIntervalSeconds = cacheIntervalSeconds
+        self.maxAgeSeconds = maxAgeSeconds
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension NavigationCachingConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNavigationCachingConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationCachingConfig {
+        return
+            try NavigationCachingConfig(
+                cacheIntervalSeconds: FfiConverterInt64.read(from: &buf), 
+                maxAgeSeconds: FfiConverterInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NavigationCachingConfig, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.cacheIntervalSeconds, into: &buf)
+        FfiConverterInt64.write(value.maxAgeSeconds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationCachingConfig_lift(_ buf: RustBuffer) throws -> NavigationCachingConfig {
+    return try FfiConverterTypeNavigationCachingConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationCachingConfig_lower(_ value: NavigationCachingConfig) -> RustBuffer {
+    return FfiConverterTypeNavigationCachingConfig.lower(value)
+}
+
+
+public struct NavigationControllerConfig {
     /**
-     * A tolerance in meters from the edge centerline used for determining the side of the street
-     * that the location is on.
-     * If the distance to the centerline is less than this tolerance,
-     * no side will be inferred.
-     * Otherwise, the left or right side will be selected depending on the direction of travel.
+     * Configures when navigation advances to the next waypoint in the route.
      */
-    public var streetSideTolerance: UInt16?
+    public var waypointAdvance: WaypointAdvanceMode
     /**
-     * The max distance in meters that the input coordinates or display lat/lon can be
-     * from the edge centerline for them to be used for determining the side of the street.
-     * Beyond this distance, no street side is inferred.
+     * Configures when navigation advances to the next step in the route.
      */
-    public var streetSideMaxDistance: UInt16?
+    public var stepAdvanceCondition: StepAdvanceCondition
     /**
-     * Disables the `preferred_side` when set to `same` or `opposite`
-     * if the edge has a road class less than that provided by `street_side_cutoff`.
+     * A special advance condition used for the final 2 route steps (last and arrival).
+     *
+     * This exists because several of our step advance conditions require entry and
+     * exit from a step's geometry. The end of the route/arrival doesn't always accommodate
+     * the expected location updates for the core step advance condition.
      */
-    public var streetSideCutoff: ValhallaRoadClass?
+    public var arrivalStepAdvanceCondition: StepAdvanceCondition
     /**
-     * A set of optional filters to exclude candidate edges based on their attributes.
+     * Configures when the user is deemed to be off course.
+     *
+     * NOTE: This is distinct from the action that is taken.
+     * It is only the determination that the user has deviated from the expected route.
      */
-    public var searchFilter: ValhallaLocationSearchFilter?
+    public var routeDeviationTracking: RouteDeviationTracking
     /**
-     * Determines whether U-Turns are allowed at the waypoint
-     * (if it is an intermediate waypoint, not the first or last).
-     *
-     * Defaults to `true`. This has the effect of converting a [`WaypointKind::Break`]
-     * into a Valhalla `break_through`, and a [`WaypointKind::Via`] into a `through`.
+     * Configures how the heading component of the snapped location is reported in [`TripState`].
      */
-    public var allowUturns: Bool?
+    public var snappedLocationCourseFiltering: CourseFiltering
     /**
-     * Location or business name.
-     *
-     * The name may be used in the route narration directions,
-     * such as "You have arrived at &lt;business name&gt;."
+     * Enables map matching of user locations to the route.
      *
-     * WARNING: this param is not echoed back in the response, so this can disappear when rerouting!
-     *
-     * NOTE: Serialized as `waypoint_name` in the waypoint properties blob so it cannot
-     * collide with the `name` of [`OsrmWaypointProperties`](crate::routing_adapters::osrm::models::OsrmWaypointProperties)
-     * (the snapped street name), which occupies the same blob on waypoints rebuilt from a
-     * route response (e.g. the remaining waypoints used for reroute requests).
-     * It is still sent to Valhalla as `name`.
+     * When [`None`], each location is snapped to the closest point on the current step.
      */
-    public var name: String?
+    public var mapMatching: MapMatchingConfig?
+    /**
+     * Enables the adaptive ETA in [`TripProgress::adaptive_duration_remaining`].
+     */
+    public var adaptiveEta: AdaptiveEtaConfig?
+    /**
+     * Enables dead reckoning along the route during GPS outages.
+     */
+    public var deadReckoning: DeadReckoningConfig?
+    /**
+     * Enables the rejection of invalid, out-of-order, and implausible location fixes.
+     */
+    public var locationFilter: LocationFilterConfig?
+    /**
+     * Enables smoothing of location fixes before they are snapped to the route.
+     */
+    public var locationSmoothing: LocationSmoothing?
+    /**
+     * Enables moving back to an earlier step when the user is clearly back on it.
+     */
+    public var stepRegression: StepRegressionConfig?
+    /**
+     * Enables flagging users who travel faster than the speed limit.
+     */
+    public var speeding: SpeedingConfig?
+    /**
+     * Enables the preview of upcoming maneuvers.
+     */
+    public var maneuverPreview: ManeuverPreviewConfig?
+    /**
+     * Enables the reporting of incidents along the route.
+     */
+    public var incidents: IncidentsConfig?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init(
         /**
-         * Preferred direction of travel for the start from the location.
-         *
-         * The heading is indicated in degrees from north in a clockwise direction, where north is 0°, east is 90°, south is 180°, and west is 270°.
-         * Avoid specifying this unless you really know what you're doing.
-         * Most use cases for this are better served by `preferred_side`.
-         */heading: UInt16? = nil, 
-        /**
-         * How close in degrees a given street's angle must be
-         * in order for it to be considered as in the same direction of the heading parameter.
-         */headingTolerance: UInt16? = nil, 
+         * Configures when navigation advances to the next waypoint in the route.
+         */waypointAdvance: WaypointAdvanceMode, 
         /**
-         * Minimum number of nodes (intersections) reachable for a given edge
-         * (road between intersections) to consider that edge as belonging to a connected region.
-         * Disconnected edges are ignored.
-         */minimumReachability: UInt16? = nil, 
+         * Configures when navigation advances to the next step in the route.
+         */stepAdvanceCondition: StepAdvanceCondition, 
         /**
-         * The number of meters about this input location within which edges
-         * will be considered as candidates for said location.
-         * If there are no candidates within this distance,
-         * it will return the closest candidate within reason.
+         * A special advance condition used for the final 2 route steps (last and arrival).
          *
-         * This allows the routing engine to match another edge which is NOT
-         * the closest to your location, but in within this range.
-         * This can be useful if you have other constraints and want to disambiguate,
-         * but beware that this can lead to very strange results,
-         * particularly if you have specified other parameters like `heading`.
-         * This is an advanced feature and should only be used with extreme care.
-         */radius: UInt16? = nil, 
+         * This exists because several of our step advance conditions require entry and
+         * exit from a step's geometry. The end of the route/arrival doesn't always accommodate
+         * the expected location updates for the core step advance condition.
+         */arrivalStepAdvanceCondition: StepAdvanceCondition, 
         /**
-         * Determines whether the location should be visited from the same, opposite or either side of the road,
-         * with respect to the side of the road the given locale drives on.
+         * Configures when the user is deemed to be off course.
          *
-         * NOTE: If the location is not offset from the road centerline
-         * or is very close to an intersection, this option has no effect!
-         */preferredSide: ValhallaWaypointPreferredSide? = nil, 
+         * NOTE: This is distinct from the action that is taken.
+         * It is only the determination that the user has deviated from the expected route.
+         */routeDeviationTracking: RouteDeviationTracking, 
         /**
-         * Latitude of the map location in degrees.
+         * Configures how the heading component of the snapped location is reported in [`TripState`].
+         */snappedLocationCourseFiltering: CourseFiltering, 
+        /**
+         * Enables map matching of user locations to the route.
          *
-         * If provided, the waypoint location will still be used for routing,
-         * but these coordinates will determine the side of the street.
-         */displayCoordinate: GeographicCoordinate? = nil, 
+         * When [`None`], each location is snapped to the closest point on the current step.
+         */mapMatching: MapMatchingConfig? = nil, 
         /**
-         * The cutoff at which we will assume the input is too far away from civilization
-         * to be worth correlating to the nearest graph elements.
-         */searchCutoff: UInt32? = nil, 
+         * Enables the adaptive ETA in [`TripProgress::adaptive_duration_remaining`].
+         */adaptiveEta: AdaptiveEtaConfig? = nil, 
         /**
-         * During edge correlation, this is the tolerance used to determine whether to snap
-         * to the intersection rather than along the street.
-         * If the snap location is within this distance from the intersection,
-         * the intersection is used instead.
-         */nodeSnapTolerance: UInt16? = nil, 
+         * Enables dead reckoning along the route during GPS outages.
+         */deadReckoning: DeadReckoningConfig? = nil, 
         /**
-         * A tolerance in meters from the edge centerline used for determining the side of the street
-         * that the location is on.
-         * If the distance to the centerline is less than this tolerance,
-         * no side will be inferred.
-         * Otherwise, the left or right side will be selected depending on the direction of travel.
-         */streetSideTolerance: UInt16? = nil, 
+         * Enables the rejection of invalid, out-of-order, and implausible location fixes.
+         */locationFilter: LocationFilterConfig? = nil, 
         /**
-         * The max distance in meters that the input coordinates or display lat/lon can be
-         * from the edge centerline for them to be used for determining the side of the street.
-         * Beyond this distance, no street side is inferred.
-         */streetSideMaxDistance: UInt16? = nil, 
+         * Enables smoothing of location fixes before they are snapped to the route.
+         */locationSmoothing: LocationSmoothing? = nil, 
         /**
-         * Disables the `preferred_side` when set to `same` or `opposite`
-         * if the edge has a road class less than that provided by `street_side_cutoff`.
-         */streetSideCutoff: ValhallaRoadClass? = nil, 
+         * Enables moving back to an earlier step when the user is clearly back on it.
+         */stepRegression: StepRegressionConfig? = nil, 
         /**
-         * A set of optional filters to exclude candidate edges based on their attributes.
-         */searchFilter: ValhallaLocationSearchFilter? = nil, 
+         * Enables flagging users who travel faster than the speed limit.
+         */speeding: SpeedingConfig? = nil, 
         /**
-         * Determines whether U-Turns are allowed at the waypoint
-         * (if it is an intermediate waypoint, not the first or last).
-         *
-         * Defaults to `true`. This has the effect of converting a [`WaypointKind::Break`]
-         * into a Valhalla `break_through`, and a [`WaypointKind::Via`] into a `through`.
-         */allowUturns: Bool? = nil, 
+         * Enables the preview of upcoming maneuvers.
+         */maneuverPreview: ManeuverPreviewConfig? = nil, 
         /**
-         * Location or business name.
-         *
-         * The name may be used in the route narration directions,
-         * such as "You have arrived at &lt;business name&gt;."
-         *
-         * WARNING: this param is not echoed back in the response, so this can disappear when rerouting!
-         *
-         * NOTE: Serialized as `waypoint_name` in the waypoint properties blob so it cannot
-         * collide with the `name` of [`OsrmWaypointProperties`](crate::routing_adapters::osrm::models::OsrmWaypointProperties)
-         * (the snapped street name), which occupies the same blob on waypoints rebuilt from a
-         * route response (e.g. the remaining waypoints used for reroute requests).
-         * It is still sent to Valhalla as `name`.
-         */name: String? = nil) {
-        self.heading = heading
-        self.headingTolerance = headingTolerance
-        self.minimumReachability = minimumReachability
-        self.radius = radius
-        self.preferredSide = preferredSide
-        self.displayCoordinate = displayCoordinate
-        self.searchCutoff = searchCutoff
-        self.nodeSnapTolerance = nodeSnapTolerance
-        self.streetSideTolerance = streetSideTolerance
-        self.streetSideMaxDistance = streetSideMaxDistance
-        self.streetSideCutoff = streetSideCutoff
-        self.searchFilter = searchFilter
-        self.allowUturns = allowUturns
+         * Enables the reporting of incidents along the route.
+         */incidents: IncidentsConfig? = nil) {
+        self.waypointAdvance = waypointAdvance
+        self.stepAdvanceCondition = stepAdvanceCondition
+        self.arrivalStepAdvanceCondition = arrivalStepAdvanceCondition
+        self.routeDeviationTracking = routeDeviationTracking
+        self.snappedLocationCourseFiltering = snappedLocationCourseFiltering
+        self.mapMatching = mapMatching
+        self.adaptiveEta = adaptiveEta
+        self.deadReckoning = deadReckoning
+        self.locationFilter = locationFilter
+        self.locationSmoothing = locationSmoothing
+        self.stepRegression = stepRegression
+        self.speeding = speeding
+        self.maneuverPreview = maneuverPreview
+        self.incidents = incidents
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension NavigationControllerConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNavigationControllerConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationControllerConfig {
+        return
+            try NavigationControllerConfig(
+                waypointAdvance: FfiConverterTypeWaypointAdvanceMode.read(from: &buf), 
+                stepAdvanceCondition: FfiConverterTypeStepAdvanceCondition.read(from: &buf), 
+                arrivalStepAdvanceCondition: FfiConverterTypeStepAdvanceCondition.read(from: &buf), 
+                routeDeviationTracking: FfiConverterTypeRouteDeviationTracking.read(from: &buf), 
+                snappedLocationCourseFiltering: FfiConverterTypeCourseFiltering.read(from: &buf), 
+                mapMatching: FfiConverterOptionTypeMapMatchingConfig.read(from: &buf), 
+                adaptiveEta: FfiConverterOptionTypeAdaptiveEtaConfig.read(from: &buf), 
+                deadReckoning: FfiConverterOptionTypeDeadReckoningConfig.read(from: &buf), 
+                locationFilter: FfiConverterOptionTypeLocationFilterConfig.read(from: &buf), 
+                locationSmoothing: FfiConverterOptionTypeLocationSmoothing.read(from: &buf), 
+                stepRegression: FfiConverterOptionTypeStepRegressionConfig.read(from: &buf), 
+                speeding: FfiConverterOptionTypeSpeedingConfig.read(from: &buf), 
+                maneuverPreview: FfiConverterOptionTypeManeuverPreviewConfig.read(from: &buf), 
+                incidents: FfiConverterOptionTypeIncidentsConfig.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NavigationControllerConfig, into buf: inout [UInt8]) {
+        FfiConverterTypeWaypointAdvanceMode.write(value.waypointAdvance, into: &buf)
+        FfiConverterTypeStepAdvanceCondition.write(value.stepAdvanceCondition, into: &buf)
+        FfiConverterTypeStepAdvanceCondition.write(value.arrivalStepAdvanceCondition, into: &buf)
+        FfiConverterTypeRouteDeviationTracking.write(value.routeDeviationTracking, into: &buf)
+        FfiConverterTypeCourseFiltering.write(value.snappedLocationCourseFiltering, into: &buf)
+        FfiConverterOptionTypeMapMatchingConfig.write(value.mapMatching, into: &buf)
+        FfiConverterOptionTypeAdaptiveEtaConfig.write(value.adaptiveEta, into: &buf)
+        FfiConverterOptionTypeDeadReckoningConfig.write(value.deadReckoning, into: &buf)
+        FfiConverterOptionTypeLocationFilterConfig.write(value.locationFilter, into: &buf)
+        FfiConverterOptionTypeLocationSmoothing.write(value.locationSmoothing, into: &buf)
+        FfiConverterOptionTypeStepRegressionConfig.write(value.stepRegression, into: &buf)
+        FfiConverterOptionTypeSpeedingConfig.write(value.speeding, into: &buf)
+        FfiConverterOptionTypeManeuverPreviewConfig.write(value.maneuverPreview, into: &buf)
+        FfiConverterOptionTypeIncidentsConfig.write(value.incidents, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationControllerConfig_lift(_ buf: RustBuffer) throws -> NavigationControllerConfig {
+    return try FfiConverterTypeNavigationControllerConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationControllerConfig_lower(_ value: NavigationControllerConfig) -> RustBuffer {
+    return FfiConverterTypeNavigationControllerConfig.lower(value)
+}
+
+
+/**
+ * An event that occurs during navigation.
+ *
+ * This is used for the optional session recording / telemetry.
+ */
+public struct NavigationRecordingEvent: Equatable, Hashable, Codable {
+    /**
+     * The timestamp of the event in milliseconds since Jan 1, 1970 UTC.
+     */
+    public var timestamp: Int64
+    /**
+     * Data associated with the event.
+     */
+    public var eventData: NavigationRecordingEventData
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The timestamp of the event in milliseconds since Jan 1, 1970 UTC.
+         */timestamp: Int64, 
+        /**
+         * Data associated with the event.
+         */eventData: NavigationRecordingEventData) {
+        self.timestamp = timestamp
+        self.eventData = eventData
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension NavigationRecordingEvent: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNavigationRecordingEvent: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationRecordingEvent {
+        return
+            try NavigationRecordingEvent(
+                timestamp: FfiConverterInt64.read(from: &buf), 
+                eventData: FfiConverterTypeNavigationRecordingEventData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NavigationRecordingEvent, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.timestamp, into: &buf)
+        FfiConverterTypeNavigationRecordingEventData.write(value.eventData, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationRecordingEvent_lift(_ buf: RustBuffer) throws -> NavigationRecordingEvent {
+    return try FfiConverterTypeNavigationRecordingEvent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationRecordingEvent_lower(_ value: NavigationRecordingEvent) -> RustBuffer {
+    return FfiConverterTypeNavigationRecordingEvent.lower(value)
+}
+
+
+public struct NavigationSessionSnapshot: Equatable, Hashable, Codable {
+    public var savedAt: UtcDateTime
+    public var route: Route
+    public var tripState: TripState?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(savedAt: UtcDateTime, route: Route, tripState: TripState?) {
+        self.savedAt = savedAt
+        self.route = route
+        self.tripState = tripState
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension NavigationSessionSnapshot: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNavigationSessionSnapshot: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationSessionSnapshot {
+        return
+            try NavigationSessionSnapshot(
+                savedAt: FfiConverterTypeUtcDateTime.read(from: &buf), 
+                route: FfiConverterTypeRoute.read(from: &buf), 
+                tripState: FfiConverterOptionTypeTripState.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NavigationSessionSnapshot, into buf: inout [UInt8]) {
+        FfiConverterTypeUtcDateTime.write(value.savedAt, into: &buf)
+        FfiConverterTypeRoute.write(value.route, into: &buf)
+        FfiConverterOptionTypeTripState.write(value.tripState, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationSessionSnapshot_lift(_ buf: RustBuffer) throws -> NavigationSessionSnapshot {
+    return try FfiConverterTypeNavigationSessionSnapshot.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNavigationSessionSnapshot_lower(_ value: NavigationSessionSnapshot) -> RustBuffer {
+    return FfiConverterTypeNavigationSessionSnapshot.lower(value)
+}
+
+
+/**
+ * Waypoint properties parsed from an OSRM-compatible server response.
+ *
+ * NOTE: Some servers (such as Valhalla) may support additional parameters at request time
+ * which are _not_ echoed back in the response time.
+ * This is unfortunate; PRs upstream would likely be welcomed!
+ * Similarly, if your server is OSRM-compatible and returns additional attributes,
+ * feel free to open a PR to include these as optional properties.
+ */
+public struct OsrmWaypointProperties: Equatable, Hashable, Codable {
+    /**
+     * The name of the street that the waypoint snapped to.
+     */
+    public var name: String?
+    /**
+     * The distance (in meters) between the snapped point and the input coordinate.
+     */
+    public var distance: Double?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The name of the street that the waypoint snapped to.
+         */name: String?, 
+        /**
+         * The distance (in meters) between the snapped point and the input coordinate.
+         */distance: Double?) {
         self.name = name
+        self.distance = distance
     }
 
-    
+    
+
+    
+}
+
+#if compiler(>=6)
+extension OsrmWaypointProperties: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOsrmWaypointProperties: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OsrmWaypointProperties {
+        return
+            try OsrmWaypointProperties(
+                name: FfiConverterOptionString.read(from: &buf), 
+                distance: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OsrmWaypointProperties, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.name, into: &buf)
+        FfiConverterOptionDouble.write(value.distance, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOsrmWaypointProperties_lift(_ buf: RustBuffer) throws -> OsrmWaypointProperties {
+    return try FfiConverterTypeOsrmWaypointProperties.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOsrmWaypointProperties_lower(_ value: OsrmWaypointProperties) -> RustBuffer {
+    return FfiConverterTypeOsrmWaypointProperties.lower(value)
+}
+
+
+/**
+ * Configures when the [`RerouteController`](super::RerouteController) decides to fetch a new route.
+ */
+public struct RerouteConfig: Equatable, Hashable, Codable {
+    /**
+     * How long (in seconds) the user must be continuously off the route
+     * before a new route is requested.
+     *
+     * This smooths over a single noisy location fix triggering a reroute.
+     */
+    public var debounceSeconds: UInt64
+    /**
+     * The minimum time (in seconds) between two consecutive reroute attempts.
+     *
+     * This applies regardless of whether the previous attempt succeeded,
+     * so a failing routing backend is not hammered with requests.
+     */
+    public var minimumIntervalSeconds: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * How long (in seconds) the user must be continuously off the route
+         * before a new route is requested.
+         *
+         * This smooths over a single noisy location fix triggering a reroute.
+         */debounceSeconds: UInt64, 
+        /**
+         * The minimum time (in seconds) between two consecutive reroute attempts.
+         *
+         * This applies regardless of whether the previous attempt succeeded,
+         * so a failing routing backend is not hammered with requests.
+         */minimumIntervalSeconds: UInt64) {
+        self.debounceSeconds = debounceSeconds
+        self.minimumIntervalSeconds = minimumIntervalSeconds
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension RerouteConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRerouteConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RerouteConfig {
+        return
+            try RerouteConfig(
+                debounceSeconds: FfiConverterUInt64.read(from: &buf), 
+                minimumIntervalSeconds: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RerouteConfig, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.debounceSeconds, into: &buf)
+        FfiConverterUInt64.write(value.minimumIntervalSeconds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRerouteConfig_lift(_ buf: RustBuffer) throws -> RerouteConfig {
+    return try FfiConverterTypeRerouteConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRerouteConfig_lower(_ value: RerouteConfig) -> RustBuffer {
+    return FfiConverterTypeRerouteConfig.lower(value)
+}
+
+
+/**
+ * Information describing the series of steps needed to travel between two or more points.
+ *
+ * NOTE: This type is unstable and is still under active development and should be
+ * considered unstable.
+ */
+public struct Route: Equatable, Hashable, Codable {
+    public var geometry: [GeographicCoordinate]
+    public var bbox: BoundingBox
+    /**
+     * The total route distance, in meters.
+     */
+    public var distance: Double
+    /**
+     * The ordered list of waypoints to visit, including the starting point.
+     * Note that this is distinct from the *geometry* which includes all points visited.
+     * A waypoint represents a start/end point for a route leg.
+     */
+    public var waypoints: [Waypoint]
+    public var steps: [RouteStep]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(geometry: [GeographicCoordinate], bbox: BoundingBox, 
+        /**
+         * The total route distance, in meters.
+         */distance: Double, 
+        /**
+         * The ordered list of waypoints to visit, including the starting point.
+         * Note that this is distinct from the *geometry* which includes all points visited.
+         * A waypoint represents a start/end point for a route leg.
+         */waypoints: [Waypoint], steps: [RouteStep]) {
+        self.geometry = geometry
+        self.bbox = bbox
+        self.distance = distance
+        self.waypoints = waypoints
+        self.steps = steps
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Route: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRoute: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Route {
+        return
+            try Route(
+                geometry: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
+                bbox: FfiConverterTypeBoundingBox.read(from: &buf), 
+                distance: FfiConverterDouble.read(from: &buf), 
+                waypoints: FfiConverterSequenceTypeWaypoint.read(from: &buf), 
+                steps: FfiConverterSequenceTypeRouteStep.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Route, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeGeographicCoordinate.write(value.geometry, into: &buf)
+        FfiConverterTypeBoundingBox.write(value.bbox, into: &buf)
+        FfiConverterDouble.write(value.distance, into: &buf)
+        FfiConverterSequenceTypeWaypoint.write(value.waypoints, into: &buf)
+        FfiConverterSequenceTypeRouteStep.write(value.steps, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRoute_lift(_ buf: RustBuffer) throws -> Route {
+    return try FfiConverterTypeRoute.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRoute_lower(_ value: Route) -> RustBuffer {
+    return FfiConverterTypeRoute.lower(value)
+}
+
+
+/**
+ * State carried between location updates by deviation tracking modes with hysteresis.
+ *
+ * This lives in the [`NavState`](crate::navigation_controller::models::NavState),
+ * so tracking remains functionally pure.
+ */
+public struct RouteDeviationState: Equatable, Hashable, Codable {
+    /**
+     * The first location of the current streak of locations beyond the deviation threshold.
+     */
+    public var offRouteSince: UserLocation?
+    /**
+     * The first location of the current streak of locations heading against the route.
+     */
+    public var wrongDirectionSince: UserLocation?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The first location of the current streak of locations beyond the deviation threshold.
+         */offRouteSince: UserLocation? = nil, 
+        /**
+         * The first location of the current streak of locations heading against the route.
+         */wrongDirectionSince: UserLocation? = nil) {
+        self.offRouteSince = offRouteSince
+        self.wrongDirectionSince = wrongDirectionSince
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension RouteDeviationState: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRouteDeviationState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteDeviationState {
+        return
+            try RouteDeviationState(
+                offRouteSince: FfiConverterOptionTypeUserLocation.read(from: &buf), 
+                wrongDirectionSince: FfiConverterOptionTypeUserLocation.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RouteDeviationState, into buf: inout [UInt8]) {
+        FfiConverterOptionTypeUserLocation.write(value.offRouteSince, into: &buf)
+        FfiConverterOptionTypeUserLocation.write(value.wrongDirectionSince, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteDeviationState_lift(_ buf: RustBuffer) throws -> RouteDeviationState {
+    return try FfiConverterTypeRouteDeviationState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteDeviationState_lower(_ value: RouteDeviationState) -> RustBuffer {
+    return FfiConverterTypeRouteDeviationState.lower(value)
+}
+
+
+/**
+ * A maneuver (such as a turn or merge) followed by travel of a certain distance until reaching
+ * the next step.
+ */
+public struct RouteStep: Equatable, Hashable, Codable {
+    /**
+     * The full route geometry for this step.
+     */
+    public var geometry: [GeographicCoordinate]
+    /**
+     * The distance, in meters, to travel along the route after the maneuver to reach the next step.
+     */
+    public var distance: Double
+    /**
+     * The estimated duration, in seconds, that it will take to complete this step.
+     */
+    public var duration: Double
+    /**
+     * The name of the road being traveled on (useful for certain UI styles).
+     */
+    public var roadName: String?
+    /**
+     * A list of exits (name or number).
+     */
+    public var exits: [String]
+    /**
+     * A description of the maneuver (ex: "Turn wright onto main street").
+     *
+     * Note for UI implementers: the context this appears in (or doesn't)
+     * depends somewhat on your use case and routing engine.
+     * For example, this field is useful as a written instruction in Valhalla.
+     */
+    public var instruction: String
+    /**
+     * A list of instructions for visual display (usually as banners) at specific points along the step.
+     */
+    public var visualInstructions: [VisualInstruction]
+    /**
+     * A list of prompts to announce (via speech synthesis) at specific points along the step.
+     */
+    public var spokenInstructions: [SpokenInstruction]
+    /**
+     * A list of json encoded strings representing annotations between each coordinate along the step.
+     */
+    public var annotations: [String]?
+    /**
+     * A list of incidents that occur along the step.
+     */
+    public var incidents: [Incident]
+    /**
+     * Which side of the road traffic drives on for this step.
+     *
+     * This is relevant for roundabouts: left-hand traffic (e.g. UK) uses clockwise roundabouts,
+     * while right-hand traffic uses counterclockwise roundabouts.
+     */
+    public var drivingSide: DrivingSide?
+    /**
+     * The exit number when entering a roundabout (1 = first exit, 2 = second, etc.).
+     */
+    public var roundaboutExitNumber: UInt8?
+    /**
+     * The index of the route leg this step belongs to.
+     *
+     * A route has one leg between each pair of consecutive (break) waypoints.
+     */
+    public var legIndex: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The full route geometry for this step.
+         */geometry: [GeographicCoordinate], 
+        /**
+         * The distance, in meters, to travel along the route after the maneuver to reach the next step.
+         */distance: Double, 
+        /**
+         * The estimated duration, in seconds, that it will take to complete this step.
+         */duration: Double, 
+        /**
+         * The name of the road being traveled on (useful for certain UI styles).
+         */roadName: String?, 
+        /**
+         * A list of exits (name or number).
+         */exits: [String], 
+        /**
+         * A description of the maneuver (ex: "Turn wright onto main street").
+         *
+         * Note for UI implementers: the context this appears in (or doesn't)
+         * depends somewhat on your use case and routing engine.
+         * For example, this field is useful as a written instruction in Valhalla.
+         */instruction: String, 
+        /**
+         * A list of instructions for visual display (usually as banners) at specific points along the step.
+         */visualInstructions: [VisualInstruction], 
+        /**
+         * A list of prompts to announce (via speech synthesis) at specific points along the step.
+         */spokenInstructions: [SpokenInstruction], 
+        /**
+         * A list of json encoded strings representing annotations between each coordinate along the step.
+         */annotations: [String]?, 
+        /**
+         * A list of incidents that occur along the step.
+         */incidents: [Incident], 
+        /**
+         * Which side of the road traffic drives on for this step.
+         *
+         * This is relevant for roundabouts: left-hand traffic (e.g. UK) uses clockwise roundabouts,
+         * while right-hand traffic uses counterclockwise roundabouts.
+         */drivingSide: DrivingSide?, 
+        /**
+         * The exit number when entering a roundabout (1 = first exit, 2 = second, etc.).
+         */roundaboutExitNumber: UInt8?, 
+        /**
+         * The index of the route leg this step belongs to.
+         *
+         * A route has one leg between each pair of consecutive (break) waypoints.
+         */legIndex: UInt32 = UInt32(0)) {
+        self.geometry = geometry
+        self.distance = distance
+        self.duration = duration
+        self.roadName = roadName
+        self.exits = exits
+        self.instruction = instruction
+        self.visualInstructions = visualInstructions
+        self.spokenInstructions = spokenInstructions
+        self.annotations = annotations
+        self.incidents = incidents
+        self.drivingSide = drivingSide
+        self.roundaboutExitNumber = roundaboutExitNumber
+        self.legIndex = legIndex
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension RouteStep: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRouteStep: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RouteStep {
+        return
+            try RouteStep(
+                geometry: FfiConverterSequenceTypeGeographicCoordinate.read(from: &buf), 
+                distance: FfiConverterDouble.read(from: &buf), 
+                duration: FfiConverterDouble.read(from: &buf), 
+                roadName: FfiConverterOptionString.read(from: &buf), 
+                exits: FfiConverterSequenceString.read(from: &buf), 
+                instruction: FfiConverterString.read(from: &buf), 
+                visualInstructions: FfiConverterSequenceTypeVisualInstruction.read(from: &buf), 
+                spokenInstructions: FfiConverterSequenceTypeSpokenInstruction.read(from: &buf), 
+                annotations: FfiConverterOptionSequenceString.read(from: &buf), 
+                incidents: FfiConverterSequenceTypeIncident.read(from: &buf), 
+                drivingSide: FfiConverterOptionTypeDrivingSide.read(from: &buf), 
+                roundaboutExitNumber: FfiConverterOptionUInt8.read(from: &buf), 
+                legIndex: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RouteStep, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeGeographicCoordinate.write(value.geometry, into: &buf)
+        FfiConverterDouble.write(value.distance, into: &buf)
+        FfiConverterDouble.write(value.duration, into: &buf)
+        FfiConverterOptionString.write(value.roadName, into: &buf)
+        FfiConverterSequenceString.write(value.exits, into: &buf)
+        FfiConverterString.write(value.instruction, into: &buf)
+        FfiConverterSequenceTypeVisualInstruction.write(value.visualInstructions, into: &buf)
+        FfiConverterSequenceTypeSpokenInstruction.write(value.spokenInstructions, into: &buf)
+        FfiConverterOptionSequenceString.write(value.annotations, into: &buf)
+        FfiConverterSequenceTypeIncident.write(value.incidents, into: &buf)
+        FfiConverterOptionTypeDrivingSide.write(value.drivingSide, into: &buf)
+        FfiConverterOptionUInt8.write(value.roundaboutExitNumber, into: &buf)
+        FfiConverterUInt32.write(value.legIndex, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteStep_lift(_ buf: RustBuffer) throws -> RouteStep {
+    return try FfiConverterTypeRouteStep.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRouteStep_lower(_ value: RouteStep) -> RustBuffer {
+    return FfiConverterTypeRouteStep.lower(value)
+}
+
+
+/**
+ * Typed values of the common annotations for a segment of a route step
+ * (the span between two consecutive coordinates).
+ *
+ * Values which are missing from the route response are [`None`].
+ */
+public struct SegmentAnnotation: Equatable, Hashable, Codable {
+    /**
+     * The distance of the segment, in meters.
+     */
+    public var distance: Double?
+    /**
+     * The estimated travel time of the segment, in seconds.
+     */
+    public var duration: Double?
+    /**
+     * The estimated speed along the segment, in meters per second.
+     */
+    public var speed: Double?
+    /**
+     * The speed limit along the segment.
+     */
+    public var maxSpeed: SegmentMaxSpeed?
+    /**
+     * The level of traffic congestion along the segment.
+     */
+    public var congestion: Congestion?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The distance of the segment, in meters.
+         */distance: Double?, 
+        /**
+         * The estimated travel time of the segment, in seconds.
+         */duration: Double?, 
+        /**
+         * The estimated speed along the segment, in meters per second.
+         */speed: Double?, 
+        /**
+         * The speed limit along the segment.
+         */maxSpeed: SegmentMaxSpeed?, 
+        /**
+         * The level of traffic congestion along the segment.
+         */congestion: Congestion?) {
+        self.distance = distance
+        self.duration = duration
+        self.speed = speed
+        self.maxSpeed = maxSpeed
+        self.congestion = congestion
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension SegmentAnnotation: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSegmentAnnotation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SegmentAnnotation {
+        return
+            try SegmentAnnotation(
+                distance: FfiConverterOptionDouble.read(from: &buf), 
+                duration: FfiConverterOptionDouble.read(from: &buf), 
+                speed: FfiConverterOptionDouble.read(from: &buf), 
+                maxSpeed: FfiConverterOptionTypeSegmentMaxSpeed.read(from: &buf), 
+                congestion: FfiConverterOptionTypeCongestion.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SegmentAnnotation, into buf: inout [UInt8]) {
+        FfiConverterOptionDouble.write(value.distance, into: &buf)
+        FfiConverterOptionDouble.write(value.duration, into: &buf)
+        FfiConverterOptionDouble.write(value.speed, into: &buf)
+        FfiConverterOptionTypeSegmentMaxSpeed.write(value.maxSpeed, into: &buf)
+        FfiConverterOptionTypeCongestion.write(value.congestion, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSegmentAnnotation_lift(_ buf: RustBuffer) throws -> SegmentAnnotation {
+    return try FfiConverterTypeSegmentAnnotation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSegmentAnnotation_lower(_ value: SegmentAnnotation) -> RustBuffer {
+    return FfiConverterTypeSegmentAnnotation.lower(value)
+}
+
+
+public struct SerializableNavState: Equatable, Hashable, Codable {
+    public var tripState: TripState
+    public var stepAdvanceCondition: SerializableStepAdvanceCondition
+    public var recentLocations: [UserLocation]
+    public var deviationState: RouteDeviationState
+    public var smoothedSpeed: Double?
+    public var events: [NavigationEvent]
+    public var announcedUtteranceIds: [Uuid]
+    public var deadReckoningAnchor: UserLocation?
+    public var locationSmoothingState: LocationSmoothingState?
+    public var completedStepCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(tripState: TripState, stepAdvanceCondition: SerializableStepAdvanceCondition, recentLocations: [UserLocation] = [], deviationState: RouteDeviationState = RouteDeviationState(), smoothedSpeed: Double? = nil, events: [NavigationEvent] = [], announcedUtteranceIds: [Uuid] = [], deadReckoningAnchor: UserLocation? = nil, locationSmoothingState: LocationSmoothingState? = nil, completedStepCount: UInt32 = UInt32(0)) {
+        self.tripState = tripState
+        self.stepAdvanceCondition = stepAdvanceCondition
+        self.recentLocations = recentLocations
+        self.deviationState = deviationState
+        self.smoothedSpeed = smoothedSpeed
+        self.events = events
+        self.announcedUtteranceIds = announcedUtteranceIds
+        self.deadReckoningAnchor = deadReckoningAnchor
+        self.locationSmoothingState = locationSmoothingState
+        self.completedStepCount = completedStepCount
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension SerializableNavState: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSerializableNavState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SerializableNavState {
+        return
+            try SerializableNavState(
+                tripState: FfiConverterTypeTripState.read(from: &buf), 
+                stepAdvanceCondition: FfiConverterTypeSerializableStepAdvanceCondition.read(from: &buf), 
+                recentLocations: FfiConverterSequenceTypeUserLocation.read(from: &buf), 
+                deviationState: FfiConverterTypeRouteDeviationState.read(from: &buf), 
+                smoothedSpeed: FfiConverterOptionDouble.read(from: &buf), 
+                events: FfiConverterSequenceTypeNavigationEvent.read(from: &buf), 
+                announcedUtteranceIds: FfiConverterSequenceTypeUuid.read(from: &buf), 
+                deadReckoningAnchor: FfiConverterOptionTypeUserLocation.read(from: &buf), 
+                locationSmoothingState: FfiConverterOptionTypeLocationSmoothingState.read(from: &buf), 
+                completedStepCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SerializableNavState, into buf: inout [UInt8]) {
+        FfiConverterTypeTripState.write(value.tripState, into: &buf)
+        FfiConverterTypeSerializableStepAdvanceCondition.write(value.stepAdvanceCondition, into: &buf)
+        FfiConverterSequenceTypeUserLocation.write(value.recentLocations, into: &buf)
+        FfiConverterTypeRouteDeviationState.write(value.deviationState, into: &buf)
+        FfiConverterOptionDouble.write(value.smoothedSpeed, into: &buf)
+        FfiConverterSequenceTypeNavigationEvent.write(value.events, into: &buf)
+        FfiConverterSequenceTypeUuid.write(value.announcedUtteranceIds, into: &buf)
+        FfiConverterOptionTypeUserLocation.write(value.deadReckoningAnchor, into: &buf)
+        FfiConverterOptionTypeLocationSmoothingState.write(value.locationSmoothingState, into: &buf)
+        FfiConverterUInt32.write(value.completedStepCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSerializableNavState_lift(_ buf: RustBuffer) throws -> SerializableNavState {
+    return try FfiConverterTypeSerializableNavState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSerializableNavState_lower(_ value: SerializableNavState) -> RustBuffer {
+    return FfiConverterTypeSerializableNavState.lower(value)
+}
+
+
+/**
+ * The speed of the user from the location provider.
+ */
+public struct Speed: Equatable, Hashable, Codable {
+    /**
+     * The user's speed in meters per second.
+     */
+    public var value: Double
+    /**
+     * The accuracy of the speed value, measured in meters per second.
+     */
+    public var accuracy: Double?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The user's speed in meters per second.
+         */value: Double, 
+        /**
+         * The accuracy of the speed value, measured in meters per second.
+         */accuracy: Double?) {
+        self.value = value
+        self.accuracy = accuracy
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Speed: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpeed: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Speed {
+        return
+            try Speed(
+                value: FfiConverterDouble.read(from: &buf), 
+                accuracy: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Speed, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.value, into: &buf)
+        FfiConverterOptionDouble.write(value.accuracy, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeed_lift(_ buf: RustBuffer) throws -> Speed {
+    return try FfiConverterTypeSpeed.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeed_lower(_ value: Speed) -> RustBuffer {
+    return FfiConverterTypeSpeed.lower(value)
+}
+
+
+/**
+ * A change of the speed limit ahead of the user.
+ */
+public struct SpeedLimitChange: Equatable, Hashable, Codable {
+    /**
+     * The speed limit after the change.
+     */
+    public var speedLimit: SegmentMaxSpeed
+    /**
+     * The distance (in meters) along the route from the user to where the new limit applies.
+     */
+    public var distanceToChange: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The speed limit after the change.
+         */speedLimit: SegmentMaxSpeed, 
+        /**
+         * The distance (in meters) along the route from the user to where the new limit applies.
+         */distanceToChange: Double) {
+        self.speedLimit = speedLimit
+        self.distanceToChange = distanceToChange
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension SpeedLimitChange: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpeedLimitChange: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpeedLimitChange {
+        return
+            try SpeedLimitChange(
+                speedLimit: FfiConverterTypeSegmentMaxSpeed.read(from: &buf), 
+                distanceToChange: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SpeedLimitChange, into buf: inout [UInt8]) {
+        FfiConverterTypeSegmentMaxSpeed.write(value.speedLimit, into: &buf)
+        FfiConverterDouble.write(value.distanceToChange, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeedLimitChange_lift(_ buf: RustBuffer) throws -> SpeedLimitChange {
+    return try FfiConverterTypeSpeedLimitChange.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeedLimitChange_lower(_ value: SpeedLimitChange) -> RustBuffer {
+    return FfiConverterTypeSpeedLimitChange.lower(value)
+}
+
+
+/**
+ * Configures flagging users who travel faster than the speed limit
+ * (see `is_speeding` in [`TripState::Navigating`]).
+ *
+ * Users are never flagged when either their speed or the speed limit is unknown.
+ */
+public struct SpeedingConfig: Equatable, Hashable, Codable {
+    /**
+     * How much (in meters per second) the user's speed may exceed the speed limit
+     * before they are flagged.
+     */
+    public var tolerance: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * How much (in meters per second) the user's speed may exceed the speed limit
+         * before they are flagged.
+         */tolerance: Double) {
+        self.tolerance = tolerance
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension SpeedingConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpeedingConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpeedingConfig {
+        return
+            try SpeedingConfig(
+                tolerance: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SpeedingConfig, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.tolerance, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeedingConfig_lift(_ buf: RustBuffer) throws -> SpeedingConfig {
+    return try FfiConverterTypeSpeedingConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpeedingConfig_lower(_ value: SpeedingConfig) -> RustBuffer {
+    return FfiConverterTypeSpeedingConfig.lower(value)
+}
+
+
+/**
+ * An instruction that can be synthesized using a TTS engine to announce an upcoming maneuver.
+ *
+ * Note that these do not have any locale information attached.
+ */
+public struct SpokenInstruction: Equatable, Hashable, Codable {
+    /**
+     * Plain-text instruction which can be synthesized with a TTS engine.
+     */
+    public var text: String
+    /**
+     * Speech Synthesis Markup Language, which should be preferred by clients capable of understanding it.
+     */
+    public var ssml: String?
+    /**
+     * How far (in meters) from the upcoming maneuver the instruction should start being spoken.
+     */
+    public var triggerDistanceBeforeManeuver: Double
+    /**
+     * A unique identifier for this instruction.
+     *
+     * This is provided so that platform-layer integrations can easily disambiguate between distinct utterances,
+     * which may have the same textual content.
+     * UUIDs conveniently fill this purpose.
+     *
+     * NOTE: While it is possible to deterministically create UUIDs, we do not do so at this time.
+     * This should be theoretically possible though if someone cares to write up a proposal and a PR.
+     */
+    public var utteranceId: Uuid
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Plain-text instruction which can be synthesized with a TTS engine.
+         */text: String, 
+        /**
+         * Speech Synthesis Markup Language, which should be preferred by clients capable of understanding it.
+         */ssml: String?, 
+        /**
+         * How far (in meters) from the upcoming maneuver the instruction should start being spoken.
+         */triggerDistanceBeforeManeuver: Double, 
+        /**
+         * A unique identifier for this instruction.
+         *
+         * This is provided so that platform-layer integrations can easily disambiguate between distinct utterances,
+         * which may have the same textual content.
+         * UUIDs conveniently fill this purpose.
+         *
+         * NOTE: While it is possible to deterministically create UUIDs, we do not do so at this time.
+         * This should be theoretically possible though if someone cares to write up a proposal and a PR.
+         */utteranceId: Uuid) {
+        self.text = text
+        self.ssml = ssml
+        self.triggerDistanceBeforeManeuver = triggerDistanceBeforeManeuver
+        self.utteranceId = utteranceId
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension SpokenInstruction: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpokenInstruction: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpokenInstruction {
+        return
+            try SpokenInstruction(
+                text: FfiConverterString.read(from: &buf), 
+                ssml: FfiConverterOptionString.read(from: &buf), 
+                triggerDistanceBeforeManeuver: FfiConverterDouble.read(from: &buf), 
+                utteranceId: FfiConverterTypeUuid.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SpokenInstruction, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionString.write(value.ssml, into: &buf)
+        FfiConverterDouble.write(value.triggerDistanceBeforeManeuver, into: &buf)
+        FfiConverterTypeUuid.write(value.utteranceId, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpokenInstruction_lift(_ buf: RustBuffer) throws -> SpokenInstruction {
+    return try FfiConverterTypeSpokenInstruction.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpokenInstruction_lower(_ value: SpokenInstruction) -> RustBuffer {
+    return FfiConverterTypeSpokenInstruction.lower(value)
+}
+
+
+/**
+ * The step advance result is produced on every iteration of the navigation state machine and
+ * used by the navigation to build a new [`NavState`](super::NavState) instance for that update.
+ */
+public struct StepAdvanceResult {
+    /**
+     * The step should be advanced.
+     */
+    public var shouldAdvance: Bool
+    /**
+     * The next iteration of the step advance condition.
+     *
+     * This is what the navigation controller passes to the next instance of [`NavState`](super::NavState) on the completion of
+     * an update (e.g. a user location update). Use the helper methods on [`StepAdvanceResult`] to ensure proper state handling:
+     *
+     * - [`StepAdvanceResult::next()`] - Unified method that takes a boolean and handles state appropriately
+     * - [`StepAdvanceResult::advance()`] - Creates an advancing result with automatically reset state
+     * - [`StepAdvanceResult::no_advance()`] - Creates a non-advancing result preserving current state
+     *
+     * The trait method [`StepAdvanceCondition::new_instance()`] ensures that composite conditions
+     * (Or/And) properly reset all nested conditions when any condition triggers advancement.
+     *
+     * **CRITICAL**: When advancing, this must be a clean/reset state to prevent state leakage between steps.
+     */
+    public var nextIteration: StepAdvanceCondition
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The step should be advanced.
+         */shouldAdvance: Bool, 
+        /**
+         * The next iteration of the step advance condition.
+         *
+         * This is what the navigation controller passes to the next instance of [`NavState`](super::NavState) on the completion of
+         * an update (e.g. a user location update). Use the helper methods on [`StepAdvanceResult`] to ensure proper state handling:
+         *
+         * - [`StepAdvanceResult::next()`] - Unified method that takes a boolean and handles state appropriately
+         * - [`StepAdvanceResult::advance()`] - Creates an advancing result with automatically reset state
+         * - [`StepAdvanceResult::no_advance()`] - Creates a non-advancing result preserving current state
+         *
+         * The trait method [`StepAdvanceCondition::new_instance()`] ensures that composite conditions
+         * (Or/And) properly reset all nested conditions when any condition triggers advancement.
+         *
+         * **CRITICAL**: When advancing, this must be a clean/reset state to prevent state leakage between steps.
+         */nextIteration: StepAdvanceCondition) {
+        self.shouldAdvance = shouldAdvance
+        self.nextIteration = nextIteration
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension StepAdvanceResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStepAdvanceResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StepAdvanceResult {
+        return
+            try StepAdvanceResult(
+                shouldAdvance: FfiConverterBool.read(from: &buf), 
+                nextIteration: FfiConverterTypeStepAdvanceCondition.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StepAdvanceResult, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.shouldAdvance, into: &buf)
+        FfiConverterTypeStepAdvanceCondition.write(value.nextIteration, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStepAdvanceResult_lift(_ buf: RustBuffer) throws -> StepAdvanceResult {
+    return try FfiConverterTypeStepAdvanceResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStepAdvanceResult_lower(_ value: StepAdvanceResult) -> RustBuffer {
+    return FfiConverterTypeStepAdvanceResult.lower(value)
+}
+
+
+/**
+ * Configures moving back to an earlier step when the user is clearly back on it
+ * (ex: after driving around a parking lot, or GPS drift).
+ *
+ * The most recently completed step within range is reactivated as the current step,
+ * and instructions and progress are derived from it again.
+ * Waypoints which have already been reached are not restored.
+ */
+public struct StepRegressionConfig: Equatable, Hashable, Codable {
+    /**
+     * The user must be within this distance (in meters) of an earlier step's line.
+     */
+    public var maximumDistanceFromStep: Double
+    /**
+     * The user must be farther than this distance (in meters) from the current step's line.
+     *
+     * This keeps GPS noise around the start of the current step
+     * from flipping back and forth between steps.
+     */
+    public var minimumDistanceFromCurrentStep: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The user must be within this distance (in meters) of an earlier step's line.
+         */maximumDistanceFromStep: Double, 
+        /**
+         * The user must be farther than this distance (in meters) from the current step's line.
+         *
+         * This keeps GPS noise around the start of the current step
+         * from flipping back and forth between steps.
+         */minimumDistanceFromCurrentStep: Double) {
+        self.maximumDistanceFromStep = maximumDistanceFromStep
+        self.minimumDistanceFromCurrentStep = minimumDistanceFromCurrentStep
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension StepRegressionConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeStepRegressionConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> StepRegressionConfig {
+        return
+            try StepRegressionConfig(
+                maximumDistanceFromStep: FfiConverterDouble.read(from: &buf), 
+                minimumDistanceFromCurrentStep: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: StepRegressionConfig, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.maximumDistanceFromStep, into: &buf)
+        FfiConverterDouble.write(value.minimumDistanceFromCurrentStep, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStepRegressionConfig_lift(_ buf: RustBuffer) throws -> StepRegressionConfig {
+    return try FfiConverterTypeStepRegressionConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeStepRegressionConfig_lower(_ value: StepRegressionConfig) -> RustBuffer {
+    return FfiConverterTypeStepRegressionConfig.lower(value)
+}
+
+
+/**
+ * High-level state describing progress through a route.
+ */
+public struct TripProgress: Equatable, Hashable, Codable {
+    /**
+     * The distance to the next maneuver, in meters.
+     */
+    public var distanceToNextManeuver: Double
+    /**
+     * The total distance remaining in the trip, in meters.
+     *
+     * This is the sum of the distance remaining in the current step and the distance remaining in all subsequent steps.
+     */
+    public var distanceRemaining: Double
+    /**
+     * The total duration remaining in the trip, in seconds.
+     */
+    public var durationRemaining: Double
+    /**
+     * The total duration remaining in the trip, in seconds,
+     * adjusted for the speed the user has recently been traveling at.
+     *
+     * This is only available when [`NavigationControllerConfig::adaptive_eta`] is set.
+     * Unlike [`TripProgress::duration_remaining`], which relies solely on the planned durations of the route,
+     * this reflects e.g. crawling through congestion the route did not anticipate.
+     */
+    public var adaptiveDurationRemaining: Double?
+    /**
+     * The index of the route leg the user is currently on.
+     */
+    public var currentLegIndex: UInt32
+    /**
+     * Progress towards the end of each remaining route leg, starting with the current one.
+     *
+     * Each leg ends at a (break) waypoint,
+     * so this gives the distance and duration to each remaining stop.
+     * The last entry covers the whole remaining trip.
+     */
+    public var remainingLegs: [LegProgress]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The distance to the next maneuver, in meters.
+         */distanceToNextManeuver: Double, 
+        /**
+         * The total distance remaining in the trip, in meters.
+         *
+         * This is the sum of the distance remaining in the current step and the distance remaining in all subsequent steps.
+         */distanceRemaining: Double, 
+        /**
+         * The total duration remaining in the trip, in seconds.
+         */durationRemaining: Double, 
+        /**
+         * The total duration remaining in the trip, in seconds,
+         * adjusted for the speed the user has recently been traveling at.
+         *
+         * This is only available when [`NavigationControllerConfig::adaptive_eta`] is set.
+         * Unlike [`TripProgress::duration_remaining`], which relies solely on the planned durations of the route,
+         * this reflects e.g. crawling through congestion the route did not anticipate.
+         */adaptiveDurationRemaining: Double? = nil, 
+        /**
+         * The index of the route leg the user is currently on.
+         */currentLegIndex: UInt32 = UInt32(0), 
+        /**
+         * Progress towards the end of each remaining route leg, starting with the current one.
+         *
+         * Each leg ends at a (break) waypoint,
+         * so this gives the distance and duration to each remaining stop.
+         * The last entry covers the whole remaining trip.
+         */remainingLegs: [LegProgress] = []) {
+        self.distanceToNextManeuver = distanceToNextManeuver
+        self.distanceRemaining = distanceRemaining
+        self.durationRemaining = durationRemaining
+        self.adaptiveDurationRemaining = adaptiveDurationRemaining
+        self.currentLegIndex = currentLegIndex
+        self.remainingLegs = remainingLegs
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension TripProgress: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTripProgress: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripProgress {
+        return
+            try TripProgress(
+                distanceToNextManeuver: FfiConverterDouble.read(from: &buf), 
+                distanceRemaining: FfiConverterDouble.read(from: &buf), 
+                durationRemaining: FfiConverterDouble.read(from: &buf), 
+                adaptiveDurationRemaining: FfiConverterOptionDouble.read(from: &buf), 
+                currentLegIndex: FfiConverterUInt32.read(from: &buf), 
+                remainingLegs: FfiConverterSequenceTypeLegProgress.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TripProgress, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.distanceToNextManeuver, into: &buf)
+        FfiConverterDouble.write(value.distanceRemaining, into: &buf)
+        FfiConverterDouble.write(value.durationRemaining, into: &buf)
+        FfiConverterOptionDouble.write(value.adaptiveDurationRemaining, into: &buf)
+        FfiConverterUInt32.write(value.currentLegIndex, into: &buf)
+        FfiConverterSequenceTypeLegProgress.write(value.remainingLegs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripProgress_lift(_ buf: RustBuffer) throws -> TripProgress {
+    return try FfiConverterTypeTripProgress.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripProgress_lower(_ value: TripProgress) -> RustBuffer {
+    return FfiConverterTypeTripProgress.lower(value)
+}
+
+
+/**
+ * Information pertaining to the user's full navigation trip. This includes
+ * simple stats like total duration and distance.
+ */
+public struct TripSummary: Equatable, Hashable, Codable {
+    /**
+     * The total raw distance traveled in the trip, in meters.
+     */
+    public var distanceTraveled: Double
+    /**
+     * The total snapped distance traveled in the trip, in meters.
+     */
+    public var snappedDistanceTraveled: Double
+    /**
+     * When the trip was started.
+     */
+    public var startedAt: UtcDateTime
+    /**
+     * When the trip was completed or canceled.
+     */
+    public var endedAt: UtcDateTime?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The total raw distance traveled in the trip, in meters.
+         */distanceTraveled: Double, 
+        /**
+         * The total snapped distance traveled in the trip, in meters.
+         */snappedDistanceTraveled: Double, 
+        /**
+         * When the trip was started.
+         */startedAt: UtcDateTime, 
+        /**
+         * When the trip was completed or canceled.
+         */endedAt: UtcDateTime?) {
+        self.distanceTraveled = distanceTraveled
+        self.snappedDistanceTraveled = snappedDistanceTraveled
+        self.startedAt = startedAt
+        self.endedAt = endedAt
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension TripSummary: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTripSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TripSummary {
+        return
+            try TripSummary(
+                distanceTraveled: FfiConverterDouble.read(from: &buf), 
+                snappedDistanceTraveled: FfiConverterDouble.read(from: &buf), 
+                startedAt: FfiConverterTypeUtcDateTime.read(from: &buf), 
+                endedAt: FfiConverterOptionTypeUtcDateTime.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TripSummary, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.distanceTraveled, into: &buf)
+        FfiConverterDouble.write(value.snappedDistanceTraveled, into: &buf)
+        FfiConverterTypeUtcDateTime.write(value.startedAt, into: &buf)
+        FfiConverterOptionTypeUtcDateTime.write(value.endedAt, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripSummary_lift(_ buf: RustBuffer) throws -> TripSummary {
+    return try FfiConverterTypeTripSummary.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTripSummary_lower(_ value: TripSummary) -> RustBuffer {
+    return FfiConverterTypeTripSummary.lower(value)
+}
+
+
+/**
+ * An incident along the route (see `upcoming_incident` in [`TripState::Navigating`]).
+ */
+public struct UpcomingIncident: Equatable, Hashable, Codable {
+    /**
+     * The incident.
+     */
+    public var incident: Incident
+    /**
+     * The distance (in meters) along the route from the user's snapped location
+     * to the start of the incident.
+     *
+     * This is zero when the user is inside the incident.
+     */
+    public var distanceToIncident: Double
+    /**
+     * The estimated travel time (in seconds) from the user's snapped location
+     * to the start of the incident.
+     */
+    public var durationToIncident: Double
+    /**
+     * Whether the user is currently between the start and end of the incident.
+     *
+     * Only incidents on the current step can be this one,
+     * as route steps only carry the incidents which lie entirely within them.
+     */
+    public var isInside: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The incident.
+         */incident: Incident, 
+        /**
+         * The distance (in meters) along the route from the user's snapped location
+         * to the start of the incident.
+         *
+         * This is zero when the user is inside the incident.
+         */distanceToIncident: Double, 
+        /**
+         * The estimated travel time (in seconds) from the user's snapped location
+         * to the start of the incident.
+         */durationToIncident: Double, 
+        /**
+         * Whether the user is currently between the start and end of the incident.
+         *
+         * Only incidents on the current step can be this one,
+         * as route steps only carry the incidents which lie entirely within them.
+         */isInside: Bool) {
+        self.incident = incident
+        self.distanceToIncident = distanceToIncident
+        self.durationToIncident = durationToIncident
+        self.isInside = isInside
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension UpcomingIncident: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUpcomingIncident: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UpcomingIncident {
+        return
+            try UpcomingIncident(
+                incident: FfiConverterTypeIncident.read(from: &buf), 
+                distanceToIncident: FfiConverterDouble.read(from: &buf), 
+                durationToIncident: FfiConverterDouble.read(from: &buf), 
+                isInside: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UpcomingIncident, into buf: inout [UInt8]) {
+        FfiConverterTypeIncident.write(value.incident, into: &buf)
+        FfiConverterDouble.write(value.distanceToIncident, into: &buf)
+        FfiConverterDouble.write(value.durationToIncident, into: &buf)
+        FfiConverterBool.write(value.isInside, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUpcomingIncident_lift(_ buf: RustBuffer) throws -> UpcomingIncident {
+    return try FfiConverterTypeUpcomingIncident.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUpcomingIncident_lower(_ value: UpcomingIncident) -> RustBuffer {
+    return FfiConverterTypeUpcomingIncident.lower(value)
+}
+
+
+/**
+ * A maneuver ahead of the user (see `upcoming_maneuvers` in [`TripState::Navigating`]).
+ */
+public struct UpcomingManeuver: Equatable, Hashable, Codable {
+    /**
+     * The broad class of the maneuver, if known.
+     */
+    public var maneuverType: ManeuverType?
+    /**
+     * Additional detail about the maneuver, if known.
+     */
+    public var maneuverModifier: ManeuverModifier?
+    /**
+     * The name of the road after the maneuver.
+     */
+    public var roadName: String?
+    /**
+     * The distance (in meters) along the route from the user's snapped location to the maneuver.
+     */
+    public var distanceToManeuver: Double
+    /**
+     * The estimated travel time (in seconds) from the user's snapped location to the maneuver.
+     */
+    public var durationToManeuver: Double
+    /**
+     * Whether this maneuver closely follows the previous one
+     * (see [`ManeuverPreviewConfig::closely_spaced_distance`]).
+     *
+     * This is never set for the first upcoming maneuver.
+     */
+    public var isCloselySpaced: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The broad class of the maneuver, if known.
+         */maneuverType: ManeuverType?, 
+        /**
+         * Additional detail about the maneuver, if known.
+         */maneuverModifier: ManeuverModifier?, 
+        /**
+         * The name of the road after the maneuver.
+         */roadName: String?, 
+        /**
+         * The distance (in meters) along the route from the user's snapped location to the maneuver.
+         */distanceToManeuver: Double, 
+        /**
+         * The estimated travel time (in seconds) from the user's snapped location to the maneuver.
+         */durationToManeuver: Double, 
+        /**
+         * Whether this maneuver closely follows the previous one
+         * (see [`ManeuverPreviewConfig::closely_spaced_distance`]).
+         *
+         * This is never set for the first upcoming maneuver.
+         */isCloselySpaced: Bool) {
+        self.maneuverType = maneuverType
+        self.maneuverModifier = maneuverModifier
+        self.roadName = roadName
+        self.distanceToManeuver = distanceToManeuver
+        self.durationToManeuver = durationToManeuver
+        self.isCloselySpaced = isCloselySpaced
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension UpcomingManeuver: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUpcomingManeuver: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UpcomingManeuver {
+        return
+            try UpcomingManeuver(
+                maneuverType: FfiConverterOptionTypeManeuverType.read(from: &buf), 
+                maneuverModifier: FfiConverterOptionTypeManeuverModifier.read(from: &buf), 
+                roadName: FfiConverterOptionString.read(from: &buf), 
+                distanceToManeuver: FfiConverterDouble.read(from: &buf), 
+                durationToManeuver: FfiConverterDouble.read(from: &buf), 
+                isCloselySpaced: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UpcomingManeuver, into buf: inout [UInt8]) {
+        FfiConverterOptionTypeManeuverType.write(value.maneuverType, into: &buf)
+        FfiConverterOptionTypeManeuverModifier.write(value.maneuverModifier, into: &buf)
+        FfiConverterOptionString.write(value.roadName, into: &buf)
+        FfiConverterDouble.write(value.distanceToManeuver, into: &buf)
+        FfiConverterDouble.write(value.durationToManeuver, into: &buf)
+        FfiConverterBool.write(value.isCloselySpaced, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUpcomingManeuver_lift(_ buf: RustBuffer) throws -> UpcomingManeuver {
+    return try FfiConverterTypeUpcomingManeuver.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUpcomingManeuver_lower(_ value: UpcomingManeuver) -> RustBuffer {
+    return FfiConverterTypeUpcomingManeuver.lower(value)
+}
+
+
+/**
+ * The location of the user that is navigating.
+ *
+ * In addition to coordinates, this includes estimated accuracy and course information,
+ * which can influence navigation logic and UI.
+ *
+ * NOTE: Heading is absent on purpose.
+ * Heading updates are not related to a change in the user's location.
+ */
+public struct UserLocation: Equatable, Hashable, Codable {
+    public var coordinates: GeographicCoordinate
+    /**
+     * The estimated accuracy of the coordinate (in meters)
+     */
+    public var horizontalAccuracy: Double
+    public var courseOverGround: CourseOverGround?
+    public var timestamp: Date
+    public var speed: Speed?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(coordinates: GeographicCoordinate, 
+        /**
+         * The estimated accuracy of the coordinate (in meters)
+         */horizontalAccuracy: Double, courseOverGround: CourseOverGround?, timestamp: Date, speed: Speed?) {
+        self.coordinates = coordinates
+        self.horizontalAccuracy = horizontalAccuracy
+        self.courseOverGround = courseOverGround
+        self.timestamp = timestamp
+        self.speed = speed
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension UserLocation: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUserLocation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserLocation {
+        return
+            try UserLocation(
+                coordinates: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
+                horizontalAccuracy: FfiConverterDouble.read(from: &buf), 
+                courseOverGround: FfiConverterOptionTypeCourseOverGround.read(from: &buf), 
+                timestamp: FfiConverterTimestamp.read(from: &buf), 
+                speed: FfiConverterOptionTypeSpeed.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UserLocation, into buf: inout [UInt8]) {
+        FfiConverterTypeGeographicCoordinate.write(value.coordinates, into: &buf)
+        FfiConverterDouble.write(value.horizontalAccuracy, into: &buf)
+        FfiConverterOptionTypeCourseOverGround.write(value.courseOverGround, into: &buf)
+        FfiConverterTimestamp.write(value.timestamp, into: &buf)
+        FfiConverterOptionTypeSpeed.write(value.speed, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserLocation_lift(_ buf: RustBuffer) throws -> UserLocation {
+    return try FfiConverterTypeUserLocation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserLocation_lower(_ value: UserLocation) -> RustBuffer {
+    return FfiConverterTypeUserLocation.lower(value)
+}
+
+
+/**
+ * A set of optional filters to exclude candidate edges based on their attributes.
+ */
+public struct ValhallaLocationSearchFilter: Equatable, Hashable, Codable {
+    /**
+     * Whether to exclude roads marked as tunnels.
+     */
+    public var excludeTunnel: Bool?
+    /**
+     * Whether to exclude roads marked as bridges.
+     */
+    public var excludeBridge: Bool?
+    /**
+     * Whether to exclude roads with tolls.
+     */
+    public var excludeTolls: Bool?
+    /**
+     * Whether to exclude ferries.
+     */
+    public var excludeFerry: Bool?
+    /**
+     * Whether to exclude roads marked as ramps.
+     */
+    public var excludeRamp: Bool?
+    /**
+     * Whether to exclude roads marked as closed due to a live traffic closure.
+     */
+    public var excludeClosures: Bool?
+    /**
+     * The lowest road class allowed.
+     */
+    public var minRoadClass: ValhallaRoadClass?
+    /**
+     * The highest road class allowed.
+     */
+    public var maxRoadClass: ValhallaRoadClass?
+    /**
+     * If specified, will only consider edges that are on or traverse the passed floor level.
+     * It will set `search_cutoff` to a default value of 300 meters if no cutoff value is passed.
+     * Additionally, if a `search_cutoff` is passed, it will be clamped to 1000 meters.
+     */
+    public var level: Float?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Whether to exclude roads marked as tunnels.
+         */excludeTunnel: Bool? = nil, 
+        /**
+         * Whether to exclude roads marked as bridges.
+         */excludeBridge: Bool? = nil, 
+        /**
+         * Whether to exclude roads with tolls.
+         */excludeTolls: Bool? = nil, 
+        /**
+         * Whether to exclude ferries.
+         */excludeFerry: Bool? = nil, 
+        /**
+         * Whether to exclude roads marked as ramps.
+         */excludeRamp: Bool? = nil, 
+        /**
+         * Whether to exclude roads marked as closed due to a live traffic closure.
+         */excludeClosures: Bool? = nil, 
+        /**
+         * The lowest road class allowed.
+         */minRoadClass: ValhallaRoadClass? = nil, 
+        /**
+         * The highest road class allowed.
+         */maxRoadClass: ValhallaRoadClass? = nil, 
+        /**
+         * If specified, will only consider edges that are on or traverse the passed floor level.
+         * It will set `search_cutoff` to a default value of 300 meters if no cutoff value is passed.
+         * Additionally, if a `search_cutoff` is passed, it will be clamped to 1000 meters.
+         */level: Float? = nil) {
+        self.excludeTunnel = excludeTunnel
+        self.excludeBridge = excludeBridge
+        self.excludeTolls = excludeTolls
+        self.excludeFerry = excludeFerry
+        self.excludeRamp = excludeRamp
+        self.excludeClosures = excludeClosures
+        self.minRoadClass = minRoadClass
+        self.maxRoadClass = maxRoadClass
+        self.level = level
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension ValhallaLocationSearchFilter: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeValhallaLocationSearchFilter: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ValhallaLocationSearchFilter {
+        return
+            try ValhallaLocationSearchFilter(
+                excludeTunnel: FfiConverterOptionBool.read(from: &buf), 
+                excludeBridge: FfiConverterOptionBool.read(from: &buf), 
+                excludeTolls: FfiConverterOptionBool.read(from: &buf), 
+                excludeFerry: FfiConverterOptionBool.read(from: &buf), 
+                excludeRamp: FfiConverterOptionBool.read(from: &buf), 
+                excludeClosures: FfiConverterOptionBool.read(from: &buf), 
+                minRoadClass: FfiConverterOptionTypeValhallaRoadClass.read(from: &buf), 
+                maxRoadClass: FfiConverterOptionTypeValhallaRoadClass.read(from: &buf), 
+                level: FfiConverterOptionFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ValhallaLocationSearchFilter, into buf: inout [UInt8]) {
+        FfiConverterOptionBool.write(value.excludeTunnel, into: &buf)
+        FfiConverterOptionBool.write(value.excludeBridge, into: &buf)
+        FfiConverterOptionBool.write(value.excludeTolls, into: &buf)
+        FfiConverterOptionBool.write(value.excludeFerry, into: &buf)
+        FfiConverterOptionBool.write(value.excludeRamp, into: &buf)
+        FfiConverterOptionBool.write(value.excludeClosures, into: &buf)
+        FfiConverterOptionTypeValhallaRoadClass.write(value.minRoadClass, into: &buf)
+        FfiConverterOptionTypeValhallaRoadClass.write(value.maxRoadClass, into: &buf)
+        FfiConverterOptionFloat.write(value.level, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeValhallaLocationSearchFilter_lift(_ buf: RustBuffer) throws -> ValhallaLocationSearchFilter {
+    return try FfiConverterTypeValhallaLocationSearchFilter.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeValhallaLocationSearchFilter_lower(_ value: ValhallaLocationSearchFilter) -> RustBuffer {
+    return FfiConverterTypeValhallaLocationSearchFilter.lower(value)
+}
+
+
+/**
+ * Waypoint properties supported by Valhalla servers.
+ *
+ * Our docstrings are short here, since Valhalla is the final authority.
+ * Refer to <https://valhalla.github.io/valhalla/api/turn-by-turn/api-reference/#locations>
+ * for more details, including default values.
+ * Other Valhalla-based APIs such as Stadia Maps or Mapbox may have slightly different defaults.
+ * Refer to your vendor's documentation when in doubt.
+ *
+ * NOTE: Waypoint properties will NOT currently be echoed back in OSRM format,
+ * so these are sent to the server one time.
+ */
+public struct ValhallaWaypointProperties: Equatable, Hashable, Codable {
+    /**
+     * Preferred direction of travel for the start from the location.
+     *
+     * The heading is indicated in degrees from north in a clockwise direction, where north is 0°, east is 90°, south is 180°, and west is 270°.
+     * Avoid specifying this unless you really know what you're doing.
+     * Most use cases for this are better served by `preferred_side`.
+     */
+    public var heading: UInt16?
+    /**
+     * How close in degrees a given street's angle must be
+     * in order for it to be considered as in the same direction of the heading parameter.
+     */
+    public var headingTolerance: UInt16?
+    /**
+     * Minimum number of nodes (intersections) reachable for a given edge
+     * (road between intersections) to consider that edge as belonging to a connected region.
+     * Disconnected edges are ignored.
+     */
+    public var minimumReachability: UInt16?
+    /**
+     * The number of meters about this input location within which edges
+     * will be considered as candidates for said location.
+     * If there are no candidates within this distance,
+     * it will return the closest candidate within reason.
+     *
+     * This allows the routing engine to match another edge which is NOT
+     * the closest to your location, but in within this range.
+     * This can be useful if you have other constraints and want to disambiguate,
+     * but beware that this can lead to very strange results,
+     * particularly if you have specified other parameters like `heading`.
+     * This is an advanced feature and should only be used with extreme care.
+     */
+    public var radius: UInt16?
+    /**
+     * Determines whether the location should be visited from the same, opposite or either side of the road,
+     * with respect to the side of the road the given locale drives on.
+     *
+     * NOTE: If the location is not offset from the road centerline
+     * or is very close to an intersection, this option has no effect!
+     */
+    public var preferredSide: ValhallaWaypointPreferredSide?
+    /**
+     * Latitude of the map location in degrees.
+     *
+     * If provided, the waypoint location will still be used for routing,
+     * but these coordinates will determine the side of the street.
+     */
+    public var displayCoordinate: GeographicCoordinate?
+    /**
+     * The cutoff at which we will assume the input is too far away from civilization
+     * to be worth correlating to the nearest graph elements.
+     */
+    public var searchCutoff: UInt32?
+    /**
+     * During edge correlation, this is the tolerance used to determine whether to snap
+     * to the intersection rather than along the street.
+     * If the snap location is within this distance from the intersection,
+     * the intersection is used instead.
+     */
+    public var nodeSnapTolerance: UInt16?
+    /**
+     * A tolerance in meters from the edge centerline used for determining the side of the street
+     * that the location is on.
+     * If the distance to the centerline is less than this tolerance,
+     * no side will be inferred.
+     * Otherwise, the left or right side will be selected depending on the direction of travel.
+     */
+    public var streetSideTolerance: UInt16?
+    /**
+     * The max distance in meters that the input coordinates or display lat/lon can be
+     * from the edge centerline for them to be used for determining the side of the street.
+     * Beyond this distance, no street side is inferred.
+     */
+    public var streetSideMaxDistance: UInt16?
+    /**
+     * Disables the `preferred_side` when set to `same` or `opposite`
+     * if the edge has a road class less than that provided by `street_side_cutoff`.
+     */
+    public var streetSideCutoff: ValhallaRoadClass?
+    /**
+     * A set of optional filters to exclude candidate edges based on their attributes.
+     */
+    public var searchFilter: ValhallaLocationSearchFilter?
+    /**
+     * Determines whether U-Turns are allowed at the waypoint
+     * (if it is an intermediate waypoint, not the first or last).
+     *
+     * Defaults to `true`. This has the effect of converting a [`WaypointKind::Break`]
+     * into a Valhalla `break_through`, and a [`WaypointKind::Via`] into a `through`.
+     */
+    public var allowUturns: Bool?
+    /**
+     * Location or business name.
+     *
+     * The name may be used in the route narration directions,
+     * such as "You have arrived at &lt;business name&gt;."
+     *
+     * WARNING: this param is not echoed back in the response, so this can disappear when rerouting!
+     *
+     * NOTE: Serialized as `waypoint_name` in the waypoint properties blob so it cannot
+     * collide with the `name` of [`OsrmWaypointProperties`](crate::routing_adapters::osrm::models::OsrmWaypointProperties)
+     * (the snapped street name), which occupies the same blob on waypoints rebuilt from a
+     * route response (e.g. the remaining waypoints used for reroute requests).
+     * It is still sent to Valhalla as `name`.
+     */
+    public var name: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Preferred direction of travel for the start from the location.
+         *
+         * The heading is indicated in degrees from north in a clockwise direction, where north is 0°, east is 90°, south is 180°, and west is 270°.
+         * Avoid specifying this unless you really know what you're doing.
+         * Most use cases for this are better served by `preferred_side`.
+         */heading: UInt16? = nil, 
+        /**
+         * How close in degrees a given street's angle must be
+         * in order for it to be considered as in the same direction of the heading parameter.
+         */headingTolerance: UInt16? = nil, 
+        /**
+         * Minimum number of nodes (intersections) reachable for a given edge
+         * (road between intersections) to consider that edge as belonging to a connected region.
+         * Disconnected edges are ignored.
+         */minimumReachability: UInt16? = nil, 
+        /**
+         * The number of meters about this input location within which edges
+         * will be considered as candidates for said location.
+         * If there are no candidates within this distance,
+         * it will return the closest candidate within reason.
+         *
+         * This allows the routing engine to match another edge which is NOT
+         * the closest to your location, but in within this range.
+         * This can be useful if you have other constraints and want to disambiguate,
+         * but beware that this can lead to very strange results,
+         * particularly if you have specified other parameters like `heading`.
+         * This is an advanced feature and should only be used with extreme care.
+         */radius: UInt16? = nil, 
+        /**
+         * Determines whether the location should be visited from the same, opposite or either side of the road,
+         * with respect to the side of the road the given locale drives on.
+         *
+         * NOTE: If the location is not offset from the road centerline
+         * or is very close to an intersection, this option has no effect!
+         */preferredSide: ValhallaWaypointPreferredSide? = nil, 
+        /**
+         * Latitude of the map location in degrees.
+         *
+         * If provided, the waypoint location will still be used for routing,
+         * but these coordinates will determine the side of the street.
+         */displayCoordinate: GeographicCoordinate? = nil, 
+        /**
+         * The cutoff at which we will assume the input is too far away from civilization
+         * to be worth correlating to the nearest graph elements.
+         */searchCutoff: UInt32? = nil, 
+        /**
+         * During edge correlation, this is the tolerance used to determine whether to snap
+         * to the intersection rather than along the street.
+         * If the snap location is within this distance from the intersection,
+         * the intersection is used instead.
+         */nodeSnapTolerance: UInt16? = nil, 
+        /**
+         * A tolerance in meters from the edge centerline used for determining the side of the street
+         * that the location is on.
+         * If the distance to the centerline is less than this tolerance,
+         * no side will be inferred.
+         * Otherwise, the left or right side will be selected depending on the direction of travel.
+         */streetSideTolerance: UInt16? = nil, 
+        /**
+         * The max distance in meters that the input coordinates or display lat/lon can be
+         * from the edge centerline for them to be used for determining the side of the street.
+         * Beyond this distance, no street side is inferred.
+         */streetSideMaxDistance: UInt16? = nil, 
+        /**
+         * Disables the `preferred_side` when set to `same` or `opposite`
+         * if the edge has a road class less than that provided by `street_side_cutoff`.
+         */streetSideCutoff: ValhallaRoadClass? = nil, 
+        /**
+         * A set of optional filters to exclude candidate edges based on their attributes.
+         */searchFilter: ValhallaLocationSearchFilter? = nil, 
+        /**
+         * Determines whether U-Turns are allowed at the waypoint
+         * (if it is an intermediate waypoint, not the first or last).
+         *
+         * Defaults to `true`. This has the effect of converting a [`WaypointKind::Break`]
+         * into a Valhalla `break_through`, and a [`WaypointKind::Via`] into a `through`.
+         */allowUturns: Bool? = nil, 
+        /**
+         * Location or business name.
+         *
+         * The name may be used in the route narration directions,
+         * such as "You have arrived at &lt;business name&gt;."
+         *
+         * WARNING: this param is not echoed back in the response, so this can disappear when rerouting!
+         *
+         * NOTE: Serialized as `waypoint_name` in the waypoint properties blob so it cannot
+         * collide with the `name` of [`OsrmWaypointProperties`](crate::routing_adapters::osrm::models::OsrmWaypointProperties)
+         * (the snapped street name), which occupies the same blob on waypoints rebuilt from a
+         * route response (e.g. the remaining waypoints used for reroute requests).
+         * It is still sent to Valhalla as `name`.
+         */name: String? = nil) {
+        self.heading = heading
+        self.headingTolerance = headingTolerance
+        self.minimumReachability = minimumReachability
+        self.radius = radius
+        self.preferredSide = preferredSide
+        self.displayCoordinate = displayCoordinate
+        self.searchCutoff = searchCutoff
+        self.nodeSnapTolerance = nodeSnapTolerance
+        self.streetSideTolerance = streetSideTolerance
+        self.streetSideMaxDistance = streetSideMaxDistance
+        self.streetSideCutoff = streetSideCutoff
+        self.searchFilter = searchFilter
+        self.allowUturns = allowUturns
+        self.name = name
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension ValhallaWaypointProperties: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeValhallaWaypointProperties: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ValhallaWaypointProperties {
+        return
+            try ValhallaWaypointProperties(
+                heading: FfiConverterOptionUInt16.read(from: &buf), 
+                headingTolerance: FfiConverterOptionUInt16.read(from: &buf), 
+                minimumReachability: FfiConverterOptionUInt16.read(from: &buf), 
+                radius: FfiConverterOptionUInt16.read(from: &buf), 
+                preferredSide: FfiConverterOptionTypeValhallaWaypointPreferredSide.read(from: &buf), 
+                displayCoordinate: FfiConverterOptionTypeGeographicCoordinate.read(from: &buf), 
+                searchCutoff: FfiConverterOptionUInt32.read(from: &buf), 
+                nodeSnapTolerance: FfiConverterOptionUInt16.read(from: &buf), 
+                streetSideTolerance: FfiConverterOptionUInt16.read(from: &buf), 
+                streetSideMaxDistance: FfiConverterOptionUInt16.read(from: &buf), 
+                streetSideCutoff: FfiConverterOptionTypeValhallaRoadClass.read(from: &buf), 
+                searchFilter: FfiConverterOptionTypeValhallaLocationSearchFilter.read(from: &buf), 
+                allowUturns: FfiConverterOptionBool.read(from: &buf), 
+                name: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ValhallaWaypointProperties, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt16.write(value.heading, into: &buf)
+        FfiConverterOptionUInt16.write(value.headingTolerance, into: &buf)
+        FfiConverterOptionUInt16.write(value.minimumReachability, into: &buf)
+        FfiConverterOptionUInt16.write(value.radius, into: &buf)
+        FfiConverterOptionTypeValhallaWaypointPreferredSide.write(value.preferredSide, into: &buf)
+        FfiConverterOptionTypeGeographicCoordinate.write(value.displayCoordinate, into: &buf)
+        FfiConverterOptionUInt32.write(value.searchCutoff, into: &buf)
+        FfiConverterOptionUInt16.write(value.nodeSnapTolerance, into: &buf)
+        FfiConverterOptionUInt16.write(value.streetSideTolerance, into: &buf)
+        FfiConverterOptionUInt16.write(value.streetSideMaxDistance, into: &buf)
+        FfiConverterOptionTypeValhallaRoadClass.write(value.streetSideCutoff, into: &buf)
+        FfiConverterOptionTypeValhallaLocationSearchFilter.write(value.searchFilter, into: &buf)
+        FfiConverterOptionBool.write(value.allowUturns, into: &buf)
+        FfiConverterOptionString.write(value.name, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeValhallaWaypointProperties_lift(_ buf: RustBuffer) throws -> ValhallaWaypointProperties {
+    return try FfiConverterTypeValhallaWaypointProperties.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeValhallaWaypointProperties_lower(_ value: ValhallaWaypointProperties) -> RustBuffer {
+    return FfiConverterTypeValhallaWaypointProperties.lower(value)
+}
+
+
+/**
+ * An instruction for visual display (usually as banners) at a specific point along a [`RouteStep`].
+ */
+public struct VisualInstruction: Equatable, Hashable, Codable {
+    /**
+     * The primary instruction content.
+     *
+     * This is usually given more visual weight.
+     */
+    public var primaryContent: VisualInstructionContent
+    /**
+     * Optional secondary instruction content.
+     */
+    public var secondaryContent: VisualInstructionContent?
+    /**
+     * Optional sub-maneuver instruction content.
+     */
+    public var subContent: VisualInstructionContent?
+    /**
+     * How far (in meters) from the upcoming maneuver the instruction should start being displayed
+     */
+    public var triggerDistanceBeforeManeuver: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The primary instruction content.
+         *
+         * This is usually given more visual weight.
+         */primaryContent: VisualInstructionContent, 
+        /**
+         * Optional secondary instruction content.
+         */secondaryContent: VisualInstructionContent?, 
+        /**
+         * Optional sub-maneuver instruction content.
+         */subContent: VisualInstructionContent?, 
+        /**
+         * How far (in meters) from the upcoming maneuver the instruction should start being displayed
+         */triggerDistanceBeforeManeuver: Double) {
+        self.primaryContent = primaryContent
+        self.secondaryContent = secondaryContent
+        self.subContent = subContent
+        self.triggerDistanceBeforeManeuver = triggerDistanceBeforeManeuver
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension VisualInstruction: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVisualInstruction: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisualInstruction {
+        return
+            try VisualInstruction(
+                primaryContent: FfiConverterTypeVisualInstructionContent.read(from: &buf), 
+                secondaryContent: FfiConverterOptionTypeVisualInstructionContent.read(from: &buf), 
+                subContent: FfiConverterOptionTypeVisualInstructionContent.read(from: &buf), 
+                triggerDistanceBeforeManeuver: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VisualInstruction, into buf: inout [UInt8]) {
+        FfiConverterTypeVisualInstructionContent.write(value.primaryContent, into: &buf)
+        FfiConverterOptionTypeVisualInstructionContent.write(value.secondaryContent, into: &buf)
+        FfiConverterOptionTypeVisualInstructionContent.write(value.subContent, into: &buf)
+        FfiConverterDouble.write(value.triggerDistanceBeforeManeuver, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisualInstruction_lift(_ buf: RustBuffer) throws -> VisualInstruction {
+    return try FfiConverterTypeVisualInstruction.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisualInstruction_lower(_ value: VisualInstruction) -> RustBuffer {
+    return FfiConverterTypeVisualInstruction.lower(value)
+}
+
+
+/**
+ * The content of a visual instruction.
+ */
+public struct VisualInstructionContent: Equatable, Hashable, Codable {
+    /**
+     * The text to display.
+     */
+    public var text: String
+    /**
+     * A standardized maneuver type (if any).
+     */
+    public var maneuverType: ManeuverType?
+    /**
+     * A standardized maneuver modifier (if any).
+     */
+    public var maneuverModifier: ManeuverModifier?
+    /**
+     * If applicable, the number of degrees you need to go around the roundabout before exiting.
+     *
+     * For example, entering and exiting the roundabout in the same direction of travel
+     * (as if you had gone straight, apart from the detour)
+     * would be an exit angle of 180 degrees.
+     */
+    public var roundaboutExitDegrees: UInt16?
+    /**
+     * Detailed information about the lanes. This is typically only present in sub-maneuver instructions.
+     */
+    public var laneInfo: [LaneInfo]?
+    /**
+     * The exit number (or similar identifier like "8B").
+     */
+    public var exitNumbers: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The text to display.
+         */text: String, 
+        /**
+         * A standardized maneuver type (if any).
+         */maneuverType: ManeuverType?, 
+        /**
+         * A standardized maneuver modifier (if any).
+         */maneuverModifier: ManeuverModifier?, 
+        /**
+         * If applicable, the number of degrees you need to go around the roundabout before exiting.
+         *
+         * For example, entering and exiting the roundabout in the same direction of travel
+         * (as if you had gone straight, apart from the detour)
+         * would be an exit angle of 180 degrees.
+         */roundaboutExitDegrees: UInt16?, 
+        /**
+         * Detailed information about the lanes. This is typically only present in sub-maneuver instructions.
+         */laneInfo: [LaneInfo]?, 
+        /**
+         * The exit number (or similar identifier like "8B").
+         */exitNumbers: [String]) {
+        self.text = text
+        self.maneuverType = maneuverType
+        self.maneuverModifier = maneuverModifier
+        self.roundaboutExitDegrees = roundaboutExitDegrees
+        self.laneInfo = laneInfo
+        self.exitNumbers = exitNumbers
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension VisualInstructionContent: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVisualInstructionContent: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisualInstructionContent {
+        return
+            try VisualInstructionContent(
+                text: FfiConverterString.read(from: &buf), 
+                maneuverType: FfiConverterOptionTypeManeuverType.read(from: &buf), 
+                maneuverModifier: FfiConverterOptionTypeManeuverModifier.read(from: &buf), 
+                roundaboutExitDegrees: FfiConverterOptionUInt16.read(from: &buf), 
+                laneInfo: FfiConverterOptionSequenceTypeLaneInfo.read(from: &buf), 
+                exitNumbers: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VisualInstructionContent, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionTypeManeuverType.write(value.maneuverType, into: &buf)
+        FfiConverterOptionTypeManeuverModifier.write(value.maneuverModifier, into: &buf)
+        FfiConverterOptionUInt16.write(value.roundaboutExitDegrees, into: &buf)
+        FfiConverterOptionSequenceTypeLaneInfo.write(value.laneInfo, into: &buf)
+        FfiConverterSequenceString.write(value.exitNumbers, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisualInstructionContent_lift(_ buf: RustBuffer) throws -> VisualInstructionContent {
+    return try FfiConverterTypeVisualInstructionContent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisualInstructionContent_lower(_ value: VisualInstructionContent) -> RustBuffer {
+    return FfiConverterTypeVisualInstructionContent.lower(value)
+}
+
+
+/**
+ * A waypoint along a route.
+ *
+ * Within the context of Ferrostar, a route request consists of exactly one [`UserLocation`]
+ * and at least one [`Waypoint`]. The route starts from the user's location (which may
+ * contain other useful information like their current course for the [`crate::routing_adapters::RouteRequestGenerator`]
+ * to use) and proceeds through one or more waypoints.
+ *
+ * Waypoints are used during route calculation, are tracked throughout the lifecycle of a trip,
+ * and are used for recalculating when the user deviates from the expected route.
+ *
+ * Note that support for properties beyond basic geographic coordinates varies by routing engine.
+ */
+public struct Waypoint: Equatable, Hashable, Codable {
+    public var coordinate: GeographicCoordinate
+    public var kind: WaypointKind
+    /**
+     * Optional additional properties that will be passed on to the [`crate::routing_adapters::RouteRequestGenerator`].
+     *
+     * Most users should prefer convenience functions like [`Waypoint::new_with_valhalla_properties`]
+     * (or, on platforms like iOS and Android with `UniFFI` bindings, [`crate::routing_adapters::valhalla::create_waypoint_with_valhalla_properties`]).
+     *
+     * # Format guidelines
+     *
+     * This MAY be any format agreed upon by both the request generator and response parser.
+     * However, to promote interoperability, all implementations in the Ferrostar codebase
+     * MUST use JSON.
+     *
+     * We selected JSON is selected not because it is good,
+     * but because generics (i.e., becoming `Waypoint<T>`, where an example `T` is `ValhallaProperties`)
+     * would be way too painful, particularly for foreign code.
+     * Especially JavaScript.
+     *
+     * In any case, [`crate::routing_adapters::RouteRequestGenerator`] and [`crate::routing_adapters::RouteResponseParser`]
+     * implementations SHOULD document their level support for this,
+     * ideally with an exportable record type.
+     */
+    public var properties: Data?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(coordinate: GeographicCoordinate, kind: WaypointKind, 
+        /**
+         * Optional additional properties that will be passed on to the [`crate::routing_adapters::RouteRequestGenerator`].
+         *
+         * Most users should prefer convenience functions like [`Waypoint::new_with_valhalla_properties`]
+         * (or, on platforms like iOS and Android with `UniFFI` bindings, [`crate::routing_adapters::valhalla::create_waypoint_with_valhalla_properties`]).
+         *
+         * # Format guidelines
+         *
+         * This MAY be any format agreed upon by both the request generator and response parser.
+         * However, to promote interoperability, all implementations in the Ferrostar codebase
+         * MUST use JSON.
+         *
+         * We selected JSON is selected not because it is good,
+         * but because generics (i.e., becoming `Waypoint<T>`, where an example `T` is `ValhallaProperties`)
+         * would be way too painful, particularly for foreign code.
+         * Especially JavaScript.
+         *
+         * In any case, [`crate::routing_adapters::RouteRequestGenerator`] and [`crate::routing_adapters::RouteResponseParser`]
+         * implementations SHOULD document their level support for this,
+         * ideally with an exportable record type.
+         */properties: Data? = nil) {
+        self.coordinate = coordinate
+        self.kind = kind
+        self.properties = properties
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Waypoint: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWaypoint: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Waypoint {
+        return
+            try Waypoint(
+                coordinate: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
+                kind: FfiConverterTypeWaypointKind.read(from: &buf), 
+                properties: FfiConverterOptionData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Waypoint, into buf: inout [UInt8]) {
+        FfiConverterTypeGeographicCoordinate.write(value.coordinate, into: &buf)
+        FfiConverterTypeWaypointKind.write(value.kind, into: &buf)
+        FfiConverterOptionData.write(value.properties, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypoint_lift(_ buf: RustBuffer) throws -> Waypoint {
+    return try FfiConverterTypeWaypoint.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypoint_lower(_ value: Waypoint) -> RustBuffer {
+    return FfiConverterTypeWaypoint.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The lane type blocked by the incident.
+ */
+
+public enum BlockedLane: Equatable, Hashable, Codable {
+    
+    case left
+    case leftCenter
+    case leftTurnLane
+    case center
+    case right
+    case rightCenter
+    case rightTurnLane
+    case hov
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension BlockedLane: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBlockedLane: FfiConverterRustBuffer {
+    typealias SwiftType = BlockedLane
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BlockedLane {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .left
+        
+        case 2: return .leftCenter
+        
+        case 3: return .leftTurnLane
+        
+        case 4: return .center
+        
+        case 5: return .right
+        
+        case 6: return .rightCenter
+        
+        case 7: return .rightTurnLane
+        
+        case 8: return .hov
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: BlockedLane, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .left:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .leftCenter:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .leftTurnLane:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .center:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .right:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .rightCenter:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .rightTurnLane:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .hov:
+            writeInt(&buf, Int32(8))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBlockedLane_lift(_ buf: RustBuffer) throws -> BlockedLane {
+    return try FfiConverterTypeBlockedLane.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBlockedLane_lower(_ value: BlockedLane) -> RustBuffer {
+    return FfiConverterTypeBlockedLane.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The reason a trip was canceled before reaching the end of the route.
+ */
+
+public enum CancellationReason: Equatable, Hashable, Codable {
+    
+    /**
+     * The user ended navigation.
+     */
+    case userCanceled
+    /**
+     * Navigation was ended in favor of a new trip (ex: the user picked a different destination).
+     */
+    case superseded
+    /**
+     * Navigation was ended for an application-specific reason.
+     */
+    case other(description: String
+    )
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension CancellationReason: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCancellationReason: FfiConverterRustBuffer {
+    typealias SwiftType = CancellationReason
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancellationReason {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .userCanceled
+        
+        case 2: return .superseded
+        
+        case 3: return .other(description: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CancellationReason, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .userCanceled:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .superseded:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .other(description):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(description, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancellationReason_lift(_ buf: RustBuffer) throws -> CancellationReason {
+    return try FfiConverterTypeCancellationReason.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancellationReason_lower(_ value: CancellationReason) -> RustBuffer {
+    return FfiConverterTypeCancellationReason.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Controls filtering/post-processing of user course by the [`NavigationController`].
+ */
+
+public enum CourseFiltering: Equatable, Hashable, Codable {
+    
+    /**
+     * Snap the user's course to the current step's linestring using the next index in the step's geometry.
+
+     */
+    case snapToRoute
+    /**
+     * Use the raw course as reported by the location provider with no processing.
+     */
+    case raw
+    /**
+     * Like [`CourseFiltering::SnapToRoute`], but uses the device heading
+     * (see [`Navigator::update_heading`](super::Navigator::update_heading)) at low speed.
+     *
+     * When moving slowly, neither the reported course nor the route reveal
+     * which way the user is facing (ex: at the start of a trip, or when walking).
+     */
+    case snapToRouteWithHeading(
+        /**
+         * Below this speed (in meters per second), or when the speed is unknown,
+         * a recent device heading is used instead of the route's bearing.
+         */maximumSpeed: Double
+    )
+
+
+
+
 
-    
 }
 
 #if compiler(>=6)
-extension ValhallaWaypointProperties: Sendable {}
+extension CourseFiltering: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeValhallaWaypointProperties: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ValhallaWaypointProperties {
-        return
-            try ValhallaWaypointProperties(
-                heading: FfiConverterOptionUInt16.read(from: &buf), 
-                headingTolerance: FfiConverterOptionUInt16.read(from: &buf), 
-                minimumReachability: FfiConverterOptionUInt16.read(from: &buf), 
-                radius: FfiConverterOptionUInt16.read(from: &buf), 
-                preferredSide: FfiConverterOptionTypeValhallaWaypointPreferredSide.read(from: &buf), 
-                displayCoordinate: FfiConverterOptionTypeGeographicCoordinate.read(from: &buf), 
-                searchCutoff: FfiConverterOptionUInt32.read(from: &buf), 
-                nodeSnapTolerance: FfiConverterOptionUInt16.read(from: &buf), 
-                streetSideTolerance: FfiConverterOptionUInt16.read(from: &buf), 
-                streetSideMaxDistance: FfiConverterOptionUInt16.read(from: &buf), 
-                streetSideCutoff: FfiConverterOptionTypeValhallaRoadClass.read(from: &buf), 
-                searchFilter: FfiConverterOptionTypeValhallaLocationSearchFilter.read(from: &buf), 
-                allowUturns: FfiConverterOptionBool.read(from: &buf), 
-                name: FfiConverterOptionString.read(from: &buf)
+public struct FfiConverterTypeCourseFiltering: FfiConverterRustBuffer {
+    typealias SwiftType = CourseFiltering
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CourseFiltering {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .snapToRoute
+        
+        case 2: return .raw
+        
+        case 3: return .snapToRouteWithHeading(maximumSpeed: try FfiConverterDouble.read(from: &buf)
         )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: ValhallaWaypointProperties, into buf: inout [UInt8]) {
-        FfiConverterOptionUInt16.write(value.heading, into: &buf)
-        FfiConverterOptionUInt16.write(value.headingTolerance, into: &buf)
-        FfiConverterOptionUInt16.write(value.minimumReachability, into: &buf)
-        FfiConverterOptionUInt16.write(value.radius, into: &buf)
-        FfiConverterOptionTypeValhallaWaypointPreferredSide.write(value.preferredSide, into: &buf)
-        FfiConverterOptionTypeGeographicCoordinate.write(value.displayCoordinate, into: &buf)
-        FfiConverterOptionUInt32.write(value.searchCutoff, into: &buf)
-        FfiConverterOptionUInt16.write(value.nodeSnapTolerance, into: &buf)
-        FfiConverterOptionUInt16.write(value.streetSideTolerance, into: &buf)
-        FfiConverterOptionUInt16.write(value.streetSideMaxDistance, into: &buf)
-        FfiConverterOptionTypeValhallaRoadClass.write(value.streetSideCutoff, into: &buf)
-        FfiConverterOptionTypeValhallaLocationSearchFilter.write(value.searchFilter, into: &buf)
-        FfiConverterOptionBool.write(value.allowUturns, into: &buf)
-        FfiConverterOptionString.write(value.name, into: &buf)
+    public static func write(_ value: CourseFiltering, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .snapToRoute:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .raw:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .snapToRouteWithHeading(maximumSpeed):
+            writeInt(&buf, Int32(3))
+            FfiConverterDouble.write(maximumSpeed, into: &buf)
+            
+        }
     }
 }
 
@@ -6474,92 +9941,202 @@ public struct FfiConverterTypeValhallaWaypointProperties: FfiConverterRustBuffer
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeValhallaWaypointProperties_lift(_ buf: RustBuffer) throws -> ValhallaWaypointProperties {
-    return try FfiConverterTypeValhallaWaypointProperties.lift(buf)
+public func FfiConverterTypeCourseFiltering_lift(_ buf: RustBuffer) throws -> CourseFiltering {
+    return try FfiConverterTypeCourseFiltering.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeValhallaWaypointProperties_lower(_ value: ValhallaWaypointProperties) -> RustBuffer {
-    return FfiConverterTypeValhallaWaypointProperties.lower(value)
+public func FfiConverterTypeCourseFiltering_lower(_ value: CourseFiltering) -> RustBuffer {
+    return FfiConverterTypeCourseFiltering.lower(value)
 }
 
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * An instruction for visual display (usually as banners) at a specific point along a [`RouteStep`].
+ * Controls when a deviation-aware step-advance condition is allowed to evaluate,
+ * based on the user's current
+ * [`RouteDeviation`](crate::deviation_detection::RouteDeviation) status.
  */
-public struct VisualInstruction: Equatable, Hashable, Codable {
+
+public enum DeviationCalculationPolicy: Equatable, Hashable, Codable {
+    
     /**
-     * The primary instruction content.
-     *
-     * This is usually given more visual weight.
+     * Always evaluate, regardless of deviation status.
      */
-    public var primaryContent: VisualInstructionContent
+    case always
     /**
-     * Optional secondary instruction content.
+     * Evaluate while the user is still somewhere on the route polyline
+     * (current step or any future step),
+     * but suspend if the user is completely off the route.
      */
-    public var secondaryContent: VisualInstructionContent?
+    case whileOnRoute
     /**
-     * Optional sub-maneuver instruction content.
+     * Evaluate only while the user is within an acceptable deviation of the current step's polyline.
+     *
+     * Suspend on any deviation
+     * (whether off-step-but-on-route, or completely off-route).
      */
-    public var subContent: VisualInstructionContent?
+    case whileOnCurrentStep
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension DeviationCalculationPolicy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDeviationCalculationPolicy: FfiConverterRustBuffer {
+    typealias SwiftType = DeviationCalculationPolicy
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeviationCalculationPolicy {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .always
+        
+        case 2: return .whileOnRoute
+        
+        case 3: return .whileOnCurrentStep
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DeviationCalculationPolicy, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .always:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .whileOnRoute:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .whileOnCurrentStep:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDeviationCalculationPolicy_lift(_ buf: RustBuffer) throws -> DeviationCalculationPolicy {
+    return try FfiConverterTypeDeviationCalculationPolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDeviationCalculationPolicy_lower(_ value: DeviationCalculationPolicy) -> RustBuffer {
+    return FfiConverterTypeDeviationCalculationPolicy.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The kind of deviation from the expected route.
+ */
+
+public enum DeviationKind: Equatable, Hashable, Codable {
+    
     /**
-     * How far (in meters) from the upcoming maneuver the instruction should start being displayed
+     * The user is off the current step, but still on the route polyline (on a future step).
+     *
+     * This can happen when the user takes a shortcut, GPS noise places them on a future step,
+     * or the route self-intersects. Step advance conditions can use this to limit advancement.
      */
-    public var triggerDistanceBeforeManeuver: Double
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The primary instruction content.
-         *
-         * This is usually given more visual weight.
-         */primaryContent: VisualInstructionContent, 
+    case offStepOnRoute(
         /**
-         * Optional secondary instruction content.
-         */secondaryContent: VisualInstructionContent?, 
+         * The deviation from the current step line, in meters.
+         */deviationFromStepLine: Double
+    )
+    /**
+     * The user is off the expected route entirely
+     * (not within threshold of any remaining step,
+     * neither the current one nor any future one).
+     */
+    case completelyOffRoute(
         /**
-         * Optional sub-maneuver instruction content.
-         */subContent: VisualInstructionContent?, 
+         * The deviation from the route line, in meters.
+         */deviationFromRouteLine: Double
+    )
+    /**
+     * The user is on the route, but traveling against its direction
+     * (for example, after making a U-turn).
+     */
+    case wrongDirection(
         /**
-         * How far (in meters) from the upcoming maneuver the instruction should start being displayed
-         */triggerDistanceBeforeManeuver: Double) {
-        self.primaryContent = primaryContent
-        self.secondaryContent = secondaryContent
-        self.subContent = subContent
-        self.triggerDistanceBeforeManeuver = triggerDistanceBeforeManeuver
-    }
+         * The difference between the user's course and the route bearing, in degrees.
+         */deviationFromRouteBearing: UInt16
+    )
+
+
+
 
-    
 
-    
 }
 
 #if compiler(>=6)
-extension VisualInstruction: Sendable {}
+extension DeviationKind: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVisualInstruction: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisualInstruction {
-        return
-            try VisualInstruction(
-                primaryContent: FfiConverterTypeVisualInstructionContent.read(from: &buf), 
-                secondaryContent: FfiConverterOptionTypeVisualInstructionContent.read(from: &buf), 
-                subContent: FfiConverterOptionTypeVisualInstructionContent.read(from: &buf), 
-                triggerDistanceBeforeManeuver: FfiConverterDouble.read(from: &buf)
+public struct FfiConverterTypeDeviationKind: FfiConverterRustBuffer {
+    typealias SwiftType = DeviationKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeviationKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .offStepOnRoute(deviationFromStepLine: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 2: return .completelyOffRoute(deviationFromRouteLine: try FfiConverterDouble.read(from: &buf)
         )
+        
+        case 3: return .wrongDirection(deviationFromRouteBearing: try FfiConverterUInt16.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: VisualInstruction, into buf: inout [UInt8]) {
-        FfiConverterTypeVisualInstructionContent.write(value.primaryContent, into: &buf)
-        FfiConverterOptionTypeVisualInstructionContent.write(value.secondaryContent, into: &buf)
-        FfiConverterOptionTypeVisualInstructionContent.write(value.subContent, into: &buf)
-        FfiConverterDouble.write(value.triggerDistanceBeforeManeuver, into: &buf)
+    public static func write(_ value: DeviationKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .offStepOnRoute(deviationFromStepLine):
+            writeInt(&buf, Int32(1))
+            FfiConverterDouble.write(deviationFromStepLine, into: &buf)
+            
+        
+        case let .completelyOffRoute(deviationFromRouteLine):
+            writeInt(&buf, Int32(2))
+            FfiConverterDouble.write(deviationFromRouteLine, into: &buf)
+            
+        
+        case let .wrongDirection(deviationFromRouteBearing):
+            writeInt(&buf, Int32(3))
+            FfiConverterUInt16.write(deviationFromRouteBearing, into: &buf)
+            
+        }
     }
 }
 
@@ -6567,116 +10144,73 @@ public struct FfiConverterTypeVisualInstruction: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisualInstruction_lift(_ buf: RustBuffer) throws -> VisualInstruction {
-    return try FfiConverterTypeVisualInstruction.lift(buf)
+public func FfiConverterTypeDeviationKind_lift(_ buf: RustBuffer) throws -> DeviationKind {
+    return try FfiConverterTypeDeviationKind.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisualInstruction_lower(_ value: VisualInstruction) -> RustBuffer {
-    return FfiConverterTypeVisualInstruction.lower(value)
+public func FfiConverterTypeDeviationKind_lower(_ value: DeviationKind) -> RustBuffer {
+    return FfiConverterTypeDeviationKind.lower(value)
 }
 
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * The content of a visual instruction.
+ * Which side of the road traffic drives on.
+ *
+ * This is needed by consumers like Android Auto to determine whether
+ * a roundabout should be rendered as clockwise (right-hand traffic)
+ * or counterclockwise (left-hand traffic).
  */
-public struct VisualInstructionContent: Equatable, Hashable, Codable {
-    /**
-     * The text to display.
-     */
-    public var text: String
-    /**
-     * A standardized maneuver type (if any).
-     */
-    public var maneuverType: ManeuverType?
-    /**
-     * A standardized maneuver modifier (if any).
-     */
-    public var maneuverModifier: ManeuverModifier?
-    /**
-     * If applicable, the number of degrees you need to go around the roundabout before exiting.
-     *
-     * For example, entering and exiting the roundabout in the same direction of travel
-     * (as if you had gone straight, apart from the detour)
-     * would be an exit angle of 180 degrees.
-     */
-    public var roundaboutExitDegrees: UInt16?
-    /**
-     * Detailed information about the lanes. This is typically only present in sub-maneuver instructions.
-     */
-    public var laneInfo: [LaneInfo]?
-    /**
-     * The exit number (or similar identifier like "8B").
-     */
-    public var exitNumbers: [String]
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * The text to display.
-         */text: String, 
-        /**
-         * A standardized maneuver type (if any).
-         */maneuverType: ManeuverType?, 
-        /**
-         * A standardized maneuver modifier (if any).
-         */maneuverModifier: ManeuverModifier?, 
-        /**
-         * If applicable, the number of degrees you need to go around the roundabout before exiting.
-         *
-         * For example, entering and exiting the roundabout in the same direction of travel
-         * (as if you had gone straight, apart from the detour)
-         * would be an exit angle of 180 degrees.
-         */roundaboutExitDegrees: UInt16?, 
-        /**
-         * Detailed information about the lanes. This is typically only present in sub-maneuver instructions.
-         */laneInfo: [LaneInfo]?, 
-        /**
-         * The exit number (or similar identifier like "8B").
-         */exitNumbers: [String]) {
-        self.text = text
-        self.maneuverType = maneuverType
-        self.maneuverModifier = maneuverModifier
-        self.roundaboutExitDegrees = roundaboutExitDegrees
-        self.laneInfo = laneInfo
-        self.exitNumbers = exitNumbers
-    }
 
+public enum DrivingSide: Equatable, Hashable, Codable {
     
+    case left
+    case right
+
+
+
+
 
-    
 }
 
 #if compiler(>=6)
-extension VisualInstructionContent: Sendable {}
+extension DrivingSide: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVisualInstructionContent: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisualInstructionContent {
-        return
-            try VisualInstructionContent(
-                text: FfiConverterString.read(from: &buf), 
-                maneuverType: FfiConverterOptionTypeManeuverType.read(from: &buf), 
-                maneuverModifier: FfiConverterOptionTypeManeuverModifier.read(from: &buf), 
-                roundaboutExitDegrees: FfiConverterOptionUInt16.read(from: &buf), 
-                laneInfo: FfiConverterOptionSequenceTypeLaneInfo.read(from: &buf), 
-                exitNumbers: FfiConverterSequenceString.read(from: &buf)
-        )
+public struct FfiConverterTypeDrivingSide: FfiConverterRustBuffer {
+    typealias SwiftType = DrivingSide
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DrivingSide {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .left
+        
+        case 2: return .right
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: VisualInstructionContent, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.text, into: &buf)
-        FfiConverterOptionTypeManeuverType.write(value.maneuverType, into: &buf)
-        FfiConverterOptionTypeManeuverModifier.write(value.maneuverModifier, into: &buf)
-        FfiConverterOptionUInt16.write(value.roundaboutExitDegrees, into: &buf)
-        FfiConverterOptionSequenceTypeLaneInfo.write(value.laneInfo, into: &buf)
-        FfiConverterSequenceString.write(value.exitNumbers, into: &buf)
+    public static func write(_ value: DrivingSide, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .left:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .right:
+            writeInt(&buf, Int32(2))
+        
+        }
     }
 }
 
@@ -6684,112 +10218,66 @@ public struct FfiConverterTypeVisualInstructionContent: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisualInstructionContent_lift(_ buf: RustBuffer) throws -> VisualInstructionContent {
-    return try FfiConverterTypeVisualInstructionContent.lift(buf)
+public func FfiConverterTypeDrivingSide_lift(_ buf: RustBuffer) throws -> DrivingSide {
+    return try FfiConverterTypeDrivingSide.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisualInstructionContent_lower(_ value: VisualInstructionContent) -> RustBuffer {
-    return FfiConverterTypeVisualInstructionContent.lower(value)
+public func FfiConverterTypeDrivingSide_lower(_ value: DrivingSide) -> RustBuffer {
+    return FfiConverterTypeDrivingSide.lower(value)
 }
 
 
-/**
- * A waypoint along a route.
- *
- * Within the context of Ferrostar, a route request consists of exactly one [`UserLocation`]
- * and at least one [`Waypoint`]. The route starts from the user's location (which may
- * contain other useful information like their current course for the [`crate::routing_adapters::RouteRequestGenerator`]
- * to use) and proceeds through one or more waypoints.
- *
- * Waypoints are used during route calculation, are tracked throughout the lifecycle of a trip,
- * and are used for recalculating when the user deviates from the expected route.
- *
- * Note that support for properties beyond basic geographic coordinates varies by routing engine.
- */
-public struct Waypoint: Equatable, Hashable, Codable {
-    public var coordinate: GeographicCoordinate
-    public var kind: WaypointKind
-    /**
-     * Optional additional properties that will be passed on to the [`crate::routing_adapters::RouteRequestGenerator`].
-     *
-     * Most users should prefer convenience functions like [`Waypoint::new_with_valhalla_properties`]
-     * (or, on platforms like iOS and Android with `UniFFI` bindings, [`crate::routing_adapters::valhalla::create_waypoint_with_valhalla_properties`]).
-     *
-     * # Format guidelines
-     *
-     * This MAY be any format agreed upon by both the request generator and response parser.
-     * However, to promote interoperability, all implementations in the Ferrostar codebase
-     * MUST use JSON.
-     *
-     * We selected JSON is selected not because it is good,
-     * but because generics (i.e., becoming `Waypoint<T>`, where an example `T` is `ValhallaProperties`)
-     * would be way too painful, particularly for foreign code.
-     * Especially JavaScript.
-     *
-     * In any case, [`crate::routing_adapters::RouteRequestGenerator`] and [`crate::routing_adapters::RouteResponseParser`]
-     * implementations SHOULD document their level support for this,
-     * ideally with an exportable record type.
-     */
-    public var properties: Data?
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(coordinate: GeographicCoordinate, kind: WaypointKind, 
-        /**
-         * Optional additional properties that will be passed on to the [`crate::routing_adapters::RouteRequestGenerator`].
-         *
-         * Most users should prefer convenience functions like [`Waypoint::new_with_valhalla_properties`]
-         * (or, on platforms like iOS and Android with `UniFFI` bindings, [`crate::routing_adapters::valhalla::create_waypoint_with_valhalla_properties`]).
-         *
-         * # Format guidelines
-         *
-         * This MAY be any format agreed upon by both the request generator and response parser.
-         * However, to promote interoperability, all implementations in the Ferrostar codebase
-         * MUST use JSON.
-         *
-         * We selected JSON is selected not because it is good,
-         * but because generics (i.e., becoming `Waypoint<T>`, where an example `T` is `ValhallaProperties`)
-         * would be way too painful, particularly for foreign code.
-         * Especially JavaScript.
-         *
-         * In any case, [`crate::routing_adapters::RouteRequestGenerator`] and [`crate::routing_adapters::RouteResponseParser`]
-         * implementations SHOULD document their level support for this,
-         * ideally with an exportable record type.
-         */properties: Data? = nil) {
-        self.coordinate = coordinate
-        self.kind = kind
-        self.properties = properties
-    }
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
+public enum GraphHopperVoiceUnits: Equatable, Hashable, Codable {
     
+    case metric
+    case imperial
+
+
+
+
 
-    
 }
 
 #if compiler(>=6)
-extension Waypoint: Sendable {}
+extension GraphHopperVoiceUnits: Sendable {}
 #endif
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeWaypoint: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Waypoint {
-        return
-            try Waypoint(
-                coordinate: FfiConverterTypeGeographicCoordinate.read(from: &buf), 
-                kind: FfiConverterTypeWaypointKind.read(from: &buf), 
-                properties: FfiConverterOptionData.read(from: &buf)
-        )
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGraphHopperVoiceUnits: FfiConverterRustBuffer {
+    typealias SwiftType = GraphHopperVoiceUnits
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GraphHopperVoiceUnits {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .metric
+        
+        case 2: return .imperial
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
     }
 
-    public static func write(_ value: Waypoint, into buf: inout [UInt8]) {
-        FfiConverterTypeGeographicCoordinate.write(value.coordinate, into: &buf)
-        FfiConverterTypeWaypointKind.write(value.kind, into: &buf)
-        FfiConverterOptionData.write(value.properties, into: &buf)
+    public static func write(_ value: GraphHopperVoiceUnits, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .metric:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .imperial:
+            writeInt(&buf, Int32(2))
+        
+        }
     }
 }
 
@@ -6797,33 +10285,31 @@ public struct FfiConverterTypeWaypoint: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypoint_lift(_ buf: RustBuffer) throws -> Waypoint {
-    return try FfiConverterTypeWaypoint.lift(buf)
+public func FfiConverterTypeGraphHopperVoiceUnits_lift(_ buf: RustBuffer) throws -> GraphHopperVoiceUnits {
+    return try FfiConverterTypeGraphHopperVoiceUnits.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypoint_lower(_ value: Waypoint) -> RustBuffer {
-    return FfiConverterTypeWaypoint.lower(value)
+public func FfiConverterTypeGraphHopperVoiceUnits_lower(_ value: GraphHopperVoiceUnits) -> RustBuffer {
+    return FfiConverterTypeGraphHopperVoiceUnits.lower(value)
 }
 
+
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * The lane type blocked by the incident.
+ * The impact of the incident that has occurred.
  */
 
-public enum BlockedLane: Equatable, Hashable, Codable {
+public enum Impact: Equatable, Hashable, Codable {
     
-    case left
-    case leftCenter
-    case leftTurnLane
-    case center
-    case right
-    case rightCenter
-    case rightTurnLane
-    case hov
+    case unknown
+    case critical
+    case major
+    case minor
+    case low
 
 
 
@@ -6832,74 +10318,56 @@ public enum BlockedLane: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension BlockedLane: Sendable {}
+extension Impact: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeBlockedLane: FfiConverterRustBuffer {
-    typealias SwiftType = BlockedLane
+public struct FfiConverterTypeImpact: FfiConverterRustBuffer {
+    typealias SwiftType = Impact
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BlockedLane {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Impact {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .left
-        
-        case 2: return .leftCenter
-        
-        case 3: return .leftTurnLane
-        
-        case 4: return .center
+        case 1: return .unknown
         
-        case 5: return .right
+        case 2: return .critical
         
-        case 6: return .rightCenter
+        case 3: return .major
         
-        case 7: return .rightTurnLane
+        case 4: return .minor
         
-        case 8: return .hov
+        case 5: return .low
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: BlockedLane, into buf: inout [UInt8]) {
+    public static func write(_ value: Impact, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .left:
+        case .unknown:
             writeInt(&buf, Int32(1))
         
         
-        case .leftCenter:
+        case .critical:
             writeInt(&buf, Int32(2))
         
         
-        case .leftTurnLane:
+        case .major:
             writeInt(&buf, Int32(3))
         
         
-        case .center:
+        case .minor:
             writeInt(&buf, Int32(4))
         
         
-        case .right:
+        case .low:
             writeInt(&buf, Int32(5))
         
-        
-        case .rightCenter:
-            writeInt(&buf, Int32(6))
-        
-        
-        case .rightTurnLane:
-            writeInt(&buf, Int32(7))
-        
-        
-        case .hov:
-            writeInt(&buf, Int32(8))
-        
         }
     }
 }
@@ -6908,35 +10376,38 @@ public struct FfiConverterTypeBlockedLane: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeBlockedLane_lift(_ buf: RustBuffer) throws -> BlockedLane {
-    return try FfiConverterTypeBlockedLane.lift(buf)
+public func FfiConverterTypeImpact_lift(_ buf: RustBuffer) throws -> Impact {
+    return try FfiConverterTypeImpact.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeBlockedLane_lower(_ value: BlockedLane) -> RustBuffer {
-    return FfiConverterTypeBlockedLane.lower(value)
+public func FfiConverterTypeImpact_lower(_ value: Impact) -> RustBuffer {
+    return FfiConverterTypeImpact.lower(value)
 }
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Controls filtering/post-processing of user course by the [`NavigationController`].
+ * The type of incident that has occurred.
  */
 
-public enum CourseFiltering: Equatable, Hashable, Codable {
+public enum IncidentType: Equatable, Hashable, Codable {
     
-    /**
-     * Snap the user's course to the current step's linestring using the next index in the step's geometry.
-
-     */
-    case snapToRoute
-    /**
-     * Use the raw course as reported by the location provider with no processing.
-     */
-    case raw
+    case accident
+    case congestion
+    case construction
+    case disabledVehicle
+    case laneRestriction
+    case massTransit
+    case miscellaneous
+    case otherNews
+    case plannedEvent
+    case roadClosure
+    case roadHazard
+    case weather
 
 
 
@@ -6945,38 +10416,98 @@ public enum CourseFiltering: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension CourseFiltering: Sendable {}
+extension IncidentType: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCourseFiltering: FfiConverterRustBuffer {
-    typealias SwiftType = CourseFiltering
+public struct FfiConverterTypeIncidentType: FfiConverterRustBuffer {
+    typealias SwiftType = IncidentType
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CourseFiltering {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IncidentType {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .snapToRoute
+        case 1: return .accident
         
-        case 2: return .raw
+        case 2: return .congestion
+        
+        case 3: return .construction
+        
+        case 4: return .disabledVehicle
+        
+        case 5: return .laneRestriction
+        
+        case 6: return .massTransit
+        
+        case 7: return .miscellaneous
+        
+        case 8: return .otherNews
+        
+        case 9: return .plannedEvent
+        
+        case 10: return .roadClosure
+        
+        case 11: return .roadHazard
+        
+        case 12: return .weather
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: CourseFiltering, into buf: inout [UInt8]) {
+    public static func write(_ value: IncidentType, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .snapToRoute:
+        case .accident:
             writeInt(&buf, Int32(1))
         
         
-        case .raw:
+        case .congestion:
             writeInt(&buf, Int32(2))
         
+        
+        case .construction:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .disabledVehicle:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .laneRestriction:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .massTransit:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .miscellaneous:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .otherNews:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .plannedEvent:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .roadClosure:
+            writeInt(&buf, Int32(10))
+        
+        
+        case .roadHazard:
+            writeInt(&buf, Int32(11))
+        
+        
+        case .weather:
+            writeInt(&buf, Int32(12))
+        
         }
     }
 }
@@ -6985,90 +10516,68 @@ public struct FfiConverterTypeCourseFiltering: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCourseFiltering_lift(_ buf: RustBuffer) throws -> CourseFiltering {
-    return try FfiConverterTypeCourseFiltering.lift(buf)
+public func FfiConverterTypeIncidentType_lift(_ buf: RustBuffer) throws -> IncidentType {
+    return try FfiConverterTypeIncidentType.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCourseFiltering_lower(_ value: CourseFiltering) -> RustBuffer {
-    return FfiConverterTypeCourseFiltering.lower(value)
+public func FfiConverterTypeIncidentType_lower(_ value: IncidentType) -> RustBuffer {
+    return FfiConverterTypeIncidentType.lower(value)
 }
 
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * Controls when a deviation-aware step-advance condition is allowed to evaluate,
- * based on the user's current
- * [`RouteDeviation`](crate::deviation_detection::RouteDeviation) status.
- */
-
-public enum DeviationCalculationPolicy: Equatable, Hashable, Codable {
-    
-    /**
-     * Always evaluate, regardless of deviation status.
-     */
-    case always
-    /**
-     * Evaluate while the user is still somewhere on the route polyline
-     * (current step or any future step),
-     * but suspend if the user is completely off the route.
-     */
-    case whileOnRoute
-    /**
-     * Evaluate only while the user is within an acceptable deviation of the current step's polyline.
-     *
-     * Suspend on any deviation
-     * (whether off-step-but-on-route, or completely off-route).
-     */
-    case whileOnCurrentStep
 
+public enum InstantiationError: Swift.Error, Equatable, Hashable, Codable, Foundation.LocalizedError {
 
+    
+    
+    case OptionsJsonParseError
 
+    
 
+    
 
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
 }
 
 #if compiler(>=6)
-extension DeviationCalculationPolicy: Sendable {}
+extension InstantiationError: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDeviationCalculationPolicy: FfiConverterRustBuffer {
-    typealias SwiftType = DeviationCalculationPolicy
+public struct FfiConverterTypeInstantiationError: FfiConverterRustBuffer {
+    typealias SwiftType = InstantiationError
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeviationCalculationPolicy {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InstantiationError {
         let variant: Int32 = try readInt(&buf)
         switch variant {
+
         
-        case 1: return .always
-        
-        case 2: return .whileOnRoute
-        
-        case 3: return .whileOnCurrentStep
+
         
-        default: throw UniffiInternalError.unexpectedEnumCase
+        case 1: return .OptionsJsonParseError
+
+         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: DeviationCalculationPolicy, into buf: inout [UInt8]) {
+    public static func write(_ value: InstantiationError, into buf: inout [UInt8]) {
         switch value {
+
         
-        
-        case .always:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .whileOnRoute:
-            writeInt(&buf, Int32(2))
+
         
         
-        case .whileOnCurrentStep:
-            writeInt(&buf, Int32(3))
+        case .OptionsJsonParseError:
+            writeInt(&buf, Int32(1))
         
         }
     }
@@ -7078,47 +10587,55 @@ public struct FfiConverterTypeDeviationCalculationPolicy: FfiConverterRustBuffer
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDeviationCalculationPolicy_lift(_ buf: RustBuffer) throws -> DeviationCalculationPolicy {
-    return try FfiConverterTypeDeviationCalculationPolicy.lift(buf)
+public func FfiConverterTypeInstantiationError_lift(_ buf: RustBuffer) throws -> InstantiationError {
+    return try FfiConverterTypeInstantiationError.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDeviationCalculationPolicy_lower(_ value: DeviationCalculationPolicy) -> RustBuffer {
-    return FfiConverterTypeDeviationCalculationPolicy.lower(value)
+public func FfiConverterTypeInstantiationError_lower(_ value: InstantiationError) -> RustBuffer {
+    return FfiConverterTypeInstantiationError.lower(value)
 }
 
-
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * The kind of deviation from the expected route.
+ * Controls how simulated locations deviate from the actual route line.
+ * This simulates real-world GPS behavior where readings often have systematic bias.
  */
 
-public enum DeviationKind: Equatable, Hashable, Codable {
+public enum LocationBias: Equatable, Hashable, Codable {
     
     /**
-     * The user is off the current step, but still on the route polyline (on a future step).
-     *
-     * This can happen when the user takes a shortcut, GPS noise places them on a future step,
-     * or the route self-intersects. Step advance conditions can use this to limit advancement.
+     * Simulates GPS bias by offsetting locations to the left of the route direction.
+     * The f64 parameter specifies the offset distance in meters.
      */
-    case offStepOnRoute(
-        /**
-         * The deviation from the current step line, in meters.
-         */deviationFromStepLine: Double
+    case left(Double
     )
     /**
-     * The user is off the expected route entirely
-     * (not within threshold of any remaining step,
-     * neither the current one nor any future one).
+     * Simulates GPS bias by offsetting locations to the right of the route direction.
+     * The f64 parameter specifies the offset distance in meters.
      */
-    case completelyOffRoute(
-        /**
-         * The deviation from the route line, in meters.
-         */deviationFromRouteLine: Double
+    case right(Double
+    )
+    /**
+     * Simulates GPS bias by randomly choosing left or right offset on initialization
+     * and maintaining that bias throughout the route.
+     * The f64 parameter specifies the offset distance in meters.
+     *
+     * This mimics real-world GPS behavior where bias direction is random but typically
+     * remains consistent during a trip.
+     */
+    case random(Double
     )
+    /**
+     * No position bias - locations follow the route line exactly.
+     *
+     * This provides "perfect" GPS behavior, useful for testing basic route following
+     * without position uncertainty.
+     */
+    case none
 
 
 
@@ -7127,42 +10644,56 @@ public enum DeviationKind: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension DeviationKind: Sendable {}
+extension LocationBias: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDeviationKind: FfiConverterRustBuffer {
-    typealias SwiftType = DeviationKind
+public struct FfiConverterTypeLocationBias: FfiConverterRustBuffer {
+    typealias SwiftType = LocationBias
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeviationKind {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationBias {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .offStepOnRoute(deviationFromStepLine: try FfiConverterDouble.read(from: &buf)
+        case 1: return .left(try FfiConverterDouble.read(from: &buf)
         )
         
-        case 2: return .completelyOffRoute(deviationFromRouteLine: try FfiConverterDouble.read(from: &buf)
+        case 2: return .right(try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 3: return .random(try FfiConverterDouble.read(from: &buf)
         )
         
+        case 4: return .none
+        
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: DeviationKind, into buf: inout [UInt8]) {
+    public static func write(_ value: LocationBias, into buf: inout [UInt8]) {
         switch value {
         
         
-        case let .offStepOnRoute(deviationFromStepLine):
+        case let .left(v1):
             writeInt(&buf, Int32(1))
-            FfiConverterDouble.write(deviationFromStepLine, into: &buf)
+            FfiConverterDouble.write(v1, into: &buf)
             
         
-        case let .completelyOffRoute(deviationFromRouteLine):
+        case let .right(v1):
             writeInt(&buf, Int32(2))
-            FfiConverterDouble.write(deviationFromRouteLine, into: &buf)
+            FfiConverterDouble.write(v1, into: &buf)
             
+        
+        case let .random(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterDouble.write(v1, into: &buf)
+            
+        
+        case .none:
+            writeInt(&buf, Int32(4))
+        
         }
     }
 }
@@ -7171,32 +10702,46 @@ public struct FfiConverterTypeDeviationKind: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDeviationKind_lift(_ buf: RustBuffer) throws -> DeviationKind {
-    return try FfiConverterTypeDeviationKind.lift(buf)
+public func FfiConverterTypeLocationBias_lift(_ buf: RustBuffer) throws -> LocationBias {
+    return try FfiConverterTypeLocationBias.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDeviationKind_lower(_ value: DeviationKind) -> RustBuffer {
-    return FfiConverterTypeDeviationKind.lower(value)
+public func FfiConverterTypeLocationBias_lower(_ value: LocationBias) -> RustBuffer {
+    return FfiConverterTypeLocationBias.lower(value)
 }
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Which side of the road traffic drives on.
- *
- * This is needed by consumers like Android Auto to determine whether
- * a roundabout should be rendered as clockwise (right-hand traffic)
- * or counterclockwise (left-hand traffic).
+ * The reason a location fix was rejected (see [`LocationFilterConfig`]).
  */
 
-public enum DrivingSide: Equatable, Hashable, Codable {
+public enum LocationRejectionReason: Equatable, Hashable, Codable {
     
-    case left
-    case right
+    /**
+     * The coordinates are not finite, or out of range.
+     */
+    case invalidCoordinates
+    /**
+     * The fix has the same timestamp as the last accepted one.
+     */
+    case duplicate
+    /**
+     * The fix is older than the last accepted one.
+     */
+    case outOfOrder
+    /**
+     * Reaching the fix from the last accepted one would require moving implausibly fast.
+     */
+    case implausibleSpeed(
+        /**
+         * The speed (in meters per second) implied by the two fixes.
+         */impliedSpeed: Double
+    )
 
 
 
@@ -7205,38 +10750,52 @@ public enum DrivingSide: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension DrivingSide: Sendable {}
+extension LocationRejectionReason: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDrivingSide: FfiConverterRustBuffer {
-    typealias SwiftType = DrivingSide
+public struct FfiConverterTypeLocationRejectionReason: FfiConverterRustBuffer {
+    typealias SwiftType = LocationRejectionReason
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DrivingSide {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationRejectionReason {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .left
+        case 1: return .invalidCoordinates
         
-        case 2: return .right
+        case 2: return .duplicate
+        
+        case 3: return .outOfOrder
+        
+        case 4: return .implausibleSpeed(impliedSpeed: try FfiConverterDouble.read(from: &buf)
+        )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: DrivingSide, into buf: inout [UInt8]) {
+    public static func write(_ value: LocationRejectionReason, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .left:
+        case .invalidCoordinates:
             writeInt(&buf, Int32(1))
         
         
-        case .right:
+        case .duplicate:
             writeInt(&buf, Int32(2))
         
+        
+        case .outOfOrder:
+            writeInt(&buf, Int32(3))
+        
+        
+        case let .implausibleSpeed(impliedSpeed):
+            writeInt(&buf, Int32(4))
+            FfiConverterDouble.write(impliedSpeed, into: &buf)
+            
         }
     }
 }
@@ -7245,25 +10804,57 @@ public struct FfiConverterTypeDrivingSide: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDrivingSide_lift(_ buf: RustBuffer) throws -> DrivingSide {
-    return try FfiConverterTypeDrivingSide.lift(buf)
+public func FfiConverterTypeLocationRejectionReason_lift(_ buf: RustBuffer) throws -> LocationRejectionReason {
+    return try FfiConverterTypeLocationRejectionReason.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDrivingSide_lower(_ value: DrivingSide) -> RustBuffer {
-    return FfiConverterTypeDrivingSide.lower(value)
+public func FfiConverterTypeLocationRejectionReason_lower(_ value: LocationRejectionReason) -> RustBuffer {
+    return FfiConverterTypeLocationRejectionReason.lower(value)
 }
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Configures the smoothing of location fixes before they are used for navigation.
+ *
+ * | Mode      | Acceleration sigma | Min. measurement sigma |
+ * |-----------|--------------------|------------------------|
+ * | Driving   | 3 m/s²             | 5 m                    |
+ * | Cycling   | 1.5 m/s²           | 4 m                    |
+ * | Walking   | 0.5 m/s²           | 3 m                    |
+ * | Transit   | 2 m/s²             | 10 m                   |
+ */
 
-public enum GraphHopperVoiceUnits: Equatable, Hashable, Codable {
+public enum LocationSmoothing: Equatable, Hashable, Codable {
     
-    case metric
-    case imperial
+    /**
+     * Smooths locations using sensible defaults for the mode of travel.
+     */
+    case defaultFor(
+        /**
+         * The mode of travel to use defaults for.
+         */modeOfTravel: ModeOfTravel
+    )
+    /**
+     * Smooths locations with custom filter parameters.
+     */
+    case custom(
+        /**
+         * The standard deviation (in m/s²) of the user's acceleration.
+         *
+         * Larger values follow changes in speed and direction more quickly,
+         * at the expense of smoothing less.
+         */accelerationSigma: Double, 
+        /**
+         * The minimum standard deviation (in meters) assumed for location measurements.
+         *
+         * The reported horizontal accuracy is used when it is larger than this.
+         */minimumMeasurementSigma: Double
+    )
 
 
 
@@ -7272,38 +10863,43 @@ public enum GraphHopperVoiceUnits: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension GraphHopperVoiceUnits: Sendable {}
+extension LocationSmoothing: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeGraphHopperVoiceUnits: FfiConverterRustBuffer {
-    typealias SwiftType = GraphHopperVoiceUnits
+public struct FfiConverterTypeLocationSmoothing: FfiConverterRustBuffer {
+    typealias SwiftType = LocationSmoothing
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GraphHopperVoiceUnits {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationSmoothing {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .metric
+        case 1: return .defaultFor(modeOfTravel: try FfiConverterTypeModeOfTravel.read(from: &buf)
+        )
         
-        case 2: return .imperial
+        case 2: return .custom(accelerationSigma: try FfiConverterDouble.read(from: &buf), minimumMeasurementSigma: try FfiConverterDouble.read(from: &buf)
+        )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: GraphHopperVoiceUnits, into buf: inout [UInt8]) {
+    public static func write(_ value: LocationSmoothing, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .metric:
+        case let .defaultFor(modeOfTravel):
             writeInt(&buf, Int32(1))
+            FfiConverterTypeModeOfTravel.write(modeOfTravel, into: &buf)
+            
         
-        
-        case .imperial:
+        case let .custom(accelerationSigma,minimumMeasurementSigma):
             writeInt(&buf, Int32(2))
-        
+            FfiConverterDouble.write(accelerationSigma, into: &buf)
+            FfiConverterDouble.write(minimumMeasurementSigma, into: &buf)
+            
         }
     }
 }
@@ -7312,31 +10908,34 @@ public struct FfiConverterTypeGraphHopperVoiceUnits: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeGraphHopperVoiceUnits_lift(_ buf: RustBuffer) throws -> GraphHopperVoiceUnits {
-    return try FfiConverterTypeGraphHopperVoiceUnits.lift(buf)
+public func FfiConverterTypeLocationSmoothing_lift(_ buf: RustBuffer) throws -> LocationSmoothing {
+    return try FfiConverterTypeLocationSmoothing.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeGraphHopperVoiceUnits_lower(_ value: GraphHopperVoiceUnits) -> RustBuffer {
-    return FfiConverterTypeGraphHopperVoiceUnits.lower(value)
+public func FfiConverterTypeLocationSmoothing_lower(_ value: LocationSmoothing) -> RustBuffer {
+    return FfiConverterTypeLocationSmoothing.lower(value)
 }
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * The impact of the incident that has occurred.
+ * Additional information to further specify a [`ManeuverType`].
  */
 
-public enum Impact: Equatable, Hashable, Codable {
+public enum ManeuverModifier: Equatable, Hashable, Codable {
     
-    case unknown
-    case critical
-    case major
-    case minor
-    case low
+    case uTurn
+    case sharpRight
+    case right
+    case slightRight
+    case straight
+    case slightLeft
+    case left
+    case sharpLeft
 
 
 
@@ -7345,56 +10944,74 @@ public enum Impact: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension Impact: Sendable {}
+extension ManeuverModifier: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeImpact: FfiConverterRustBuffer {
-    typealias SwiftType = Impact
+public struct FfiConverterTypeManeuverModifier: FfiConverterRustBuffer {
+    typealias SwiftType = ManeuverModifier
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Impact {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManeuverModifier {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .unknown
+        case 1: return .uTurn
         
-        case 2: return .critical
+        case 2: return .sharpRight
         
-        case 3: return .major
+        case 3: return .right
         
-        case 4: return .minor
+        case 4: return .slightRight
         
-        case 5: return .low
+        case 5: return .straight
+        
+        case 6: return .slightLeft
+        
+        case 7: return .left
+        
+        case 8: return .sharpLeft
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: Impact, into buf: inout [UInt8]) {
+    public static func write(_ value: ManeuverModifier, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .unknown:
+        case .uTurn:
             writeInt(&buf, Int32(1))
         
         
-        case .critical:
+        case .sharpRight:
             writeInt(&buf, Int32(2))
         
         
-        case .major:
+        case .right:
             writeInt(&buf, Int32(3))
         
         
-        case .minor:
+        case .slightRight:
             writeInt(&buf, Int32(4))
         
         
-        case .low:
+        case .straight:
             writeInt(&buf, Int32(5))
         
+        
+        case .slightLeft:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .left:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .sharpLeft:
+            writeInt(&buf, Int32(8))
+        
         }
     }
 }
@@ -7403,38 +11020,44 @@ public struct FfiConverterTypeImpact: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeImpact_lift(_ buf: RustBuffer) throws -> Impact {
-    return try FfiConverterTypeImpact.lift(buf)
+public func FfiConverterTypeManeuverModifier_lift(_ buf: RustBuffer) throws -> ManeuverModifier {
+    return try FfiConverterTypeManeuverModifier.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeImpact_lower(_ value: Impact) -> RustBuffer {
-    return FfiConverterTypeImpact.lower(value)
+public func FfiConverterTypeManeuverModifier_lower(_ value: ManeuverModifier) -> RustBuffer {
+    return FfiConverterTypeManeuverModifier.lower(value)
 }
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * The type of incident that has occurred.
+ * The broad class of maneuver to perform.
+ *
+ * This is usually combined with [`ManeuverModifier`] in [`VisualInstructionContent`].
  */
 
-public enum IncidentType: Equatable, Hashable, Codable {
+public enum ManeuverType: Equatable, Hashable, Codable {
     
-    case accident
-    case congestion
-    case construction
-    case disabledVehicle
-    case laneRestriction
-    case massTransit
-    case miscellaneous
-    case otherNews
-    case plannedEvent
-    case roadClosure
-    case roadHazard
-    case weather
+    case turn
+    case newName
+    case depart
+    case arrive
+    case merge
+    case onRamp
+    case offRamp
+    case fork
+    case endOfRoad
+    case `continue`
+    case roundabout
+    case rotary
+    case roundaboutTurn
+    case notification
+    case exitRoundabout
+    case exitRotary
 
 
 
@@ -7443,98 +11066,122 @@ public enum IncidentType: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension IncidentType: Sendable {}
+extension ManeuverType: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeIncidentType: FfiConverterRustBuffer {
-    typealias SwiftType = IncidentType
+public struct FfiConverterTypeManeuverType: FfiConverterRustBuffer {
+    typealias SwiftType = ManeuverType
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IncidentType {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManeuverType {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .accident
+        case 1: return .turn
         
-        case 2: return .congestion
+        case 2: return .newName
         
-        case 3: return .construction
+        case 3: return .depart
         
-        case 4: return .disabledVehicle
+        case 4: return .arrive
         
-        case 5: return .laneRestriction
+        case 5: return .merge
         
-        case 6: return .massTransit
+        case 6: return .onRamp
         
-        case 7: return .miscellaneous
+        case 7: return .offRamp
         
-        case 8: return .otherNews
+        case 8: return .fork
         
-        case 9: return .plannedEvent
+        case 9: return .endOfRoad
         
-        case 10: return .roadClosure
+        case 10: return .`continue`
         
-        case 11: return .roadHazard
+        case 11: return .roundabout
         
-        case 12: return .weather
+        case 12: return .rotary
+        
+        case 13: return .roundaboutTurn
+        
+        case 14: return .notification
+        
+        case 15: return .exitRoundabout
+        
+        case 16: return .exitRotary
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: IncidentType, into buf: inout [UInt8]) {
+    public static func write(_ value: ManeuverType, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .accident:
+        case .turn:
             writeInt(&buf, Int32(1))
         
         
-        case .congestion:
+        case .newName:
             writeInt(&buf, Int32(2))
         
         
-        case .construction:
+        case .depart:
             writeInt(&buf, Int32(3))
         
         
-        case .disabledVehicle:
+        case .arrive:
             writeInt(&buf, Int32(4))
         
         
-        case .laneRestriction:
+        case .merge:
             writeInt(&buf, Int32(5))
         
         
-        case .massTransit:
+        case .onRamp:
             writeInt(&buf, Int32(6))
         
         
-        case .miscellaneous:
+        case .offRamp:
             writeInt(&buf, Int32(7))
         
         
-        case .otherNews:
+        case .fork:
             writeInt(&buf, Int32(8))
         
         
-        case .plannedEvent:
+        case .endOfRoad:
             writeInt(&buf, Int32(9))
         
         
-        case .roadClosure:
+        case .`continue`:
             writeInt(&buf, Int32(10))
         
         
-        case .roadHazard:
+        case .roundabout:
             writeInt(&buf, Int32(11))
         
         
-        case .weather:
+        case .rotary:
             writeInt(&buf, Int32(12))
         
+        
+        case .roundaboutTurn:
+            writeInt(&buf, Int32(13))
+        
+        
+        case .notification:
+            writeInt(&buf, Int32(14))
+        
+        
+        case .exitRoundabout:
+            writeInt(&buf, Int32(15))
+        
+        
+        case .exitRotary:
+            writeInt(&buf, Int32(16))
+        
         }
     }
 }
@@ -7543,184 +11190,174 @@ public struct FfiConverterTypeIncidentType: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIncidentType_lift(_ buf: RustBuffer) throws -> IncidentType {
-    return try FfiConverterTypeIncidentType.lift(buf)
+public func FfiConverterTypeManeuverType_lift(_ buf: RustBuffer) throws -> ManeuverType {
+    return try FfiConverterTypeManeuverType.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeIncidentType_lower(_ value: IncidentType) -> RustBuffer {
-    return FfiConverterTypeIncidentType.lower(value)
+public func FfiConverterTypeManeuverType_lower(_ value: ManeuverType) -> RustBuffer {
+    return FfiConverterTypeManeuverType.lower(value)
 }
 
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The mode of travel for a trip.
+ *
+ * This is used to pick sensible defaults for behavior that depends on
+ * how fast (and how precisely) the user moves, such as deviation detection.
+ */
 
-public enum InstantiationError: Swift.Error, Equatable, Hashable, Codable, Foundation.LocalizedError {
-
-    
+public enum ModeOfTravel: Equatable, Hashable, Codable {
     
-    case OptionsJsonParseError
+    /**
+     * Driving a car, truck, motorcycle, etc.
+     */
+    case driving
+    /**
+     * Riding a bicycle, scooter, etc.
+     */
+    case cycling
+    /**
+     * Walking, running, hiking, etc.
+     */
+    case walking
+    /**
+     * Riding public transit.
+     *
+     * Route geometry tends to be approximate and location quality inside vehicles is often poor.
+     */
+    case transit
+
+
 
-    
 
-    
 
-    
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
-    
 }
 
 #if compiler(>=6)
-extension InstantiationError: Sendable {}
+extension ModeOfTravel: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeInstantiationError: FfiConverterRustBuffer {
-    typealias SwiftType = InstantiationError
+public struct FfiConverterTypeModeOfTravel: FfiConverterRustBuffer {
+    typealias SwiftType = ModeOfTravel
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InstantiationError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ModeOfTravel {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-
         
-
+        case 1: return .driving
         
-        case 1: return .OptionsJsonParseError
-
-         default: throw UniffiInternalError.unexpectedEnumCase
+        case 2: return .cycling
+        
+        case 3: return .walking
+        
+        case 4: return .transit
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: InstantiationError, into buf: inout [UInt8]) {
+    public static func write(_ value: ModeOfTravel, into buf: inout [UInt8]) {
         switch value {
-
-        
-
         
         
-        case .OptionsJsonParseError:
+        case .driving:
             writeInt(&buf, Int32(1))
         
-        }
-    }
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeInstantiationError_lift(_ buf: RustBuffer) throws -> InstantiationError {
-    return try FfiConverterTypeInstantiationError.lift(buf)
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeInstantiationError_lower(_ value: InstantiationError) -> RustBuffer {
-    return FfiConverterTypeInstantiationError.lower(value)
-}
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * Controls how simulated locations deviate from the actual route line.
- * This simulates real-world GPS behavior where readings often have systematic bias.
- */
-
-public enum LocationBias: Equatable, Hashable, Codable {
-    
-    /**
-     * Simulates GPS bias by offsetting locations to the left of the route direction.
-     * The f64 parameter specifies the offset distance in meters.
-     */
-    case left(Double
-    )
-    /**
-     * Simulates GPS bias by offsetting locations to the right of the route direction.
-     * The f64 parameter specifies the offset distance in meters.
-     */
-    case right(Double
-    )
-    /**
-     * Simulates GPS bias by randomly choosing left or right offset on initialization
-     * and maintaining that bias throughout the route.
-     * The f64 parameter specifies the offset distance in meters.
-     *
-     * This mimics real-world GPS behavior where bias direction is random but typically
-     * remains consistent during a trip.
-     */
-    case random(Double
-    )
-    /**
-     * No position bias - locations follow the route line exactly.
-     *
-     * This provides "perfect" GPS behavior, useful for testing basic route following
-     * without position uncertainty.
-     */
-    case none
+        
+        case .cycling:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .walking:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .transit:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeModeOfTravel_lift(_ buf: RustBuffer) throws -> ModeOfTravel {
+    return try FfiConverterTypeModeOfTravel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeModeOfTravel_lower(_ value: ModeOfTravel) -> RustBuffer {
+    return FfiConverterTypeModeOfTravel.lower(value)
+}
+
 
 
+public enum ModelError: Swift.Error, Equatable, Hashable, Codable, Foundation.LocalizedError {
+
+    
+    
+    case PolylineGenerationError(error: String
+    )
 
+    
 
+    
 
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
 }
 
 #if compiler(>=6)
-extension LocationBias: Sendable {}
+extension ModelError: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLocationBias: FfiConverterRustBuffer {
-    typealias SwiftType = LocationBias
+public struct FfiConverterTypeModelError: FfiConverterRustBuffer {
+    typealias SwiftType = ModelError
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocationBias {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ModelError {
         let variant: Int32 = try readInt(&buf)
         switch variant {
+
         
-        case 1: return .left(try FfiConverterDouble.read(from: &buf)
-        )
-        
-        case 2: return .right(try FfiConverterDouble.read(from: &buf)
-        )
-        
-        case 3: return .random(try FfiConverterDouble.read(from: &buf)
-        )
-        
-        case 4: return .none
+
         
-        default: throw UniffiInternalError.unexpectedEnumCase
+        case 1: return .PolylineGenerationError(
+            error: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: LocationBias, into buf: inout [UInt8]) {
+    public static func write(_ value: ModelError, into buf: inout [UInt8]) {
         switch value {
+
         
+
         
-        case let .left(v1):
-            writeInt(&buf, Int32(1))
-            FfiConverterDouble.write(v1, into: &buf)
-            
-        
-        case let .right(v1):
-            writeInt(&buf, Int32(2))
-            FfiConverterDouble.write(v1, into: &buf)
-            
         
-        case let .random(v1):
-            writeInt(&buf, Int32(3))
-            FfiConverterDouble.write(v1, into: &buf)
+        case let .PolylineGenerationError(error):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(error, into: &buf)
             
-        
-        case .none:
-            writeInt(&buf, Int32(4))
-        
         }
     }
 }
@@ -7729,34 +11366,82 @@ public struct FfiConverterTypeLocationBias: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLocationBias_lift(_ buf: RustBuffer) throws -> LocationBias {
-    return try FfiConverterTypeLocationBias.lift(buf)
+public func FfiConverterTypeModelError_lift(_ buf: RustBuffer) throws -> ModelError {
+    return try FfiConverterTypeModelError.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLocationBias_lower(_ value: LocationBias) -> RustBuffer {
-    return FfiConverterTypeLocationBias.lower(value)
+public func FfiConverterTypeModelError_lower(_ value: ModelError) -> RustBuffer {
+    return FfiConverterTypeModelError.lower(value)
 }
 
-
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * Additional information to further specify a [`ManeuverType`].
+ * Something notable that happened as a result of a navigation state update.
  */
 
-public enum ManeuverModifier: Equatable, Hashable, Codable {
+public enum NavigationEvent: Equatable, Hashable, Codable {
     
-    case uTurn
-    case sharpRight
-    case right
-    case slightRight
-    case straight
-    case slightLeft
-    case left
-    case sharpLeft
+    /**
+     * The user advanced to a new step.
+     *
+     * If several steps were passed in a single update, one event is emitted for each, in order.
+     */
+    case stepAdvanced(
+        /**
+         * The new current step.
+         */step: RouteStep
+    )
+    /**
+     * The user moved back to an earlier step
+     * (see [`NavigationControllerConfig::step_regression`](super::models::NavigationControllerConfig::step_regression)).
+     */
+    case returnedToStep(
+        /**
+         * The new current step.
+         */step: RouteStep
+    )
+    /**
+     * The user reached an intermediate waypoint.
+     */
+    case waypointReached(waypoint: Waypoint
+    )
+    /**
+     * The user deviated from the route.
+     */
+    case deviationStarted(kind: DeviationKind
+    )
+    /**
+     * The user is back on the route after a deviation.
+     */
+    case deviationCleared
+    /**
+     * A spoken instruction is due and should be announced.
+     *
+     * Each utterance is only announced once per trip.
+     * If several instructions became due in a single update
+     * (ex: when passing several short steps at once), one event is emitted for each, in order.
+     */
+    case spokenInstructionTriggered(instruction: SpokenInstruction
+    )
+    /**
+     * The user reached the end of the route.
+     */
+    case arrived(summary: TripSummary
+    )
+    /**
+     * The trip was canceled before reaching the end of the route.
+     */
+    case canceled(reason: CancellationReason
+    )
+    /**
+     * A location fix was rejected, and did not affect the navigation state.
+     */
+    case locationRejected(location: UserLocation, reason: LocationRejectionReason
+    )
 
 
 
@@ -7765,74 +11450,97 @@ public enum ManeuverModifier: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension ManeuverModifier: Sendable {}
+extension NavigationEvent: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeManeuverModifier: FfiConverterRustBuffer {
-    typealias SwiftType = ManeuverModifier
+public struct FfiConverterTypeNavigationEvent: FfiConverterRustBuffer {
+    typealias SwiftType = NavigationEvent
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManeuverModifier {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationEvent {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .uTurn
+        case 1: return .stepAdvanced(step: try FfiConverterTypeRouteStep.read(from: &buf)
+        )
         
-        case 2: return .sharpRight
+        case 2: return .returnedToStep(step: try FfiConverterTypeRouteStep.read(from: &buf)
+        )
         
-        case 3: return .right
+        case 3: return .waypointReached(waypoint: try FfiConverterTypeWaypoint.read(from: &buf)
+        )
         
-        case 4: return .slightRight
+        case 4: return .deviationStarted(kind: try FfiConverterTypeDeviationKind.read(from: &buf)
+        )
         
-        case 5: return .straight
+        case 5: return .deviationCleared
         
-        case 6: return .slightLeft
+        case 6: return .spokenInstructionTriggered(instruction: try FfiConverterTypeSpokenInstruction.read(from: &buf)
+        )
         
-        case 7: return .left
+        case 7: return .arrived(summary: try FfiConverterTypeTripSummary.read(from: &buf)
+        )
         
-        case 8: return .sharpLeft
+        case 8: return .canceled(reason: try FfiConverterTypeCancellationReason.read(from: &buf)
+        )
+        
+        case 9: return .locationRejected(location: try FfiConverterTypeUserLocation.read(from: &buf), reason: try FfiConverterTypeLocationRejectionReason.read(from: &buf)
+        )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ManeuverModifier, into buf: inout [UInt8]) {
+    public static func write(_ value: NavigationEvent, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .uTurn:
+        case let .stepAdvanced(step):
             writeInt(&buf, Int32(1))
+            FfiConverterTypeRouteStep.write(step, into: &buf)
+            
         
-        
-        case .sharpRight:
+        case let .returnedToStep(step):
             writeInt(&buf, Int32(2))
+            FfiConverterTypeRouteStep.write(step, into: &buf)
+            
         
-        
-        case .right:
+        case let .waypointReached(waypoint):
             writeInt(&buf, Int32(3))
+            FfiConverterTypeWaypoint.write(waypoint, into: &buf)
+            
         
-        
-        case .slightRight:
+        case let .deviationStarted(kind):
             writeInt(&buf, Int32(4))
+            FfiConverterTypeDeviationKind.write(kind, into: &buf)
+            
         
-        
-        case .straight:
+        case .deviationCleared:
             writeInt(&buf, Int32(5))
         
         
-        case .slightLeft:
+        case let .spokenInstructionTriggered(instruction):
             writeInt(&buf, Int32(6))
+            FfiConverterTypeSpokenInstruction.write(instruction, into: &buf)
+            
         
-        
-        case .left:
+        case let .arrived(summary):
             writeInt(&buf, Int32(7))
+            FfiConverterTypeTripSummary.write(summary, into: &buf)
+            
         
-        
-        case .sharpLeft:
+        case let .canceled(reason):
             writeInt(&buf, Int32(8))
+            FfiConverterTypeCancellationReason.write(reason, into: &buf)
+            
         
+        case let .locationRejected(location,reason):
+            writeInt(&buf, Int32(9))
+            FfiConverterTypeUserLocation.write(location, into: &buf)
+            FfiConverterTypeLocationRejectionReason.write(reason, into: &buf)
+            
         }
     }
 }
@@ -7841,44 +11549,40 @@ public struct FfiConverterTypeManeuverModifier: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeManeuverModifier_lift(_ buf: RustBuffer) throws -> ManeuverModifier {
-    return try FfiConverterTypeManeuverModifier.lift(buf)
+public func FfiConverterTypeNavigationEvent_lift(_ buf: RustBuffer) throws -> NavigationEvent {
+    return try FfiConverterTypeNavigationEvent.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeManeuverModifier_lower(_ value: ManeuverModifier) -> RustBuffer {
-    return FfiConverterTypeManeuverModifier.lower(value)
+public func FfiConverterTypeNavigationEvent_lower(_ value: NavigationEvent) -> RustBuffer {
+    return FfiConverterTypeNavigationEvent.lower(value)
 }
 
 
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * The broad class of maneuver to perform.
+ * The event type.
  *
- * This is usually combined with [`ManeuverModifier`] in [`VisualInstructionContent`].
+ * For full replayability, we record things like rerouting, and not just location updates.
  */
 
-public enum ManeuverType: Equatable, Hashable, Codable {
+public enum NavigationRecordingEventData: Equatable, Hashable, Codable {
     
-    case turn
-    case newName
-    case depart
-    case arrive
-    case merge
-    case onRamp
-    case offRamp
-    case fork
-    case endOfRoad
-    case `continue`
-    case roundabout
-    case rotary
-    case roundaboutTurn
-    case notification
-    case exitRoundabout
-    case exitRotary
+    case stateUpdate(tripState: TripState, stepAdvanceCondition: SerializableStepAdvanceCondition
+    )
+    /**
+     * The route was replaced mid-trip (e.g. after a reroute).
+     *
+     * This is followed by a state update with the state on the new route.
+     */
+    case routeUpdate(
+        /**
+         * Updated route.
+         */route: Route
+    )
 
 
 
@@ -7887,122 +11591,43 @@ public enum ManeuverType: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension ManeuverType: Sendable {}
+extension NavigationRecordingEventData: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeManeuverType: FfiConverterRustBuffer {
-    typealias SwiftType = ManeuverType
+public struct FfiConverterTypeNavigationRecordingEventData: FfiConverterRustBuffer {
+    typealias SwiftType = NavigationRecordingEventData
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ManeuverType {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationRecordingEventData {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .turn
-        
-        case 2: return .newName
-        
-        case 3: return .depart
-        
-        case 4: return .arrive
-        
-        case 5: return .merge
-        
-        case 6: return .onRamp
-        
-        case 7: return .offRamp
-        
-        case 8: return .fork
-        
-        case 9: return .endOfRoad
-        
-        case 10: return .`continue`
-        
-        case 11: return .roundabout
-        
-        case 12: return .rotary
-        
-        case 13: return .roundaboutTurn
-        
-        case 14: return .notification
-        
-        case 15: return .exitRoundabout
+        case 1: return .stateUpdate(tripState: try FfiConverterTypeTripState.read(from: &buf), stepAdvanceCondition: try FfiConverterTypeSerializableStepAdvanceCondition.read(from: &buf)
+        )
         
-        case 16: return .exitRotary
+        case 2: return .routeUpdate(route: try FfiConverterTypeRoute.read(from: &buf)
+        )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ManeuverType, into buf: inout [UInt8]) {
+    public static func write(_ value: NavigationRecordingEventData, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .turn:
+        case let .stateUpdate(tripState,stepAdvanceCondition):
             writeInt(&buf, Int32(1))
+            FfiConverterTypeTripState.write(tripState, into: &buf)
+            FfiConverterTypeSerializableStepAdvanceCondition.write(stepAdvanceCondition, into: &buf)
+            
         
-        
-        case .newName:
+        case let .routeUpdate(route):
             writeInt(&buf, Int32(2))
-        
-        
-        case .depart:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .arrive:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .merge:
-            writeInt(&buf, Int32(5))
-        
-        
-        case .onRamp:
-            writeInt(&buf, Int32(6))
-        
-        
-        case .offRamp:
-            writeInt(&buf, Int32(7))
-        
-        
-        case .fork:
-            writeInt(&buf, Int32(8))
-        
-        
-        case .endOfRoad:
-            writeInt(&buf, Int32(9))
-        
-        
-        case .`continue`:
-            writeInt(&buf, Int32(10))
-        
-        
-        case .roundabout:
-            writeInt(&buf, Int32(11))
-        
-        
-        case .rotary:
-            writeInt(&buf, Int32(12))
-        
-        
-        case .roundaboutTurn:
-            writeInt(&buf, Int32(13))
-        
-        
-        case .notification:
-            writeInt(&buf, Int32(14))
-        
-        
-        case .exitRoundabout:
-            writeInt(&buf, Int32(15))
-        
-        
-        case .exitRotary:
-            writeInt(&buf, Int32(16))
-        
+            FfiConverterTypeRoute.write(route, into: &buf)
+            
         }
     }
 }
@@ -8011,25 +11636,32 @@ public struct FfiConverterTypeManeuverType: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeManeuverType_lift(_ buf: RustBuffer) throws -> ManeuverType {
-    return try FfiConverterTypeManeuverType.lift(buf)
+public func FfiConverterTypeNavigationRecordingEventData_lift(_ buf: RustBuffer) throws -> NavigationRecordingEventData {
+    return try FfiConverterTypeNavigationRecordingEventData.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeManeuverType_lower(_ value: ManeuverType) -> RustBuffer {
-    return FfiConverterTypeManeuverType.lower(value)
+public func FfiConverterTypeNavigationRecordingEventData_lower(_ value: NavigationRecordingEventData) -> RustBuffer {
+    return FfiConverterTypeNavigationRecordingEventData.lower(value)
 }
 
 
 
-public enum ModelError: Swift.Error, Equatable, Hashable, Codable, Foundation.LocalizedError {
+public enum ParsingError: Swift.Error, Equatable, Hashable, Codable, Foundation.LocalizedError {
 
     
     
-    case PolylineGenerationError(error: String
+    case InvalidRouteObject(error: String
+    )
+    case InvalidGeometry(error: String
+    )
+    case MalformedAnnotations(error: String
+    )
+    case InvalidStatusCode(code: String, description: String?
     )
+    case UnknownParsingError
 
     
 
@@ -8043,41 +11675,72 @@ public enum ModelError: Swift.Error, Equatable, Hashable, Codable, Foundation.Lo
 }
 
 #if compiler(>=6)
-extension ModelError: Sendable {}
+extension ParsingError: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeModelError: FfiConverterRustBuffer {
-    typealias SwiftType = ModelError
+public struct FfiConverterTypeParsingError: FfiConverterRustBuffer {
+    typealias SwiftType = ParsingError
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ModelError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParsingError {
         let variant: Int32 = try readInt(&buf)
         switch variant {
 
         
 
         
-        case 1: return .PolylineGenerationError(
+        case 1: return .InvalidRouteObject(
+            error: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .InvalidGeometry(
+            error: try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .MalformedAnnotations(
             error: try FfiConverterString.read(from: &buf)
             )
+        case 4: return .InvalidStatusCode(
+            code: try FfiConverterString.read(from: &buf), 
+            description: try FfiConverterOptionString.read(from: &buf)
+            )
+        case 5: return .UnknownParsingError
 
          default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ModelError, into buf: inout [UInt8]) {
+    public static func write(_ value: ParsingError, into buf: inout [UInt8]) {
         switch value {
 
         
 
         
         
-        case let .PolylineGenerationError(error):
+        case let .InvalidRouteObject(error):
             writeInt(&buf, Int32(1))
             FfiConverterString.write(error, into: &buf)
             
+        
+        case let .InvalidGeometry(error):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(error, into: &buf)
+            
+        
+        case let .MalformedAnnotations(error):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(error, into: &buf)
+            
+        
+        case let .InvalidStatusCode(code,description):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterOptionString.write(description, into: &buf)
+            
+        
+        case .UnknownParsingError:
+            writeInt(&buf, Int32(5))
+        
         }
     }
 }
@@ -8086,78 +11749,99 @@ public struct FfiConverterTypeModelError: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeModelError_lift(_ buf: RustBuffer) throws -> ModelError {
-    return try FfiConverterTypeModelError.lift(buf)
+public func FfiConverterTypeParsingError_lift(_ buf: RustBuffer) throws -> ParsingError {
+    return try FfiConverterTypeParsingError.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeModelError_lower(_ value: ModelError) -> RustBuffer {
-    return FfiConverterTypeModelError.lower(value)
+public func FfiConverterTypeParsingError_lower(_ value: ParsingError) -> RustBuffer {
+    return FfiConverterTypeParsingError.lower(value)
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
 /**
- * The event type.
- *
- * For full replayability, we record things like rerouting, and not just location updates.
+ * A session recording error.
  */
+public enum RecordingError: Swift.Error, Equatable, Hashable, Codable, Foundation.LocalizedError {
 
-public enum NavigationRecordingEventData: Equatable, Hashable, Codable {
     
-    case stateUpdate(tripState: TripState, stepAdvanceCondition: SerializableStepAdvanceCondition
+    
+    /**
+     * Error during serialization.
+     */
+    case SerializationError(error: String
     )
-    case routeUpdate(
-        /**
-         * Updated route.
-         */route: Route
+    /**
+     * Recording is not enabled for this controller.
+     */
+    case RecordingNotEnabled
+    /**
+     * Error during deserialization.
+     */
+    case DeserializationError(error: String
     )
 
+    
 
+    
 
-
-
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
 }
 
 #if compiler(>=6)
-extension NavigationRecordingEventData: Sendable {}
+extension RecordingError: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeNavigationRecordingEventData: FfiConverterRustBuffer {
-    typealias SwiftType = NavigationRecordingEventData
+public struct FfiConverterTypeRecordingError: FfiConverterRustBuffer {
+    typealias SwiftType = RecordingError
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NavigationRecordingEventData {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecordingError {
         let variant: Int32 = try readInt(&buf)
         switch variant {
+
         
-        case 1: return .stateUpdate(tripState: try FfiConverterTypeTripState.read(from: &buf), stepAdvanceCondition: try FfiConverterTypeSerializableStepAdvanceCondition.read(from: &buf)
-        )
-        
-        case 2: return .routeUpdate(route: try FfiConverterTypeRoute.read(from: &buf)
-        )
+
         
-        default: throw UniffiInternalError.unexpectedEnumCase
+        case 1: return .SerializationError(
+            error: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .RecordingNotEnabled
+        case 3: return .DeserializationError(
+            error: try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: NavigationRecordingEventData, into buf: inout [UInt8]) {
+    public static func write(_ value: RecordingError, into buf: inout [UInt8]) {
         switch value {
+
+        
+
         
         
-        case let .stateUpdate(tripState,stepAdvanceCondition):
+        case let .SerializationError(error):
             writeInt(&buf, Int32(1))
-            FfiConverterTypeTripState.write(tripState, into: &buf)
-            FfiConverterTypeSerializableStepAdvanceCondition.write(stepAdvanceCondition, into: &buf)
+            FfiConverterString.write(error, into: &buf)
             
         
-        case let .routeUpdate(route):
+        case .RecordingNotEnabled:
             writeInt(&buf, Int32(2))
-            FfiConverterTypeRoute.write(route, into: &buf)
+        
+        
+        case let .DeserializationError(error):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(error, into: &buf)
             
         }
     }
@@ -8167,32 +11851,33 @@ public struct FfiConverterTypeNavigationRecordingEventData: FfiConverterRustBuff
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationRecordingEventData_lift(_ buf: RustBuffer) throws -> NavigationRecordingEventData {
-    return try FfiConverterTypeNavigationRecordingEventData.lift(buf)
+public func FfiConverterTypeRecordingError_lift(_ buf: RustBuffer) throws -> RecordingError {
+    return try FfiConverterTypeRecordingError.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeNavigationRecordingEventData_lower(_ value: NavigationRecordingEventData) -> RustBuffer {
-    return FfiConverterTypeNavigationRecordingEventData.lower(value)
+public func FfiConverterTypeRecordingError_lower(_ value: RecordingError) -> RustBuffer {
+    return FfiConverterTypeRecordingError.lower(value)
 }
 
 
-
-public enum ParsingError: Swift.Error, Equatable, Hashable, Codable, Foundation.LocalizedError {
+/**
+ * An error raised while rerouting.
+ */
+public enum RerouteError: Swift.Error, Equatable, Hashable, Codable, Foundation.LocalizedError {
 
     
     
-    case InvalidRouteObject(error: String
-    )
-    case InvalidGeometry(error: String
+    case RequestGenerationError(error: String
     )
-    case MalformedAnnotations(error: String
+    case FetchError(error: String
     )
-    case InvalidStatusCode(code: String, description: String?
+    case ParsingError(error: String
     )
-    case UnknownParsingError
+    case NoRoutes
+    case UnknownFetchError
 
     
 
@@ -8206,70 +11891,65 @@ public enum ParsingError: Swift.Error, Equatable, Hashable, Codable, Foundation.
 }
 
 #if compiler(>=6)
-extension ParsingError: Sendable {}
+extension RerouteError: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeParsingError: FfiConverterRustBuffer {
-    typealias SwiftType = ParsingError
+public struct FfiConverterTypeRerouteError: FfiConverterRustBuffer {
+    typealias SwiftType = RerouteError
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParsingError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RerouteError {
         let variant: Int32 = try readInt(&buf)
         switch variant {
 
         
 
         
-        case 1: return .InvalidRouteObject(
+        case 1: return .RequestGenerationError(
             error: try FfiConverterString.read(from: &buf)
             )
-        case 2: return .InvalidGeometry(
+        case 2: return .FetchError(
             error: try FfiConverterString.read(from: &buf)
             )
-        case 3: return .MalformedAnnotations(
+        case 3: return .ParsingError(
             error: try FfiConverterString.read(from: &buf)
             )
-        case 4: return .InvalidStatusCode(
-            code: try FfiConverterString.read(from: &buf), 
-            description: try FfiConverterOptionString.read(from: &buf)
-            )
-        case 5: return .UnknownParsingError
+        case 4: return .NoRoutes
+        case 5: return .UnknownFetchError
 
          default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ParsingError, into buf: inout [UInt8]) {
+    public static func write(_ value: RerouteError, into buf: inout [UInt8]) {
         switch value {
 
         
 
         
         
-        case let .InvalidRouteObject(error):
+        case let .RequestGenerationError(error):
             writeInt(&buf, Int32(1))
             FfiConverterString.write(error, into: &buf)
             
         
-        case let .InvalidGeometry(error):
+        case let .FetchError(error):
             writeInt(&buf, Int32(2))
             FfiConverterString.write(error, into: &buf)
             
         
-        case let .MalformedAnnotations(error):
+        case let .ParsingError(error):
             writeInt(&buf, Int32(3))
             FfiConverterString.write(error, into: &buf)
             
         
-        case let .InvalidStatusCode(code,description):
+        case .NoRoutes:
             writeInt(&buf, Int32(4))
-            FfiConverterString.write(code, into: &buf)
-            FfiConverterOptionString.write(description, into: &buf)
-            
         
-        case .UnknownParsingError:
+        
+        case .UnknownFetchError:
             writeInt(&buf, Int32(5))
         
         }
@@ -8280,99 +11960,93 @@ public struct FfiConverterTypeParsingError: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeParsingError_lift(_ buf: RustBuffer) throws -> ParsingError {
-    return try FfiConverterTypeParsingError.lift(buf)
+public func FfiConverterTypeRerouteError_lift(_ buf: RustBuffer) throws -> RerouteError {
+    return try FfiConverterTypeRerouteError.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeParsingError_lower(_ value: ParsingError) -> RustBuffer {
-    return FfiConverterTypeParsingError.lower(value)
+public func FfiConverterTypeRerouteError_lower(_ value: RerouteError) -> RustBuffer {
+    return FfiConverterTypeRerouteError.lower(value)
 }
 
-
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
- * A session recording error.
+ * The outcome of a [`RerouteController`](super::RerouteController) check.
  */
-public enum RecordingError: Swift.Error, Equatable, Hashable, Codable, Foundation.LocalizedError {
 
-    
+public enum RerouteStatus {
     
     /**
-     * Error during serialization.
+     * The user is following the route (or not navigating); nothing to do.
      */
-    case SerializationError(error: String
-    )
+    case notNeeded
     /**
-     * Recording is not enabled for this controller.
+     * The user is off the route, but the debounce period or minimum interval has not elapsed yet.
      */
-    case RecordingNotEnabled
+    case pending
     /**
-     * Error during deserialization.
-     */
-    case DeserializationError(error: String
+     * A new route was fetched.
+     *
+     * The state is positioned on the new route,
+     * and carries over the [`TripSummary`](crate::navigation_controller::models::TripSummary)
+     * from the state which triggered the reroute.
+     * Your navigator is still on the old route; switch it over with
+     * [`Navigator::replace_route`](crate::navigation_controller::Navigator::replace_route)
+     * (which produces an equivalent state), or create a new one for `route`.
+     */
+    case rerouted(route: Route, state: NavState
     )
 
-    
 
-    
 
-    
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
-    
+
+
 }
 
 #if compiler(>=6)
-extension RecordingError: Sendable {}
+extension RerouteStatus: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRecordingError: FfiConverterRustBuffer {
-    typealias SwiftType = RecordingError
+public struct FfiConverterTypeRerouteStatus: FfiConverterRustBuffer {
+    typealias SwiftType = RerouteStatus
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecordingError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RerouteStatus {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-
         
-
+        case 1: return .notNeeded
         
-        case 1: return .SerializationError(
-            error: try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .RecordingNotEnabled
-        case 3: return .DeserializationError(
-            error: try FfiConverterString.read(from: &buf)
-            )
-
-         default: throw UniffiInternalError.unexpectedEnumCase
+        case 2: return .pending
+        
+        case 3: return .rerouted(route: try FfiConverterTypeRoute.read(from: &buf), state: try FfiConverterTypeNavState.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: RecordingError, into buf: inout [UInt8]) {
+    public static func write(_ value: RerouteStatus, into buf: inout [UInt8]) {
         switch value {
-
-        
-
         
         
-        case let .SerializationError(error):
+        case .notNeeded:
             writeInt(&buf, Int32(1))
-            FfiConverterString.write(error, into: &buf)
-            
         
-        case .RecordingNotEnabled:
+        
+        case .pending:
             writeInt(&buf, Int32(2))
         
         
-        case let .DeserializationError(error):
+        case let .rerouted(route,state):
             writeInt(&buf, Int32(3))
-            FfiConverterString.write(error, into: &buf)
+            FfiConverterTypeRoute.write(route, into: &buf)
+            FfiConverterTypeNavState.write(state, into: &buf)
             
         }
     }
@@ -8382,24 +12056,24 @@ public struct FfiConverterTypeRecordingError: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRecordingError_lift(_ buf: RustBuffer) throws -> RecordingError {
-    return try FfiConverterTypeRecordingError.lift(buf)
+public func FfiConverterTypeRerouteStatus_lift(_ buf: RustBuffer) throws -> RerouteStatus {
+    return try FfiConverterTypeRerouteStatus.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRecordingError_lower(_ value: RecordingError) -> RustBuffer {
-    return FfiConverterTypeRecordingError.lower(value)
+public func FfiConverterTypeRerouteStatus_lower(_ value: RerouteStatus) -> RustBuffer {
+    return FfiConverterTypeRerouteStatus.lower(value)
 }
 
+
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 /**
  * Status information that describes whether the user is proceeding according to the route or not.
  *
  * Note that the name is intentionally a bit generic to allow for expansion of other states.
- * For example, we could conceivably add a "wrong way" status in the future.
  */
 
 public enum RouteDeviation: Equatable, Hashable, Codable {
@@ -8412,7 +12086,8 @@ public enum RouteDeviation: Equatable, Hashable, Codable {
      * The user has deviated from the expected route.
      *
      * Check the [`DeviationKind`] to determine if the user is still on the route polyline
-     * (off the current step but on a future step) or off the route entirely.
+     * (off the current step but on a future step), off the route entirely,
+     * or on the route but traveling the wrong direction.
      */
     case deviation(kind: DeviationKind
     )
@@ -8505,6 +12180,103 @@ public enum RouteDeviationTracking {
          * is greater than this threshold, it will be flagged as an off route condition.
          */maxAcceptableDeviation: Double
     )
+    /**
+     * Detects deviation from the route using sensible defaults for the mode of travel.
+     *
+     * Like [`RouteDeviationTracking::StaticThreshold`],
+     * this flags the user as off route when they are too far from the route line,
+     * and ignores inaccurate location updates.
+     * In addition, the user must stay off the route for a minimum duration
+     * *and* move a minimum distance before being flagged,
+     * so that a single noisy location update does not mark the user as [`DeviationKind::CompletelyOffRoute`].
+     *
+     * | Mode      | Max deviation | Min. accuracy | Min. duration | Min. distance |
+     * |-----------|---------------|---------------|---------------|---------------|
+     * | Driving   | 50 m          | 40 m          | 3 s           | 30 m          |
+     * | Cycling   | 30 m          | 30 m          | 5 s           | 20 m          |
+     * | Walking   | 20 m          | 25 m          | 10 s          | 15 m          |
+     * | Transit   | 100 m         | 75 m          | 15 s          | 100 m         |
+     */
+    case defaultFor(
+        /**
+         * The mode of travel to use defaults for.
+         */modeOfTravel: ModeOfTravel
+    )
+    /**
+     * Detects deviation from the route with configurable hysteresis.
+     *
+     * The user is flagged as [`DeviationKind::CompletelyOffRoute`]
+     * only after staying beyond the entry threshold for at least the minimum duration
+     * *or* moving at least the minimum distance, whichever comes first.
+     * The duration ensures that a user who stops off the route is eventually flagged,
+     * while the distance flags a user who quickly drives away sooner.
+     * Once flagged, the deviation is not cleared until the user comes back within the
+     * (tighter) exit threshold, so that a user hovering around the entry threshold
+     * does not flip-flop between on and off route.
+     *
+     * The tracking state is carried between location updates in the
+     * [`NavState`](crate::navigation_controller::models::NavState).
+     */
+    case hysteresis(
+        /**
+         * The minimum required horizontal accuracy of the user location, in meters.
+         * Less accurate location updates neither set nor clear a deviation.
+         */minimumHorizontalAccuracy: UInt16, 
+        /**
+         * The distance from the route line (in meters) beyond which the user may be flagged as off route.
+         */entryThreshold: Double, 
+        /**
+         * The distance from the route line (in meters) within which the user must return
+         * before a deviation is cleared.
+         *
+         * This should be less than or equal to the entry threshold;
+         * larger values are treated as equal to the entry threshold.
+         */exitThreshold: Double, 
+        /**
+         * How long (in seconds) the user must be beyond the entry threshold before being flagged.
+         */minimumDuration: Double, 
+        /**
+         * How far (in meters) the user must move while beyond the entry threshold before being flagged,
+         * if this happens before the minimum duration has passed.
+         */minimumDistance: Double
+    )
+    /**
+     * Detects deviation from the route like [`RouteDeviationTracking::StaticThreshold`],
+     * and additionally flags travel in the wrong direction along the route.
+     *
+     * While the user is on the route, their course over ground is compared against
+     * the bearing of the route segment they are on.
+     * If the difference exceeds the tolerance (plus the reported course accuracy)
+     * for at least the minimum duration, this reports [`DeviationKind::WrongDirection`].
+     * This catches cases like a U-turn back along the route,
+     * which a purely distance-based check never flags.
+     *
+     * While the user is (nearly) stationary, the course is meaningless,
+     * so the device heading is used instead when available
+     * (see [`Navigator::update_heading`](crate::navigation_controller::Navigator::update_heading)).
+     * This catches users who start a trip facing the wrong way.
+     */
+    case headingAware(
+        /**
+         * The minimum required horizontal accuracy of the user location, in meters.
+         * Values larger than this will not trigger route deviation warnings.
+         */minimumHorizontalAccuracy: UInt16, 
+        /**
+         * The maximum acceptable deviation from the route line, in meters.
+         */maxAcceptableDeviation: Double, 
+        /**
+         * The maximum acceptable difference (in degrees) between the user's course
+         * and the bearing of the route.
+         */maxCourseDeviation: UInt16, 
+        /**
+         * Courses with a reported accuracy worse than this (in degrees) are ignored.
+         *
+         * Courses without an accuracy are assumed to be accurate.
+         */minimumCourseAccuracy: UInt16, 
+        /**
+         * How long (in seconds) the mismatch must persist before the user is flagged.
+         */minimumDuration: Double
+    )
     /**
      * An arbitrary user-defined implementation.
      * You decide with your own [`RouteDeviationDetector`] implementation!
@@ -8537,7 +12309,16 @@ public struct FfiConverterTypeRouteDeviationTracking: FfiConverterRustBuffer {
         case 2: return .staticThreshold(minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), maxAcceptableDeviation: try FfiConverterDouble.read(from: &buf)
         )
         
-        case 3: return .custom(detector: try FfiConverterTypeRouteDeviationDetector.read(from: &buf)
+        case 3: return .defaultFor(modeOfTravel: try FfiConverterTypeModeOfTravel.read(from: &buf)
+        )
+        
+        case 4: return .hysteresis(minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), entryThreshold: try FfiConverterDouble.read(from: &buf), exitThreshold: try FfiConverterDouble.read(from: &buf), minimumDuration: try FfiConverterDouble.read(from: &buf), minimumDistance: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 5: return .headingAware(minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), maxAcceptableDeviation: try FfiConverterDouble.read(from: &buf), maxCourseDeviation: try FfiConverterUInt16.read(from: &buf), minimumCourseAccuracy: try FfiConverterUInt16.read(from: &buf), minimumDuration: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 6: return .custom(detector: try FfiConverterTypeRouteDeviationDetector.read(from: &buf)
         )
         
         default: throw UniffiInternalError.unexpectedEnumCase
@@ -8558,8 +12339,31 @@ public struct FfiConverterTypeRouteDeviationTracking: FfiConverterRustBuffer {
             FfiConverterDouble.write(maxAcceptableDeviation, into: &buf)
             
         
-        case let .custom(detector):
+        case let .defaultFor(modeOfTravel):
             writeInt(&buf, Int32(3))
+            FfiConverterTypeModeOfTravel.write(modeOfTravel, into: &buf)
+            
+        
+        case let .hysteresis(minimumHorizontalAccuracy,entryThreshold,exitThreshold,minimumDuration,minimumDistance):
+            writeInt(&buf, Int32(4))
+            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
+            FfiConverterDouble.write(entryThreshold, into: &buf)
+            FfiConverterDouble.write(exitThreshold, into: &buf)
+            FfiConverterDouble.write(minimumDuration, into: &buf)
+            FfiConverterDouble.write(minimumDistance, into: &buf)
+            
+        
+        case let .headingAware(minimumHorizontalAccuracy,maxAcceptableDeviation,maxCourseDeviation,minimumCourseAccuracy,minimumDuration):
+            writeInt(&buf, Int32(5))
+            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
+            FfiConverterDouble.write(maxAcceptableDeviation, into: &buf)
+            FfiConverterUInt16.write(maxCourseDeviation, into: &buf)
+            FfiConverterUInt16.write(minimumCourseAccuracy, into: &buf)
+            FfiConverterDouble.write(minimumDuration, into: &buf)
+            
+        
+        case let .custom(detector):
+            writeInt(&buf, Int32(6))
             FfiConverterTypeRouteDeviationDetector.write(detector, into: &buf)
             
         }
@@ -8743,6 +12547,173 @@ public func FfiConverterTypeRoutingRequestGenerationError_lower(_ value: Routing
     return FfiConverterTypeRoutingRequestGenerationError.lower(value)
 }
 
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The speed limit along a segment of the route.
+ */
+
+public enum SegmentMaxSpeed: Equatable, Hashable, Codable {
+    
+    /**
+     * There is no speed limit (ex: on parts of the German Autobahn).
+     */
+    case noLimit
+    /**
+     * The speed limit is not known.
+     */
+    case unknown
+    /**
+     * A known speed limit.
+     */
+    case known(speed: Double, unit: SegmentSpeedUnit
+    )
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension SegmentMaxSpeed: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSegmentMaxSpeed: FfiConverterRustBuffer {
+    typealias SwiftType = SegmentMaxSpeed
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SegmentMaxSpeed {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .noLimit
+        
+        case 2: return .unknown
+        
+        case 3: return .known(speed: try FfiConverterDouble.read(from: &buf), unit: try FfiConverterTypeSegmentSpeedUnit.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SegmentMaxSpeed, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .noLimit:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .unknown:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .known(speed,unit):
+            writeInt(&buf, Int32(3))
+            FfiConverterDouble.write(speed, into: &buf)
+            FfiConverterTypeSegmentSpeedUnit.write(unit, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSegmentMaxSpeed_lift(_ buf: RustBuffer) throws -> SegmentMaxSpeed {
+    return try FfiConverterTypeSegmentMaxSpeed.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSegmentMaxSpeed_lower(_ value: SegmentMaxSpeed) -> RustBuffer {
+    return FfiConverterTypeSegmentMaxSpeed.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * The unit of a speed limit.
+ */
+
+public enum SegmentSpeedUnit: Equatable, Hashable, Codable {
+    
+    case kilometersPerHour
+    case milesPerHour
+    case knots
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension SegmentSpeedUnit: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSegmentSpeedUnit: FfiConverterRustBuffer {
+    typealias SwiftType = SegmentSpeedUnit
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SegmentSpeedUnit {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .kilometersPerHour
+        
+        case 2: return .milesPerHour
+        
+        case 3: return .knots
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SegmentSpeedUnit, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .kilometersPerHour:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .milesPerHour:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .knots:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSegmentSpeedUnit_lift(_ buf: RustBuffer) throws -> SegmentSpeedUnit {
+    return try FfiConverterTypeSegmentSpeedUnit.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSegmentSpeedUnit_lower(_ value: SegmentSpeedUnit) -> RustBuffer {
+    return FfiConverterTypeSegmentSpeedUnit.lower(value)
+}
+
+
 // Note that we don't yet support `indirect` for enums.
 // See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
@@ -8751,8 +12722,12 @@ public enum SerializableStepAdvanceCondition: Equatable, Hashable, Codable {
     case manual
     case distanceToEndOfStep(distance: UInt16, minimumHorizontalAccuracy: UInt16
     )
+    case speedScaledDistanceToEndOfStep(secondsToEndOfStep: Double, minimumDistance: UInt16, maximumDistance: UInt16, minimumHorizontalAccuracy: UInt16
+    )
     case distanceFromStep(distance: UInt16, minimumHorizontalAccuracy: UInt16, calculationPolicy: DeviationCalculationPolicy
     )
+    case nextStepBearing(maxBearingDeviation: UInt16, minimumCourseAccuracy: UInt16
+    )
     case distanceEntryExit(distanceToEndOfStep: UInt16, distanceAfterEndStep: UInt16, minimumHorizontalAccuracy: UInt16, hasReachedEndOfCurrentStep: Bool
     )
     case distanceEntryAndSnappedExit(distanceToEndOfStep: UInt16, distanceAfterEndStep: UInt16, minimumHorizontalAccuracy: UInt16, hasReachedEndOfCurrentStep: Bool
@@ -8782,24 +12757,30 @@ public struct FfiConverterTypeSerializableStepAdvanceCondition: FfiConverterRust
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .manual
+        case 1: return .manual
+        
+        case 2: return .distanceToEndOfStep(distance: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf)
+        )
+        
+        case 3: return .speedScaledDistanceToEndOfStep(secondsToEndOfStep: try FfiConverterDouble.read(from: &buf), minimumDistance: try FfiConverterUInt16.read(from: &buf), maximumDistance: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf)
+        )
         
-        case 2: return .distanceToEndOfStep(distance: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf)
+        case 4: return .distanceFromStep(distance: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), calculationPolicy: try FfiConverterTypeDeviationCalculationPolicy.read(from: &buf)
         )
         
-        case 3: return .distanceFromStep(distance: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), calculationPolicy: try FfiConverterTypeDeviationCalculationPolicy.read(from: &buf)
+        case 5: return .nextStepBearing(maxBearingDeviation: try FfiConverterUInt16.read(from: &buf), minimumCourseAccuracy: try FfiConverterUInt16.read(from: &buf)
         )
         
-        case 4: return .distanceEntryExit(distanceToEndOfStep: try FfiConverterUInt16.read(from: &buf), distanceAfterEndStep: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), hasReachedEndOfCurrentStep: try FfiConverterBool.read(from: &buf)
+        case 6: return .distanceEntryExit(distanceToEndOfStep: try FfiConverterUInt16.read(from: &buf), distanceAfterEndStep: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), hasReachedEndOfCurrentStep: try FfiConverterBool.read(from: &buf)
         )
         
-        case 5: return .distanceEntryAndSnappedExit(distanceToEndOfStep: try FfiConverterUInt16.read(from: &buf), distanceAfterEndStep: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), hasReachedEndOfCurrentStep: try FfiConverterBool.read(from: &buf)
+        case 7: return .distanceEntryAndSnappedExit(distanceToEndOfStep: try FfiConverterUInt16.read(from: &buf), distanceAfterEndStep: try FfiConverterUInt16.read(from: &buf), minimumHorizontalAccuracy: try FfiConverterUInt16.read(from: &buf), hasReachedEndOfCurrentStep: try FfiConverterBool.read(from: &buf)
         )
         
-        case 6: return .orAdvanceConditions(conditions: try FfiConverterSequenceTypeSerializableStepAdvanceCondition.read(from: &buf)
+        case 8: return .orAdvanceConditions(conditions: try FfiConverterSequenceTypeSerializableStepAdvanceCondition.read(from: &buf)
         )
         
-        case 7: return .andAdvanceConditions(conditions: try FfiConverterSequenceTypeSerializableStepAdvanceCondition.read(from: &buf)
+        case 9: return .andAdvanceConditions(conditions: try FfiConverterSequenceTypeSerializableStepAdvanceCondition.read(from: &buf)
         )
         
         default: throw UniffiInternalError.unexpectedEnumCase
@@ -8820,15 +12801,29 @@ public struct FfiConverterTypeSerializableStepAdvanceCondition: FfiConverterRust
             FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
             
         
-        case let .distanceFromStep(distance,minimumHorizontalAccuracy,calculationPolicy):
+        case let .speedScaledDistanceToEndOfStep(secondsToEndOfStep,minimumDistance,maximumDistance,minimumHorizontalAccuracy):
             writeInt(&buf, Int32(3))
+            FfiConverterDouble.write(secondsToEndOfStep, into: &buf)
+            FfiConverterUInt16.write(minimumDistance, into: &buf)
+            FfiConverterUInt16.write(maximumDistance, into: &buf)
+            FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
+            
+        
+        case let .distanceFromStep(distance,minimumHorizontalAccuracy,calculationPolicy):
+            writeInt(&buf, Int32(4))
             FfiConverterUInt16.write(distance, into: &buf)
             FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
             FfiConverterTypeDeviationCalculationPolicy.write(calculationPolicy, into: &buf)
             
         
+        case let .nextStepBearing(maxBearingDeviation,minimumCourseAccuracy):
+            writeInt(&buf, Int32(5))
+            FfiConverterUInt16.write(maxBearingDeviation, into: &buf)
+            FfiConverterUInt16.write(minimumCourseAccuracy, into: &buf)
+            
+        
         case let .distanceEntryExit(distanceToEndOfStep,distanceAfterEndStep,minimumHorizontalAccuracy,hasReachedEndOfCurrentStep):
-            writeInt(&buf, Int32(4))
+            writeInt(&buf, Int32(6))
             FfiConverterUInt16.write(distanceToEndOfStep, into: &buf)
             FfiConverterUInt16.write(distanceAfterEndStep, into: &buf)
             FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
@@ -8836,7 +12831,7 @@ public struct FfiConverterTypeSerializableStepAdvanceCondition: FfiConverterRust
             
         
         case let .distanceEntryAndSnappedExit(distanceToEndOfStep,distanceAfterEndStep,minimumHorizontalAccuracy,hasReachedEndOfCurrentStep):
-            writeInt(&buf, Int32(5))
+            writeInt(&buf, Int32(7))
             FfiConverterUInt16.write(distanceToEndOfStep, into: &buf)
             FfiConverterUInt16.write(distanceAfterEndStep, into: &buf)
             FfiConverterUInt16.write(minimumHorizontalAccuracy, into: &buf)
@@ -8844,12 +12839,12 @@ public struct FfiConverterTypeSerializableStepAdvanceCondition: FfiConverterRust
             
         
         case let .orAdvanceConditions(conditions):
-            writeInt(&buf, Int32(6))
+            writeInt(&buf, Int32(8))
             FfiConverterSequenceTypeSerializableStepAdvanceCondition.write(conditions, into: &buf)
             
         
         case let .andAdvanceConditions(conditions):
-            writeInt(&buf, Int32(7))
+            writeInt(&buf, Int32(9))
             FfiConverterSequenceTypeSerializableStepAdvanceCondition.write(conditions, into: &buf)
             
         }
@@ -9005,7 +13000,8 @@ public enum TripState: Equatable, Hashable, Codable {
          * The ordered list of steps that remain in the trip.
          *
          * The step at the front of the list is always the current step.
-         * We currently assume that you cannot move backward to a previous step.
+         * The user only moves back to a previous step
+         * when [`NavigationControllerConfig::step_regression`] is set.
          */remainingSteps: [RouteStep], 
         /**
          * Remaining waypoints to visit on the route.
@@ -9034,21 +13030,103 @@ public enum TripState: Equatable, Hashable, Codable {
         /**
          * The most recent spoken instruction that should be synthesized using TTS.
          *
-         * Note it is the responsibility of the platform layer to ensure that utterances are not synthesized multiple times. This property simply reports the current spoken instruction.
+         * This property simply reports the current spoken instruction.
+         * To synthesize each utterance exactly once (including ones that would otherwise be
+         * skipped when several become due in a single update), use the
+         * [`NavigationEvent::SpokenInstructionTriggered`] events of the [`NavState`] instead.
          */spokenInstruction: SpokenInstruction?, 
         /**
          * Annotation data at the current location.
          * This is represented as a json formatted byte array to allow for flexible encoding of custom annotations.
-         */annotationJson: String?
+         */annotationJson: String?, 
+        /**
+         * Typed values of the common annotations at the current location.
+         *
+         * Unlike `annotation_json`, this does not include custom annotations.
+         */segmentAnnotation: SegmentAnnotation? = nil, 
+        /**
+         * The speed limit at the current location, if the route has speed limit annotations.
+         */speedLimit: SegmentMaxSpeed? = nil, 
+        /**
+         * The next change of the speed limit along the route, if any.
+         */nextSpeedLimitChange: SpeedLimitChange? = nil, 
+        /**
+         * Whether the user is traveling faster than the current speed limit.
+         *
+         * This is only ever set when [`NavigationControllerConfig::speeding`] is set.
+         */isSpeeding: Bool = false, 
+        /**
+         * The next maneuvers along the route, starting with the one at the end of the current step.
+         *
+         * This is only populated when [`NavigationControllerConfig::maneuver_preview`] is set.
+         */upcomingManeuvers: [UpcomingManeuver] = [], 
+        /**
+         * The incident the user is in, or else the nearest one ahead along the route.
+         *
+         * Incidents which are not expected to be in effect when the user reaches them
+         * (based on their start and end times) are ignored.
+         * This is only populated when [`NavigationControllerConfig::incidents`] is set.
+         */upcomingIncident: UpcomingIncident? = nil, 
+        /**
+         * Whether the user's location is an estimate rather than a location fix.
+         *
+         * This is the case while dead reckoning through a GPS outage (ex: in a tunnel);
+         * see [`NavigationControllerConfig::dead_reckoning`].
+         */locationEstimated: Bool = false, 
+        /**
+         * The location fix as reported, before smoothing.
+         *
+         * This is only populated when [`NavigationControllerConfig::location_smoothing`] is set,
+         * in which case `user_location` is the smoothed location.
+         */rawUserLocation: UserLocation? = nil, 
+        /**
+         * The latest heading of the device (ex: from a compass),
+         * as provided via [`Navigator::update_heading`](super::Navigator::update_heading).
+         */heading: Heading? = nil
+    )
+    /**
+     * Guidance is paused (ex: at a rest stop), and progress along the route is frozen.
+     *
+     * While paused, location updates only update the user's raw location.
+     * No distance is added to the trip summary, no instructions are issued,
+     * and neither route deviation nor step advancement are checked until the trip is resumed.
+     */
+    case paused(
+        /**
+         * The user's raw location.
+         */userLocation: UserLocation, 
+        /**
+         * The user's snapped location at the time the trip was paused.
+         */snappedUserLocation: UserLocation, 
+        /**
+         * The ordered list of steps that remain in the trip.
+         */remainingSteps: [RouteStep], 
+        /**
+         * Remaining waypoints to visit on the route.
+         */remainingWaypoints: [Waypoint], 
+        /**
+         * The trip progress at the time the trip was paused.
+         */progress: TripProgress, 
+        /**
+         * Information pertaining to the user's full navigation trip.
+         */summary: TripSummary, 
+        /**
+         * The latest device heading (ex: from a compass), if any.
+         *
+         * This is restored when the trip is resumed.
+         */heading: Heading? = nil
     )
     /**
-     * The navigation controller has reached the end of the trip.
+     * The trip has ended, either by reaching the end of the route or by being canceled.
      */
     case complete(userLocation: UserLocation, 
         /**
          * Information pertaining to the user's full navigation trip. This includes
          * simple stats like total duration, and distance.
-         */summary: TripSummary
+         */summary: TripSummary, 
+        /**
+         * Why the trip was canceled, or `None` if the user reached the end of the route.
+         */cancellationReason: CancellationReason? = nil
     )
 
 
@@ -9074,10 +13152,13 @@ public struct FfiConverterTypeTripState: FfiConverterRustBuffer {
         case 1: return .idle(userLocation: try FfiConverterOptionTypeUserLocation.read(from: &buf)
         )
         
-        case 2: return .navigating(currentStepGeometryIndex: try FfiConverterOptionUInt64.read(from: &buf), userLocation: try FfiConverterTypeUserLocation.read(from: &buf), snappedUserLocation: try FfiConverterTypeUserLocation.read(from: &buf), remainingSteps: try FfiConverterSequenceTypeRouteStep.read(from: &buf), remainingWaypoints: try FfiConverterSequenceTypeWaypoint.read(from: &buf), progress: try FfiConverterTypeTripProgress.read(from: &buf), summary: try FfiConverterTypeTripSummary.read(from: &buf), deviation: try FfiConverterTypeRouteDeviation.read(from: &buf), visualInstruction: try FfiConverterOptionTypeVisualInstruction.read(from: &buf), spokenInstruction: try FfiConverterOptionTypeSpokenInstruction.read(from: &buf), annotationJson: try FfiConverterOptionString.read(from: &buf)
+        case 2: return .navigating(currentStepGeometryIndex: try FfiConverterOptionUInt64.read(from: &buf), userLocation: try FfiConverterTypeUserLocation.read(from: &buf), snappedUserLocation: try FfiConverterTypeUserLocation.read(from: &buf), remainingSteps: try FfiConverterSequenceTypeRouteStep.read(from: &buf), remainingWaypoints: try FfiConverterSequenceTypeWaypoint.read(from: &buf), progress: try FfiConverterTypeTripProgress.read(from: &buf), summary: try FfiConverterTypeTripSummary.read(from: &buf), deviation: try FfiConverterTypeRouteDeviation.read(from: &buf), visualInstruction: try FfiConverterOptionTypeVisualInstruction.read(from: &buf), spokenInstruction: try FfiConverterOptionTypeSpokenInstruction.read(from: &buf), annotationJson: try FfiConverterOptionString.read(from: &buf), segmentAnnotation: try FfiConverterOptionTypeSegmentAnnotation.read(from: &buf), speedLimit: try FfiConverterOptionTypeSegmentMaxSpeed.read(from: &buf), nextSpeedLimitChange: try FfiConverterOptionTypeSpeedLimitChange.read(from: &buf), isSpeeding: try FfiConverterBool.read(from: &buf), upcomingManeuvers: try FfiConverterSequenceTypeUpcomingManeuver.read(from: &buf), upcomingIncident: try FfiConverterOptionTypeUpcomingIncident.read(from: &buf), locationEstimated: try FfiConverterBool.read(from: &buf), rawUserLocation: try FfiConverterOptionTypeUserLocation.read(from: &buf), heading: try FfiConverterOptionTypeHeading.read(from: &buf)
+        )
+        
+        case 3: return .paused(userLocation: try FfiConverterTypeUserLocation.read(from: &buf), snappedUserLocation: try FfiConverterTypeUserLocation.read(from: &buf), remainingSteps: try FfiConverterSequenceTypeRouteStep.read(from: &buf), remainingWaypoints: try FfiConverterSequenceTypeWaypoint.read(from: &buf), progress: try FfiConverterTypeTripProgress.read(from: &buf), summary: try FfiConverterTypeTripSummary.read(from: &buf), heading: try FfiConverterOptionTypeHeading.read(from: &buf)
         )
         
-        case 3: return .complete(userLocation: try FfiConverterTypeUserLocation.read(from: &buf), summary: try FfiConverterTypeTripSummary.read(from: &buf)
+        case 4: return .complete(userLocation: try FfiConverterTypeUserLocation.read(from: &buf), summary: try FfiConverterTypeTripSummary.read(from: &buf), cancellationReason: try FfiConverterOptionTypeCancellationReason.read(from: &buf)
         )
         
         default: throw UniffiInternalError.unexpectedEnumCase
@@ -9093,7 +13174,7 @@ public struct FfiConverterTypeTripState: FfiConverterRustBuffer {
             FfiConverterOptionTypeUserLocation.write(userLocation, into: &buf)
             
         
-        case let .navigating(currentStepGeometryIndex,userLocation,snappedUserLocation,remainingSteps,remainingWaypoints,progress,summary,deviation,visualInstruction,spokenInstruction,annotationJson):
+        case let .navigating(currentStepGeometryIndex,userLocation,snappedUserLocation,remainingSteps,remainingWaypoints,progress,summary,deviation,visualInstruction,spokenInstruction,annotationJson,segmentAnnotation,speedLimit,nextSpeedLimitChange,isSpeeding,upcomingManeuvers,upcomingIncident,locationEstimated,rawUserLocation,heading):
             writeInt(&buf, Int32(2))
             FfiConverterOptionUInt64.write(currentStepGeometryIndex, into: &buf)
             FfiConverterTypeUserLocation.write(userLocation, into: &buf)
@@ -9106,12 +13187,33 @@ public struct FfiConverterTypeTripState: FfiConverterRustBuffer {
             FfiConverterOptionTypeVisualInstruction.write(visualInstruction, into: &buf)
             FfiConverterOptionTypeSpokenInstruction.write(spokenInstruction, into: &buf)
             FfiConverterOptionString.write(annotationJson, into: &buf)
+            FfiConverterOptionTypeSegmentAnnotation.write(segmentAnnotation, into: &buf)
+            FfiConverterOptionTypeSegmentMaxSpeed.write(speedLimit, into: &buf)
+            FfiConverterOptionTypeSpeedLimitChange.write(nextSpeedLimitChange, into: &buf)
+            FfiConverterBool.write(isSpeeding, into: &buf)
+            FfiConverterSequenceTypeUpcomingManeuver.write(upcomingManeuvers, into: &buf)
+            FfiConverterOptionTypeUpcomingIncident.write(upcomingIncident, into: &buf)
+            FfiConverterBool.write(locationEstimated, into: &buf)
+            FfiConverterOptionTypeUserLocation.write(rawUserLocation, into: &buf)
+            FfiConverterOptionTypeHeading.write(heading, into: &buf)
             
         
-        case let .complete(userLocation,summary):
+        case let .paused(userLocation,snappedUserLocation,remainingSteps,remainingWaypoints,progress,summary,heading):
             writeInt(&buf, Int32(3))
             FfiConverterTypeUserLocation.write(userLocation, into: &buf)
+            FfiConverterTypeUserLocation.write(snappedUserLocation, into: &buf)
+            FfiConverterSequenceTypeRouteStep.write(remainingSteps, into: &buf)
+            FfiConverterSequenceTypeWaypoint.write(remainingWaypoints, into: &buf)
+            FfiConverterTypeTripProgress.write(progress, into: &buf)
+            FfiConverterTypeTripSummary.write(summary, into: &buf)
+            FfiConverterOptionTypeHeading.write(heading, into: &buf)
+            
+        
+        case let .complete(userLocation,summary,cancellationReason):
+            writeInt(&buf, Int32(4))
+            FfiConverterTypeUserLocation.write(userLocation, into: &buf)
             FfiConverterTypeTripSummary.write(summary, into: &buf)
+            FfiConverterOptionTypeCancellationReason.write(cancellationReason, into: &buf)
             
         }
     }
@@ -9387,41 +13489,244 @@ public enum WaypointAdvanceMode: Equatable, Hashable, Codable {
 }
 
 #if compiler(>=6)
-extension WaypointAdvanceMode: Sendable {}
+extension WaypointAdvanceMode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWaypointAdvanceMode: FfiConverterRustBuffer {
+    typealias SwiftType = WaypointAdvanceMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WaypointAdvanceMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .waypointWithinRange(try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 2: return .waypointAlongAdvancingStep(try FfiConverterDouble.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: WaypointAdvanceMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .waypointWithinRange(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterDouble.write(v1, into: &buf)
+            
+        
+        case let .waypointAlongAdvancingStep(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterDouble.write(v1, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypointAdvanceMode_lift(_ buf: RustBuffer) throws -> WaypointAdvanceMode {
+    return try FfiConverterTypeWaypointAdvanceMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypointAdvanceMode_lower(_ value: WaypointAdvanceMode) -> RustBuffer {
+    return FfiConverterTypeWaypointAdvanceMode.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Describes characteristics of the waypoint for routing purposes.
+ */
+
+public enum WaypointKind: Equatable, Hashable, Codable {
+    
+    /**
+     * Starts or ends a leg of the trip.
+     *
+     * Most routing engines will generate arrival and departure instructions.
+     * Some routing engines do not support multi-leg routes,
+     * so an intermediate break may behave like a [`WaypointKind::Via`].
+     */
+    case `break`
+    /**
+     * A waypoint that is simply passed through,
+     * but will not have any arrival or departure instructions.
+     */
+    case via
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension WaypointKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWaypointKind: FfiConverterRustBuffer {
+    typealias SwiftType = WaypointKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WaypointKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .`break`
+        
+        case 2: return .via
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: WaypointKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .`break`:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .via:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypointKind_lift(_ buf: RustBuffer) throws -> WaypointKind {
+    return try FfiConverterTypeWaypointKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWaypointKind_lower(_ value: WaypointKind) -> RustBuffer {
+    return FfiConverterTypeWaypointKind.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Configurations for built-in route providers.
+ */
+
+public enum WellKnownRouteProvider: Equatable, Hashable, Codable {
+    
+    /**
+     * A Valhalla-based routing API.
+     *
+     * [Stadia Maps](https://docs.stadiamaps.com/) offers a commercially supported API.
+     * You can also substitute any URL to use a self-hosted server.
+     */
+    case valhalla(
+        /**
+         * The endpoint URL to use (e.g. <https://api.stadiamaps.com/route/v1?api_key=YOUR-API-KEY>).
+         */endpointUrl: String, 
+        /**
+         * The costing model (e.g. `auto`).
+         */profile: String, 
+        /**
+         * Additional options to be incorporated into the request parameters.
+         *
+         * This value must be a stringified representation of a JSON object.
+         */optionsJson: String? = nil
+    )
+    /**
+     * A GraphHopper-based routing API.
+     *
+     * [GraphHopper](https://www.graphhopper.com/) offers a commercially supported API.
+     * You can also substitute any other URL to use a self-hosted server.
+     */
+    case graphHopper(
+        /**
+         * The endpoint URL to use (e.g. <https://graphhopper.com/api/1/navigate/?key=YOUR-API-KEY>).
+         */endpointUrl: String, 
+        /**
+         * The routing profile (e.g. `car`).
+         */profile: String, 
+        /**
+         * The locale to give directions in (e.g. `en`).
+         */locale: String, 
+        /**
+         * The units to use for spoken instructions.
+         */voiceUnits: GraphHopperVoiceUnits, 
+        /**
+         * Additional options to be incorporated into the request parameters.
+         *
+         * This value must be a stringified representation of a JSON object.
+         */optionsJson: String? = nil
+    )
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension WellKnownRouteProvider: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeWaypointAdvanceMode: FfiConverterRustBuffer {
-    typealias SwiftType = WaypointAdvanceMode
+public struct FfiConverterTypeWellKnownRouteProvider: FfiConverterRustBuffer {
+    typealias SwiftType = WellKnownRouteProvider
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WaypointAdvanceMode {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WellKnownRouteProvider {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .waypointWithinRange(try FfiConverterDouble.read(from: &buf)
+        case 1: return .valhalla(endpointUrl: try FfiConverterString.read(from: &buf), profile: try FfiConverterString.read(from: &buf), optionsJson: try FfiConverterOptionString.read(from: &buf)
         )
         
-        case 2: return .waypointAlongAdvancingStep(try FfiConverterDouble.read(from: &buf)
+        case 2: return .graphHopper(endpointUrl: try FfiConverterString.read(from: &buf), profile: try FfiConverterString.read(from: &buf), locale: try FfiConverterString.read(from: &buf), voiceUnits: try FfiConverterTypeGraphHopperVoiceUnits.read(from: &buf), optionsJson: try FfiConverterOptionString.read(from: &buf)
         )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: WaypointAdvanceMode, into buf: inout [UInt8]) {
+    public static func write(_ value: WellKnownRouteProvider, into buf: inout [UInt8]) {
         switch value {
         
         
-        case let .waypointWithinRange(v1):
+        case let .valhalla(endpointUrl,profile,optionsJson):
             writeInt(&buf, Int32(1))
-            FfiConverterDouble.write(v1, into: &buf)
+            FfiConverterString.write(endpointUrl, into: &buf)
+            FfiConverterString.write(profile, into: &buf)
+            FfiConverterOptionString.write(optionsJson, into: &buf)
             
         
-        case let .waypointAlongAdvancingStep(v1):
+        case let .graphHopper(endpointUrl,profile,locale,voiceUnits,optionsJson):
             writeInt(&buf, Int32(2))
-            FfiConverterDouble.write(v1, into: &buf)
+            FfiConverterString.write(endpointUrl, into: &buf)
+            FfiConverterString.write(profile, into: &buf)
+            FfiConverterString.write(locale, into: &buf)
+            FfiConverterTypeGraphHopperVoiceUnits.write(voiceUnits, into: &buf)
+            FfiConverterOptionString.write(optionsJson, into: &buf)
             
         }
     }
@@ -9431,226 +13736,335 @@ public struct FfiConverterTypeWaypointAdvanceMode: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypointAdvanceMode_lift(_ buf: RustBuffer) throws -> WaypointAdvanceMode {
-    return try FfiConverterTypeWaypointAdvanceMode.lift(buf)
+public func FfiConverterTypeWellKnownRouteProvider_lift(_ buf: RustBuffer) throws -> WellKnownRouteProvider {
+    return try FfiConverterTypeWellKnownRouteProvider.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypointAdvanceMode_lower(_ value: WaypointAdvanceMode) -> RustBuffer {
-    return FfiConverterTypeWaypointAdvanceMode.lower(value)
+public func FfiConverterTypeWellKnownRouteProvider_lower(_ value: WellKnownRouteProvider) -> RustBuffer {
+    return FfiConverterTypeWellKnownRouteProvider.lower(value)
 }
 
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * Describes characteristics of the waypoint for routing purposes.
- */
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt8: FfiConverterRustBuffer {
+    typealias SwiftType = UInt8?
 
-public enum WaypointKind: Equatable, Hashable, Codable {
-    
-    /**
-     * Starts or ends a leg of the trip.
-     *
-     * Most routing engines will generate arrival and departure instructions.
-     * Some routing engines do not support multi-leg routes,
-     * so an intermediate break may behave like a [`WaypointKind::Via`].
-     */
-    case `break`
-    /**
-     * A waypoint that is simply passed through,
-     * but will not have any arrival or departure instructions.
-     */
-    case via
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt8.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt8.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
+    typealias SwiftType = UInt16?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt16.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt16.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionFloat: FfiConverterRustBuffer {
+    typealias SwiftType = Float?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterFloat.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterFloat.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-#if compiler(>=6)
-extension WaypointKind: Sendable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
+    typealias SwiftType = Bool?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterBool.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterBool.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeWaypointKind: FfiConverterRustBuffer {
-    typealias SwiftType = WaypointKind
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WaypointKind {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .`break`
-        
-        case 2: return .via
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
     }
 
-    public static func write(_ value: WaypointKind, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .`break`:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .via:
-            writeInt(&buf, Int32(2))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypointKind_lift(_ buf: RustBuffer) throws -> WaypointKind {
-    return try FfiConverterTypeWaypointKind.lift(buf)
+fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
+    typealias SwiftType = Data?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterData.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterData.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWaypointKind_lower(_ value: WaypointKind) -> RustBuffer {
-    return FfiConverterTypeWaypointKind.lower(value)
-}
-
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * Configurations for built-in route providers.
- */
-
-public enum WellKnownRouteProvider: Equatable, Hashable, Codable {
-    
-    /**
-     * A Valhalla-based routing API.
-     *
-     * [Stadia Maps](https://docs.stadiamaps.com/) offers a commercially supported API.
-     * You can also substitute any URL to use a self-hosted server.
-     */
-    case valhalla(
-        /**
-         * The endpoint URL to use (e.g. <https://api.stadiamaps.com/route/v1?api_key=YOUR-API-KEY>).
-         */endpointUrl: String, 
-        /**
-         * The costing model (e.g. `auto`).
-         */profile: String, 
-        /**
-         * Additional options to be incorporated into the request parameters.
-         *
-         * This value must be a stringified representation of a JSON object.
-         */optionsJson: String? = nil
-    )
-    /**
-     * A GraphHopper-based routing API.
-     *
-     * [GraphHopper](https://www.graphhopper.com/) offers a commercially supported API.
-     * You can also substitute any other URL to use a self-hosted server.
-     */
-    case graphHopper(
-        /**
-         * The endpoint URL to use (e.g. <https://graphhopper.com/api/1/navigate/?key=YOUR-API-KEY>).
-         */endpointUrl: String, 
-        /**
-         * The routing profile (e.g. `car`).
-         */profile: String, 
-        /**
-         * The locale to give directions in (e.g. `en`).
-         */locale: String, 
-        /**
-         * The units to use for spoken instructions.
-         */voiceUnits: GraphHopperVoiceUnits, 
-        /**
-         * Additional options to be incorporated into the request parameters.
-         *
-         * This value must be a stringified representation of a JSON object.
-         */optionsJson: String? = nil
-    )
-
-
-
+fileprivate struct FfiConverterOptionTypeAdaptiveEtaConfig: FfiConverterRustBuffer {
+    typealias SwiftType = AdaptiveEtaConfig?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeAdaptiveEtaConfig.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeAdaptiveEtaConfig.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-#if compiler(>=6)
-extension WellKnownRouteProvider: Sendable {}
-#endif
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeWellKnownRouteProvider: FfiConverterRustBuffer {
-    typealias SwiftType = WellKnownRouteProvider
+fileprivate struct FfiConverterOptionTypeBoundingBox: FfiConverterRustBuffer {
+    typealias SwiftType = BoundingBox?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WellKnownRouteProvider {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .valhalla(endpointUrl: try FfiConverterString.read(from: &buf), profile: try FfiConverterString.read(from: &buf), optionsJson: try FfiConverterOptionString.read(from: &buf)
-        )
-        
-        case 2: return .graphHopper(endpointUrl: try FfiConverterString.read(from: &buf), profile: try FfiConverterString.read(from: &buf), locale: try FfiConverterString.read(from: &buf), voiceUnits: try FfiConverterTypeGraphHopperVoiceUnits.read(from: &buf), optionsJson: try FfiConverterOptionString.read(from: &buf)
-        )
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeBoundingBox.write(value, into: &buf)
     }
 
-    public static func write(_ value: WellKnownRouteProvider, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case let .valhalla(endpointUrl,profile,optionsJson):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(endpointUrl, into: &buf)
-            FfiConverterString.write(profile, into: &buf)
-            FfiConverterOptionString.write(optionsJson, into: &buf)
-            
-        
-        case let .graphHopper(endpointUrl,profile,locale,voiceUnits,optionsJson):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(endpointUrl, into: &buf)
-            FfiConverterString.write(profile, into: &buf)
-            FfiConverterString.write(locale, into: &buf)
-            FfiConverterTypeGraphHopperVoiceUnits.write(voiceUnits, into: &buf)
-            FfiConverterOptionString.write(optionsJson, into: &buf)
-            
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeBoundingBox.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWellKnownRouteProvider_lift(_ buf: RustBuffer) throws -> WellKnownRouteProvider {
-    return try FfiConverterTypeWellKnownRouteProvider.lift(buf)
+fileprivate struct FfiConverterOptionTypeCongestion: FfiConverterRustBuffer {
+    typealias SwiftType = Congestion?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCongestion.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCongestion.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeWellKnownRouteProvider_lower(_ value: WellKnownRouteProvider) -> RustBuffer {
-    return FfiConverterTypeWellKnownRouteProvider.lower(value)
-}
+fileprivate struct FfiConverterOptionTypeCourseOverGround: FfiConverterRustBuffer {
+    typealias SwiftType = CourseOverGround?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCourseOverGround.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCourseOverGround.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionUInt8: FfiConverterRustBuffer {
-    typealias SwiftType = UInt8?
+fileprivate struct FfiConverterOptionTypeDeadReckoningConfig: FfiConverterRustBuffer {
+    typealias SwiftType = DeadReckoningConfig?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9658,13 +14072,13 @@ fileprivate struct FfiConverterOptionUInt8: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterUInt8.write(value, into: &buf)
+        FfiConverterTypeDeadReckoningConfig.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterUInt8.read(from: &buf)
+        case 1: return try FfiConverterTypeDeadReckoningConfig.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9673,8 +14087,8 @@ fileprivate struct FfiConverterOptionUInt8: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
-    typealias SwiftType = UInt16?
+fileprivate struct FfiConverterOptionTypeGeographicCoordinate: FfiConverterRustBuffer {
+    typealias SwiftType = GeographicCoordinate?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9682,13 +14096,13 @@ fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterUInt16.write(value, into: &buf)
+        FfiConverterTypeGeographicCoordinate.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterUInt16.read(from: &buf)
+        case 1: return try FfiConverterTypeGeographicCoordinate.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9697,8 +14111,8 @@ fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
-    typealias SwiftType = UInt32?
+fileprivate struct FfiConverterOptionTypeHeading: FfiConverterRustBuffer {
+    typealias SwiftType = Heading?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9706,13 +14120,13 @@ fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterUInt32.write(value, into: &buf)
+        FfiConverterTypeHeading.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterUInt32.read(from: &buf)
+        case 1: return try FfiConverterTypeHeading.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9721,8 +14135,8 @@ fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
-    typealias SwiftType = UInt64?
+fileprivate struct FfiConverterOptionTypeIncidentsConfig: FfiConverterRustBuffer {
+    typealias SwiftType = IncidentsConfig?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9730,13 +14144,13 @@ fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterUInt64.write(value, into: &buf)
+        FfiConverterTypeIncidentsConfig.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterUInt64.read(from: &buf)
+        case 1: return try FfiConverterTypeIncidentsConfig.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9745,8 +14159,8 @@ fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionFloat: FfiConverterRustBuffer {
-    typealias SwiftType = Float?
+fileprivate struct FfiConverterOptionTypeLocationFilterConfig: FfiConverterRustBuffer {
+    typealias SwiftType = LocationFilterConfig?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9754,13 +14168,13 @@ fileprivate struct FfiConverterOptionFloat: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterFloat.write(value, into: &buf)
+        FfiConverterTypeLocationFilterConfig.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterFloat.read(from: &buf)
+        case 1: return try FfiConverterTypeLocationFilterConfig.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9769,8 +14183,8 @@ fileprivate struct FfiConverterOptionFloat: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
-    typealias SwiftType = Double?
+fileprivate struct FfiConverterOptionTypeLocationSmoothingState: FfiConverterRustBuffer {
+    typealias SwiftType = LocationSmoothingState?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9778,13 +14192,13 @@ fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterDouble.write(value, into: &buf)
+        FfiConverterTypeLocationSmoothingState.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterDouble.read(from: &buf)
+        case 1: return try FfiConverterTypeLocationSmoothingState.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9793,8 +14207,8 @@ fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
-    typealias SwiftType = Bool?
+fileprivate struct FfiConverterOptionTypeManeuverPreviewConfig: FfiConverterRustBuffer {
+    typealias SwiftType = ManeuverPreviewConfig?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9802,13 +14216,13 @@ fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterBool.write(value, into: &buf)
+        FfiConverterTypeManeuverPreviewConfig.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterBool.read(from: &buf)
+        case 1: return try FfiConverterTypeManeuverPreviewConfig.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9817,8 +14231,8 @@ fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterOptionTypeMapMatchingConfig: FfiConverterRustBuffer {
+    typealias SwiftType = MapMatchingConfig?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9826,13 +14240,13 @@ fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
+        FfiConverterTypeMapMatchingConfig.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
+        case 1: return try FfiConverterTypeMapMatchingConfig.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9841,8 +14255,8 @@ fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
-    typealias SwiftType = Data?
+fileprivate struct FfiConverterOptionTypeNavigationSessionSnapshot: FfiConverterRustBuffer {
+    typealias SwiftType = NavigationSessionSnapshot?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9850,13 +14264,13 @@ fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterData.write(value, into: &buf)
+        FfiConverterTypeNavigationSessionSnapshot.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterData.read(from: &buf)
+        case 1: return try FfiConverterTypeNavigationSessionSnapshot.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9865,8 +14279,8 @@ fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeBoundingBox: FfiConverterRustBuffer {
-    typealias SwiftType = BoundingBox?
+fileprivate struct FfiConverterOptionTypeSegmentAnnotation: FfiConverterRustBuffer {
+    typealias SwiftType = SegmentAnnotation?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9874,13 +14288,13 @@ fileprivate struct FfiConverterOptionTypeBoundingBox: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeBoundingBox.write(value, into: &buf)
+        FfiConverterTypeSegmentAnnotation.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeBoundingBox.read(from: &buf)
+        case 1: return try FfiConverterTypeSegmentAnnotation.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9889,8 +14303,8 @@ fileprivate struct FfiConverterOptionTypeBoundingBox: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeCongestion: FfiConverterRustBuffer {
-    typealias SwiftType = Congestion?
+fileprivate struct FfiConverterOptionTypeSpeed: FfiConverterRustBuffer {
+    typealias SwiftType = Speed?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9898,13 +14312,13 @@ fileprivate struct FfiConverterOptionTypeCongestion: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeCongestion.write(value, into: &buf)
+        FfiConverterTypeSpeed.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeCongestion.read(from: &buf)
+        case 1: return try FfiConverterTypeSpeed.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9913,8 +14327,8 @@ fileprivate struct FfiConverterOptionTypeCongestion: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeCourseOverGround: FfiConverterRustBuffer {
-    typealias SwiftType = CourseOverGround?
+fileprivate struct FfiConverterOptionTypeSpeedLimitChange: FfiConverterRustBuffer {
+    typealias SwiftType = SpeedLimitChange?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9922,13 +14336,13 @@ fileprivate struct FfiConverterOptionTypeCourseOverGround: FfiConverterRustBuffe
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeCourseOverGround.write(value, into: &buf)
+        FfiConverterTypeSpeedLimitChange.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeCourseOverGround.read(from: &buf)
+        case 1: return try FfiConverterTypeSpeedLimitChange.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9937,8 +14351,8 @@ fileprivate struct FfiConverterOptionTypeCourseOverGround: FfiConverterRustBuffe
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeGeographicCoordinate: FfiConverterRustBuffer {
-    typealias SwiftType = GeographicCoordinate?
+fileprivate struct FfiConverterOptionTypeSpeedingConfig: FfiConverterRustBuffer {
+    typealias SwiftType = SpeedingConfig?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9946,13 +14360,13 @@ fileprivate struct FfiConverterOptionTypeGeographicCoordinate: FfiConverterRustB
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeGeographicCoordinate.write(value, into: &buf)
+        FfiConverterTypeSpeedingConfig.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeGeographicCoordinate.read(from: &buf)
+        case 1: return try FfiConverterTypeSpeedingConfig.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9961,8 +14375,8 @@ fileprivate struct FfiConverterOptionTypeGeographicCoordinate: FfiConverterRustB
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeNavigationSessionSnapshot: FfiConverterRustBuffer {
-    typealias SwiftType = NavigationSessionSnapshot?
+fileprivate struct FfiConverterOptionTypeSpokenInstruction: FfiConverterRustBuffer {
+    typealias SwiftType = SpokenInstruction?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9970,13 +14384,13 @@ fileprivate struct FfiConverterOptionTypeNavigationSessionSnapshot: FfiConverter
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeNavigationSessionSnapshot.write(value, into: &buf)
+        FfiConverterTypeSpokenInstruction.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeNavigationSessionSnapshot.read(from: &buf)
+        case 1: return try FfiConverterTypeSpokenInstruction.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -9985,8 +14399,8 @@ fileprivate struct FfiConverterOptionTypeNavigationSessionSnapshot: FfiConverter
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeSpeed: FfiConverterRustBuffer {
-    typealias SwiftType = Speed?
+fileprivate struct FfiConverterOptionTypeStepRegressionConfig: FfiConverterRustBuffer {
+    typealias SwiftType = StepRegressionConfig?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -9994,13 +14408,13 @@ fileprivate struct FfiConverterOptionTypeSpeed: FfiConverterRustBuffer {
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeSpeed.write(value, into: &buf)
+        FfiConverterTypeStepRegressionConfig.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeSpeed.read(from: &buf)
+        case 1: return try FfiConverterTypeStepRegressionConfig.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -10009,8 +14423,8 @@ fileprivate struct FfiConverterOptionTypeSpeed: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionTypeSpokenInstruction: FfiConverterRustBuffer {
-    typealias SwiftType = SpokenInstruction?
+fileprivate struct FfiConverterOptionTypeUpcomingIncident: FfiConverterRustBuffer {
+    typealias SwiftType = UpcomingIncident?
 
     public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
         guard let value = value else {
@@ -10018,13 +14432,13 @@ fileprivate struct FfiConverterOptionTypeSpokenInstruction: FfiConverterRustBuff
             return
         }
         writeInt(&buf, Int8(1))
-        FfiConverterTypeSpokenInstruction.write(value, into: &buf)
+        FfiConverterTypeUpcomingIncident.write(value, into: &buf)
     }
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
         switch try readInt(&buf) as Int8 {
         case 0: return nil
-        case 1: return try FfiConverterTypeSpokenInstruction.read(from: &buf)
+        case 1: return try FfiConverterTypeUpcomingIncident.read(from: &buf)
         default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
@@ -10126,6 +14540,30 @@ fileprivate struct FfiConverterOptionTypeVisualInstructionContent: FfiConverterR
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeCancellationReason: FfiConverterRustBuffer {
+    typealias SwiftType = CancellationReason?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCancellationReason.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCancellationReason.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -10174,6 +14612,30 @@ fileprivate struct FfiConverterOptionTypeImpact: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeLocationSmoothing: FfiConverterRustBuffer {
+    typealias SwiftType = LocationSmoothing?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeLocationSmoothing.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeLocationSmoothing.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -10222,6 +14684,30 @@ fileprivate struct FfiConverterOptionTypeManeuverType: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeSegmentMaxSpeed: FfiConverterRustBuffer {
+    typealias SwiftType = SegmentMaxSpeed?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSegmentMaxSpeed.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSegmentMaxSpeed.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -10516,6 +15002,31 @@ fileprivate struct FfiConverterSequenceTypeLaneInfo: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeLegProgress: FfiConverterRustBuffer {
+    typealias SwiftType = [LegProgress]
+
+    public static func write(_ value: [LegProgress], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeLegProgress.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LegProgress] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [LegProgress]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeLegProgress.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -10616,6 +15127,56 @@ fileprivate struct FfiConverterSequenceTypeSpokenInstruction: FfiConverterRustBu
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeUpcomingManeuver: FfiConverterRustBuffer {
+    typealias SwiftType = [UpcomingManeuver]
+
+    public static func write(_ value: [UpcomingManeuver], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeUpcomingManeuver.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UpcomingManeuver] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UpcomingManeuver]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeUpcomingManeuver.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeUserLocation: FfiConverterRustBuffer {
+    typealias SwiftType = [UserLocation]
+
+    public static func write(_ value: [UserLocation], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeUserLocation.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UserLocation] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UserLocation]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeUserLocation.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -10691,6 +15252,31 @@ fileprivate struct FfiConverterSequenceTypeBlockedLane: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeNavigationEvent: FfiConverterRustBuffer {
+    typealias SwiftType = [NavigationEvent]
+
+    public static func write(_ value: [NavigationEvent], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeNavigationEvent.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [NavigationEvent] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [NavigationEvent]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeNavigationEvent.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -10716,6 +15302,31 @@ fileprivate struct FfiConverterSequenceTypeSerializableStepAdvanceCondition: Ffi
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeUuid: FfiConverterRustBuffer {
+    typealias SwiftType = [Uuid]
+
+    public static func write(_ value: [Uuid], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeUuid.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Uuid] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Uuid]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeUuid.read(from: &buf))
+        }
+        return seq
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -11034,6 +15645,24 @@ public func stepAdvanceManual() -> StepAdvanceCondition  {
     )
 })
 }
+/**
+ * Convenience function for creating a [`NextStepBearingCondition`].
+ *
+ * This advances to the next step once the user's course is within `max_bearing_deviation` degrees
+ * of the initial bearing of the next step.
+ * Courses less accurate than `minimum_course_accuracy` degrees are ignored.
+ *
+ * Combine this with a distance-based condition using [`step_advance_and`]
+ * to confirm that the user has taken the maneuver.
+ */
+public func stepAdvanceNextStepBearing(maxBearingDeviation: UInt16, minimumCourseAccuracy: UInt16) -> StepAdvanceCondition  {
+    return try!  FfiConverterTypeStepAdvanceCondition_lift(try! rustCall() {
+    uniffi_ferrostar_fn_func_step_advance_next_step_bearing(
+        FfiConverterUInt16.lower(maxBearingDeviation),
+        FfiConverterUInt16.lower(minimumCourseAccuracy),$0
+    )
+})
+}
 /**
  * Convenience function for creating an [`OrAdvanceConditions`].
  *
@@ -11046,6 +15675,24 @@ public func stepAdvanceOr(conditions: [StepAdvanceCondition]) -> StepAdvanceCond
     )
 })
 }
+/**
+ * Convenience function for creating a [`SpeedScaledDistanceToEndOfStepCondition`].
+ *
+ * This advances to the next step when the user is within `seconds_to_end_of_step` seconds
+ * of the last point in the current route step at their current speed.
+ * The distance is clamped between `minimum_distance` and `maximum_distance` meters.
+ * Does not advance unless the reported location accuracy is `minimum_horizontal_accuracy` meters or better.
+ */
+public func stepAdvanceSpeedScaledDistanceToEndOfStep(secondsToEndOfStep: Double, minimumDistance: UInt16, maximumDistance: UInt16, minimumHorizontalAccuracy: UInt16) -> StepAdvanceCondition  {
+    return try!  FfiConverterTypeStepAdvanceCondition_lift(try! rustCall() {
+    uniffi_ferrostar_fn_func_step_advance_speed_scaled_distance_to_end_of_step(
+        FfiConverterDouble.lower(secondsToEndOfStep),
+        FfiConverterUInt16.lower(minimumDistance),
+        FfiConverterUInt16.lower(maximumDistance),
+        FfiConverterUInt16.lower(minimumHorizontalAccuracy),$0
+    )
+})
+}
 /**
  * Creates a new navigation session for the given route and configuration.
  *
@@ -11192,9 +15839,15 @@ private let initializationResult: InitializationResult = {
     if (uniffi_ferrostar_checksum_func_step_advance_manual() != 17011) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_func_step_advance_next_step_bearing() != 11863) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_func_step_advance_or() != 23842) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_func_step_advance_speed_scaled_distance_to_end_of_step() != 7993) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_func_create_navigation_session() != 43697) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -11228,6 +15881,24 @@ private let initializationResult: InitializationResult = {
     if (uniffi_ferrostar_checksum_method_navigator_update_user_location() != 49085) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigator_replace_route() != 18579) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigator_pause() != 34875) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigator_resume() != 11216) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigator_cancel() != 53399) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigator_estimate_location() != 8320) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigator_update_heading() != 31869) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_stepadvancecondition_should_advance_step() != 41599) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -11240,21 +15911,57 @@ private let initializationResult: InitializationResult = {
     if (uniffi_ferrostar_checksum_method_navigationobserver_on_user_location_update() != 57067) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_ferrostar_checksum_method_navigationobserver_on_advance_to_next_step() != 31176) {
+    if (uniffi_ferrostar_checksum_method_navigationobserver_on_heading_update() != 15252) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationobserver_on_advance_to_next_step() != 23621) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationobserver_on_route_available() != 51134) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationobserver_on_route_replaced() != 43457) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationobserver_on_pause() != 22165) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_ferrostar_checksum_method_navigationobserver_on_route_available() != 55256) {
+    if (uniffi_ferrostar_checksum_method_navigationobserver_on_resume() != 12827) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationobserver_on_cancel() != 2692) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationobserver_on_navigation_events() != 16688) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_ferrostar_checksum_method_navigationsession_advance_to_next_step() != 3078) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationsession_cancel() != 38466) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationsession_estimate_location() != 54048) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationsession_get_initial_state() != 50596) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationsession_pause() != 15643) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationsession_replace_route() != 56876) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationsession_resume() != 61388) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationsession_route() != 30746) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationsession_update_heading() != 10949) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationsession_update_user_location() != 6536) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -11276,12 +15983,30 @@ private let initializationResult: InitializationResult = {
     if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_advance_to_next_step() != 21998) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_cancel() != 65121) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_get_initial_state() != 13801) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_heading_update() != 38842) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_navigation_events() != 48625) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_pause() != 23749) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_resume() != 10786) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_route_available() != 39648) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_route_replaced() != 65414) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationsessioncache_on_user_location_update() != 35023) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -11294,15 +16019,42 @@ private let initializationResult: InitializationResult = {
     if (uniffi_ferrostar_checksum_method_navigationrecorder_on_advance_to_next_step() != 2408) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationrecorder_on_cancel() != 50276) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationrecorder_on_get_initial_state() != 42003) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationrecorder_on_heading_update() != 10212) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationrecorder_on_navigation_events() != 58111) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationrecorder_on_pause() != 719) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_navigationrecorder_on_resume() != 9646) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationrecorder_on_route_available() != 10378) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_navigationrecorder_on_route_replaced() != 44110) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_navigationrecorder_on_user_location_update() != 46975) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_method_reroutecontroller_check_for_reroute() != 22944) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_reroutecontroller_reset() != 2206) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_ferrostar_checksum_method_routefetcher_fetch_route() != 41468) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_method_routeadapter_generate_request() != 59943) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -11327,6 +16079,9 @@ private let initializationResult: InitializationResult = {
     if (uniffi_ferrostar_checksum_constructor_navigationrecorder_new() != 32660) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_ferrostar_checksum_constructor_reroutecontroller_new() != 60095) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_ferrostar_checksum_constructor_routeadapter_from_well_known_route_provider() != 64199) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -11337,6 +16092,7 @@ private let initializationResult: InitializationResult = {
     uniffiCallbackInitNavigationCache()
     uniffiCallbackInitNavigationObserver()
     uniffiCallbackInitRouteDeviationDetector()
+    uniffiCallbackInitRouteFetcher()
     uniffiCallbackInitRouteRequestGenerator()
     uniffiCallbackInitRouteResponseParser()
     return InitializationResult.ok
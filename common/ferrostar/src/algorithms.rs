@@ -5,12 +5,19 @@ use crate::{
     navigation_controller::models::StepAdvanceStatus::{self, Advanced, EndOfRoute},
 };
 use crate::{
-    models::{GeographicCoordinate, RouteStep, UserLocation},
-    navigation_controller::models::TripProgress,
+    models::{
+        GeographicCoordinate, Heading, Incident, RouteStep, SegmentAnnotation, SegmentMaxSpeed,
+        UserLocation,
+    },
+    navigation_controller::models::{
+        IncidentsConfig, LegProgress, LocationFilterConfig, LocationRejectionReason,
+        ManeuverPreviewConfig, SpeedLimitChange, TripProgress, UpcomingIncident, UpcomingManeuver,
+    },
 };
+use chrono::{DateTime, TimeDelta, Utc};
 use geo::{
     Bearing, Closest, Coord, Distance, Euclidean, Geodesic, Haversine, HaversineClosestPoint,
-    Length, LineLocatePoint, LineString, Point,
+    InterpolatePoint, Length, LineLocatePoint, LineString, Point,
 };
 
 #[cfg(test)]
@@ -57,7 +64,7 @@ pub fn index_of_closest_segment_origin(location: UserLocation, line: &LineString
 /// Get the bearing to the next point on the `LineString`.
 ///
 /// Returns [`None`] if the index points at or past the last point in the `LineString`.
-fn get_bearing_to_next_point(
+pub(crate) fn get_bearing_to_next_point(
     index_along_line: usize,
     line: &LineString,
 ) -> Option<CourseOverGround> {
@@ -300,6 +307,48 @@ fn travel_distance_to_end_of_step(
         .map(|traversed| step_length - traversed)
 }
 
+/// Computes the travel time to the end of the current route step
+/// from the per-segment durations in [`RouteStep::annotations`].
+///
+/// The segment the user is on is prorated by the distance remaining along it.
+/// Returns [`None`] if the step has no annotations,
+/// or any of the remaining segments lacks a duration.
+fn annotated_duration_to_end_of_step(
+    snapped_location: &Point,
+    current_step_linestring: &LineString,
+    current_step_geometry_index: usize,
+    current_step: &RouteStep,
+) -> Option<f64> {
+    let annotations = current_step
+        .annotations
+        .as_ref()
+        .filter(|annotations| !annotations.is_empty())?;
+    let mut durations = annotations
+        .get(current_step_geometry_index..)?
+        .iter()
+        .map(|annotation| SegmentAnnotation::from_json(annotation).duration);
+
+    // The segment the user is currently on is only partially remaining.
+    let Some(current_segment_duration) = durations.next() else {
+        return Some(0.0);
+    };
+    let segment_start = current_step_linestring
+        .points()
+        .nth(current_step_geometry_index)?;
+    let segment_end = current_step_linestring
+        .points()
+        .nth(current_step_geometry_index + 1)?;
+    let segment_length = Haversine.distance(segment_start, segment_end);
+    let pct_remaining_current_segment = if segment_length > 0.0 {
+        (Haversine.distance(*snapped_location, segment_end) / segment_length).min(1.0)
+    } else {
+        0.0
+    };
+
+    let later_segments_duration = durations.sum::<Option<f64>>()?;
+    Some(pct_remaining_current_segment * current_segment_duration? + later_segments_duration)
+}
+
 /// Calculates the distance (in meters) between two user locations.
 pub(crate) fn distance_between_locations(
     previous_location: &UserLocation,
@@ -312,17 +361,46 @@ pub(crate) fn distance_between_locations(
 
 /// Computes the user's progress along the current trip (distance to destination, ETA, etc.).
 ///
+/// The duration remaining in the current step is prorated linearly by the distance remaining.
+/// Use [`calculate_trip_progress_at_index`] to account for per-segment duration annotations.
+///
 /// NOTE to callers: `remaining_steps` includes the current step!
 pub fn calculate_trip_progress(
     snapped_location: &Point,
     current_step_linestring: &LineString,
     remaining_steps: &[RouteStep],
+) -> TripProgress {
+    calculate_trip_progress_at_index(
+        snapped_location,
+        current_step_linestring,
+        None,
+        remaining_steps,
+    )
+}
+
+/// Computes the user's progress along the current trip (distance to destination, ETA, etc.),
+/// given the index of the segment the user is on within the current step geometry.
+///
+/// The duration remaining in the current step is derived from the per-segment `duration`
+/// annotations past `current_step_geometry_index` when they are available
+/// (so that e.g. traffic along the step is accounted for).
+/// Otherwise, the step duration is prorated linearly by the distance remaining.
+///
+/// NOTE to callers: `remaining_steps` includes the current step!
+pub fn calculate_trip_progress_at_index(
+    snapped_location: &Point,
+    current_step_linestring: &LineString,
+    current_step_geometry_index: Option<u64>,
+    remaining_steps: &[RouteStep],
 ) -> TripProgress {
     let Some(current_step) = remaining_steps.first() else {
         return TripProgress {
             distance_to_next_maneuver: 0.0,
             distance_remaining: 0.0,
             duration_remaining: 0.0,
+            adaptive_duration_remaining: None,
+            current_leg_index: 0,
+            remaining_legs: vec![],
         };
     };
 
@@ -331,16 +409,31 @@ pub fn calculate_trip_progress(
         travel_distance_to_end_of_step(snapped_location, current_step_linestring)
             .unwrap_or(current_step.distance);
 
-    // This could be improved with live traffic data along the route.
-    // TODO: Figure out the best way to enable this use case
-    let pct_remaining_current_step = if current_step.distance > 0f64 {
-        distance_to_next_maneuver / current_step.distance
-    } else {
-        0f64
-    };
+    let duration_to_next_maneuver = current_step_geometry_index
+        .and_then(|index| {
+            annotated_duration_to_end_of_step(
+                snapped_location,
+                current_step_linestring,
+                index as usize,
+                current_step,
+            )
+        })
+        .unwrap_or_else(|| {
+            let pct_remaining_current_step = if current_step.distance > 0f64 {
+                distance_to_next_maneuver / current_step.distance
+            } else {
+                0f64
+            };
 
-    // Get the percentage of duration remaining in the current step.
-    let duration_to_next_maneuver = pct_remaining_current_step * current_step.duration;
+            // Get the percentage of duration remaining in the current step.
+            pct_remaining_current_step * current_step.duration
+        });
+
+    let remaining_legs = calculate_leg_progress(
+        distance_to_next_maneuver,
+        duration_to_next_maneuver,
+        remaining_steps,
+    );
 
     // Exit early if there is only the current step:
     if remaining_steps.len() == 1 {
@@ -348,6 +441,9 @@ pub fn calculate_trip_progress(
             distance_to_next_maneuver,
             distance_remaining: distance_to_next_maneuver,
             duration_remaining: duration_to_next_maneuver,
+            adaptive_duration_remaining: None,
+            current_leg_index: current_step.leg_index,
+            remaining_legs,
         };
     }
 
@@ -368,7 +464,413 @@ pub fn calculate_trip_progress(
         distance_to_next_maneuver,
         distance_remaining,
         duration_remaining,
+        adaptive_duration_remaining: None,
+        current_leg_index: current_step.leg_index,
+        remaining_legs,
+    }
+}
+
+/// Computes the progress towards the end of each remaining route leg.
+///
+/// Leg boundaries are derived from the [`RouteStep::leg_index`] of consecutive steps.
+///
+/// NOTE to callers: `remaining_steps` includes the current step!
+fn calculate_leg_progress(
+    distance_to_next_maneuver: f64,
+    duration_to_next_maneuver: f64,
+    remaining_steps: &[RouteStep],
+) -> Vec<LegProgress> {
+    let mut distance_remaining = distance_to_next_maneuver;
+    let mut duration_remaining = duration_to_next_maneuver;
+    let mut remaining_legs = Vec::new();
+
+    for (index, step) in remaining_steps.iter().enumerate() {
+        if index > 0 {
+            distance_remaining += step.distance;
+            duration_remaining += step.duration;
+        }
+
+        let is_end_of_leg = remaining_steps
+            .get(index + 1)
+            .is_none_or(|next_step| next_step.leg_index != step.leg_index);
+        if is_end_of_leg {
+            remaining_legs.push(LegProgress {
+                leg_index: step.leg_index,
+                distance_remaining,
+                duration_remaining,
+            });
+        }
+    }
+
+    remaining_legs
+}
+
+/// Updates the exponentially smoothed speed (in meters per second) over the current step.
+///
+/// Smoothing starts from the planned speed of the step.
+/// Locations without a (valid) speed leave the smoothed speed unchanged.
+pub(crate) fn update_smoothed_speed(
+    previous_smoothed_speed: Option<f64>,
+    location: &UserLocation,
+    current_step: &RouteStep,
+    smoothing_factor: f64,
+) -> Option<f64> {
+    let previous = previous_smoothed_speed.or_else(|| {
+        (current_step.duration > 0.0).then(|| current_step.distance / current_step.duration)
+    });
+    let observed = location
+        .speed
+        .map(|speed| speed.value)
+        .filter(|value| value.is_finite() && *value >= 0.0);
+
+    match (previous, observed) {
+        (Some(previous), Some(observed)) => {
+            Some(smoothing_factor * observed + (1.0 - smoothing_factor) * previous)
+        }
+        (None, observed) => observed,
+        (previous, None) => previous,
+    }
+}
+
+/// Computes the duration remaining in the trip (in seconds),
+/// assuming the user travels the rest of the current step at `speed`
+/// and follows the planned durations of all later steps.
+///
+/// NOTE to callers: `remaining_steps` includes the current step!
+pub(crate) fn calculate_adaptive_duration_remaining(
+    progress: &TripProgress,
+    remaining_steps: &[RouteStep],
+    speed: f64,
+) -> f64 {
+    let duration_to_next_maneuver = if speed > 0.0 {
+        progress.distance_to_next_maneuver / speed
+    } else {
+        0.0
+    };
+
+    duration_to_next_maneuver
+        + remaining_steps
+            .iter()
+            .skip(1)
+            .map(|step| step.duration)
+            .sum::<f64>()
+}
+
+/// Checks whether a location fix is fit for navigation, given the last accepted fix.
+pub(crate) fn check_location_fix(
+    location: &UserLocation,
+    last_location: Option<&UserLocation>,
+    config: LocationFilterConfig,
+) -> Result<(), LocationRejectionReason> {
+    let GeographicCoordinate { lat, lng } = location.coordinates;
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lng) {
+        // NaN is not contained in any range either.
+        return Err(LocationRejectionReason::InvalidCoordinates);
+    }
+
+    let Some(last_location) = last_location else {
+        return Ok(());
+    };
+    let elapsed = match location.timestamp.duration_since(last_location.timestamp) {
+        Ok(elapsed) if elapsed.is_zero() => return Err(LocationRejectionReason::Duplicate),
+        Ok(elapsed) => elapsed.as_secs_f64(),
+        Err(_) => return Err(LocationRejectionReason::OutOfOrder),
+    };
+
+    let uncertainty = location.horizontal_accuracy + last_location.horizontal_accuracy;
+    let uncertainty = if uncertainty.is_finite() {
+        uncertainty
+    } else {
+        0.0
+    };
+    let distance = Haversine.distance(Point::from(*last_location), Point::from(*location));
+    let implied_speed = (distance - uncertainty).max(0.0) / elapsed;
+    if implied_speed > config.maximum_speed {
+        return Err(LocationRejectionReason::ImplausibleSpeed { implied_speed });
+    }
+
+    Ok(())
+}
+
+/// Estimates the user's speed (in meters per second) for dead reckoning along the current step.
+///
+/// The speed of the last trustworthy location fix is preferred.
+/// Otherwise, the `speed` annotation of the current segment is used,
+/// falling back to the planned speed of the step.
+pub(crate) fn dead_reckoning_speed(
+    last_fix: &UserLocation,
+    current_step: &RouteStep,
+    current_step_geometry_index: Option<u64>,
+) -> Option<f64> {
+    let is_valid_speed = |speed: &f64| speed.is_finite() && *speed > 0.0;
+
+    last_fix
+        .speed
+        .map(|speed| speed.value)
+        .filter(is_valid_speed)
+        .or_else(|| {
+            current_step
+                .get_segment_annotation_at_current_index(current_step_geometry_index?)?
+                .speed
+                .filter(is_valid_speed)
+        })
+        .or_else(|| Some(current_step.distance / current_step.duration).filter(is_valid_speed))
+}
+
+/// Moves `distance` meters along `line`,
+/// starting from `point` on the segment that begins at `geometry_index`.
+///
+/// The result never goes past the end of the line.
+/// Returns the new point, along with the index of the segment it lies on.
+pub(crate) fn advance_along_line(
+    point: Point,
+    line: &LineString,
+    geometry_index: usize,
+    distance: f64,
+) -> (Point, usize) {
+    let mut current = point;
+    let mut remaining = distance.max(0.0);
+    let last_segment_index = line.0.len().saturating_sub(2);
+
+    for (index, next) in line.points().enumerate().skip(geometry_index + 1) {
+        let segment_remaining = Haversine.distance(current, next);
+        if remaining <= segment_remaining {
+            return (
+                Haversine.point_at_distance_between(current, next, remaining),
+                index - 1,
+            );
+        }
+        remaining -= segment_remaining;
+        current = next;
     }
+
+    (current, last_segment_index)
+}
+
+/// Recovers the estimated travel time to the next maneuver,
+/// which is not part of the [`TripProgress`].
+///
+/// NOTE to callers: `remaining_steps` includes the current step!
+fn duration_to_next_maneuver(progress: &TripProgress, remaining_steps: &[RouteStep]) -> f64 {
+    let later_steps_duration = remaining_steps
+        .iter()
+        .skip(1)
+        .map(|step| step.duration)
+        .sum::<f64>();
+    (progress.duration_remaining - later_steps_duration).max(0.0)
+}
+
+/// Lists the maneuvers at the end of each remaining step, starting with the current one.
+///
+/// The final step is excluded, as it only marks arrival
+/// (the arrival maneuver is at the end of the step before it).
+/// Each maneuver is described by the visual instruction of the step leading up to it.
+///
+/// NOTE to callers: `remaining_steps` includes the current step!
+pub(crate) fn upcoming_maneuvers(
+    progress: &TripProgress,
+    remaining_steps: &[RouteStep],
+    config: ManeuverPreviewConfig,
+) -> Vec<UpcomingManeuver> {
+    let mut duration_to_maneuver = duration_to_next_maneuver(progress, remaining_steps);
+    let mut distance_to_maneuver = progress.distance_to_next_maneuver;
+    let mut previous_distance: Option<f64> = None;
+
+    remaining_steps
+        .windows(2)
+        .take(usize::from(config.maximum_maneuvers))
+        .map(|steps| {
+            let (step, next_step) = (&steps[0], &steps[1]);
+            if previous_distance.is_some() {
+                distance_to_maneuver += step.distance;
+                duration_to_maneuver += step.duration;
+            }
+            let is_closely_spaced = previous_distance.is_some_and(|previous| {
+                distance_to_maneuver - previous <= config.closely_spaced_distance
+            });
+            previous_distance = Some(distance_to_maneuver);
+
+            let instruction = step
+                .visual_instructions
+                .last()
+                .map(|instruction| &instruction.primary_content);
+            UpcomingManeuver {
+                maneuver_type: instruction.and_then(|content| content.maneuver_type),
+                maneuver_modifier: instruction.and_then(|content| content.maneuver_modifier),
+                road_name: next_step.road_name.clone(),
+                distance_to_maneuver,
+                duration_to_maneuver,
+                is_closely_spaced,
+            }
+        })
+        .collect()
+}
+
+/// Finds the incident the user is in, or else the nearest incident ahead along the remaining route.
+///
+/// Incidents are only considered if they are expected to be in effect
+/// when the user reaches them (starting from `now`), based on their start and end times,
+/// and if they are within [`IncidentsConfig::maximum_distance`].
+/// The user can only be inside an incident on the current step,
+/// as steps only carry the incidents which lie entirely within them.
+///
+/// NOTE to callers: `remaining_steps` includes the current step!
+pub(crate) fn upcoming_incident(
+    snapped_location: Point,
+    progress: &TripProgress,
+    remaining_steps: &[RouteStep],
+    current_step_geometry_index: Option<u64>,
+    now: DateTime<Utc>,
+    config: IncidentsConfig,
+) -> Option<UpcomingIncident> {
+    let current_index = current_step_geometry_index?;
+    let geometry_length = |geometry: Option<&[GeographicCoordinate]>| {
+        geometry.map(|geometry| Haversine.length(&get_linestring(geometry)))
+    };
+    let is_in_effect = |incident: &Incident, duration_to_incident: f64| {
+        let arrival = now + TimeDelta::milliseconds((duration_to_incident * 1000.0) as i64);
+        incident.start_time.is_none_or(|start| start <= arrival)
+            && incident.end_time.is_none_or(|end| end >= arrival)
+    };
+
+    let duration_to_next_maneuver = duration_to_next_maneuver(progress, remaining_steps);
+    let mut distance_to_step = 0.0;
+    let mut duration_to_step = 0.0;
+
+    for (step_index, step) in remaining_steps.iter().enumerate() {
+        if distance_to_step > config.maximum_distance {
+            break;
+        }
+        let step_length = geometry_length(Some(&step.geometry)).unwrap_or_default();
+        let nearest = step
+            .incidents
+            .iter()
+            .filter_map(|incident| {
+                let start = incident.geometry_index_start as usize;
+                let (distance_to_incident, duration_to_incident, is_inside) = if step_index > 0 {
+                    let along_step = geometry_length(step.geometry.get(..=start))?;
+                    let fraction = if step_length > 0.0 {
+                        along_step / step_length
+                    } else {
+                        0.0
+                    };
+                    (
+                        distance_to_step + along_step,
+                        duration_to_step + step.duration * fraction,
+                        false,
+                    )
+                } else if start <= current_index as usize {
+                    // Already passed, unless the user is still inside it
+                    incident
+                        .geometry_index_end
+                        .filter(|end| current_index < *end)?;
+                    (0.0, 0.0, true)
+                } else {
+                    let next_point = step.geometry.get(current_index as usize + 1)?;
+                    let distance_to_incident = Haversine
+                        .distance(snapped_location, Point::from(*next_point))
+                        + geometry_length(step.geometry.get(current_index as usize + 1..=start))?;
+                    let fraction = if progress.distance_to_next_maneuver > 0.0 {
+                        (distance_to_incident / progress.distance_to_next_maneuver).min(1.0)
+                    } else {
+                        0.0
+                    };
+                    (
+                        distance_to_incident,
+                        duration_to_next_maneuver * fraction,
+                        false,
+                    )
+                };
+
+                let is_reported = distance_to_incident <= config.maximum_distance
+                    && is_in_effect(incident, duration_to_incident);
+                is_reported.then(|| UpcomingIncident {
+                    incident: incident.clone(),
+                    distance_to_incident,
+                    duration_to_incident,
+                    is_inside,
+                })
+            })
+            .min_by(|a, b| a.distance_to_incident.total_cmp(&b.distance_to_incident));
+        if nearest.is_some() {
+            return nearest;
+        }
+
+        if step_index == 0 {
+            distance_to_step = progress.distance_to_next_maneuver;
+            duration_to_step = duration_to_next_maneuver;
+        } else {
+            distance_to_step += step.distance;
+            duration_to_step += step.duration;
+        }
+    }
+
+    None
+}
+
+/// Finds the next change of the speed limit along the remaining route.
+///
+/// Segments without a speed limit annotation or with an unknown limit are skipped,
+/// as they say nothing about the limit.
+/// The distance is measured from the snapped user location
+/// to the start of the first segment with a different limit.
+pub(crate) fn next_speed_limit_change(
+    snapped_location: Point,
+    remaining_steps: &[RouteStep],
+    current_step_geometry_index: Option<u64>,
+    current_speed_limit: Option<SegmentMaxSpeed>,
+) -> Option<SpeedLimitChange> {
+    let current_index = current_step_geometry_index? as usize;
+    let mut previous_point = snapped_location;
+    let mut distance_to_change = 0.0;
+
+    for (step_index, step) in remaining_steps.iter().enumerate() {
+        let first_segment = if step_index == 0 {
+            current_index + 1
+        } else {
+            0
+        };
+        for segment_index in first_segment..step.geometry.len().saturating_sub(1) {
+            let segment_start = Point::from(step.geometry[segment_index]);
+            distance_to_change += Haversine.distance(previous_point, segment_start);
+            previous_point = segment_start;
+
+            let speed_limit = step
+                .get_segment_annotation_at_current_index(segment_index as u64)
+                .and_then(|annotation| annotation.max_speed);
+            if let Some(speed_limit) = speed_limit
+                && speed_limit != SegmentMaxSpeed::Unknown
+                && Some(speed_limit) != current_speed_limit
+            {
+                return Some(SpeedLimitChange {
+                    speed_limit,
+                    distance_to_change,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// The maximum age difference (in seconds) between a device heading and a location fix
+/// for the heading to describe the user's orientation at that fix.
+const MAXIMUM_HEADING_AGE: f64 = 10.0;
+
+/// Converts a device heading into a course, if it is recent relative to the given location.
+pub(crate) fn heading_course(
+    heading: Option<Heading>,
+    location: &UserLocation,
+) -> Option<CourseOverGround> {
+    let heading = heading?;
+    let age = location
+        .timestamp
+        .duration_since(heading.timestamp)
+        .unwrap_or_else(|e| e.duration());
+    (age.as_secs_f64() <= MAXIMUM_HEADING_AGE).then_some(CourseOverGround {
+        degrees: heading.true_heading % 360,
+        accuracy: Some(heading.accuracy),
+    })
 }
 
 /// Convert a vector of geographic coordinates to a [`LineString`].
@@ -668,3 +1170,556 @@ mod bearing_snapping_tests {
 // TODO: Other unit tests
 // - Under and over distance accuracy thresholds
 // - Equator and extreme latitude
+
+#[cfg(test)]
+mod trip_progress_tests {
+    use super::*;
+    use crate::models::{SegmentAnnotation, Speed};
+    use crate::navigation_controller::test_helpers::{
+        gen_annotation_json, gen_route_step_with_coords,
+    };
+
+    /// A step with three equally long segments, the middle one of which is congested.
+    fn congested_step(segment_annotations: Option<Vec<SegmentAnnotation>>) -> RouteStep {
+        RouteStep {
+            duration: 90.0,
+            annotations: segment_annotations
+                .map(|annotations| annotations.into_iter().map(gen_annotation_json).collect()),
+            ..gen_route_step_with_coords(vec![
+                coord!(x: 0.0, y: 0.0),
+                coord!(x: 0.001, y: 0.0),
+                coord!(x: 0.002, y: 0.0),
+                coord!(x: 0.003, y: 0.0),
+            ])
+        }
+    }
+
+    fn duration_annotations(durations: &[f64]) -> Vec<SegmentAnnotation> {
+        durations
+            .iter()
+            .map(|duration| SegmentAnnotation {
+                duration: Some(*duration),
+                speed: Some(10.0),
+                ..SegmentAnnotation::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_duration_from_annotations() {
+        let step = congested_step(Some(duration_annotations(&[10.0, 70.0, 10.0])));
+        let linestring = step.get_linestring();
+
+        // Halfway along the first segment
+        let progress = calculate_trip_progress_at_index(
+            &point!(x: 0.0005, y: 0.0),
+            &linestring,
+            Some(0),
+            &[step],
+        );
+        assert!((progress.duration_remaining - 85.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_duration_from_annotations_past_congestion() {
+        let step = congested_step(Some(duration_annotations(&[10.0, 70.0, 10.0])));
+        let linestring = step.get_linestring();
+
+        // Halfway along the last segment; linear prorating would yield 15s.
+        let progress = calculate_trip_progress_at_index(
+            &point!(x: 0.0025, y: 0.0),
+            &linestring,
+            Some(2),
+            &[step],
+        );
+        assert!((progress.duration_remaining - 5.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_duration_falls_back_to_linear_prorate() {
+        for annotations in [
+            None,
+            Some(vec![]),
+            Some(vec![
+                SegmentAnnotation {
+                    speed: Some(10.0),
+                    ..SegmentAnnotation::default()
+                };
+                3
+            ]),
+        ] {
+            let step = congested_step(annotations);
+            let linestring = step.get_linestring();
+
+            let progress = calculate_trip_progress_at_index(
+                &point!(x: 0.0025, y: 0.0),
+                &linestring,
+                Some(2),
+                &[step],
+            );
+            assert!((progress.duration_remaining - 15.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_later_steps_use_step_duration() {
+        let step = congested_step(Some(duration_annotations(&[10.0, 70.0, 10.0])));
+        let next_step = RouteStep {
+            duration: 42.0,
+            ..gen_route_step_with_coords(vec![coord!(x: 0.003, y: 0.0), coord!(x: 0.003, y: 0.001)])
+        };
+        let linestring = step.get_linestring();
+
+        let progress = calculate_trip_progress_at_index(
+            &point!(x: 0.0025, y: 0.0),
+            &linestring,
+            Some(2),
+            &[step, next_step],
+        );
+        assert!((progress.duration_remaining - 47.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_smoothed_speed() {
+        // 300m planned to take 90s
+        let step = congested_step(None);
+        let planned_speed = step.distance / step.duration;
+        let mut location = make_user_location(coord!(x: 0.0, y: 0.0), 5.0);
+
+        // Without a speed observation, the planned speed is used.
+        let smoothed = update_smoothed_speed(None, &location, &step, 0.5);
+        assert_eq!(smoothed, Some(planned_speed));
+
+        location.speed = Some(Speed {
+            value: 1.0,
+            accuracy: None,
+        });
+        let smoothed = update_smoothed_speed(None, &location, &step, 0.5).unwrap();
+        assert!((smoothed - f64::midpoint(planned_speed, 1.0)).abs() < 1e-9);
+        let smoothed = update_smoothed_speed(Some(smoothed), &location, &step, 0.5).unwrap();
+        assert!((smoothed - (planned_speed + 3.0) / 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dead_reckoning_speed() {
+        let mut location = make_user_location(coord!(x: 0.0, y: 0.0), 5.0);
+
+        // Without speed annotations, the planned speed is used.
+        let step = congested_step(None);
+        let planned_speed = step.distance / step.duration;
+        assert_eq!(
+            dead_reckoning_speed(&location, &step, Some(0)),
+            Some(planned_speed)
+        );
+
+        // Annotations take precedence over the planned speed
+        let step = congested_step(Some(duration_annotations(&[10.0, 70.0, 10.0])));
+        assert_eq!(dead_reckoning_speed(&location, &step, Some(1)), Some(10.0));
+
+        // The last observed speed takes precedence over everything else
+        location.speed = Some(Speed {
+            value: 3.0,
+            accuracy: None,
+        });
+        assert_eq!(dead_reckoning_speed(&location, &step, Some(1)), Some(3.0));
+
+        // Stationary users fall back to the route's speeds.
+        location.speed = Some(Speed {
+            value: 0.0,
+            accuracy: None,
+        });
+        assert_eq!(dead_reckoning_speed(&location, &step, Some(1)), Some(10.0));
+    }
+
+    #[test]
+    fn test_advance_along_line() {
+        let line = congested_step(None).get_linestring();
+        let segment_length = Haversine.distance(point!(x: 0.0, y: 0.0), point!(x: 0.001, y: 0.0));
+
+        // Within the starting segment
+        let (point, index) = advance_along_line(point!(x: 0.0, y: 0.0), &line, 0, 50.0);
+        assert_eq!(index, 0);
+        assert!((Haversine.distance(point!(x: 0.0, y: 0.0), point) - 50.0).abs() < 0.01);
+
+        // Across a vertex
+        let (point, index) =
+            advance_along_line(point!(x: 0.0005, y: 0.0), &line, 0, segment_length);
+        assert_eq!(index, 1);
+        assert!((point.x() - 0.0015).abs() < 1e-6);
+        assert!(point.y().abs() < 1e-9);
+
+        // Never past the end of the line
+        let (point, index) = advance_along_line(point!(x: 0.0, y: 0.0), &line, 0, 10_000.0);
+        assert_eq!(index, 2);
+        assert_eq!(point, point!(x: 0.003, y: 0.0));
+    }
+
+    #[test]
+    fn test_upcoming_maneuvers() {
+        let step_along = |from: f64, to: f64, duration: f64, road_name: &str| RouteStep {
+            duration,
+            road_name: Some(road_name.to_string()),
+            ..gen_route_step_with_coords(vec![coord!(x: from, y: 0.0), coord!(x: to, y: 0.0)])
+        };
+        let remaining_steps = [
+            step_along(0.0, 0.002, 20.0, "First"),
+            // ~33m, so the next maneuver closely follows
+            step_along(0.002, 0.0023, 5.0, "Second"),
+            step_along(0.0023, 0.004, 15.0, "Third"),
+            // Arrival
+            step_along(0.004, 0.004, 0.0, "Third"),
+        ];
+        let progress = calculate_trip_progress_at_index(
+            &point!(x: 0.001, y: 0.0),
+            &remaining_steps[0].get_linestring(),
+            Some(0),
+            &remaining_steps,
+        );
+        let config = ManeuverPreviewConfig::default();
+
+        let maneuvers = upcoming_maneuvers(&progress, &remaining_steps, config);
+        assert_eq!(maneuvers.len(), 3);
+        assert_eq!(
+            maneuvers
+                .iter()
+                .map(|maneuver| maneuver.road_name.as_deref())
+                .collect::<Vec<_>>(),
+            [Some("Second"), Some("Third"), Some("Third")]
+        );
+        assert_eq!(
+            maneuvers
+                .iter()
+                .map(|maneuver| maneuver.is_closely_spaced)
+                .collect::<Vec<_>>(),
+            [false, true, false]
+        );
+        assert!(
+            (maneuvers[0].distance_to_maneuver - progress.distance_to_next_maneuver).abs() < 1e-9
+        );
+        assert!((maneuvers[0].duration_to_maneuver - 10.0).abs() < 1e-6);
+        assert!(
+            (maneuvers[1].distance_to_maneuver
+                - (progress.distance_to_next_maneuver + remaining_steps[1].distance))
+                .abs()
+                < 1e-9
+        );
+        assert!((maneuvers[1].duration_to_maneuver - 15.0).abs() < 1e-6);
+        assert!((maneuvers[2].distance_to_maneuver - progress.distance_remaining).abs() < 1e-9);
+        assert!((maneuvers[2].duration_to_maneuver - progress.duration_remaining).abs() < 1e-6);
+
+        let config = ManeuverPreviewConfig {
+            maximum_maneuvers: 1,
+            ..config
+        };
+        assert_eq!(
+            upcoming_maneuvers(&progress, &remaining_steps, config).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_next_speed_limit_change() {
+        use crate::models::{SegmentAnnotation, SegmentSpeedUnit};
+
+        let limit = |speed: f64| SegmentMaxSpeed::Known {
+            speed,
+            unit: SegmentSpeedUnit::KilometersPerHour,
+        };
+        let with_limits = |mut step: RouteStep, limits: &[Option<SegmentMaxSpeed>]| {
+            step.annotations = Some(
+                limits
+                    .iter()
+                    .map(|max_speed| {
+                        gen_annotation_json(SegmentAnnotation {
+                            max_speed: *max_speed,
+                            ..SegmentAnnotation::default()
+                        })
+                    })
+                    .collect(),
+            );
+            step
+        };
+        let segment_length = Haversine.distance(point!(x: 0.0, y: 0.0), point!(x: 0.001, y: 0.0));
+
+        // Unannotated segments don't count as a change
+        let step = with_limits(
+            congested_step(None),
+            &[Some(limit(50.0)), None, Some(limit(30.0))],
+        );
+        let next_step = with_limits(
+            gen_route_step_with_coords(vec![coord!(x: 0.003, y: 0.0), coord!(x: 0.004, y: 0.0)]),
+            &[Some(SegmentMaxSpeed::NoLimit)],
+        );
+        let remaining_steps = [step, next_step];
+
+        let change = next_speed_limit_change(
+            point!(x: 0.0005, y: 0.0),
+            &remaining_steps,
+            Some(0),
+            Some(limit(50.0)),
+        )
+        .expect("Expected a speed limit change");
+        assert_eq!(change.speed_limit, limit(30.0));
+        assert!((change.distance_to_change - segment_length * 1.5).abs() < 0.01);
+
+        // Changes on later steps are found too
+        let change = next_speed_limit_change(
+            point!(x: 0.0025, y: 0.0),
+            &remaining_steps,
+            Some(2),
+            Some(limit(30.0)),
+        )
+        .expect("Expected a speed limit change");
+        assert_eq!(change.speed_limit, SegmentMaxSpeed::NoLimit);
+        assert!((change.distance_to_change - segment_length * 0.5).abs() < 0.01);
+
+        // No change ahead
+        assert_eq!(
+            next_speed_limit_change(
+                point!(x: 0.0035, y: 0.0),
+                &remaining_steps[1..],
+                Some(0),
+                Some(SegmentMaxSpeed::NoLimit),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_upcoming_incident() {
+        use crate::models::IncidentType;
+        use chrono::TimeZone;
+
+        let incident =
+            |id: &str, geometry_index_start: u64, geometry_index_end: Option<u64>| Incident {
+                id: id.to_string(),
+                incident_type: IncidentType::Construction,
+                description: None,
+                long_description: None,
+                creation_time: None,
+                start_time: None,
+                end_time: None,
+                impact: None,
+                lanes_blocked: vec![],
+                congestion: None,
+                closed: None,
+                geometry_index_start,
+                geometry_index_end,
+                sub_type: None,
+                sub_type_description: None,
+                iso_3166_1_alpha2: None,
+                iso_3166_1_alpha3: None,
+                affected_road_names: vec![],
+                bbox: None,
+            };
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let segment_length = Haversine.distance(point!(x: 0.0, y: 0.0), point!(x: 0.001, y: 0.0));
+
+        let mut step = congested_step(None);
+        step.incidents = vec![
+            incident("ahead", 2, Some(3)),
+            incident("passed", 0, Some(1)),
+        ];
+        let mut next_step = RouteStep {
+            duration: 60.0,
+            ..gen_route_step_with_coords(vec![
+                coord!(x: 0.003, y: 0.0),
+                coord!(x: 0.004, y: 0.0),
+                coord!(x: 0.005, y: 0.0),
+            ])
+        };
+        next_step.incidents = vec![incident("later", 1, None)];
+        let remaining_steps = [step, next_step];
+        let upcoming_incident_within = |config, lng: f64, index: u64, steps: &[RouteStep]| {
+            let snapped_location = point!(x: lng, y: 0.0);
+            let progress = calculate_trip_progress_at_index(
+                &snapped_location,
+                &steps[0].get_linestring(),
+                Some(index),
+                steps,
+            );
+            upcoming_incident(snapped_location, &progress, steps, Some(index), now, config)
+        };
+        let upcoming_incident_at = |lng: f64, index: u64, steps: &[RouteStep]| {
+            upcoming_incident_within(IncidentsConfig::default(), lng, index, steps)
+        };
+
+        // The nearest incident ahead on the current step, ignoring ones already passed
+        let upcoming = upcoming_incident_at(0.0015, 1, &remaining_steps)
+            .expect("Expected an upcoming incident");
+        assert_eq!(upcoming.incident.id, "ahead");
+        assert!(!upcoming.is_inside);
+        assert!((upcoming.distance_to_incident - segment_length * 0.5).abs() < 0.01);
+        assert!((upcoming.duration_to_incident - 15.0).abs() < 0.01);
+
+        // Inside the incident
+        let upcoming = upcoming_incident_at(0.0025, 2, &remaining_steps)
+            .expect("Expected an upcoming incident");
+        assert_eq!(upcoming.incident.id, "ahead");
+        assert!(upcoming.is_inside);
+        assert!(upcoming.distance_to_incident.abs() < f64::EPSILON);
+
+        // Incidents on later steps are found once there are none left on the current step
+        let mut steps_without_ahead = remaining_steps.clone();
+        steps_without_ahead[0].incidents.remove(0);
+        let upcoming = upcoming_incident_at(0.0015, 1, &steps_without_ahead)
+            .expect("Expected an upcoming incident");
+        assert_eq!(upcoming.incident.id, "later");
+        assert!((upcoming.distance_to_incident - segment_length * 2.5).abs() < 0.01);
+        assert!((upcoming.duration_to_incident - 75.0).abs() < 0.01);
+
+        // ... unless they are farther than the maximum distance
+        let config = IncidentsConfig {
+            maximum_distance: segment_length * 2.0,
+        };
+        assert_eq!(
+            upcoming_incident_within(config, 0.0015, 1, &steps_without_ahead),
+            None
+        );
+
+        // Incidents which are over by the time the user gets there are skipped
+        let mut steps_with_expiring = steps_without_ahead.clone();
+        steps_with_expiring[1].incidents[0].end_time = Some(now + TimeDelta::seconds(60));
+        assert_eq!(upcoming_incident_at(0.0015, 1, &steps_with_expiring), None);
+
+        // As are incidents which will not have started yet
+        steps_with_expiring[1].incidents[0].end_time = None;
+        steps_with_expiring[1].incidents[0].start_time = Some(now + TimeDelta::seconds(90));
+        assert_eq!(upcoming_incident_at(0.0015, 1, &steps_with_expiring), None);
+        steps_with_expiring[1].incidents[0].start_time = Some(now + TimeDelta::seconds(70));
+        assert!(upcoming_incident_at(0.0015, 1, &steps_with_expiring).is_some());
+    }
+
+    #[test]
+    fn test_check_location_fix() {
+        use std::time::Duration;
+
+        let config = LocationFilterConfig::default();
+        let last_location = make_user_location(coord!(x: 0.0, y: 0.0), 5.0);
+        let location_after = |lng: f64, seconds: u64| UserLocation {
+            timestamp: last_location.timestamp + Duration::from_secs(seconds),
+            ..make_user_location(coord!(x: lng, y: 0.0), 5.0)
+        };
+
+        // ~111m in 10s
+        let location = location_after(0.001, 10);
+        assert_eq!(check_location_fix(&location, None, config), Ok(()));
+        assert_eq!(
+            check_location_fix(&location, Some(&last_location), config),
+            Ok(())
+        );
+
+        for invalid_location in [
+            make_user_location(coord!(x: f64::NAN, y: 0.0), 5.0),
+            make_user_location(coord!(x: 0.0, y: 91.0), 5.0),
+            make_user_location(coord!(x: f64::INFINITY, y: 0.0), 5.0),
+        ] {
+            assert_eq!(
+                check_location_fix(&invalid_location, None, config),
+                Err(LocationRejectionReason::InvalidCoordinates)
+            );
+        }
+
+        assert_eq!(
+            check_location_fix(&last_location, Some(&location), config),
+            Err(LocationRejectionReason::OutOfOrder)
+        );
+        assert_eq!(
+            check_location_fix(&location_after(0.0, 0), Some(&last_location), config),
+            Err(LocationRejectionReason::Duplicate)
+        );
+
+        // ~5.5km in 10s
+        let Err(LocationRejectionReason::ImplausibleSpeed { implied_speed }) =
+            check_location_fix(&location_after(0.05, 10), Some(&last_location), config)
+        else {
+            panic!("Expected the location to be rejected");
+        };
+        assert!((implied_speed - 555.0).abs() < 1.0);
+
+        // Jitter within the accuracy of the fixes is not a jump.
+        assert_eq!(
+            check_location_fix(
+                &UserLocation {
+                    horizontal_accuracy: 100.0,
+                    ..location_after(0.001, 1)
+                },
+                Some(&last_location),
+                config
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_adaptive_duration_remaining() {
+        let step = congested_step(None);
+        let next_step = RouteStep {
+            duration: 42.0,
+            ..gen_route_step_with_coords(vec![coord!(x: 0.003, y: 0.0), coord!(x: 0.003, y: 0.001)])
+        };
+        let remaining_steps = [step, next_step];
+        let progress = calculate_trip_progress_at_index(
+            &point!(x: 0.0, y: 0.0),
+            &remaining_steps[0].get_linestring(),
+            Some(0),
+            &remaining_steps,
+        );
+
+        let adaptive = calculate_adaptive_duration_remaining(&progress, &remaining_steps, 2.0);
+        assert!((adaptive - (progress.distance_to_next_maneuver / 2.0 + 42.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_leg_progress() {
+        let first_leg_step = RouteStep {
+            duration: 10.0,
+            ..gen_route_step_with_coords(vec![coord!(x: 0.0, y: 0.0), coord!(x: 0.001, y: 0.0)])
+        };
+        let first_leg_arrival = RouteStep {
+            duration: 0.0,
+            ..gen_route_step_with_coords(vec![coord!(x: 0.001, y: 0.0), coord!(x: 0.001, y: 0.0)])
+        };
+        let second_leg_step = RouteStep {
+            duration: 20.0,
+            leg_index: 1,
+            ..gen_route_step_with_coords(vec![coord!(x: 0.001, y: 0.0), coord!(x: 0.003, y: 0.0)])
+        };
+        let remaining_steps = [
+            first_leg_step.clone(),
+            first_leg_arrival,
+            second_leg_step.clone(),
+        ];
+
+        // Halfway along the first step
+        let progress = calculate_trip_progress_at_index(
+            &point!(x: 0.0005, y: 0.0),
+            &first_leg_step.get_linestring(),
+            Some(0),
+            &remaining_steps,
+        );
+        assert_eq!(progress.current_leg_index, 0);
+        assert_eq!(progress.remaining_legs.len(), 2);
+
+        let first_leg = &progress.remaining_legs[0];
+        assert_eq!(first_leg.leg_index, 0);
+        assert!((first_leg.distance_remaining - first_leg_step.distance / 2.0).abs() < 0.01);
+        assert!((first_leg.duration_remaining - 5.0).abs() < 0.01);
+
+        let second_leg = &progress.remaining_legs[1];
+        assert_eq!(second_leg.leg_index, 1);
+        assert!((second_leg.distance_remaining - progress.distance_remaining).abs() < 0.01);
+        assert!((second_leg.duration_remaining - 25.0).abs() < 0.01);
+
+        // On the second leg
+        let progress = calculate_trip_progress_at_index(
+            &point!(x: 0.001, y: 0.0),
+            &second_leg_step.get_linestring(),
+            Some(0),
+            &remaining_steps[2..],
+        );
+        assert_eq!(progress.current_leg_index, 1);
+        assert_eq!(progress.remaining_legs.len(), 1);
+        assert!((progress.remaining_legs[0].duration_remaining - 20.0).abs() < 0.01);
+    }
+}
@@ -12,11 +12,13 @@
 //! When architecting a Ferrostar core integration for a new platform,
 //! we suggest enforcing a similar separation of concerns.
 
-use crate::algorithms::deviation_from_line;
-use crate::models::Route;
+use crate::algorithms::{
+    deviation_from_line, distance_between_locations, get_bearing_to_next_point, heading_course,
+};
+use crate::models::{CourseOverGround, ModeOfTravel, Route, UserLocation};
 use crate::navigation_controller::models::TripState;
 #[cfg(test)]
-use crate::{models::UserLocation, navigation_controller::test_helpers::get_navigating_trip_state};
+use crate::navigation_controller::test_helpers::get_navigating_trip_state;
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
 use geo::Point;
@@ -61,7 +63,100 @@ pub enum RouteDeviationTracking {
         #[serde(alias = "max_acceptable_deviation")]
         max_acceptable_deviation: f64,
     },
-    // TODO: Standard variants that account for mode of travel. For example, `DefaultFor(modeOfTravel: ModeOfTravel)` with sensible defaults for walking, driving, cycling, etc.
+    /// Detects deviation from the route using sensible defaults for the mode of travel.
+    ///
+    /// Like [`RouteDeviationTracking::StaticThreshold`],
+    /// this flags the user as off route when they are too far from the route line,
+    /// and ignores inaccurate location updates.
+    /// In addition, the user must stay off the route for a minimum duration
+    /// *and* move a minimum distance before being flagged,
+    /// so that a single noisy location update does not mark the user as [`DeviationKind::CompletelyOffRoute`].
+    ///
+    /// | Mode      | Max deviation | Min. accuracy | Min. duration | Min. distance |
+    /// |-----------|---------------|---------------|---------------|---------------|
+    /// | Driving   | 50 m          | 40 m          | 3 s           | 30 m          |
+    /// | Cycling   | 30 m          | 30 m          | 5 s           | 20 m          |
+    /// | Walking   | 20 m          | 25 m          | 10 s          | 15 m          |
+    /// | Transit   | 100 m         | 75 m          | 15 s          | 100 m         |
+    #[serde(rename_all = "camelCase")]
+    DefaultFor {
+        /// The mode of travel to use defaults for.
+        #[serde(alias = "mode_of_travel")]
+        mode_of_travel: ModeOfTravel,
+    },
+    /// Detects deviation from the route with configurable hysteresis.
+    ///
+    /// The user is flagged as [`DeviationKind::CompletelyOffRoute`]
+    /// only after staying beyond the entry threshold for at least the minimum duration
+    /// *or* moving at least the minimum distance, whichever comes first.
+    /// The duration ensures that a user who stops off the route is eventually flagged,
+    /// while the distance flags a user who quickly drives away sooner.
+    /// Once flagged, the deviation is not cleared until the user comes back within the
+    /// (tighter) exit threshold, so that a user hovering around the entry threshold
+    /// does not flip-flop between on and off route.
+    ///
+    /// The tracking state is carried between location updates in the
+    /// [`NavState`](crate::navigation_controller::models::NavState).
+    #[serde(rename_all = "camelCase")]
+    Hysteresis {
+        /// The minimum required horizontal accuracy of the user location, in meters.
+        /// Less accurate location updates neither set nor clear a deviation.
+        #[serde(alias = "minimum_horizontal_accuracy")]
+        minimum_horizontal_accuracy: u16,
+        /// The distance from the route line (in meters) beyond which the user may be flagged as off route.
+        #[serde(alias = "entry_threshold")]
+        entry_threshold: f64,
+        /// The distance from the route line (in meters) within which the user must return
+        /// before a deviation is cleared.
+        ///
+        /// This should be less than or equal to the entry threshold;
+        /// larger values are treated as equal to the entry threshold.
+        #[serde(alias = "exit_threshold")]
+        exit_threshold: f64,
+        /// How long (in seconds) the user must be beyond the entry threshold before being flagged.
+        #[serde(alias = "minimum_duration")]
+        minimum_duration: f64,
+        /// How far (in meters) the user must move while beyond the entry threshold before being flagged,
+        /// if this happens before the minimum duration has passed.
+        #[serde(alias = "minimum_distance")]
+        minimum_distance: f64,
+    },
+    /// Detects deviation from the route like [`RouteDeviationTracking::StaticThreshold`],
+    /// and additionally flags travel in the wrong direction along the route.
+    ///
+    /// While the user is on the route, their course over ground is compared against
+    /// the bearing of the route segment they are on.
+    /// If the difference exceeds the tolerance (plus the reported course accuracy)
+    /// for at least the minimum duration, this reports [`DeviationKind::WrongDirection`].
+    /// This catches cases like a U-turn back along the route,
+    /// which a purely distance-based check never flags.
+    ///
+    /// While the user is (nearly) stationary, the course is meaningless,
+    /// so the device heading is used instead when available
+    /// (see [`Navigator::update_heading`](crate::navigation_controller::Navigator::update_heading)).
+    /// This catches users who start a trip facing the wrong way.
+    #[serde(rename_all = "camelCase")]
+    HeadingAware {
+        /// The minimum required horizontal accuracy of the user location, in meters.
+        /// Values larger than this will not trigger route deviation warnings.
+        #[serde(alias = "minimum_horizontal_accuracy")]
+        minimum_horizontal_accuracy: u16,
+        /// The maximum acceptable deviation from the route line, in meters.
+        #[serde(alias = "max_acceptable_deviation")]
+        max_acceptable_deviation: f64,
+        /// The maximum acceptable difference (in degrees) between the user's course
+        /// and the bearing of the route.
+        #[serde(alias = "max_course_deviation")]
+        max_course_deviation: u16,
+        /// Courses with a reported accuracy worse than this (in degrees) are ignored.
+        ///
+        /// Courses without an accuracy are assumed to be accurate.
+        #[serde(alias = "minimum_course_accuracy")]
+        minimum_course_accuracy: u16,
+        /// How long (in seconds) the mismatch must persist before the user is flagged.
+        #[serde(alias = "minimum_duration")]
+        minimum_duration: f64,
+    },
     /// An arbitrary user-defined implementation.
     /// You decide with your own [`RouteDeviationDetector`] implementation!
     #[serde(skip)]
@@ -71,73 +166,396 @@ pub enum RouteDeviationTracking {
 }
 
 impl RouteDeviationTracking {
+    /// Checks for deviation, ignoring any state carried between location updates.
+    #[cfg(test)]
     #[must_use]
     pub(crate) fn check_route_deviation(
         &self,
         route: &Route,
         trip_state: &TripState,
     ) -> RouteDeviation {
+        self.check_route_deviation_with_state(route, trip_state, RouteDeviationState::default())
+            .0
+    }
+
+    /// Checks for deviation, returning the state to carry over to the next location update.
+    #[must_use]
+    pub(crate) fn check_route_deviation_with_state(
+        &self,
+        route: &Route,
+        trip_state: &TripState,
+        state: RouteDeviationState,
+    ) -> (RouteDeviation, RouteDeviationState) {
         match self {
-            RouteDeviationTracking::None => RouteDeviation::NoDeviation,
+            RouteDeviationTracking::None => {
+                (RouteDeviation::NoDeviation, RouteDeviationState::default())
+            }
             RouteDeviationTracking::StaticThreshold {
                 minimum_horizontal_accuracy,
                 max_acceptable_deviation,
-            } => match trip_state {
-                TripState::Idle { .. } | TripState::Complete { .. } => RouteDeviation::NoDeviation,
-                TripState::Navigating {
-                    user_location,
-                    remaining_steps,
-                    ..
-                } => {
-                    // Short-circuit in case the GPS is going wild (e.g. in a tunnel or deep urban canyon).
-                    if user_location.horizontal_accuracy > f64::from(*minimum_horizontal_accuracy) {
-                        return RouteDeviation::NoDeviation;
+            } => (
+                static_threshold_deviation(
+                    trip_state,
+                    *minimum_horizontal_accuracy,
+                    *max_acceptable_deviation,
+                ),
+                RouteDeviationState::default(),
+            ),
+            RouteDeviationTracking::DefaultFor { mode_of_travel } => {
+                DeviationThresholds::default_for(*mode_of_travel).check(trip_state, state)
+            }
+            RouteDeviationTracking::Hysteresis {
+                minimum_horizontal_accuracy,
+                entry_threshold,
+                exit_threshold,
+                minimum_duration,
+                minimum_distance,
+            } => DeviationThresholds {
+                minimum_horizontal_accuracy: *minimum_horizontal_accuracy,
+                entry_threshold: *entry_threshold,
+                exit_threshold: exit_threshold.min(*entry_threshold),
+                minimum_duration: *minimum_duration,
+                minimum_distance: *minimum_distance,
+                dwell_requirement: DwellRequirement::Either,
+            }
+            .check(trip_state, state),
+            RouteDeviationTracking::HeadingAware {
+                minimum_horizontal_accuracy,
+                max_acceptable_deviation,
+                max_course_deviation,
+                minimum_course_accuracy,
+                minimum_duration,
+            } => {
+                let deviation = static_threshold_deviation(
+                    trip_state,
+                    *minimum_horizontal_accuracy,
+                    *max_acceptable_deviation,
+                );
+                if deviation == RouteDeviation::NoDeviation {
+                    HeadingThresholds {
+                        max_course_deviation: *max_course_deviation,
+                        minimum_course_accuracy: *minimum_course_accuracy,
+                        minimum_duration: *minimum_duration,
                     }
+                    .check(trip_state, state)
+                } else {
+                    (deviation, RouteDeviationState::default())
+                }
+            }
+            RouteDeviationTracking::Custom { detector } => (
+                detector.check_route_deviation(route.clone(), trip_state.clone()),
+                RouteDeviationState::default(),
+            ),
+        }
+    }
+}
 
-                    let user_point = Point::from(*user_location);
+/// Checks the user location against a fixed distance from the route line.
+fn static_threshold_deviation(
+    trip_state: &TripState,
+    minimum_horizontal_accuracy: u16,
+    max_acceptable_deviation: f64,
+) -> RouteDeviation {
+    match trip_state {
+        TripState::Idle { .. } | TripState::Paused { .. } | TripState::Complete { .. } => {
+            RouteDeviation::NoDeviation
+        }
+        TripState::Navigating {
+            user_location,
+            remaining_steps,
+            ..
+        } => {
+            // Short-circuit in case the GPS is going wild (e.g. in a tunnel or deep urban canyon).
+            if user_location.horizontal_accuracy > f64::from(minimum_horizontal_accuracy) {
+                return RouteDeviation::NoDeviation;
+            }
 
-                    // No steps remain, so... ;)
-                    let Some(current_step) = remaining_steps.first() else {
-                        return RouteDeviation::NoDeviation;
-                    };
+            let user_point = Point::from(*user_location);
 
-                    let current_step_distance =
-                        deviation_from_line(&user_point, &current_step.get_linestring())
-                            .unwrap_or(0.0);
+            // No steps remain, so... ;)
+            let Some(current_step) = remaining_steps.first() else {
+                return RouteDeviation::NoDeviation;
+            };
 
-                    // Check if the user is on the current step.
-                    if current_step_distance <= *max_acceptable_deviation {
-                        return RouteDeviation::NoDeviation;
-                    }
+            let current_step_distance =
+                deviation_from_line(&user_point, &current_step.get_linestring()).unwrap_or(0.0);
 
-                    // User is off the current step. Check if they're on any future step.
-                    for step in remaining_steps.iter().skip(1) {
-                        let distance = deviation_from_line(&user_point, &step.get_linestring())
-                            .unwrap_or(f64::MAX);
-                        if distance <= *max_acceptable_deviation {
-                            return RouteDeviation::Deviation {
-                                kind: DeviationKind::OffStepOnRoute {
-                                    deviation_from_step_line: current_step_distance,
-                                },
-                            };
-                        }
-                    }
+            // Check if the user is on the current step.
+            if current_step_distance <= max_acceptable_deviation {
+                return RouteDeviation::NoDeviation;
+            }
 
-                    // Not within the distance threshold of *any* step, so we're off the route.
-                    RouteDeviation::Deviation {
-                        kind: DeviationKind::CompletelyOffRoute {
-                            deviation_from_route_line: current_step_distance,
+            // User is off the current step. Check if they're on any future step.
+            for step in remaining_steps.iter().skip(1) {
+                let distance =
+                    deviation_from_line(&user_point, &step.get_linestring()).unwrap_or(f64::MAX);
+                if distance <= max_acceptable_deviation {
+                    return RouteDeviation::Deviation {
+                        kind: DeviationKind::OffStepOnRoute {
+                            deviation_from_step_line: current_step_distance,
                         },
-                    }
+                    };
                 }
+            }
+
+            // Not within the distance threshold of *any* step, so we're off the route.
+            RouteDeviation::Deviation {
+                kind: DeviationKind::CompletelyOffRoute {
+                    deviation_from_route_line: current_step_distance,
+                },
+            }
+        }
+    }
+}
+
+/// State carried between location updates by deviation tracking modes with hysteresis.
+///
+/// This lives in the [`NavState`](crate::navigation_controller::models::NavState),
+/// so tracking remains functionally pure.
+#[derive(Debug, Copy, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct RouteDeviationState {
+    /// The first location of the current streak of locations beyond the deviation threshold.
+    #[serde(alias = "off_route_since")]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub off_route_since: Option<UserLocation>,
+    /// The first location of the current streak of locations heading against the route.
+    #[serde(default, alias = "wrong_direction_since")]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub wrong_direction_since: Option<UserLocation>,
+}
+
+/// Thresholds for deviation tracking with time and distance hysteresis.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct DeviationThresholds {
+    /// Location updates less accurate than this (in meters) are ignored.
+    minimum_horizontal_accuracy: u16,
+    /// The distance from the route line (in meters) beyond which the user may be flagged.
+    entry_threshold: f64,
+    /// The distance from the route line (in meters) within which a flagged user must return
+    /// for the deviation to clear.
+    exit_threshold: f64,
+    /// How long (in seconds) the user must be beyond the threshold before being flagged.
+    minimum_duration: f64,
+    /// How far (in meters) the user must move while beyond the threshold before being flagged.
+    minimum_distance: f64,
+    /// Which of the minimum duration and distance must be reached before the user is flagged.
+    dwell_requirement: DwellRequirement,
+}
+
+/// Which of the minimum duration and distance of [`DeviationThresholds`]
+/// a user beyond the threshold must reach before being flagged.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum DwellRequirement {
+    /// Both the minimum duration and the minimum distance.
+    Both,
+    /// The minimum duration or the minimum distance, whichever comes first.
+    Either,
+}
+
+impl DeviationThresholds {
+    fn default_for(mode_of_travel: ModeOfTravel) -> Self {
+        match mode_of_travel {
+            ModeOfTravel::Driving => Self {
+                minimum_horizontal_accuracy: 40,
+                entry_threshold: 50.0,
+                exit_threshold: 50.0,
+                minimum_duration: 3.0,
+                minimum_distance: 30.0,
+                dwell_requirement: DwellRequirement::Both,
+            },
+            ModeOfTravel::Cycling => Self {
+                minimum_horizontal_accuracy: 30,
+                entry_threshold: 30.0,
+                exit_threshold: 30.0,
+                minimum_duration: 5.0,
+                minimum_distance: 20.0,
+                dwell_requirement: DwellRequirement::Both,
+            },
+            ModeOfTravel::Walking => Self {
+                minimum_horizontal_accuracy: 25,
+                entry_threshold: 20.0,
+                exit_threshold: 20.0,
+                minimum_duration: 10.0,
+                minimum_distance: 15.0,
+                dwell_requirement: DwellRequirement::Both,
             },
-            RouteDeviationTracking::Custom { detector } => {
-                detector.check_route_deviation(route.clone(), trip_state.clone())
+            ModeOfTravel::Transit => Self {
+                minimum_horizontal_accuracy: 75,
+                entry_threshold: 100.0,
+                exit_threshold: 100.0,
+                minimum_duration: 15.0,
+                minimum_distance: 100.0,
+                dwell_requirement: DwellRequirement::Both,
+            },
+        }
+    }
+
+    fn check(
+        &self,
+        trip_state: &TripState,
+        state: RouteDeviationState,
+    ) -> (RouteDeviation, RouteDeviationState) {
+        let TripState::Navigating {
+            user_location,
+            deviation: previous_deviation,
+            ..
+        } = trip_state
+        else {
+            return (RouteDeviation::NoDeviation, RouteDeviationState::default());
+        };
+
+        // Inaccurate updates tell us nothing, so keep the status quo rather than clearing it.
+        if user_location.horizontal_accuracy > f64::from(self.minimum_horizontal_accuracy) {
+            return (*previous_deviation, state);
+        }
+
+        // Once flagged, stay flagged until the user is back within the exit threshold.
+        let was_off_route = previous_deviation.is_completely_off_route();
+        let threshold = if was_off_route {
+            self.exit_threshold
+        } else {
+            self.entry_threshold
+        };
+        let deviation =
+            static_threshold_deviation(trip_state, self.minimum_horizontal_accuracy, threshold);
+        if !deviation.is_completely_off_route() {
+            return (deviation, RouteDeviationState::default());
+        }
+
+        let off_route_since = state.off_route_since.unwrap_or(*user_location);
+        let state = RouteDeviationState {
+            off_route_since: Some(off_route_since),
+            ..RouteDeviationState::default()
+        };
+
+        if was_off_route {
+            return (deviation, state);
+        }
+
+        let elapsed = user_location
+            .timestamp
+            .duration_since(off_route_since.timestamp)
+            .unwrap_or_default()
+            .as_secs_f64();
+        let distance = distance_between_locations(&off_route_since, user_location);
+        let dwelled = match self.dwell_requirement {
+            DwellRequirement::Both => {
+                elapsed >= self.minimum_duration && distance >= self.minimum_distance
             }
+            DwellRequirement::Either => {
+                elapsed >= self.minimum_duration || distance >= self.minimum_distance
+            }
+        };
+        if dwelled {
+            (deviation, state)
+        } else {
+            (RouteDeviation::NoDeviation, state)
         }
     }
 }
 
+/// Thresholds for detecting travel in the wrong direction along the route.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct HeadingThresholds {
+    /// The maximum acceptable difference between the course and the route bearing, in degrees.
+    max_course_deviation: u16,
+    /// Courses less accurate than this (in degrees) are ignored.
+    minimum_course_accuracy: u16,
+    /// How long (in seconds) the mismatch must persist before the user is flagged.
+    minimum_duration: f64,
+}
+
+impl HeadingThresholds {
+    /// Checks the course of a user who is on the route against the route bearing.
+    fn check(
+        &self,
+        trip_state: &TripState,
+        state: RouteDeviationState,
+    ) -> (RouteDeviation, RouteDeviationState) {
+        let TripState::Navigating {
+            user_location,
+            current_step_geometry_index: Some(index),
+            remaining_steps,
+            deviation: previous_deviation,
+            heading,
+            ..
+        } = trip_state
+        else {
+            return (RouteDeviation::NoDeviation, RouteDeviationState::default());
+        };
+        let Some(route_bearing) = remaining_steps
+            .first()
+            .and_then(|step| get_bearing_to_next_point(*index as usize, &step.get_linestring()))
+        else {
+            return (RouteDeviation::NoDeviation, RouteDeviationState::default());
+        };
+
+        let is_usable = |course: &CourseOverGround| {
+            course
+                .accuracy
+                .is_none_or(|accuracy| accuracy <= self.minimum_course_accuracy)
+        };
+        let is_stationary = user_location
+            .speed
+            .is_some_and(|speed| speed.value < MAXIMUM_STATIONARY_SPEED);
+        let course = user_location
+            .course_over_ground
+            .filter(|course| !is_stationary && is_usable(course))
+            .or_else(|| heading_course(*heading, user_location).filter(is_usable))
+            .or_else(|| user_location.course_over_ground.filter(is_usable));
+
+        // Without a usable course or heading, keep the status quo.
+        let Some(course) = course else {
+            let deviation = if previous_deviation.is_wrong_direction() {
+                *previous_deviation
+            } else {
+                RouteDeviation::NoDeviation
+            };
+            return (deviation, state);
+        };
+
+        let difference = course_difference(course.degrees, route_bearing.degrees);
+        let tolerance = self.max_course_deviation + course.accuracy.unwrap_or(0);
+        if difference <= tolerance {
+            return (RouteDeviation::NoDeviation, RouteDeviationState::default());
+        }
+
+        let wrong_direction_since = state.wrong_direction_since.unwrap_or(*user_location);
+        let state = RouteDeviationState {
+            wrong_direction_since: Some(wrong_direction_since),
+            ..RouteDeviationState::default()
+        };
+        let elapsed = user_location
+            .timestamp
+            .duration_since(wrong_direction_since.timestamp)
+            .unwrap_or_default()
+            .as_secs_f64();
+        if elapsed >= self.minimum_duration {
+            let deviation = RouteDeviation::Deviation {
+                kind: DeviationKind::WrongDirection {
+                    deviation_from_route_bearing: difference,
+                },
+            };
+            (deviation, state)
+        } else {
+            (RouteDeviation::NoDeviation, state)
+        }
+    }
+}
+
+/// Below this speed (in meters per second), the device heading is preferred over the course.
+const MAXIMUM_STATIONARY_SPEED: f64 = 1.0;
+
+/// The smallest angle between two bearings, in degrees (0 to 180).
+pub(crate) fn course_difference(a: u16, b: u16) -> u16 {
+    let difference = a.abs_diff(b) % 360;
+    difference.min(360 - difference)
+}
+
 /// The kind of deviation from the expected route.
 #[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
@@ -163,12 +581,19 @@ pub enum DeviationKind {
         #[serde(alias = "deviation_from_route_line")]
         deviation_from_route_line: f64,
     },
+    /// The user is on the route, but traveling against its direction
+    /// (for example, after making a U-turn).
+    #[serde(rename_all = "camelCase")]
+    WrongDirection {
+        /// The difference between the user's course and the route bearing, in degrees.
+        #[serde(alias = "deviation_from_route_bearing")]
+        deviation_from_route_bearing: u16,
+    },
 }
 
 /// Status information that describes whether the user is proceeding according to the route or not.
 ///
 /// Note that the name is intentionally a bit generic to allow for expansion of other states.
-/// For example, we could conceivably add a "wrong way" status in the future.
 #[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 #[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
@@ -179,7 +604,8 @@ pub enum RouteDeviation {
     /// The user has deviated from the expected route.
     ///
     /// Check the [`DeviationKind`] to determine if the user is still on the route polyline
-    /// (off the current step but on a future step) or off the route entirely.
+    /// (off the current step but on a future step), off the route entirely,
+    /// or on the route but traveling the wrong direction.
     Deviation { kind: DeviationKind },
 }
 
@@ -189,9 +615,16 @@ impl RouteDeviation {
     /// This can happen either because the user has moved onto a future step
     /// without triggering advance, or because they have left the route entirely.
     /// In other words: **any deviation from the current step**.
+    /// Traveling the wrong direction along the current step does not count.
     #[must_use]
     pub fn is_deviated_from_current_step(&self) -> bool {
-        matches!(self, RouteDeviation::Deviation { .. })
+        matches!(
+            self,
+            RouteDeviation::Deviation {
+                kind: DeviationKind::OffStepOnRoute { .. }
+                    | DeviationKind::CompletelyOffRoute { .. }
+            }
+        )
     }
 
     /// Whether the user has deviated from the route entirely.
@@ -207,6 +640,17 @@ impl RouteDeviation {
             }
         )
     }
+
+    /// Whether the user is on the route, but traveling in the wrong direction.
+    #[must_use]
+    pub fn is_wrong_direction(&self) -> bool {
+        matches!(
+            self,
+            RouteDeviation::Deviation {
+                kind: DeviationKind::WrongDirection { .. }
+            }
+        )
+    }
 }
 
 /// A custom deviation detector (for extending the behavior of [`RouteDeviationTracking`]).
@@ -525,6 +969,9 @@ proptest! {
                     deviation.unwrap()
                 );
             }
+            RouteDeviation::Deviation { kind: DeviationKind::WrongDirection { .. } } => {
+                prop_assert!(false, "StaticThreshold never checks the direction of travel");
+            }
         }
     }
 
@@ -723,3 +1170,408 @@ mod off_step_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod tracking_test_helpers {
+    use super::*;
+    use crate::models::GeographicCoordinate;
+    use crate::navigation_controller::test_helpers::{gen_dummy_route_step, gen_route_from_steps};
+
+    #[cfg(all(feature = "std", not(feature = "web-time")))]
+    use std::time::{Duration, SystemTime};
+
+    #[cfg(feature = "web-time")]
+    use web_time::{Duration, SystemTime};
+
+    pub(super) fn make_location(
+        lng: f64,
+        lat: f64,
+        seconds: u64,
+        horizontal_accuracy: f64,
+    ) -> UserLocation {
+        UserLocation {
+            coordinates: GeographicCoordinate { lng, lat },
+            horizontal_accuracy,
+            course_over_ground: None,
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(seconds),
+            speed: None,
+        }
+    }
+
+    /// Feeds locations through the tracker like the navigation controller does,
+    /// carrying the deviation and state over between updates.
+    pub(super) fn track(
+        tracking: &RouteDeviationTracking,
+        locations: &[UserLocation],
+    ) -> Vec<RouteDeviation> {
+        let step = gen_dummy_route_step(0.0, 0.0, 0.01, 0.0);
+        let route = gen_route_from_steps(vec![step.clone()]);
+
+        let mut deviation = RouteDeviation::NoDeviation;
+        let mut state = RouteDeviationState::default();
+        locations
+            .iter()
+            .map(|location| {
+                let trip_state =
+                    get_navigating_trip_state(*location, vec![step.clone()], vec![], deviation);
+                (deviation, state) =
+                    tracking.check_route_deviation_with_state(&route, &trip_state, state);
+                deviation
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod default_for_tests {
+    use super::tracking_test_helpers::{make_location, track};
+    use super::*;
+
+    #[test]
+    fn test_single_noisy_fix_is_ignored() {
+        let tracking = RouteDeviationTracking::DefaultFor {
+            mode_of_travel: ModeOfTravel::Driving,
+        };
+
+        // One fix ~110m off the route, then back on it.
+        let deviations = track(
+            &tracking,
+            &[
+                make_location(0.001, 0.0, 0, 5.0),
+                make_location(0.0012, 0.001, 1, 5.0),
+                make_location(0.0014, 0.0, 2, 5.0),
+            ],
+        );
+        assert!(deviations.iter().all(|d| *d == RouteDeviation::NoDeviation));
+    }
+
+    #[test]
+    fn test_sustained_deviation_is_flagged() {
+        let tracking = RouteDeviationTracking::DefaultFor {
+            mode_of_travel: ModeOfTravel::Driving,
+        };
+
+        // Driving away from the route, ~11 m/s.
+        let deviations = track(
+            &tracking,
+            &[
+                make_location(0.001, 0.0006, 0, 5.0),
+                make_location(0.001, 0.0007, 1, 5.0),
+                make_location(0.001, 0.0008, 2, 5.0),
+                make_location(0.001, 0.0009, 3, 5.0),
+                make_location(0.001, 0.001, 4, 5.0),
+                // Back on the route
+                make_location(0.001, 0.0, 5, 5.0),
+            ],
+        );
+        assert_eq!(deviations[..3], [RouteDeviation::NoDeviation; 3]);
+        assert!(deviations[3].is_completely_off_route());
+        assert!(deviations[4].is_completely_off_route());
+        assert_eq!(deviations[5], RouteDeviation::NoDeviation);
+    }
+
+    #[test]
+    fn test_requires_both_duration_and_distance() {
+        let tracking = RouteDeviationTracking::DefaultFor {
+            mode_of_travel: ModeOfTravel::Walking,
+        };
+
+        let deviations = track(
+            &tracking,
+            &[
+                make_location(0.001, 0.001, 0, 5.0),
+                // Standing still off the route for a long time
+                make_location(0.001, 0.001, 20, 5.0),
+                // Moving ~22m away
+                make_location(0.001, 0.0012, 21, 5.0),
+            ],
+        );
+        assert_eq!(deviations[..2], [RouteDeviation::NoDeviation; 2]);
+        assert!(deviations[2].is_completely_off_route());
+    }
+
+    #[test]
+    fn test_inaccurate_fixes_keep_status_quo() {
+        let tracking = RouteDeviationTracking::DefaultFor {
+            mode_of_travel: ModeOfTravel::Walking,
+        };
+
+        let deviations = track(
+            &tracking,
+            &[
+                make_location(0.001, 0.001, 0, 5.0),
+                make_location(0.001, 0.0012, 10, 5.0),
+                // A wild (inaccurate) fix right on the route does not clear the deviation.
+                make_location(0.001, 0.0, 11, 100.0),
+            ],
+        );
+        assert!(!deviations[0].is_completely_off_route());
+        assert!(deviations[1].is_completely_off_route());
+        assert!(deviations[2].is_completely_off_route());
+    }
+
+    #[test]
+    fn test_default_for_serialization() {
+        let tracking = RouteDeviationTracking::DefaultFor {
+            mode_of_travel: ModeOfTravel::Cycling,
+        };
+        let json = serde_json::to_string(&tracking).unwrap();
+        assert_eq!(json, r#"{"DefaultFor":{"modeOfTravel":"Cycling"}}"#);
+
+        let deserialized: RouteDeviationTracking =
+            serde_json::from_str(r#"{"DefaultFor":{"mode_of_travel":"Cycling"}}"#).unwrap();
+        assert!(matches!(
+            deserialized,
+            RouteDeviationTracking::DefaultFor {
+                mode_of_travel: ModeOfTravel::Cycling
+            }
+        ));
+    }
+}
+
+#[cfg(test)]
+mod heading_aware_tests {
+    use super::tracking_test_helpers::{make_location, track};
+    use super::*;
+    use crate::models::{Heading, Speed};
+
+    #[cfg(all(feature = "std", not(feature = "web-time")))]
+    use std::time::Duration;
+
+    #[cfg(feature = "web-time")]
+    use web_time::Duration;
+
+    fn heading_aware(minimum_duration: f64) -> RouteDeviationTracking {
+        RouteDeviationTracking::HeadingAware {
+            minimum_horizontal_accuracy: 25,
+            max_acceptable_deviation: 20.0,
+            max_course_deviation: 45,
+            minimum_course_accuracy: 30,
+            minimum_duration,
+        }
+    }
+
+    fn with_course(location: UserLocation, degrees: u16, accuracy: Option<u16>) -> UserLocation {
+        UserLocation {
+            course_over_ground: Some(CourseOverGround { degrees, accuracy }),
+            ..location
+        }
+    }
+
+    #[test]
+    fn test_course_difference() {
+        assert_eq!(course_difference(90, 270), 180);
+        assert_eq!(course_difference(350, 10), 20);
+        assert_eq!(course_difference(10, 350), 20);
+        assert_eq!(course_difference(0, 0), 0);
+    }
+
+    #[test]
+    fn test_sustained_wrong_direction_is_flagged() {
+        // The route heads east; the user is on it, heading west.
+        let deviations = track(
+            &heading_aware(2.0),
+            &[
+                with_course(make_location(0.003, 0.0, 0, 5.0), 270, Some(10)),
+                with_course(make_location(0.002, 0.0, 1, 5.0), 270, Some(10)),
+                with_course(make_location(0.001, 0.0, 2, 5.0), 265, Some(10)),
+                // Turned around again
+                with_course(make_location(0.002, 0.0, 3, 5.0), 90, Some(10)),
+            ],
+        );
+        assert_eq!(deviations[..2], [RouteDeviation::NoDeviation; 2]);
+        assert_eq!(
+            deviations[2],
+            RouteDeviation::Deviation {
+                kind: DeviationKind::WrongDirection {
+                    deviation_from_route_bearing: 175
+                }
+            }
+        );
+        assert!(deviations[2].is_wrong_direction());
+        assert!(!deviations[2].is_deviated_from_current_step());
+        assert_eq!(deviations[3], RouteDeviation::NoDeviation);
+    }
+
+    #[test]
+    fn test_wrong_direction_respects_course_accuracy() {
+        let deviations = track(
+            &heading_aware(0.0),
+            &[
+                // Too inaccurate to be used at all
+                with_course(make_location(0.003, 0.0, 0, 5.0), 270, Some(90)),
+                // Within the tolerance once the accuracy is accounted for
+                with_course(make_location(0.003, 0.0, 1, 5.0), 160, Some(25)),
+                // Within the tolerance
+                with_course(make_location(0.003, 0.0, 2, 5.0), 120, None),
+                // No course at all
+                make_location(0.003, 0.0, 3, 5.0),
+            ],
+        );
+        assert!(deviations.iter().all(|d| *d == RouteDeviation::NoDeviation));
+    }
+
+    #[test]
+    fn test_wrong_direction_uses_heading_when_stationary() {
+        let step = gen_dummy_route_step(0.0, 0.0, 0.01, 0.0);
+        let route = gen_route_from_steps(vec![step.clone()]);
+        let check = |location: UserLocation, heading_seconds: u64| {
+            let mut trip_state = get_navigating_trip_state(
+                location,
+                vec![step.clone()],
+                vec![],
+                RouteDeviation::NoDeviation,
+            );
+            if let TripState::Navigating { heading, .. } = &mut trip_state {
+                // Facing west on an eastbound route
+                *heading = Some(Heading {
+                    true_heading: 270,
+                    accuracy: 10,
+                    timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(heading_seconds),
+                });
+            }
+            heading_aware(0.0)
+                .check_route_deviation_with_state(
+                    &route,
+                    &trip_state,
+                    RouteDeviationState::default(),
+                )
+                .0
+        };
+        let stationary = |location: UserLocation| UserLocation {
+            speed: Some(Speed {
+                value: 0.0,
+                accuracy: None,
+            }),
+            ..location
+        };
+
+        // At the start of a trip, the course is unavailable (or meaningless when stationary).
+        assert!(check(make_location(0.003, 0.0, 0, 5.0), 0).is_wrong_direction());
+        assert!(
+            check(
+                stationary(with_course(make_location(0.003, 0.0, 0, 5.0), 90, None)),
+                0
+            )
+            .is_wrong_direction()
+        );
+        // When moving, the course wins.
+        assert_eq!(
+            check(with_course(make_location(0.003, 0.0, 0, 5.0), 90, None), 0),
+            RouteDeviation::NoDeviation
+        );
+        // Stale headings are ignored.
+        assert_eq!(
+            check(make_location(0.003, 0.0, 60, 5.0), 0),
+            RouteDeviation::NoDeviation
+        );
+    }
+
+    #[test]
+    fn test_heading_aware_still_detects_off_route() {
+        let deviations = track(
+            &heading_aware(0.0),
+            &[with_course(make_location(0.001, 0.001, 0, 5.0), 90, None)],
+        );
+        assert!(deviations[0].is_completely_off_route());
+    }
+}
+
+#[cfg(test)]
+mod hysteresis_tests {
+    use super::tracking_test_helpers::{make_location, track};
+    use super::*;
+
+    fn hysteresis(minimum_duration: f64) -> RouteDeviationTracking {
+        RouteDeviationTracking::Hysteresis {
+            minimum_horizontal_accuracy: 25,
+            entry_threshold: 50.0,
+            exit_threshold: 20.0,
+            minimum_duration,
+            minimum_distance: 100.0,
+        }
+    }
+
+    #[test]
+    fn test_hysteresis_clears_only_within_exit_threshold() {
+        let deviations = track(
+            &hysteresis(0.0),
+            &[
+                // ~33m off the route; within the entry threshold
+                make_location(0.001, 0.0003, 0, 5.0),
+                // ~67m off the route
+                make_location(0.001, 0.0006, 1, 5.0),
+                // Back to ~33m; still beyond the exit threshold
+                make_location(0.001, 0.0003, 2, 5.0),
+                // ~11m off the route
+                make_location(0.001, 0.0001, 3, 5.0),
+            ],
+        );
+        assert_eq!(deviations[0], RouteDeviation::NoDeviation);
+        assert!(deviations[1].is_completely_off_route());
+        assert!(deviations[2].is_completely_off_route());
+        assert_eq!(deviations[3], RouteDeviation::NoDeviation);
+    }
+
+    #[test]
+    fn test_hysteresis_dwell_time() {
+        let deviations = track(
+            &hysteresis(5.0),
+            &[
+                make_location(0.001, 0.0006, 0, 5.0),
+                make_location(0.001, 0.0006, 4, 5.0),
+                make_location(0.001, 0.0006, 5, 5.0),
+            ],
+        );
+        assert_eq!(deviations[..2], [RouteDeviation::NoDeviation; 2]);
+        assert!(deviations[2].is_completely_off_route());
+    }
+
+    #[test]
+    fn test_hysteresis_distance() {
+        let deviations = track(
+            &hysteresis(60.0),
+            &[
+                make_location(0.001, 0.0006, 0, 5.0),
+                // ~55m from the first off-route location
+                make_location(0.0015, 0.0006, 4, 5.0),
+                // ~111m from the first off-route location
+                make_location(0.002, 0.0006, 8, 5.0),
+            ],
+        );
+        assert_eq!(deviations[..2], [RouteDeviation::NoDeviation; 2]);
+        assert!(deviations[2].is_completely_off_route());
+    }
+
+    #[test]
+    fn test_hysteresis_exit_threshold_capped_at_entry_threshold() {
+        let tracking = RouteDeviationTracking::Hysteresis {
+            minimum_horizontal_accuracy: 25,
+            entry_threshold: 20.0,
+            exit_threshold: 50.0,
+            minimum_duration: 0.0,
+            minimum_distance: 100.0,
+        };
+        let deviations = track(
+            &tracking,
+            &[
+                // ~67m off the route
+                make_location(0.001, 0.0006, 0, 5.0),
+                // ~33m off the route; within the exit threshold, but beyond the entry threshold
+                make_location(0.001, 0.0003, 1, 5.0),
+            ],
+        );
+        assert!(deviations[0].is_completely_off_route());
+        assert!(deviations[1].is_completely_off_route());
+    }
+
+    #[test]
+    fn test_hysteresis_serialization() {
+        let deserialized: RouteDeviationTracking = serde_json::from_str(
+            r#"{"Hysteresis":{"minimumHorizontalAccuracy":25,"entryThreshold":50.0,"exitThreshold":20.0,"minimumDuration":5.0,"minimumDistance":100.0}}"#,
+        )
+        .unwrap();
+        let json = serde_json::to_string(&deserialized).unwrap();
+        assert_eq!(json, serde_json::to_string(&hysteresis(5.0)).unwrap());
+    }
+}
@@ -28,6 +28,8 @@ use android_logger::Config;
 
 pub mod algorithms;
 pub mod deviation_detection;
+pub mod location_smoothing;
+pub mod map_matching;
 pub mod models;
 pub mod navigation_controller;
 pub mod navigation_session;
@@ -0,0 +1,421 @@
+//! Kalman filter smoothing of user locations before they are snapped to the route.
+//!
+//! Location fixes jitter, especially on foot and in urban canyons.
+//! Snapping each raw fix to the route makes the puck (and the progress derived from it)
+//! bounce back and forth along the route line.
+//!
+//! The filter in this module uses a constant velocity motion model.
+//! Its state (the position and velocity along the east and north axes)
+//! is predicted forward between fixes, and corrected with each new fix
+//! (weighted by its horizontal accuracy) and its speed and course when available.
+//! The axes are independent, so each is tracked by a two-state filter,
+//! in a local tangent plane centered on the last smoothed location.
+//!
+//! The filter state is carried between location updates in the
+//! [`NavState`](crate::navigation_controller::models::NavState),
+//! so smoothing remains functionally pure.
+
+use crate::models::{CourseOverGround, GeographicCoordinate, ModeOfTravel, Speed, UserLocation};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm-bindgen")]
+use tsify::Tsify;
+
+/// Approximate length of one degree of latitude in meters.
+const METERS_PER_DEGREE: f64 = 111_320.0;
+
+/// Below this speed (in m/s), the course is too noisy to be useful.
+const MINIMUM_SPEED_FOR_COURSE: f64 = 1.0;
+
+/// The speed accuracy (in m/s) assumed when the location provider does not report one.
+const DEFAULT_SPEED_ACCURACY: f64 = 1.0;
+
+/// The course accuracy (in degrees) assumed when the location provider does not report one.
+const DEFAULT_COURSE_ACCURACY: f64 = 20.0;
+
+/// The velocity uncertainty (in m/s) assumed when the filter starts without a speed observation.
+const INITIAL_VELOCITY_SIGMA: f64 = 10.0;
+
+/// After a gap between fixes longer than this (in seconds), the filter starts over.
+const MAXIMUM_PREDICTION_INTERVAL: f64 = 30.0;
+
+/// Configures the smoothing of location fixes before they are used for navigation.
+///
+/// | Mode      | Acceleration sigma | Min. measurement sigma |
+/// |-----------|--------------------|------------------------|
+/// | Driving   | 3 m/s²             | 5 m                    |
+/// | Cycling   | 1.5 m/s²           | 4 m                    |
+/// | Walking   | 0.5 m/s²           | 3 m                    |
+/// | Transit   | 2 m/s²             | 10 m                   |
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum LocationSmoothing {
+    /// Smooths locations using sensible defaults for the mode of travel.
+    #[serde(rename_all = "camelCase")]
+    DefaultFor {
+        /// The mode of travel to use defaults for.
+        #[serde(alias = "mode_of_travel")]
+        mode_of_travel: ModeOfTravel,
+    },
+    /// Smooths locations with custom filter parameters.
+    #[serde(rename_all = "camelCase")]
+    Custom {
+        /// The standard deviation (in m/s²) of the user's acceleration.
+        ///
+        /// Larger values follow changes in speed and direction more quickly,
+        /// at the expense of smoothing less.
+        #[serde(alias = "acceleration_sigma")]
+        acceleration_sigma: f64,
+        /// The minimum standard deviation (in meters) assumed for location measurements.
+        ///
+        /// The reported horizontal accuracy is used when it is larger than this.
+        #[serde(alias = "minimum_measurement_sigma")]
+        minimum_measurement_sigma: f64,
+    },
+}
+
+/// The filter state along one axis (east or north) of the local tangent plane.
+///
+/// The position is always zero (the plane is centered on the last smoothed location),
+/// so only its variance is tracked.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct AxisEstimate {
+    /// The velocity along the axis, in meters per second.
+    pub velocity: f64,
+    /// The variance of the position, in square meters.
+    #[serde(alias = "position_variance")]
+    pub position_variance: f64,
+    /// The covariance of the position and velocity.
+    pub covariance: f64,
+    /// The variance of the velocity, in square meters per square second.
+    #[serde(alias = "velocity_variance")]
+    pub velocity_variance: f64,
+}
+
+/// State carried between location updates by [`LocationSmoothing`].
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct LocationSmoothingState {
+    /// The last smoothed location.
+    pub location: UserLocation,
+    /// The filter state along the east axis.
+    pub east: AxisEstimate,
+    /// The filter state along the north axis.
+    pub north: AxisEstimate,
+}
+
+impl LocationSmoothing {
+    /// The standard deviation of the user's acceleration (in m/s²)
+    /// and the minimum standard deviation of location measurements (in meters).
+    fn parameters(&self) -> (f64, f64) {
+        match *self {
+            LocationSmoothing::DefaultFor { mode_of_travel } => match mode_of_travel {
+                ModeOfTravel::Driving => (3.0, 5.0),
+                ModeOfTravel::Cycling => (1.5, 4.0),
+                ModeOfTravel::Walking => (0.5, 3.0),
+                ModeOfTravel::Transit => (2.0, 10.0),
+            },
+            LocationSmoothing::Custom {
+                acceleration_sigma,
+                minimum_measurement_sigma,
+            } => (acceleration_sigma, minimum_measurement_sigma),
+        }
+    }
+
+    /// Smooths a location fix,
+    /// returning the smoothed location and the state to carry over to the next update.
+    ///
+    /// The first fix (and any fix after a long gap) is returned as is.
+    #[must_use]
+    pub(crate) fn smooth(
+        &self,
+        location: UserLocation,
+        state: Option<LocationSmoothingState>,
+    ) -> (UserLocation, LocationSmoothingState) {
+        let (acceleration_sigma, minimum_measurement_sigma) = self.parameters();
+        let measurement_sigma = location.horizontal_accuracy.max(minimum_measurement_sigma);
+        let measured_velocity = measured_velocity(&location);
+
+        let elapsed = state.and_then(|state| {
+            location
+                .timestamp
+                .duration_since(state.location.timestamp)
+                .ok()
+                .map(|elapsed| elapsed.as_secs_f64())
+                .filter(|elapsed| *elapsed > 0.0 && *elapsed <= MAXIMUM_PREDICTION_INTERVAL)
+        });
+        let (Some(state), Some(elapsed)) = (state, elapsed) else {
+            let axis = |velocity: Option<(f64, f64)>| AxisEstimate {
+                velocity: velocity.map_or(0.0, |(velocity, _)| velocity),
+                position_variance: measurement_sigma.powi(2),
+                covariance: 0.0,
+                velocity_variance: velocity
+                    .map_or(INITIAL_VELOCITY_SIGMA, |(_, sigma)| sigma)
+                    .powi(2),
+            };
+            return (
+                location,
+                LocationSmoothingState {
+                    location,
+                    east: axis(measured_velocity.map(|(east, _, sigma)| (east, sigma))),
+                    north: axis(measured_velocity.map(|(_, north, sigma)| (north, sigma))),
+                },
+            );
+        };
+
+        let origin = state.location.coordinates;
+        let (measured_east, measured_north) = local_offset(origin, location.coordinates);
+        let acceleration_variance = acceleration_sigma.powi(2);
+        let measurement_variance = measurement_sigma.powi(2);
+
+        let mut east = state.east;
+        let mut north = state.north;
+        let mut east_position = east.predict(elapsed, acceleration_variance);
+        let mut north_position = north.predict(elapsed, acceleration_variance);
+        east_position = east.update_position(east_position, measured_east, measurement_variance);
+        north_position =
+            north.update_position(north_position, measured_north, measurement_variance);
+        if let Some((velocity_east, velocity_north, sigma)) = measured_velocity {
+            east_position = east.update_velocity(east_position, velocity_east, sigma.powi(2));
+            north_position = north.update_velocity(north_position, velocity_north, sigma.powi(2));
+        }
+
+        let speed = east.velocity.hypot(north.velocity);
+        let course_over_ground = if speed >= MINIMUM_SPEED_FOR_COURSE {
+            let degrees = east
+                .velocity
+                .atan2(north.velocity)
+                .to_degrees()
+                .rem_euclid(360.0);
+            Some(CourseOverGround::new(degrees, None))
+        } else {
+            location.course_over_ground
+        };
+        let smoothed_location = UserLocation {
+            coordinates: offset_coordinate(origin, east_position, north_position),
+            horizontal_accuracy: east.position_variance.max(north.position_variance).sqrt(),
+            course_over_ground,
+            timestamp: location.timestamp,
+            speed: Some(Speed {
+                value: speed,
+                accuracy: Some(
+                    f64::midpoint(east.velocity_variance, north.velocity_variance).sqrt(),
+                ),
+            }),
+        };
+
+        (
+            smoothed_location,
+            LocationSmoothingState {
+                location: smoothed_location,
+                east,
+                north,
+            },
+        )
+    }
+}
+
+impl AxisEstimate {
+    /// Predicts the state `elapsed` seconds ahead, returning the predicted position.
+    fn predict(&mut self, elapsed: f64, acceleration_variance: f64) -> f64 {
+        let (p00, p01, p11) = (
+            self.position_variance,
+            self.covariance,
+            self.velocity_variance,
+        );
+        self.position_variance = p00
+            + 2.0 * elapsed * p01
+            + elapsed.powi(2) * p11
+            + acceleration_variance * elapsed.powi(4) / 4.0;
+        self.covariance = p01 + elapsed * p11 + acceleration_variance * elapsed.powi(3) / 2.0;
+        self.velocity_variance = p11 + acceleration_variance * elapsed.powi(2);
+        self.velocity * elapsed
+    }
+
+    /// Corrects the state with a position measurement, returning the corrected position.
+    fn update_position(&mut self, position: f64, measurement: f64, variance: f64) -> f64 {
+        let (p00, p01, p11) = (
+            self.position_variance,
+            self.covariance,
+            self.velocity_variance,
+        );
+        let innovation_variance = p00 + variance;
+        let position_gain = p00 / innovation_variance;
+        let velocity_gain = p01 / innovation_variance;
+        let residual = measurement - position;
+
+        self.velocity += velocity_gain * residual;
+        self.position_variance = (1.0 - position_gain) * p00;
+        self.covariance = (1.0 - position_gain) * p01;
+        self.velocity_variance = p11 - velocity_gain * p01;
+        position + position_gain * residual
+    }
+
+    /// Corrects the state with a velocity measurement, returning the corrected position.
+    fn update_velocity(&mut self, position: f64, measurement: f64, variance: f64) -> f64 {
+        let (p00, p01, p11) = (
+            self.position_variance,
+            self.covariance,
+            self.velocity_variance,
+        );
+        let innovation_variance = p11 + variance;
+        let position_gain = p01 / innovation_variance;
+        let velocity_gain = p11 / innovation_variance;
+        let residual = measurement - self.velocity;
+
+        self.velocity += velocity_gain * residual;
+        self.position_variance = p00 - position_gain * p01;
+        self.covariance = (1.0 - velocity_gain) * p01;
+        self.velocity_variance = (1.0 - velocity_gain) * p11;
+        position + position_gain * residual
+    }
+}
+
+/// The east and north velocity (in m/s) reported with a location fix,
+/// along with its standard deviation.
+///
+/// This is only available when both speed and a meaningful course are reported.
+fn measured_velocity(location: &UserLocation) -> Option<(f64, f64, f64)> {
+    let speed = location.speed?;
+    let course = location.course_over_ground?;
+    if !speed.value.is_finite() || speed.value < MINIMUM_SPEED_FOR_COURSE {
+        return None;
+    }
+
+    let course_radians = f64::from(course.degrees).to_radians();
+    let speed_sigma = speed.accuracy.unwrap_or(DEFAULT_SPEED_ACCURACY);
+    let course_sigma = course
+        .accuracy
+        .map_or(DEFAULT_COURSE_ACCURACY, f64::from)
+        .to_radians();
+    // The course uncertainty mostly affects the velocity across the direction of travel.
+    let sigma = speed_sigma.hypot(speed.value * course_sigma);
+
+    Some((
+        speed.value * course_radians.sin(),
+        speed.value * course_radians.cos(),
+        sigma,
+    ))
+}
+
+/// The east and north offset (in meters) of a coordinate from the origin.
+fn local_offset(origin: GeographicCoordinate, coordinate: GeographicCoordinate) -> (f64, f64) {
+    (
+        (coordinate.lng - origin.lng) * METERS_PER_DEGREE * origin.lat.to_radians().cos(),
+        (coordinate.lat - origin.lat) * METERS_PER_DEGREE,
+    )
+}
+
+/// The coordinate at the given east and north offset (in meters) from the origin.
+fn offset_coordinate(origin: GeographicCoordinate, east: f64, north: f64) -> GeographicCoordinate {
+    GeographicCoordinate {
+        lng: origin.lng + east / (METERS_PER_DEGREE * origin.lat.to_radians().cos()),
+        lat: origin.lat + north / METERS_PER_DEGREE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::make_user_location;
+    use geo::{Distance, Haversine, Point, coord};
+    use std::time::Duration;
+
+    const WALKING: LocationSmoothing = LocationSmoothing::DefaultFor {
+        mode_of_travel: ModeOfTravel::Walking,
+    };
+
+    #[test]
+    fn first_fix_is_unchanged() {
+        let location = make_user_location(coord!(x: 13.4, y: 52.5), 10.0);
+        let (smoothed, state) = WALKING.smooth(location, None);
+        assert_eq!(smoothed, location);
+        assert_eq!(state.location, location);
+        assert_eq!(state.east.position_variance, 100.0);
+    }
+
+    #[test]
+    fn jitter_is_reduced() {
+        // Standing still, with fixes alternating 10m either side of the true position
+        let start = make_user_location(coord!(x: 13.4, y: 52.5), 10.0);
+        let offset = 10.0 / METERS_PER_DEGREE;
+        let mut state = None;
+        let mut max_error: f64 = 0.0;
+        for i in 0..20u32 {
+            let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+            let location = UserLocation {
+                timestamp: start.timestamp + Duration::from_secs(u64::from(i)),
+                ..make_user_location(coord!(x: 13.4, y: 52.5 + sign * offset), 10.0)
+            };
+            let (smoothed, new_state) = WALKING.smooth(location, state);
+            state = Some(new_state);
+            if i >= 10 {
+                max_error = max_error.max(Haversine.distance(
+                    Point::from(smoothed.coordinates),
+                    Point::from(start.coordinates),
+                ));
+            }
+        }
+        assert!(max_error < 3.0, "Smoothed location strayed {max_error}m");
+    }
+
+    #[test]
+    fn follows_steady_movement() {
+        // Walking north at 1.5 m/s
+        let speed = 1.5;
+        let start = make_user_location(coord!(x: 13.4, y: 52.5), 5.0);
+        let mut state = None;
+        let mut smoothed = start;
+        for i in 0..30u32 {
+            let north = f64::from(i) * speed / METERS_PER_DEGREE;
+            let location = UserLocation {
+                timestamp: start.timestamp + Duration::from_secs(u64::from(i)),
+                ..make_user_location(coord!(x: 13.4, y: 52.5 + north), 5.0)
+            };
+            let new_state;
+            (smoothed, new_state) = WALKING.smooth(location, state);
+            state = Some(new_state);
+        }
+
+        let expected = coord!(x: 13.4, y: 52.5 + 29.0 * speed / METERS_PER_DEGREE);
+        assert!(Haversine.distance(Point::from(smoothed.coordinates), Point::from(expected)) < 1.0);
+        let smoothed_speed = smoothed.speed.unwrap().value;
+        assert!((smoothed_speed - speed).abs() < 0.2);
+        let course = smoothed.course_over_ground.unwrap().degrees;
+        assert!(course <= 2 || course >= 358);
+    }
+
+    #[test]
+    fn restarts_after_long_gap() {
+        let start = make_user_location(coord!(x: 13.4, y: 52.5), 5.0);
+        let (_, state) = WALKING.smooth(start, None);
+
+        let later = UserLocation {
+            timestamp: start.timestamp + Duration::from_secs(600),
+            ..make_user_location(coord!(x: 13.41, y: 52.5), 5.0)
+        };
+        let (smoothed, _) = WALKING.smooth(later, Some(state));
+        assert_eq!(smoothed, later);
+    }
+
+    #[test]
+    fn serialization() {
+        let smoothing = LocationSmoothing::DefaultFor {
+            mode_of_travel: ModeOfTravel::Walking,
+        };
+        let json = serde_json::to_string(&smoothing).unwrap();
+        assert_eq!(json, r#"{"DefaultFor":{"modeOfTravel":"Walking"}}"#);
+        assert_eq!(
+            serde_json::from_str::<LocationSmoothing>(&json).unwrap(),
+            smoothing
+        );
+    }
+}
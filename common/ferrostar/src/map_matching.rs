@@ -0,0 +1,466 @@
+//! Hidden Markov model (HMM) map matching of user locations to a route.
+//!
+//! Snapping each location fix independently to the closest point on a line
+//! works well until the route passes close to itself.
+//! On self-intersecting routes, parallel frontage roads, and cloverleaf interchanges,
+//! the closest point can easily be on the wrong branch.
+//!
+//! The matcher in this module follows the classic approach
+//! of [Newson and Krumm (2009)](https://www.microsoft.com/en-us/research/publication/hidden-markov-map-matching-noise-sparseness/).
+//! Candidate positions for every recent fix are taken from the current step
+//! and the steps starting within a kilometer after it,
+//! and the most likely sequence of positions is found with the Viterbi algorithm.
+//! A candidate is likely if it is
+//!
+//! - close to the fix (relative to the horizontal accuracy),
+//! - heading in the same direction as the user (when the course is reliable), and
+//! - reachable from the previous position by traveling roughly the distance
+//!   the user actually traveled (derived from speed, or the distance between fixes).
+//!
+//! The last criterion is what keeps the match on the correct branch:
+//! jumping to a part of the route that is close by as the crow flies,
+//! but far away *along the route*, is very unlikely.
+
+use crate::{
+    models::{GeographicCoordinate, RouteStep, UserLocation},
+    navigation_controller::models::MapMatchingConfig,
+};
+use geo::{
+    Bearing, Closest, Distance, Euclidean, Geodesic, Haversine, HaversineClosestPoint, Line, Point,
+};
+
+/// Approximate length of one degree of latitude in meters,
+/// used for cheaply discarding segments that are obviously out of range.
+const METERS_PER_DEGREE: f64 = 111_320.0;
+
+/// How far past the end of the current step (in meters) later steps are searched for candidates.
+///
+/// This bounds the work done per location update on long routes.
+/// Steps beyond this are far out of reach of the recent fixes anyway.
+const MAXIMUM_LOOKAHEAD_DISTANCE: f64 = 1_000.0;
+
+/// Candidates closer than this (in meters along the route) are considered to be the same position.
+const DUPLICATE_CANDIDATE_TOLERANCE: f64 = 1.0;
+
+/// Below this speed (in m/s), the reported course is too noisy to be useful.
+const MINIMUM_SPEED_FOR_COURSE: f64 = 1.0;
+
+/// Courses with a reported accuracy worse than this (in degrees) are ignored.
+const MAXIMUM_COURSE_ACCURACY: u16 = 90;
+
+/// How strongly a course mismatch is penalized.
+///
+/// A candidate segment pointing in the opposite direction of travel
+/// costs twice this value in log-likelihood.
+const COURSE_CONCENTRATION: f64 = 2.0;
+
+/// The position on a route which best explains the most recent location fix.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RouteMatch {
+    /// The index of the matched step within the remaining steps (0 is the current step).
+    pub step_index: usize,
+    /// The index of the matched segment origin within the step geometry.
+    pub segment_index: usize,
+    /// The matched position.
+    pub coordinate: GeographicCoordinate,
+}
+
+/// A segment of the remaining route.
+struct RouteSegment {
+    step_index: usize,
+    segment_index: usize,
+    line: Line,
+    /// The distance along the route (in meters) at which the segment starts.
+    start_distance: f64,
+    bearing: f64,
+}
+
+/// A possible position for a single location fix.
+#[derive(Copy, Clone)]
+struct Candidate {
+    step_index: usize,
+    segment_index: usize,
+    point: Point,
+    /// The distance along the route in meters.
+    distance_along: f64,
+    /// The distance from the location fix in meters.
+    distance_from_fix: f64,
+    log_emission: f64,
+}
+
+/// Finds the most likely position on the remaining route for the last of the `recent_locations`.
+///
+/// `recent_locations` are ordered from oldest to newest,
+/// and `remaining_steps` include the current step.
+///
+/// Returns [`None`] if the most recent location is not within the search radius of the route,
+/// in which case callers should fall back to simple snapping.
+pub fn match_location_to_route(
+    recent_locations: &[UserLocation],
+    remaining_steps: &[RouteStep],
+    config: &MapMatchingConfig,
+) -> Option<RouteMatch> {
+    let segments = route_segments(remaining_steps);
+
+    // Forward pass of the Viterbi algorithm.
+    // We only need the end of the most likely path, so no back pointers are kept.
+    let mut previous: Option<(&UserLocation, Vec<(Candidate, f64)>)> = None;
+    for location in recent_locations {
+        let candidates = find_candidates(location, &segments, config);
+        if candidates.is_empty() {
+            // Skip fixes which are nowhere near the route (ex: a brief detour or a bad fix).
+            continue;
+        }
+
+        let scored = match &previous {
+            None => candidates
+                .into_iter()
+                .map(|candidate| (candidate, candidate.log_emission))
+                .collect(),
+            Some((previous_location, previous_scores)) => {
+                let expected_distance = expected_travel_distance(previous_location, location);
+                candidates
+                    .into_iter()
+                    .map(|candidate| {
+                        let best_predecessor = previous_scores
+                            .iter()
+                            .map(|(previous_candidate, score)| {
+                                score
+                                    + log_transition(
+                                        previous_candidate,
+                                        &candidate,
+                                        expected_distance,
+                                        config,
+                                    )
+                            })
+                            .fold(f64::NEG_INFINITY, f64::max);
+                        (candidate, best_predecessor + candidate.log_emission)
+                    })
+                    .collect()
+            }
+        };
+
+        previous = Some((location, scored));
+    }
+
+    let (matched_location, scores) = previous?;
+    if !recent_locations
+        .last()
+        .is_some_and(|location| location == matched_location)
+    {
+        // The most recent fix had no candidates.
+        return None;
+    }
+
+    scores
+        .into_iter()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(candidate, _)| RouteMatch {
+            step_index: candidate.step_index,
+            segment_index: candidate.segment_index,
+            coordinate: GeographicCoordinate {
+                lng: candidate.point.x(),
+                lat: candidate.point.y(),
+            },
+        })
+}
+
+/// Flattens the remaining steps into a list of segments with their position along the route.
+///
+/// Only the current step and the steps starting within [`MAXIMUM_LOOKAHEAD_DISTANCE`]
+/// after its end are included.
+fn route_segments(remaining_steps: &[RouteStep]) -> Vec<RouteSegment> {
+    let mut start_distance = 0.0;
+    let mut lookahead_end = f64::INFINITY;
+    let mut segments = Vec::new();
+    for (step_index, step) in remaining_steps.iter().enumerate() {
+        if start_distance > lookahead_end {
+            break;
+        }
+
+        for (segment_index, line) in step.get_linestring().lines().enumerate() {
+            let length = Haversine.distance(line.start_point(), line.end_point());
+            segments.push(RouteSegment {
+                step_index,
+                segment_index,
+                line,
+                start_distance,
+                bearing: Geodesic.bearing(line.start_point(), line.end_point()),
+            });
+            start_distance += length;
+        }
+
+        if step_index == 0 {
+            lookahead_end = start_distance + MAXIMUM_LOOKAHEAD_DISTANCE;
+        }
+    }
+    segments
+}
+
+/// Finds candidate positions for a location fix.
+///
+/// Every segment contributes its closest point,
+/// but only local minima of the distance to the fix are kept,
+/// so that each nearby "pass" of the route yields a single candidate.
+fn find_candidates(
+    location: &UserLocation,
+    segments: &[RouteSegment],
+    config: &MapMatchingConfig,
+) -> Vec<Candidate> {
+    let point = Point::from(location.coordinates);
+    let sigma = location
+        .horizontal_accuracy
+        .max(config.minimum_measurement_sigma);
+    let search_radius = config.search_radius.max(sigma);
+    let longitude_scale = location.coordinates.lat.to_radians().cos().abs().max(0.01);
+    let search_radius_degrees = search_radius / (METERS_PER_DEGREE * longitude_scale) * 1.1;
+
+    let projections: Vec<Option<Candidate>> = segments
+        .iter()
+        .map(|segment| {
+            if Euclidean.distance(&segment.line, &point) > search_radius_degrees {
+                return None;
+            }
+
+            let projected = match segment.line.haversine_closest_point(&point) {
+                Closest::Intersection(projected) | Closest::SinglePoint(projected) => projected,
+                Closest::Indeterminate => return None,
+            };
+            let distance_from_fix = Haversine.distance(projected, point);
+            if !distance_from_fix.is_finite() || distance_from_fix > search_radius {
+                return None;
+            }
+
+            let log_emission = -0.5 * (distance_from_fix / sigma).powi(2)
+                + log_course_likelihood(location, segment.bearing);
+
+            Some(Candidate {
+                step_index: segment.step_index,
+                segment_index: segment.segment_index,
+                point: projected,
+                distance_along: segment.start_distance
+                    + Haversine.distance(segment.line.start_point(), projected),
+                distance_from_fix,
+                log_emission,
+            })
+        })
+        .collect();
+
+    let distance_at = |index: Option<usize>| {
+        index
+            .and_then(|index| projections.get(index))
+            .and_then(|projection| projection.map(|candidate| candidate.distance_from_fix))
+            .unwrap_or(f64::INFINITY)
+    };
+
+    let mut candidates: Vec<Candidate> = Vec::new();
+    for (index, projection) in projections.iter().enumerate() {
+        let Some(candidate) = projection else {
+            continue;
+        };
+
+        let is_local_minimum = candidate.distance_from_fix <= distance_at(index.checked_sub(1))
+            && candidate.distance_from_fix <= distance_at(Some(index + 1));
+        if !is_local_minimum {
+            continue;
+        }
+
+        // Adjacent segments project onto their shared vertex at the same position.
+        if candidates.last().is_some_and(|last| {
+            candidate.distance_along - last.distance_along < DUPLICATE_CANDIDATE_TOLERANCE
+        }) {
+            continue;
+        }
+
+        candidates.push(*candidate);
+    }
+
+    candidates
+}
+
+/// The log-likelihood of traveling along a segment with the given bearing,
+/// given the user's course.
+///
+/// Returns zero (no information) when the course is missing or unreliable.
+fn log_course_likelihood(location: &UserLocation, segment_bearing: f64) -> f64 {
+    let Some(course) = location.course_over_ground else {
+        return 0.0;
+    };
+
+    let is_moving = location
+        .speed
+        .is_none_or(|speed| speed.value >= MINIMUM_SPEED_FOR_COURSE);
+    let is_accurate = course
+        .accuracy
+        .is_none_or(|accuracy| accuracy <= MAXIMUM_COURSE_ACCURACY);
+    if !is_moving || !is_accurate {
+        return 0.0;
+    }
+
+    let difference = (f64::from(course.degrees) - segment_bearing).to_radians();
+    COURSE_CONCENTRATION * (difference.cos() - 1.0)
+}
+
+/// How far the user is expected to have traveled along the route between two fixes.
+///
+/// The reported speed is preferred, since the straight-line distance between fixes
+/// underestimates travel along curved roads.
+fn expected_travel_distance(previous: &UserLocation, current: &UserLocation) -> f64 {
+    let elapsed = current
+        .timestamp
+        .duration_since(previous.timestamp)
+        .unwrap_or_default()
+        .as_secs_f64();
+
+    match current.speed {
+        Some(speed) if elapsed > 0.0 && speed.value.is_finite() => speed.value * elapsed,
+        _ => Haversine.distance(
+            Point::from(previous.coordinates),
+            Point::from(current.coordinates),
+        ),
+    }
+}
+
+/// The log-likelihood of moving from one candidate to the next.
+///
+/// This compares the distance traveled along the route with the expected travel distance.
+/// Moving backwards along the route counts as a (large) difference.
+fn log_transition(
+    from: &Candidate,
+    to: &Candidate,
+    expected_distance: f64,
+    config: &MapMatchingConfig,
+) -> f64 {
+    let route_distance = to.distance_along - from.distance_along;
+    -(route_distance - expected_distance).abs() / config.transition_beta
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CourseOverGround, Speed};
+    use crate::navigation_controller::test_helpers::gen_route_step_with_coords;
+    use crate::test_utils::make_user_location;
+    use geo::coord;
+    use std::time::{Duration, SystemTime};
+
+    /// A route which goes east, turns around, and comes back west on a parallel road ~22m north.
+    fn parallel_roads_steps() -> Vec<RouteStep> {
+        vec![
+            gen_route_step_with_coords(vec![
+                coord! {x: 0.0, y: 0.0},
+                coord! {x: 0.001, y: 0.0},
+                coord! {x: 0.002, y: 0.0},
+            ]),
+            gen_route_step_with_coords(vec![
+                coord! {x: 0.002, y: 0.0},
+                coord! {x: 0.002, y: 0.0002},
+            ]),
+            gen_route_step_with_coords(vec![
+                coord! {x: 0.002, y: 0.0002},
+                coord! {x: 0.001, y: 0.0002},
+                coord! {x: 0.0, y: 0.0002},
+            ]),
+        ]
+    }
+
+    fn eastbound_fix(x: f64, y: f64, seconds: u64) -> UserLocation {
+        UserLocation {
+            course_over_ground: Some(CourseOverGround::new(90.0, Some(10))),
+            speed: Some(Speed {
+                value: 11.0,
+                accuracy: None,
+            }),
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(seconds),
+            ..make_user_location(coord! {x: x, y: y}, 5.0)
+        }
+    }
+
+    #[test]
+    fn test_stays_on_branch_when_closer_to_parallel_road() {
+        let steps = parallel_roads_steps();
+        // Drifting north while driving east; the last fix is slightly closer to the westbound road.
+        let fixes = vec![
+            eastbound_fix(0.0002, 0.00003, 0),
+            eastbound_fix(0.0003, 0.00006, 1),
+            eastbound_fix(0.0004, 0.00009, 2),
+            eastbound_fix(0.0005, 0.00011, 3),
+        ];
+
+        let route_match =
+            match_location_to_route(&fixes, &steps, &MapMatchingConfig::default()).unwrap();
+        assert_eq!(route_match.step_index, 0);
+        assert_eq!(route_match.segment_index, 0);
+        assert!(route_match.coordinate.lat.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_continuity_without_course() {
+        let steps = parallel_roads_steps();
+        // No course or speed information; continuity along the route alone should win.
+        let fixes: Vec<_> = [
+            (0.0002, 0.00003),
+            (0.0003, 0.00006),
+            (0.0004, 0.00009),
+            (0.0005, 0.000_105),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, (x, y))| UserLocation {
+            timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(i as u64),
+            ..make_user_location(coord! {x: x, y: y}, 5.0)
+        })
+        .collect();
+
+        let route_match =
+            match_location_to_route(&fixes, &steps, &MapMatchingConfig::default()).unwrap();
+        assert_eq!(route_match.step_index, 0);
+    }
+
+    #[test]
+    fn test_matches_later_step() {
+        let steps = parallel_roads_steps();
+        let fixes = vec![UserLocation {
+            course_over_ground: Some(CourseOverGround::new(270.0, Some(10))),
+            ..eastbound_fix(0.0005, 0.00019, 0)
+        }];
+
+        let route_match =
+            match_location_to_route(&fixes, &steps, &MapMatchingConfig::default()).unwrap();
+        assert_eq!(route_match.step_index, 2);
+        assert_eq!(route_match.segment_index, 1);
+    }
+
+    #[test]
+    fn test_no_match_outside_search_radius() {
+        let steps = parallel_roads_steps();
+        let fixes = vec![eastbound_fix(0.0005, 0.01, 0)];
+
+        assert_eq!(
+            match_location_to_route(&fixes, &steps, &MapMatchingConfig::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_ignores_steps_beyond_lookahead() {
+        // A long detour (~2.2km) which comes back right next to the start
+        let mut steps = parallel_roads_steps();
+        steps.insert(
+            1,
+            gen_route_step_with_coords(vec![
+                coord! {x: 0.002, y: 0.0},
+                coord! {x: 0.002, y: 0.01},
+                coord! {x: 0.002, y: 0.0},
+            ]),
+        );
+        let fixes = vec![UserLocation {
+            course_over_ground: Some(CourseOverGround::new(270.0, Some(10))),
+            ..eastbound_fix(0.0005, 0.00019, 0)
+        }];
+
+        let route_match =
+            match_location_to_route(&fixes, &steps, &MapMatchingConfig::default()).unwrap();
+        assert_eq!(route_match.step_index, 0);
+    }
+}
@@ -140,6 +140,27 @@ pub enum WaypointKind {
     Via,
 }
 
+/// The mode of travel for a trip.
+///
+/// This is used to pick sensible defaults for behavior that depends on
+/// how fast (and how precisely) the user moves, such as deviation detection.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum ModeOfTravel {
+    /// Driving a car, truck, motorcycle, etc.
+    Driving,
+    /// Riding a bicycle, scooter, etc.
+    Cycling,
+    /// Walking, running, hiking, etc.
+    Walking,
+    /// Riding public transit.
+    ///
+    /// Route geometry tends to be approximate and location quality inside vehicles is often poor.
+    Transit,
+}
+
 /// A geographic bounding box defined by its corners.
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -162,14 +183,19 @@ impl From<Rect> for BoundingBox {
 }
 
 /// The heading of the user/device.
-#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
 pub struct Heading {
     /// The heading in degrees relative to true north.
+    #[serde(alias = "true_heading")]
     pub true_heading: u16,
     /// The platform specific accuracy of the heading value.
     pub accuracy: u16,
     /// The time at which the heading was recorded.
+    #[serde(with = "system_time_format")]
     pub timestamp: SystemTime,
 }
 
@@ -390,8 +416,17 @@ pub struct RouteStep {
     /// The exit number when entering a roundabout (1 = first exit, 2 = second, etc.).
     #[serde(alias = "roundabout_exit_number")]
     pub roundabout_exit_number: Option<u8>,
+    /// The index of the route leg this step belongs to.
+    ///
+    /// A route has one leg between each pair of consecutive (break) waypoints.
+    #[serde(default, alias = "leg_index")]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub leg_index: u32,
 }
 
+/// The fudge factor (in meters) applied when checking whether an instruction's trigger distance has been reached.
+const INSTRUCTION_TRIGGER_FUDGE_FACTOR: f64 = 5.0;
+
 impl RouteStep {
     pub(crate) fn get_linestring(&self) -> LineString {
         get_linestring(&self.geometry)
@@ -407,7 +442,8 @@ impl RouteStep {
         // We have a fudge factor to account for imprecision in calculation methodologies from different engines and CPUs,
         // particularly at the start of a step.
         self.visual_instructions.iter().rev().find(|instruction| {
-            distance_to_end_of_step - instruction.trigger_distance_before_maneuver <= 5.0
+            distance_to_end_of_step - instruction.trigger_distance_before_maneuver
+                <= INSTRUCTION_TRIGGER_FUDGE_FACTOR
         })
     }
 
@@ -419,7 +455,8 @@ impl RouteStep {
     /// Take care to characteristics of your synthesis engine,
     /// including whether utterances are queued or cut off the currently playing one.
     /// You will also need some sort of check to ensure you don't make the same announcement
-    /// more times than necessary.
+    /// more times than necessary
+    /// (the navigation controller's spoken instruction events take care of this).
     pub fn get_current_spoken_instruction(
         &self,
         distance_to_end_of_step: f64,
@@ -429,7 +466,20 @@ impl RouteStep {
         // We have a fudge factor to account for imprecision in calculation methodologies from different engines and CPUs,
         // particularly at the start of a step.
         self.spoken_instructions.iter().rev().find(|instruction| {
-            distance_to_end_of_step - instruction.trigger_distance_before_maneuver <= 5.0
+            distance_to_end_of_step - instruction.trigger_distance_before_maneuver
+                <= INSTRUCTION_TRIGGER_FUDGE_FACTOR
+        })
+    }
+
+    /// Gets all spoken instructions whose trigger distance has been reached
+    /// at a specific point along the step, in the order they should be announced.
+    pub fn get_due_spoken_instructions(
+        &self,
+        distance_to_end_of_step: f64,
+    ) -> impl Iterator<Item = &SpokenInstruction> {
+        self.spoken_instructions.iter().filter(move |instruction| {
+            distance_to_end_of_step - instruction.trigger_distance_before_maneuver
+                <= INSTRUCTION_TRIGGER_FUDGE_FACTOR
         })
     }
 
@@ -441,6 +491,21 @@ impl RouteStep {
             .as_ref()
             .and_then(|annotations| annotations.get(at_coordinate_index as usize).cloned())
     }
+
+    /// Get the typed annotation values at a specific point along the step.
+    ///
+    /// These are parsed from the annotation data at the same index
+    /// (see [`RouteStep::get_annotation_at_current_index`]).
+    /// `at_coordinate_index` is the index of the coordinate in the step geometry.
+    pub fn get_segment_annotation_at_current_index(
+        &self,
+        at_coordinate_index: u64,
+    ) -> Option<SegmentAnnotation> {
+        self.annotations
+            .as_ref()
+            .and_then(|annotations| annotations.get(at_coordinate_index as usize))
+            .map(|annotation| SegmentAnnotation::from_json(annotation))
+    }
 }
 
 /// An instruction that can be synthesized using a TTS engine to announce an upcoming maneuver.
@@ -600,14 +665,14 @@ pub enum BlockedLane {
     HOV,
 }
 
-/// Details about congestion for an incident.
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+/// Details about congestion for an incident or a segment of the route.
+#[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
 #[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
 pub struct Congestion {
-    /// The level of congestion caused by the incident.
+    /// The level of congestion.
     ///
     /// 0 = no congestion
     ///
@@ -757,6 +822,136 @@ pub struct AnyAnnotationValue {
     pub value: HashMap<String, Value>,
 }
 
+/// Typed values of the common annotations for a segment of a route step
+/// (the span between two consecutive coordinates).
+///
+/// Values which are missing from the route response are [`None`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct SegmentAnnotation {
+    /// The distance of the segment, in meters.
+    pub distance: Option<f64>,
+    /// The estimated travel time of the segment, in seconds.
+    pub duration: Option<f64>,
+    /// The estimated speed along the segment, in meters per second.
+    pub speed: Option<f64>,
+    /// The speed limit along the segment.
+    #[serde(alias = "max_speed")]
+    pub max_speed: Option<SegmentMaxSpeed>,
+    /// The level of traffic congestion along the segment.
+    pub congestion: Option<Congestion>,
+}
+
+impl SegmentAnnotation {
+    /// Parses the common annotations from a JSON-encoded annotation (see [`RouteStep::annotations`]).
+    ///
+    /// Values which are missing or malformed are [`None`].
+    pub(crate) fn from_json(annotation: &str) -> Self {
+        serde_json::from_str::<AnyAnnotationValue>(annotation)
+            .map(|annotation| Self::from(&annotation))
+            .unwrap_or_default()
+    }
+}
+
+impl From<&AnyAnnotationValue> for SegmentAnnotation {
+    /// Extracts the common annotations, ignoring any values which are missing or malformed.
+    fn from(annotation: &AnyAnnotationValue) -> Self {
+        let get = |key: &str| annotation.value.get(key);
+        SegmentAnnotation {
+            distance: get("distance").and_then(Value::as_f64),
+            duration: get("duration").and_then(Value::as_f64),
+            speed: get("speed").and_then(Value::as_f64),
+            max_speed: get("maxspeed")
+                .or_else(|| get("max_speed"))
+                .and_then(parse_max_speed),
+            congestion: get("congestion_numeric")
+                .and_then(Value::as_u64)
+                .and_then(|value| u8::try_from(value).ok())
+                .or_else(|| get("congestion").and_then(parse_congestion_level))
+                .map(|value| Congestion { value }),
+        }
+    }
+}
+
+/// Parses an OSRM-style `maxspeed` annotation
+/// (ex: `{"speed": 56, "unit": "km/h"}`, `{"unknown": true}` or `{"none": true}`).
+fn parse_max_speed(value: &Value) -> Option<SegmentMaxSpeed> {
+    let flag = |key: &str| value.get(key).and_then(Value::as_bool) == Some(true);
+    if flag("none") {
+        Some(SegmentMaxSpeed::NoLimit)
+    } else if flag("unknown") {
+        Some(SegmentMaxSpeed::Unknown)
+    } else {
+        Some(SegmentMaxSpeed::Known {
+            speed: value.get("speed")?.as_f64()?,
+            unit: SegmentSpeedUnit::deserialize(value.get("unit")?).ok()?,
+        })
+    }
+}
+
+/// Converts an OSRM-style `congestion` level to the lower bound of its `congestion_numeric` range.
+fn parse_congestion_level(value: &Value) -> Option<u8> {
+    match value.as_str()? {
+        "low" => Some(0),
+        "moderate" => Some(40),
+        "heavy" => Some(60),
+        "severe" => Some(80),
+        _ => None,
+    }
+}
+
+/// The speed limit along a segment of the route.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum SegmentMaxSpeed {
+    /// There is no speed limit (ex: on parts of the German Autobahn).
+    NoLimit,
+    /// The speed limit is not known.
+    Unknown,
+    /// A known speed limit.
+    Known { speed: f64, unit: SegmentSpeedUnit },
+}
+
+impl SegmentMaxSpeed {
+    /// The speed limit in meters per second, if there is a known limit.
+    pub fn meters_per_second(&self) -> Option<f64> {
+        match self {
+            SegmentMaxSpeed::Known { speed, unit } => Some(unit.to_meters_per_second(*speed)),
+            SegmentMaxSpeed::NoLimit | SegmentMaxSpeed::Unknown => None,
+        }
+    }
+}
+
+/// The unit of a speed limit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum SegmentSpeedUnit {
+    #[serde(rename = "km/h")]
+    KilometersPerHour,
+    #[serde(rename = "mph", alias = "mi/h")]
+    MilesPerHour,
+    #[serde(rename = "knots")]
+    Knots,
+}
+
+impl SegmentSpeedUnit {
+    /// Converts a speed in this unit to meters per second.
+    pub fn to_meters_per_second(self, speed: f64) -> f64 {
+        match self {
+            SegmentSpeedUnit::KilometersPerHour => speed / 3.6,
+            SegmentSpeedUnit::MilesPerHour => speed * 0.447_04,
+            SegmentSpeedUnit::Knots => speed * 1852.0 / 3600.0,
+        }
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "uniffi")]
 mod tests {
@@ -780,4 +975,59 @@ mod tests {
         let polyline6 = get_route_polyline(&route, 6).expect("Unable to encode polyline for route");
         insta::assert_yaml_snapshot!(polyline6);
     }
+
+    #[test]
+    fn test_segment_annotation() {
+        let annotations = [
+            r#"{"distance": 1.2, "duration": 4, "speed": 10, "congestion": "heavy",
+                "maxspeed": {"speed": 56, "unit": "km/h"}, "custom": "kept as JSON"}"#,
+            r#"{"max_speed": {"speed": 30, "unit": "mph"}, "congestion": "gridlock"}"#,
+            r#"{"maxspeed": {"unknown": true}, "congestion": "low", "congestion_numeric": 12}"#,
+            r#"{"maxspeed": {"none": true}}"#,
+            r#"{"maxspeed": {"speed": 30, "unit": "furlongs per fortnight"}, "speed": "fast"}"#,
+            "not JSON",
+        ];
+        let result: Vec<SegmentAnnotation> = annotations
+            .iter()
+            .map(|annotation| SegmentAnnotation::from_json(annotation))
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![
+                SegmentAnnotation {
+                    distance: Some(1.2),
+                    duration: Some(4.0),
+                    speed: Some(10.0),
+                    max_speed: Some(SegmentMaxSpeed::Known {
+                        speed: 56.0,
+                        unit: SegmentSpeedUnit::KilometersPerHour
+                    }),
+                    congestion: Some(Congestion { value: 60 }),
+                },
+                SegmentAnnotation {
+                    max_speed: Some(SegmentMaxSpeed::Known {
+                        speed: 30.0,
+                        unit: SegmentSpeedUnit::MilesPerHour
+                    }),
+                    ..SegmentAnnotation::default()
+                },
+                SegmentAnnotation {
+                    max_speed: Some(SegmentMaxSpeed::Unknown),
+                    congestion: Some(Congestion { value: 12 }),
+                    ..SegmentAnnotation::default()
+                },
+                SegmentAnnotation {
+                    max_speed: Some(SegmentMaxSpeed::NoLimit),
+                    ..SegmentAnnotation::default()
+                },
+                SegmentAnnotation::default(),
+                SegmentAnnotation::default(),
+            ]
+        );
+
+        let max_speed = result[0].max_speed.unwrap();
+        assert!((max_speed.meters_per_second().unwrap() - 56.0 / 3.6).abs() < 1e-9);
+        assert_eq!(SegmentMaxSpeed::Unknown.meters_per_second(), None);
+    }
 }
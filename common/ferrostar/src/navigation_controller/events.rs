@@ -0,0 +1,369 @@
+//! Discrete events describing what happened between two navigation states.
+//!
+//! Rather than having every platform diff consecutive [`NavState`](super::models::NavState)s,
+//! the navigation controller attaches the events produced by each update to the resulting state.
+
+use crate::deviation_detection::{DeviationKind, RouteDeviation};
+use crate::models::{RouteStep, SpokenInstruction, UserLocation, Waypoint};
+use crate::navigation_controller::models::{
+    CancellationReason, LocationRejectionReason, TripState, TripSummary,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "wasm-bindgen")]
+use tsify::Tsify;
+
+/// Something notable that happened as a result of a navigation state update.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+#[allow(clippy::large_enum_variant)]
+pub enum NavigationEvent {
+    /// The user advanced to a new step.
+    ///
+    /// If several steps were passed in a single update, one event is emitted for each, in order.
+    StepAdvanced {
+        /// The new current step.
+        step: RouteStep,
+    },
+    /// The user moved back to an earlier step
+    /// (see [`NavigationControllerConfig::step_regression`](super::models::NavigationControllerConfig::step_regression)).
+    ReturnedToStep {
+        /// The new current step.
+        step: RouteStep,
+    },
+    /// The user reached an intermediate waypoint.
+    WaypointReached { waypoint: Waypoint },
+    /// The user deviated from the route.
+    DeviationStarted { kind: DeviationKind },
+    /// The user is back on the route after a deviation.
+    DeviationCleared,
+    /// A spoken instruction is due and should be announced.
+    ///
+    /// Each utterance is only announced once per trip.
+    /// If several instructions became due in a single update
+    /// (ex: when passing several short steps at once), one event is emitted for each, in order.
+    SpokenInstructionTriggered { instruction: SpokenInstruction },
+    /// The user reached the end of the route.
+    Arrived { summary: TripSummary },
+    /// The trip was canceled before reaching the end of the route.
+    Canceled { reason: CancellationReason },
+    /// A location fix was rejected, and did not affect the navigation state.
+    LocationRejected {
+        location: UserLocation,
+        reason: LocationRejectionReason,
+    },
+}
+
+/// Determines the events that happened when moving from the `previous` trip state to the `current` one.
+///
+/// Both states must describe progress along the same route.
+/// Spoken instructions are not included,
+/// as those depend on what has already been announced (see [`NavState`](super::models::NavState)).
+pub(crate) fn navigation_events(previous: &TripState, current: &TripState) -> Vec<NavigationEvent> {
+    match (previous, current) {
+        (
+            TripState::Navigating {
+                remaining_steps: previous_steps,
+                remaining_waypoints: previous_waypoints,
+                deviation: previous_deviation,
+                ..
+            },
+            TripState::Navigating {
+                remaining_steps,
+                remaining_waypoints,
+                deviation,
+                ..
+            },
+        ) => {
+            if remaining_steps.len() > previous_steps.len() {
+                return remaining_steps
+                    .first()
+                    .map(|step| NavigationEvent::ReturnedToStep { step: step.clone() })
+                    .into_iter()
+                    .chain(deviation_event(*previous_deviation, *deviation))
+                    .collect();
+            }
+
+            let steps_advanced = previous_steps.len().saturating_sub(remaining_steps.len());
+            let waypoints_reached = previous_waypoints
+                .len()
+                .saturating_sub(remaining_waypoints.len());
+
+            previous_steps
+                .iter()
+                .skip(1)
+                .take(steps_advanced)
+                .map(|step| NavigationEvent::StepAdvanced { step: step.clone() })
+                .chain(
+                    previous_waypoints
+                        .iter()
+                        .take(waypoints_reached)
+                        .map(|waypoint| NavigationEvent::WaypointReached {
+                            waypoint: waypoint.clone(),
+                        }),
+                )
+                .chain(deviation_event(*previous_deviation, *deviation))
+                .collect()
+        }
+        // Starting (or resuming) a trip
+        (
+            TripState::Idle { .. } | TripState::Paused { .. },
+            TripState::Navigating { deviation, .. },
+        ) => deviation_event(RouteDeviation::NoDeviation, *deviation)
+            .into_iter()
+            .collect(),
+        (
+            TripState::Navigating { .. } | TripState::Paused { .. },
+            TripState::Complete {
+                summary,
+                cancellation_reason,
+                ..
+            },
+        ) => vec![match cancellation_reason {
+            Some(reason) => NavigationEvent::Canceled {
+                reason: reason.clone(),
+            },
+            None => NavigationEvent::Arrived {
+                summary: summary.clone(),
+            },
+        }],
+        _ => Vec::new(),
+    }
+}
+
+fn deviation_event(previous: RouteDeviation, current: RouteDeviation) -> Option<NavigationEvent> {
+    match (previous, current) {
+        (RouteDeviation::NoDeviation, RouteDeviation::Deviation { kind }) => {
+            Some(NavigationEvent::DeviationStarted { kind })
+        }
+        (RouteDeviation::Deviation { .. }, RouteDeviation::NoDeviation) => {
+            Some(NavigationEvent::DeviationCleared)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the spoken instructions of the current step which are due in the given trip state.
+///
+/// Nothing is due when spoken instructions are suppressed (ex: while completely off route).
+pub(crate) fn due_spoken_instructions(trip_state: &TripState) -> Vec<SpokenInstruction> {
+    match trip_state {
+        TripState::Navigating {
+            remaining_steps,
+            progress,
+            spoken_instruction: Some(_),
+            ..
+        } => remaining_steps
+            .first()
+            .map(|step| {
+                step.get_due_spoken_instructions(progress.distance_to_next_maneuver)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::navigation_controller::models::TripProgress;
+    use crate::navigation_controller::test_helpers::gen_route_step_with_coords;
+    use crate::test_utils::make_user_location;
+    use chrono::DateTime;
+    use geo::coord;
+    use uuid::Uuid;
+
+    fn location() -> UserLocation {
+        make_user_location(coord!(x: 0.0, y: 0.0), 5.0)
+    }
+
+    fn summary() -> TripSummary {
+        TripSummary {
+            distance_traveled: 0.0,
+            snapped_distance_traveled: 0.0,
+            started_at: DateTime::from_timestamp(1_000, 0).unwrap(),
+            ended_at: None,
+        }
+    }
+
+    fn spoken_instruction(text: &str, trigger_distance_before_maneuver: f64) -> SpokenInstruction {
+        SpokenInstruction {
+            text: text.into(),
+            ssml: None,
+            trigger_distance_before_maneuver,
+            utterance_id: Uuid::new_v4(),
+        }
+    }
+
+    fn navigating(
+        remaining_steps: Vec<RouteStep>,
+        deviation: RouteDeviation,
+        spoken_instruction: Option<SpokenInstruction>,
+    ) -> TripState {
+        TripState::Navigating {
+            current_step_geometry_index: Some(0),
+            user_location: location(),
+            snapped_user_location: location(),
+            remaining_steps,
+            remaining_waypoints: vec![],
+            progress: TripProgress {
+                distance_to_next_maneuver: 0.0,
+                distance_remaining: 0.0,
+                duration_remaining: 0.0,
+                adaptive_duration_remaining: None,
+                current_leg_index: 0,
+                remaining_legs: vec![],
+            },
+            summary: summary(),
+            deviation,
+            visual_instruction: None,
+            spoken_instruction,
+            annotation_json: None,
+            segment_annotation: None,
+            speed_limit: None,
+            next_speed_limit_change: None,
+            is_speeding: false,
+            upcoming_maneuvers: vec![],
+            upcoming_incident: None,
+            location_estimated: false,
+            raw_user_location: None,
+            heading: None,
+        }
+    }
+
+    fn steps() -> Vec<RouteStep> {
+        (0..4)
+            .map(|i| {
+                let lng = f64::from(i) * 0.001;
+                gen_route_step_with_coords(vec![
+                    coord!(x: lng, y: 0.0),
+                    coord!(x: lng + 0.001, y: 0.0),
+                ])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn no_events_when_nothing_changes() {
+        let state = navigating(steps(), RouteDeviation::NoDeviation, None);
+        assert_eq!(navigation_events(&state, &state), []);
+    }
+
+    #[test]
+    fn step_advanced_for_each_passed_step() {
+        let steps = steps();
+        let previous = navigating(steps.clone(), RouteDeviation::NoDeviation, None);
+        let current = navigating(steps[2..].to_vec(), RouteDeviation::NoDeviation, None);
+
+        assert_eq!(
+            navigation_events(&previous, &current),
+            vec![
+                NavigationEvent::StepAdvanced {
+                    step: steps[1].clone()
+                },
+                NavigationEvent::StepAdvanced {
+                    step: steps[2].clone()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn returned_to_earlier_step() {
+        let steps = steps();
+        let previous = navigating(steps[2..].to_vec(), RouteDeviation::NoDeviation, None);
+        let current = navigating(steps[1..].to_vec(), RouteDeviation::NoDeviation, None);
+
+        assert_eq!(
+            navigation_events(&previous, &current),
+            vec![NavigationEvent::ReturnedToStep {
+                step: steps[1].clone()
+            }]
+        );
+    }
+
+    #[test]
+    fn deviation_started_and_cleared() {
+        let kind = DeviationKind::CompletelyOffRoute {
+            deviation_from_route_line: 100.0,
+        };
+        let on_route = navigating(steps(), RouteDeviation::NoDeviation, None);
+        let off_route = navigating(steps(), RouteDeviation::Deviation { kind }, None);
+
+        assert_eq!(
+            navigation_events(&on_route, &off_route),
+            vec![NavigationEvent::DeviationStarted { kind }]
+        );
+        assert_eq!(navigation_events(&off_route, &off_route), []);
+        assert_eq!(
+            navigation_events(&off_route, &on_route),
+            vec![NavigationEvent::DeviationCleared]
+        );
+    }
+
+    #[test]
+    fn due_spoken_instructions_unless_suppressed() {
+        let mut step =
+            gen_route_step_with_coords(vec![coord!(x: 0.0, y: 0.0), coord!(x: 0.001, y: 0.0)]);
+        step.spoken_instructions = vec![
+            spoken_instruction("In 100 meters, turn left", 100.0),
+            spoken_instruction("Turn left", 10.0),
+        ];
+        let due_at = |distance_to_next_maneuver: f64, suppressed: bool| {
+            let spoken_instruction = if suppressed {
+                None
+            } else {
+                step.get_current_spoken_instruction(distance_to_next_maneuver)
+                    .cloned()
+            };
+            let mut state = navigating(
+                vec![step.clone()],
+                RouteDeviation::NoDeviation,
+                spoken_instruction,
+            );
+            if let TripState::Navigating { progress, .. } = &mut state {
+                progress.distance_to_next_maneuver = distance_to_next_maneuver;
+            }
+            due_spoken_instructions(&state)
+        };
+
+        assert_eq!(due_at(200.0, false), []);
+        assert_eq!(due_at(50.0, false), step.spoken_instructions[..1]);
+        assert_eq!(due_at(5.0, false), step.spoken_instructions);
+        // Nothing is due while instructions are suppressed (ex: completely off route)
+        assert_eq!(due_at(5.0, true), []);
+    }
+
+    #[test]
+    fn arrived_or_canceled() {
+        let navigating = navigating(steps(), RouteDeviation::NoDeviation, None);
+        let arrived = TripState::Complete {
+            user_location: location(),
+            summary: summary(),
+            cancellation_reason: None,
+        };
+        let canceled = TripState::Complete {
+            user_location: location(),
+            summary: summary(),
+            cancellation_reason: Some(CancellationReason::UserCanceled),
+        };
+
+        assert_eq!(
+            navigation_events(&navigating, &arrived),
+            vec![NavigationEvent::Arrived { summary: summary() }]
+        );
+        assert_eq!(
+            navigation_events(&navigating, &canceled),
+            vec![NavigationEvent::Canceled {
+                reason: CancellationReason::UserCanceled
+            }]
+        );
+        assert_eq!(navigation_events(&arrived, &arrived), []);
+    }
+}
@@ -1,5 +1,6 @@
 //! The navigation state machine.
 
+pub mod events;
 pub mod models;
 pub mod step_advance;
 pub mod waypoint_advance;
@@ -13,24 +14,42 @@ use crate::navigation_controller::models::{
 };
 use crate::{
     algorithms::{
-        advance_step, apply_snapped_course, calculate_trip_progress,
-        index_of_closest_segment_origin, snap_user_location_to_line,
+        advance_along_line, advance_step, apply_snapped_course,
+        calculate_adaptive_duration_remaining, calculate_trip_progress_at_index,
+        check_location_fix, dead_reckoning_speed, deviation_from_line, heading_course,
+        index_of_closest_segment_origin, next_speed_limit_change, snap_user_location_to_line,
+        upcoming_incident, upcoming_maneuvers, update_smoothed_speed,
+    },
+    deviation_detection::{RouteDeviation, RouteDeviationState},
+    map_matching::match_location_to_route,
+    models::{
+        GeographicCoordinate, Heading, Route, RouteStep, SegmentAnnotation, SegmentMaxSpeed, Speed,
+        SpokenInstruction, UserLocation, Waypoint,
     },
-    deviation_detection::RouteDeviation,
-    models::{Route, RouteStep, UserLocation, Waypoint},
     navigation_controller::{
-        models::TripSummary,
+        events::{NavigationEvent, due_spoken_instructions},
+        models::{SpeedLimitChange, TripProgress, TripSummary, UpcomingIncident, UpcomingManeuver},
+        step_advance::StepAdvanceCondition,
         waypoint_advance::{WaypointAdvanceChecker, WaypointAdvanceResult, WaypointCheckEvent},
     },
     navigation_session::{NavigationObserver, NavigationSession, recording::NavigationRecorder},
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+#[cfg(feature = "wasm-bindgen")]
+use core::time::Duration;
+use geo::Point;
 use geo::geometry::LineString;
-use models::{NavState, NavigationControllerConfig, StepAdvanceStatus, TripState};
+use models::{
+    CancellationReason, NavState, NavigationControllerConfig, StepAdvanceStatus, TripState,
+};
 use std::clone::Clone;
-use std::sync::Arc;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard};
+#[cfg(all(feature = "std", not(feature = "web-time")))]
+use std::time::SystemTime;
 #[cfg(feature = "wasm-bindgen")]
 use wasm_bindgen::{JsValue, prelude::wasm_bindgen};
+#[cfg(feature = "web-time")]
+use web_time::SystemTime;
 
 /// Core interface for navigation functionalities.
 ///
@@ -43,6 +62,12 @@ pub trait Navigator: Send + Sync {
     fn get_initial_state(&self, location: UserLocation) -> NavState;
     fn advance_to_next_step(&self, state: NavState) -> NavState;
     fn update_user_location(&self, location: UserLocation, state: NavState) -> NavState;
+    fn replace_route(&self, route: Route, state: NavState) -> NavState;
+    fn pause(&self, state: NavState) -> NavState;
+    fn resume(&self, state: NavState) -> NavState;
+    fn cancel(&self, state: NavState, reason: CancellationReason) -> NavState;
+    fn estimate_location(&self, timestamp: SystemTime, state: NavState) -> NavState;
+    fn update_heading(&self, heading: Heading, state: NavState) -> NavState;
 }
 
 /// Creates a new navigation controller for the given route and configuration.
@@ -75,11 +100,13 @@ pub fn create_navigator(
 /// returning an updated state given inputs like user location.
 ///
 /// Notes for implementing a new platform:
-/// - A controller is bound to a single route; if you want recalculation, create a new instance.
-/// - This is a pure type (no interior mutability), so a core function of your platform code is responsibly managing mutable state.
+/// - A controller navigates a single route at a time; use [`Navigator::replace_route`] after recalculation.
+/// - The only interior mutability is the current route.
+///   Everything else about the trip lives in [`NavState`],
+///   so a core function of your platform code is responsibly managing mutable state.
 #[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
 pub struct NavigationController {
-    route: Route,
+    route: RwLock<Route>,
     config: NavigationControllerConfig,
 }
 
@@ -88,20 +115,21 @@ impl NavigationController {
     #[cfg_attr(feature = "uniffi", uniffi::constructor)]
     /// Create a navigation controller for a route and configuration.
     pub fn new(route: Route, config: NavigationControllerConfig) -> Self {
-        Self { route, config }
+        Self {
+            route: RwLock::new(route),
+            config,
+        }
     }
 }
 
 impl Navigator for NavigationController {
     /// The route associated with this controller.
     fn route(&self) -> Route {
-        self.route.clone()
+        self.read_route().clone()
     }
 
     /// Returns initial trip state as if the user had just started the route with no progress.
     fn get_initial_state(&self, location: UserLocation) -> NavState {
-        let remaining_steps = self.route.steps.clone();
-
         let initial_summary = TripSummary {
             distance_traveled: 0.0,
             snapped_distance_traveled: 0.0,
@@ -109,96 +137,168 @@ impl Navigator for NavigationController {
             ended_at: None,
         };
 
-        let Some(current_route_step) = remaining_steps.first() else {
-            // Bail early; if we don't have any steps, this is a useless route
-            return NavState::complete(location, initial_summary);
-        };
-
-        // TODO: We could move this to the Route struct or NavigationController directly to only calculate it once.
-        let current_step_linestring = current_route_step.get_linestring();
-        let (current_step_geometry_index, snapped_user_location) =
-            self.snap_user_to_line(location, &current_step_linestring);
-
-        let progress = calculate_trip_progress(
-            &snapped_user_location.into(),
-            &current_step_linestring,
-            &remaining_steps,
-        );
-
-        let visual_instruction = current_route_step
-            .get_active_visual_instruction(progress.distance_to_next_maneuver)
-            .cloned();
-        let spoken_instruction = current_route_step
-            .get_current_spoken_instruction(progress.distance_to_next_maneuver)
-            .cloned();
-
-        let annotation_json = current_step_geometry_index
-            .and_then(|index| current_route_step.get_annotation_at_current_index(index));
-
-        let initial_trip_state = TripState::Navigating {
-            current_step_geometry_index,
-            user_location: location,
-            snapped_user_location,
+        let (remaining_steps, remaining_waypoints) = self.route_steps_and_waypoints();
+        self.start_route(
+            location,
             remaining_steps,
-            // Skip the first waypoint, as it is the current one
-            remaining_waypoints: self.route.waypoints.iter().skip(1).cloned().collect(),
-            progress,
-            summary: initial_summary,
-            deviation: RouteDeviation::NoDeviation,
-            visual_instruction,
-            spoken_instruction,
-            annotation_json,
-        };
+            remaining_waypoints,
+            initial_summary,
+            Arc::clone(&self.config.step_advance_condition),
+            self.updated_recent_locations(&[], location),
+        )
+        .with_events_since(&NavState::idle(None), Vec::new())
+    }
 
-        let deviation = self
-            .config
-            .route_deviation_tracking
-            .check_route_deviation(&self.route, &initial_trip_state);
+    /// Replaces the route being navigated (e.g. after a reroute) without losing trip history.
+    ///
+    /// The user is snapped to the new route as if they had just started it,
+    /// but the [`TripSummary`], location smoothing and device heading of the previous state are carried over.
+    /// Waypoints are recomputed from the new route,
+    /// and the step advance condition keeps its state.
+    /// A paused trip stays paused.
+    fn replace_route(&self, route: Route, state: NavState) -> NavState {
+        *self.route.write().unwrap_or_else(PoisonError::into_inner) = route;
 
-        let trip_state = if let TripState::Navigating {
-            current_step_geometry_index,
-            user_location,
-            snapped_user_location,
-            remaining_steps,
-            remaining_waypoints,
-            progress,
-            summary,
-            visual_instruction,
-            spoken_instruction,
-            annotation_json,
-            ..
-        } = initial_trip_state
-        {
-            // If the user starts completely off the route, suppress instructions for the
-            // same reason as in `create_intermediate_trip_state`: the snap-derived distance
-            // to the next maneuver is geometrically unsound, so any countdown surfaced
-            // from it would mislead the user. `OffStepOnRoute` is intentionally not
-            // suppressed here — the user is still on the route polyline (just on a future
-            // step), and the step-advance flow will reconcile shortly.
-            let (visual_instruction, spoken_instruction) = if deviation.is_completely_off_route() {
-                (None, None)
-            } else {
-                (visual_instruction, spoken_instruction)
-            };
+        match state.trip_state() {
+            TripState::Navigating {
+                user_location,
+                summary,
+                heading,
+                ..
+            } => {
+                let (remaining_steps, remaining_waypoints) = self.route_steps_and_waypoints();
+                let new_state = self
+                    .start_route(
+                        user_location,
+                        remaining_steps,
+                        remaining_waypoints,
+                        summary,
+                        state.step_advance_condition(),
+                        state.recent_locations().to_vec(),
+                    )
+                    .with_location_smoothing_state(state.location_smoothing_state());
+                self.apply_heading(heading, new_state)
+                    .with_events_since(&state, Vec::new())
+            }
+            TripState::Paused {
+                user_location,
+                summary,
+                heading,
+                ..
+            } => {
+                let (remaining_steps, remaining_waypoints) = self.route_steps_and_waypoints();
+                let state = self
+                    .start_route(
+                        user_location,
+                        remaining_steps,
+                        remaining_waypoints,
+                        summary,
+                        state.step_advance_condition(),
+                        Vec::new(),
+                    )
+                    .with_location_smoothing_state(state.location_smoothing_state());
+                self.pause(self.apply_heading(heading, state))
+            }
+            // Pass through
+            TripState::Idle { .. } | TripState::Complete { .. } => state.with_events(Vec::new()),
+        }
+    }
+
+    /// Pauses guidance, freezing progress along the route.
+    ///
+    /// See [`TripState::Paused`] for what this entails.
+    /// Deviation tracking, location smoothing and the smoothed speed are kept for when the trip resumes.
+    /// States other than [`TripState::Navigating`] are returned unchanged.
+    fn pause(&self, state: NavState) -> NavState {
+        match state.trip_state() {
             TripState::Navigating {
-                current_step_geometry_index,
                 user_location,
                 snapped_user_location,
                 remaining_steps,
                 remaining_waypoints,
                 progress,
                 summary,
-                deviation, // Use the newly calculated deviation
-                visual_instruction,
-                spoken_instruction,
-                annotation_json,
+                heading,
+                ..
+            } => state
+                .clone()
+                .with_trip_state(TripState::Paused {
+                    user_location,
+                    snapped_user_location,
+                    remaining_steps,
+                    remaining_waypoints,
+                    progress,
+                    summary,
+                    heading,
+                })
+                .with_dead_reckoning_anchor(None),
+            // Pass through
+            TripState::Idle { .. } | TripState::Paused { .. } | TripState::Complete { .. } => {
+                state.clone()
             }
-        } else {
-            unreachable!("initial_trip_state should always be Navigating variant")
-        };
+        }
+        .with_events_since(&state, Vec::new())
+    }
+
+    /// Resumes guidance from the user's latest location.
+    ///
+    /// The user is snapped to the remaining steps as if they had just started navigating them,
+    /// and the step advance condition is reset to a fresh instance.
+    /// The deviation tracking, location smoothing, smoothed speed and device heading from before the pause are carried over.
+    /// States other than [`TripState::Paused`] are returned unchanged.
+    fn resume(&self, state: NavState) -> NavState {
+        match state.trip_state() {
+            TripState::Paused {
+                user_location,
+                remaining_steps,
+                remaining_waypoints,
+                summary,
+                heading,
+                ..
+            } => self.apply_heading(
+                heading,
+                self.start_route(
+                    user_location,
+                    remaining_steps,
+                    remaining_waypoints,
+                    summary,
+                    state.step_advance_condition().new_instance(),
+                    self.updated_recent_locations(&[], user_location),
+                )
+                .with_deviation_state(state.deviation_state())
+                .with_smoothed_speed(state.smoothed_speed())
+                .with_location_smoothing_state(state.location_smoothing_state())
+                .with_completed_step_count(state.completed_step_count()),
+            ),
+            // Pass through
+            TripState::Idle { .. } | TripState::Navigating { .. } | TripState::Complete { .. } => {
+                state.clone()
+            }
+        }
+        .with_events_since(&state, Vec::new())
+    }
 
-        let next_advance = Arc::clone(&self.config.step_advance_condition);
-        NavState::new(trip_state, next_advance)
+    /// Ends the trip before reaching the end of the route.
+    ///
+    /// The resulting [`TripState::Complete`] state carries the `reason`,
+    /// and its summary is stamped with the time the trip ended.
+    /// States without an active trip are returned unchanged.
+    fn cancel(&self, state: NavState, reason: CancellationReason) -> NavState {
+        match state.trip_state() {
+            TripState::Navigating {
+                user_location,
+                summary,
+                ..
+            }
+            | TripState::Paused {
+                user_location,
+                summary,
+                ..
+            } => NavState::canceled(user_location, &summary, reason),
+            // Pass through
+            TripState::Idle { .. } | TripState::Complete { .. } => state.clone(),
+        }
+        .with_events_since(&state, Vec::new())
     }
 
     /// Advances navigation to the next step (or finishes the route).
@@ -209,7 +309,105 @@ impl Navigator for NavigationController {
     ///
     /// This method takes the intermediate state (e.g., from `update_user_location`) and advances if necessary,
     /// and does not handle anything like snapping.
+    /// Like the automatic advance, spoken instructions which became due on the step being passed are still announced.
     fn advance_to_next_step(&self, state: NavState) -> NavState {
+        self.advance_state(&state)
+            .with_events_since(&state, due_spoken_instructions(&state.trip_state()))
+    }
+
+    /// Updates the user's current location and updates the navigation state accordingly.
+    ///
+    /// When [`NavigationControllerConfig::location_filter`] is set,
+    /// implausible fixes are rejected with a [`NavigationEvent::LocationRejected`] event,
+    /// leaving the rest of the state unchanged.
+    ///
+    /// # Panics
+    ///
+    /// If there is no current step ([`TripState::Navigating`] has an empty `remainingSteps` value),
+    /// this function will panic.
+    fn update_user_location(&self, location: UserLocation, state: NavState) -> NavState {
+        if let Some(config) = self.config.location_filter {
+            // Estimates are not fixes, so check against the last trustworthy fix while dead reckoning.
+            let last_location = state
+                .dead_reckoning_anchor()
+                .or_else(|| state.trip_state().user_location());
+            if let Err(reason) = check_location_fix(&location, last_location.as_ref(), config) {
+                return state
+                    .with_events(vec![NavigationEvent::LocationRejected { location, reason }]);
+            }
+        }
+
+        let is_trustworthy = self.config.dead_reckoning.is_none_or(|config| {
+            location.horizontal_accuracy.is_finite()
+                && location.horizontal_accuracy <= config.maximum_horizontal_accuracy
+        });
+        if !is_trustworthy
+            && let Some(estimated_state) = self.dead_reckon(location.timestamp, &state)
+        {
+            return estimated_state;
+        }
+
+        let (location, raw_location, smoothing_state) =
+            match (&self.config.location_smoothing, state.trip_state()) {
+                (Some(smoothing), TripState::Navigating { .. }) => {
+                    let (smoothed_location, smoothing_state) =
+                        smoothing.smooth(location, state.location_smoothing_state());
+                    (smoothed_location, Some(location), Some(smoothing_state))
+                }
+                // Keep the filter state for when a paused trip resumes
+                _ => (location, None, state.location_smoothing_state()),
+            };
+
+        let mut passed_spoken_instructions = Vec::new();
+        self.update_state(location, &state, &mut passed_spoken_instructions)
+            .with_location_smoothing_state(smoothing_state)
+            .with_raw_user_location(raw_location)
+            .with_events_since(&state, passed_spoken_instructions)
+    }
+
+    /// Estimates the user's progress along the route at the given time,
+    /// in the absence of (trustworthy) location fixes.
+    ///
+    /// Platforms should call this periodically when location updates stop arriving (ex: in a tunnel).
+    /// The state is returned unchanged unless [`NavigationControllerConfig::dead_reckoning`] is set,
+    /// the user is navigating, and the last trustworthy fix is recent enough.
+    fn estimate_location(&self, timestamp: SystemTime, state: NavState) -> NavState {
+        self.dead_reckon(timestamp, &state)
+            .unwrap_or_else(|| state.with_events(Vec::new()))
+    }
+
+    /// Updates the device heading (ex: from a compass).
+    ///
+    /// The heading is used for course filtering
+    /// (see [`CourseFiltering::SnapToRouteWithHeading`](models::CourseFiltering::SnapToRouteWithHeading))
+    /// and for wrong-direction detection while the user is (nearly) stationary,
+    /// when the course reported by the location provider is meaningless.
+    /// A paused trip only records the heading for when it resumes.
+    /// Other states are returned unchanged.
+    fn update_heading(&self, heading: Heading, state: NavState) -> NavState {
+        self.apply_heading(Some(heading), state.clone())
+            .with_events_since(&state, Vec::new())
+    }
+}
+
+/// Converts a location timestamp to a date and time, falling back to the current time.
+fn date_time(timestamp: SystemTime) -> DateTime<Utc> {
+    timestamp
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .ok()
+        .and_then(|since_epoch| {
+            DateTime::from_timestamp(
+                i64::try_from(since_epoch.as_secs()).ok()?,
+                since_epoch.subsec_nanos(),
+            )
+        })
+        .unwrap_or_else(Utc::now)
+}
+
+// Shared functionality for the navigation controller that is not exported by `UniFFI`.
+impl NavigationController {
+    /// Implements [`Navigator::advance_to_next_step`], without computing navigation events.
+    fn advance_state(&self, state: &NavState) -> NavState {
         match state.trip_state() {
             TripState::Navigating {
                 user_location,
@@ -225,7 +423,7 @@ impl Navigator for NavigationController {
                         // Trim the remaining waypoints if needed.
                         let waypoints_result = self.get_new_waypoints(
                             &state.trip_state(),
-                            WaypointCheckEvent::StepAdvanced(current_step.clone()),
+                            WaypointCheckEvent::StepAdvanced(current_step),
                         );
                         let remaining_waypoints = match waypoints_result {
                             WaypointAdvanceResult::Unchanged => remaining_waypoints.clone(),
@@ -235,39 +433,57 @@ impl Navigator for NavigationController {
                         // Apply the updates
                         let mut remaining_steps = remaining_steps.clone();
                         remaining_steps.remove(0);
+                        let completed_step_count = if self.config.step_regression.is_some() {
+                            state.completed_step_count() + 1
+                        } else {
+                            0
+                        };
 
                         // Create a new trip state with the updated current_step
                         // and remaining_steps
-                        let trip_state = self.create_intermediate_trip_state(
+                        let intermediate_trip_state = self.create_intermediate_trip_state(
                             state.trip_state(),
                             user_location,
-                            current_step,
+                            state.recent_locations(),
                             remaining_steps,
                             remaining_waypoints,
                             deviation,
                         );
+                        // The speed is smoothed per step, so start over.
+                        let (trip_state, smoothed_speed) =
+                            self.apply_adaptive_eta(intermediate_trip_state, None);
 
                         // Reset condition state on every step advance. Auto-advance gets a fresh
                         // condition via `should_advance_step` returning `advance_to_new_instance`,
                         // but manual advance bypasses that — without this reset, stateful latches
                         // would leak from the previous step into the next one.
                         NavState::new(trip_state, state.step_advance_condition().new_instance())
+                            .with_recent_locations(state.recent_locations().to_vec())
+                            .with_deviation_state(state.deviation_state())
+                            .with_smoothed_speed(smoothed_speed)
+                            .with_location_smoothing_state(state.location_smoothing_state())
+                            .with_completed_step_count(completed_step_count)
                     }
-                    StepAdvanceStatus::EndOfRoute => NavState::complete(user_location, summary),
+                    StepAdvanceStatus::EndOfRoute => NavState::complete(user_location, &summary),
                 }
             }
             // Pass through
-            TripState::Idle { .. } | TripState::Complete { .. } => state.clone(),
+            TripState::Idle { .. } | TripState::Paused { .. } | TripState::Complete { .. } => {
+                state.clone()
+            }
         }
     }
 
-    /// Updates the user's current location and updates the navigation state accordingly.
-    ///
-    /// # Panics
+    /// Implements [`Navigator::update_user_location`], without computing navigation events.
     ///
-    /// If there is no current step ([`TripState::Navigating`] has an empty `remainingSteps` value),
-    /// this function will panic.
-    fn update_user_location(&self, location: UserLocation, state: NavState) -> NavState {
+    /// Spoken instructions which became due on any steps passed along the way
+    /// are collected in `passed_spoken_instructions`.
+    fn update_state(
+        &self,
+        location: UserLocation,
+        state: &NavState,
+        passed_spoken_instructions: &mut Vec<SpokenInstruction>,
+    ) -> NavState {
         match state.trip_state() {
             TripState::Navigating {
                 remaining_steps,
@@ -276,9 +492,17 @@ impl Navigator for NavigationController {
                 ..
             } => {
                 // Remaining steps is empty, the route is finished.
-                let Some(current_step) = remaining_steps.first().cloned() else {
-                    return NavState::complete(location, summary);
-                };
+                if remaining_steps.is_empty() {
+                    return NavState::complete(location, &summary);
+                }
+
+                if let Some(regressed_state) = self.regress_to_earlier_step(location, state) {
+                    return self.update_state(
+                        location,
+                        &regressed_state,
+                        passed_spoken_instructions,
+                    );
+                }
 
                 // Trim the remaining waypoints if needed.
                 let waypoints_result = self
@@ -288,20 +512,29 @@ impl Navigator for NavigationController {
                     WaypointAdvanceResult::Changed(new_waypoints) => new_waypoints,
                 };
 
-                let deviation = self
+                let (deviation, deviation_state) = self
                     .config
                     .route_deviation_tracking
-                    .check_route_deviation(&self.route, &state.trip_state());
+                    .check_route_deviation_with_state(
+                        &self.read_route(),
+                        &state.trip_state(),
+                        state.deviation_state(),
+                    );
+
+                let recent_locations =
+                    self.updated_recent_locations(state.recent_locations(), location);
 
                 let is_arriving = remaining_steps.len() <= 2;
                 let intermediate_trip_state = self.create_intermediate_trip_state(
                     state.trip_state(),
                     location,
-                    current_step,
+                    &recent_locations,
                     remaining_steps,
                     remaining_waypoints,
                     deviation,
                 );
+                let (intermediate_trip_state, smoothed_speed) =
+                    self.apply_adaptive_eta(intermediate_trip_state, state.smoothed_speed());
 
                 // Get the step advance condition result.
                 let step_advance_result = if is_arriving {
@@ -316,74 +549,497 @@ impl Navigator for NavigationController {
 
                 let should_advance = step_advance_result.should_advance();
                 let intermediate_nav_state =
-                    NavState::new(intermediate_trip_state, step_advance_result.next_iteration);
+                    NavState::new(intermediate_trip_state, step_advance_result.next_iteration)
+                        .with_recent_locations(recent_locations)
+                        .with_deviation_state(deviation_state)
+                        .with_smoothed_speed(smoothed_speed)
+                        .with_completed_step_count(state.completed_step_count());
 
                 if should_advance {
+                    // Instructions which became due on the step being passed still count.
+                    passed_spoken_instructions.extend(due_spoken_instructions(
+                        &intermediate_nav_state.trip_state(),
+                    ));
+
                     // Advance to the next step
-                    let updated_state = self.advance_to_next_step(intermediate_nav_state);
+                    let updated_state = self.advance_state(&intermediate_nav_state);
 
                     return if is_arriving {
                         updated_state
                     } else {
                         // Recurse ("speed run" behavior)
-                        self.update_user_location(location, updated_state)
+                        self.update_state(location, &updated_state, passed_spoken_instructions)
                     };
                 }
 
                 intermediate_nav_state
             }
+            // Only track the user's location; progress is frozen while paused.
+            TripState::Paused {
+                snapped_user_location,
+                remaining_steps,
+                remaining_waypoints,
+                progress,
+                summary,
+                heading,
+                ..
+            } => state.clone().with_trip_state(TripState::Paused {
+                user_location: location,
+                snapped_user_location,
+                remaining_steps,
+                remaining_waypoints,
+                progress,
+                summary,
+                heading,
+            }),
             // Pass through
             TripState::Idle { .. } | TripState::Complete { .. } => state.clone(),
         }
     }
-}
 
-// Shared functionality for the navigation controller that is not exported by `UniFFI`.
-impl NavigationController {
-    /// Create an intermediate trip state with updated values,
-    /// but does _not_ advance to the next step or handle arrival.
-    ///
-    /// Parameters:
-    /// - `trip_state`: The existing/last trip state.
-    /// - `location`: The user's current location.
-    /// - `current_step`: The current route step.
-    /// - `remaining_steps`: The remaining route steps.
-    /// - `remaining_waypoints`: The remaining waypoints.
+    /// Reactivates the most recently completed step that the user is clearly back on
+    /// as the current step (see [`StepRegressionConfig`](models::StepRegressionConfig)).
     ///
-    /// Returns:
-    /// - `TripState`: The intermediate trip state.
-    fn create_intermediate_trip_state(
+    /// Returns [`None`] when the user should stay on the current step.
+    fn regress_to_earlier_step(
         &self,
-        trip_state: TripState,
-        current_user_location: UserLocation,
-        current_step: RouteStep,
-        remaining_steps: Vec<RouteStep>,
-        remaining_waypoints: Vec<Waypoint>,
-        deviation: RouteDeviation,
-    ) -> TripState {
-        match trip_state {
+        location: UserLocation,
+        state: &NavState,
+    ) -> Option<NavState> {
+        let config = self.config.step_regression?;
+        let TripState::Navigating {
+            remaining_steps,
+            remaining_waypoints,
+            ..
+        } = state.trip_state()
+        else {
+            return None;
+        };
+
+        let point = Point::from(location);
+        let distance_from_current_step =
+            deviation_from_line(&point, &remaining_steps.first()?.get_linestring())?;
+        if distance_from_current_step <= config.minimum_distance_from_current_step {
+            return None;
+        }
+
+        // The completed steps are the ones preceding the remaining steps on the route.
+        let (step_index, restored_steps) = {
+            let route = self.read_route();
+            let completed_steps = route.steps.get(..state.completed_step_count() as usize)?;
+            let step_index = completed_steps.iter().rposition(|step| {
+                deviation_from_line(&point, &step.get_linestring())
+                    .is_some_and(|distance| distance <= config.maximum_distance_from_step)
+            })?;
+            let restored_steps: Vec<_> = completed_steps[step_index..]
+                .iter()
+                .cloned()
+                .chain(remaining_steps)
+                .collect();
+            (u32::try_from(step_index).ok()?, restored_steps)
+        };
+        let trip_state = self.create_intermediate_trip_state(
+            state.trip_state(),
+            location,
+            state.recent_locations(),
+            restored_steps,
+            remaining_waypoints,
+            RouteDeviation::NoDeviation,
+        );
+        // The speed is smoothed per step, so start over.
+        let (trip_state, smoothed_speed) = self.apply_adaptive_eta(trip_state, None);
+
+        Some(
+            NavState::new(trip_state, state.step_advance_condition().new_instance())
+                .with_recent_locations(state.recent_locations().to_vec())
+                .with_deviation_state(state.deviation_state())
+                .with_smoothed_speed(smoothed_speed)
+                .with_location_smoothing_state(state.location_smoothing_state())
+                .with_completed_step_count(step_index),
+        )
+    }
+
+    /// Sets the device heading of a navigating or paused state (see [`Navigator::update_heading`]),
+    /// re-filtering the snapped course of a navigating one.
+    ///
+    /// The state is returned unchanged when there is no heading.
+    fn apply_heading(&self, heading: Option<Heading>, state: NavState) -> NavState {
+        let Some(heading) = heading else {
+            return state;
+        };
+        let snapped_user_location = match state.trip_state() {
             TripState::Navigating {
-                user_location: previous_user_location,
-                snapped_user_location: previous_snapped_user_location,
-                summary: previous_summary,
+                current_step_geometry_index,
+                snapped_user_location,
+                remaining_steps,
                 ..
-            } => {
-                // Find the nearest point on the route line
-                let current_step_linestring = current_step.get_linestring();
-                let (current_step_geometry_index, snapped_user_location) =
-                    self.snap_user_to_line(current_user_location, &current_step_linestring);
+            } => remaining_steps
+                .first()
+                .map_or(snapped_user_location, |current_step| {
+                    self.filter_snapped_course(
+                        snapped_user_location,
+                        current_step_geometry_index,
+                        &current_step.get_linestring(),
+                        Some(heading),
+                    )
+                }),
+            TripState::Paused {
+                snapped_user_location,
+                ..
+            } => snapped_user_location,
+            TripState::Idle { .. } | TripState::Complete { .. } => return state,
+        };
+        state.with_heading(heading, snapped_user_location)
+    }
 
-                // Update trip summary with accumulated distance
-                let updated_summary = previous_summary.update(
-                    &previous_user_location,
-                    &current_user_location,
-                    &previous_snapped_user_location,
-                    &snapped_user_location,
-                );
+    /// Updates the state with an estimate of the user's location at the given time (see [`DeadReckoningConfig`](models::DeadReckoningConfig)).
+    ///
+    /// Returns [`None`] when the location cannot (or should no longer) be estimated.
+    fn dead_reckon(&self, timestamp: SystemTime, state: &NavState) -> Option<NavState> {
+        let config = self.config.dead_reckoning?;
+        let TripState::Navigating {
+            current_step_geometry_index,
+            user_location,
+            snapped_user_location,
+            remaining_steps,
+            ..
+        } = state.trip_state()
+        else {
+            return None;
+        };
 
-                let progress = calculate_trip_progress(
-                    &snapped_user_location.into(),
+        // The anchor is the last trustworthy fix, which is the current one unless already estimating.
+        let anchor = state.dead_reckoning_anchor().unwrap_or(user_location);
+        let time_since_anchor = timestamp.duration_since(anchor.timestamp).ok()?;
+        if time_since_anchor.as_secs_f64() > config.maximum_duration {
+            return None;
+        }
+
+        let current_step = remaining_steps.first()?;
+        let speed = dead_reckoning_speed(&anchor, current_step, current_step_geometry_index)?;
+        let elapsed = timestamp
+            .duration_since(user_location.timestamp)
+            .unwrap_or_default()
+            .as_secs_f64();
+
+        let line = current_step.get_linestring();
+        let (point, segment_index) = advance_along_line(
+            snapped_user_location.into(),
+            &line,
+            current_step_geometry_index.unwrap_or(0) as usize,
+            speed * elapsed,
+        );
+        let estimated_location = apply_snapped_course(
+            UserLocation {
+                coordinates: GeographicCoordinate {
+                    lng: point.x(),
+                    lat: point.y(),
+                },
+                horizontal_accuracy: anchor.horizontal_accuracy,
+                course_over_ground: anchor.course_over_ground,
+                timestamp,
+                speed: Some(Speed {
+                    value: speed,
+                    accuracy: None,
+                }),
+            },
+            Some(segment_index as u64),
+            &line,
+        );
+
+        // Estimates are not smoothed, and smoothing starts over once fixes are trustworthy again.
+        let mut passed_spoken_instructions = Vec::new();
+        Some(
+            self.update_state(estimated_location, state, &mut passed_spoken_instructions)
+                .with_raw_user_location(None)
+                .with_dead_reckoning_anchor(Some(anchor))
+                .with_events_since(state, passed_spoken_instructions),
+        )
+    }
+
+    /// Returns the current route.
+    ///
+    /// The route is only ever replaced as a whole, so a poisoned lock still holds a valid route
+    /// and is recovered rather than panicking.
+    fn read_route(&self) -> RwLockReadGuard<'_, Route> {
+        self.route.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the steps and waypoints (excluding the starting point) of the current route.
+    fn route_steps_and_waypoints(&self) -> (Vec<RouteStep>, Vec<Waypoint>) {
+        let route = self.read_route();
+        (
+            route.steps.clone(),
+            // Skip the first waypoint, as it is the current one
+            route.waypoints.iter().skip(1).cloned().collect(),
+        )
+    }
+
+    /// Builds the state for a user at the start of the given remaining steps.
+    ///
+    /// This is shared by [`Navigator::get_initial_state`], [`Navigator::replace_route`]
+    /// and [`Navigator::resume`],
+    /// which differ only in the steps, trip summary and step advance condition they start with.
+    fn start_route(
+        &self,
+        location: UserLocation,
+        remaining_steps: Vec<RouteStep>,
+        remaining_waypoints: Vec<Waypoint>,
+        summary: TripSummary,
+        step_advance_condition: Arc<dyn StepAdvanceCondition>,
+        recent_locations: Vec<UserLocation>,
+    ) -> NavState {
+        let Some(current_route_step) = remaining_steps.first() else {
+            // Bail early; if we don't have any steps, this is a useless route
+            return NavState::complete(location, &summary);
+        };
+
+        // TODO: We could move this to the Route struct or NavigationController directly to only calculate it once.
+        let current_step_linestring = current_route_step.get_linestring();
+        let (current_step_geometry_index, snapped_user_location) = self.snap_user_to_route(
+            location,
+            &current_step_linestring,
+            &remaining_steps,
+            &recent_locations,
+            None,
+        );
+
+        let progress = calculate_trip_progress_at_index(
+            &snapped_user_location.into(),
+            &current_step_linestring,
+            current_step_geometry_index,
+            &remaining_steps,
+        );
+
+        let visual_instruction = current_route_step
+            .get_active_visual_instruction(progress.distance_to_next_maneuver)
+            .cloned();
+        let spoken_instruction = current_route_step
+            .get_current_spoken_instruction(progress.distance_to_next_maneuver)
+            .cloned();
+
+        let annotation_json = current_step_geometry_index
+            .and_then(|index| current_route_step.get_annotation_at_current_index(index));
+        let segment_annotation = current_step_geometry_index
+            .and_then(|index| current_route_step.get_segment_annotation_at_current_index(index));
+        let (speed_limit, next_speed_limit_change, is_speeding) = self.speed_limit_status(
+            &location,
+            &snapped_user_location,
+            &remaining_steps,
+            current_step_geometry_index,
+            segment_annotation,
+        );
+        let upcoming_maneuvers = self.upcoming_maneuvers(&progress, &remaining_steps);
+        let upcoming_incident = self.upcoming_incident(
+            &snapped_user_location,
+            &progress,
+            &remaining_steps,
+            current_step_geometry_index,
+            location.timestamp,
+        );
+
+        let initial_trip_state = TripState::Navigating {
+            current_step_geometry_index,
+            user_location: location,
+            snapped_user_location,
+            remaining_steps,
+            remaining_waypoints,
+            progress,
+            summary,
+            deviation: RouteDeviation::NoDeviation,
+            visual_instruction,
+            spoken_instruction,
+            annotation_json,
+            segment_annotation,
+            speed_limit,
+            next_speed_limit_change,
+            is_speeding,
+            upcoming_maneuvers,
+            upcoming_incident,
+            location_estimated: false,
+            raw_user_location: None,
+            heading: None,
+        };
+
+        let (deviation, deviation_state) = self
+            .config
+            .route_deviation_tracking
+            .check_route_deviation_with_state(
+                &self.read_route(),
+                &initial_trip_state,
+                RouteDeviationState::default(),
+            );
+
+        let trip_state = Self::with_initial_deviation(initial_trip_state, deviation);
+        let (trip_state, smoothed_speed) = self.apply_adaptive_eta(trip_state, None);
+
+        NavState::new(trip_state, step_advance_condition)
+            .with_recent_locations(recent_locations)
+            .with_deviation_state(deviation_state)
+            .with_smoothed_speed(smoothed_speed)
+    }
+
+    /// Applies the route deviation found when starting a route to its initial trip state.
+    fn with_initial_deviation(
+        initial_trip_state: TripState,
+        deviation: RouteDeviation,
+    ) -> TripState {
+        if let TripState::Navigating {
+            current_step_geometry_index,
+            user_location,
+            snapped_user_location,
+            remaining_steps,
+            remaining_waypoints,
+            progress,
+            summary,
+            visual_instruction,
+            spoken_instruction,
+            annotation_json,
+            segment_annotation,
+            speed_limit,
+            next_speed_limit_change,
+            is_speeding,
+            upcoming_maneuvers,
+            upcoming_incident,
+            ..
+        } = initial_trip_state
+        {
+            // If the user starts completely off the route, suppress instructions for the
+            // same reason as in `create_intermediate_trip_state`: the snap-derived distance
+            // to the next maneuver is geometrically unsound, so any countdown surfaced
+            // from it would mislead the user. `OffStepOnRoute` is intentionally not
+            // suppressed here — the user is still on the route polyline (just on a future
+            // step), and the step-advance flow will reconcile shortly.
+            let (visual_instruction, spoken_instruction) = if deviation.is_completely_off_route() {
+                (None, None)
+            } else {
+                (visual_instruction, spoken_instruction)
+            };
+            TripState::Navigating {
+                current_step_geometry_index,
+                user_location,
+                snapped_user_location,
+                remaining_steps,
+                remaining_waypoints,
+                progress,
+                summary,
+                deviation, // Use the newly calculated deviation
+                visual_instruction,
+                spoken_instruction,
+                annotation_json,
+                segment_annotation,
+                speed_limit,
+                next_speed_limit_change,
+                is_speeding,
+                upcoming_maneuvers,
+                upcoming_incident,
+                location_estimated: false,
+                raw_user_location: None,
+                heading: None,
+            }
+        } else {
+            unreachable!("initial_trip_state should always be Navigating variant")
+        }
+    }
+
+    /// Fills in the adaptive ETA of a navigating trip state (if enabled).
+    ///
+    /// Returns the updated trip state along with the smoothed speed to carry over to the next update.
+    fn apply_adaptive_eta(
+        &self,
+        mut trip_state: TripState,
+        previous_smoothed_speed: Option<f64>,
+    ) -> (TripState, Option<f64>) {
+        let Some(config) = &self.config.adaptive_eta else {
+            return (trip_state, None);
+        };
+        let TripState::Navigating {
+            user_location,
+            ref remaining_steps,
+            ref mut progress,
+            ..
+        } = trip_state
+        else {
+            return (trip_state, None);
+        };
+        let Some(current_step) = remaining_steps.first() else {
+            return (trip_state, None);
+        };
+
+        let smoothed_speed = update_smoothed_speed(
+            previous_smoothed_speed,
+            &user_location,
+            current_step,
+            config.smoothing_factor,
+        );
+        progress.adaptive_duration_remaining = smoothed_speed.map(|speed| {
+            calculate_adaptive_duration_remaining(
+                progress,
+                remaining_steps,
+                speed.max(config.minimum_speed),
+            )
+        });
+
+        (trip_state, smoothed_speed)
+    }
+
+    /// Create an intermediate trip state with updated values,
+    /// but does _not_ advance to the next step or handle arrival.
+    ///
+    /// Parameters:
+    /// - `trip_state`: The existing/last trip state.
+    /// - `location`: The user's current location.
+    /// - `recent_locations`: The recent location history used for map matching (if enabled).
+    /// - `remaining_steps`: The remaining route steps (the first of which is the current step).
+    /// - `remaining_waypoints`: The remaining waypoints.
+    ///
+    /// Returns:
+    /// - `TripState`: The intermediate trip state.
+    fn create_intermediate_trip_state(
+        &self,
+        trip_state: TripState,
+        current_user_location: UserLocation,
+        recent_locations: &[UserLocation],
+        remaining_steps: Vec<RouteStep>,
+        remaining_waypoints: Vec<Waypoint>,
+        deviation: RouteDeviation,
+    ) -> TripState {
+        let Some(current_step) = remaining_steps.first().cloned() else {
+            // Nothing to snap to; callers always pass at least the current step.
+            return trip_state;
+        };
+
+        match trip_state {
+            TripState::Navigating {
+                user_location: previous_user_location,
+                snapped_user_location: previous_snapped_user_location,
+                summary: previous_summary,
+                raw_user_location,
+                heading,
+                ..
+            } => {
+                // Find the user's position on the route line
+                let current_step_linestring = current_step.get_linestring();
+                let (current_step_geometry_index, snapped_user_location) = self.snap_user_to_route(
+                    current_user_location,
+                    &current_step_linestring,
+                    &remaining_steps,
+                    recent_locations,
+                    heading,
+                );
+
+                // Update trip summary with accumulated distance
+                let updated_summary = previous_summary.update(
+                    &previous_user_location,
+                    &current_user_location,
+                    &previous_snapped_user_location,
+                    &snapped_user_location,
+                );
+
+                let progress = calculate_trip_progress_at_index(
+                    &snapped_user_location.into(),
                     &current_step_linestring,
+                    current_step_geometry_index,
                     &remaining_steps,
                 );
 
@@ -415,6 +1071,23 @@ impl NavigationController {
                     };
                 let annotation_json = current_step_geometry_index
                     .and_then(|index| current_step.get_annotation_at_current_index(index));
+                let segment_annotation = current_step_geometry_index
+                    .and_then(|index| current_step.get_segment_annotation_at_current_index(index));
+                let (speed_limit, next_speed_limit_change, is_speeding) = self.speed_limit_status(
+                    &current_user_location,
+                    &snapped_user_location,
+                    &remaining_steps,
+                    current_step_geometry_index,
+                    segment_annotation,
+                );
+                let upcoming_maneuvers = self.upcoming_maneuvers(&progress, &remaining_steps);
+                let upcoming_incident = self.upcoming_incident(
+                    &snapped_user_location,
+                    &progress,
+                    &remaining_steps,
+                    current_step_geometry_index,
+                    current_user_location.timestamp,
+                );
 
                 TripState::Navigating {
                     current_step_geometry_index,
@@ -428,13 +1101,83 @@ impl NavigationController {
                     visual_instruction,
                     spoken_instruction,
                     annotation_json,
+                    segment_annotation,
+                    speed_limit,
+                    next_speed_limit_change,
+                    is_speeding,
+                    upcoming_maneuvers,
+                    upcoming_incident,
+                    location_estimated: false,
+                    raw_user_location,
+                    heading,
                 }
             }
             // Pass through
-            TripState::Idle { .. } | TripState::Complete { .. } => trip_state,
+            TripState::Idle { .. } | TripState::Paused { .. } | TripState::Complete { .. } => {
+                trip_state
+            }
         }
     }
 
+    /// Determines the current speed limit, the next change of the speed limit,
+    /// and whether the user is speeding.
+    fn speed_limit_status(
+        &self,
+        user_location: &UserLocation,
+        snapped_user_location: &UserLocation,
+        remaining_steps: &[RouteStep],
+        current_step_geometry_index: Option<u64>,
+        segment_annotation: Option<SegmentAnnotation>,
+    ) -> (Option<SegmentMaxSpeed>, Option<SpeedLimitChange>, bool) {
+        let speed_limit = segment_annotation.and_then(|annotation| annotation.max_speed);
+        let next_speed_limit_change = next_speed_limit_change(
+            Point::from(*snapped_user_location),
+            remaining_steps,
+            current_step_geometry_index,
+            speed_limit,
+        );
+        let is_speeding = self.config.speeding.is_some_and(|config| {
+            let limit = speed_limit.and_then(|speed_limit| speed_limit.meters_per_second());
+            match (user_location.speed, limit) {
+                (Some(speed), Some(limit)) => speed.value > limit + config.tolerance,
+                _ => false,
+            }
+        });
+
+        (speed_limit, next_speed_limit_change, is_speeding)
+    }
+
+    /// Lists the upcoming maneuvers, if enabled.
+    fn upcoming_maneuvers(
+        &self,
+        progress: &TripProgress,
+        remaining_steps: &[RouteStep],
+    ) -> Vec<UpcomingManeuver> {
+        self.config
+            .maneuver_preview
+            .map(|config| upcoming_maneuvers(progress, remaining_steps, config))
+            .unwrap_or_default()
+    }
+
+    /// Finds the incident the user is in or the nearest one ahead, if enabled.
+    fn upcoming_incident(
+        &self,
+        snapped_user_location: &UserLocation,
+        progress: &TripProgress,
+        remaining_steps: &[RouteStep],
+        current_step_geometry_index: Option<u64>,
+        timestamp: SystemTime,
+    ) -> Option<UpcomingIncident> {
+        upcoming_incident(
+            Point::from(*snapped_user_location),
+            progress,
+            remaining_steps,
+            current_step_geometry_index,
+            date_time(timestamp),
+            self.config.incidents?,
+        )
+    }
+
     /// Snaps the user's location to the route line and updates the user's course if necessary.
     ///
     /// This bundles all work related to snapping the user's location to the route line and is not intended to be exported.
@@ -444,6 +1187,7 @@ impl NavigationController {
         &self,
         location: UserLocation,
         line: &LineString,
+        heading: Option<Heading>,
     ) -> (Option<u64>, UserLocation) {
         // Snap the user's latitude and longitude to the line.
         let snapped_user_location = snap_user_location_to_line(location, line);
@@ -452,16 +1196,124 @@ impl NavigationController {
         let current_step_geometry_index =
             index_of_closest_segment_origin(snapped_user_location, line);
 
-        // Snap the user's course to the line if the configuration specifies it.
-        let snapped_with_course: UserLocation = match &self.config.snapped_location_course_filtering
-        {
+        (
+            current_step_geometry_index,
+            self.filter_snapped_course(
+                snapped_user_location,
+                current_step_geometry_index,
+                line,
+                heading,
+            ),
+        )
+    }
+
+    /// Snaps the user's location to the current step using map matching when configured,
+    /// falling back to [`Self::snap_user_to_line`].
+    ///
+    /// The map matcher considers the current step and the steps starting within a kilometer after it
+    /// (see [`crate::map_matching`]).
+    /// When the best match lies on a later step,
+    /// the user is placed at the end of the current step,
+    /// so that step advance can catch up.
+    fn snap_user_to_route(
+        &self,
+        location: UserLocation,
+        line: &LineString,
+        remaining_steps: &[RouteStep],
+        recent_locations: &[UserLocation],
+        heading: Option<Heading>,
+    ) -> (Option<u64>, UserLocation) {
+        let route_match =
+            self.config.map_matching.as_ref().and_then(|config| {
+                match_location_to_route(recent_locations, remaining_steps, config)
+            });
+        let Some(route_match) = route_match else {
+            return self.snap_user_to_line(location, line, heading);
+        };
+
+        let (segment_index, coordinates) = if route_match.step_index == 0 {
+            (route_match.segment_index, route_match.coordinate)
+        } else {
+            let last_coordinate =
+                line.0
+                    .last()
+                    .map_or(location.coordinates, |coord| GeographicCoordinate {
+                        lng: coord.x,
+                        lat: coord.y,
+                    });
+            (line.0.len().saturating_sub(2), last_coordinate)
+        };
+        let current_step_geometry_index = Some(segment_index as u64);
+        let snapped_user_location = UserLocation {
+            coordinates,
+            ..location
+        };
+
+        (
+            current_step_geometry_index,
+            self.filter_snapped_course(
+                snapped_user_location,
+                current_step_geometry_index,
+                line,
+                heading,
+            ),
+        )
+    }
+
+    /// Snaps the user's course to the line if the configuration specifies it.
+    fn filter_snapped_course(
+        &self,
+        snapped_user_location: UserLocation,
+        current_step_geometry_index: Option<u64>,
+        line: &LineString,
+        heading: Option<Heading>,
+    ) -> UserLocation {
+        match &self.config.snapped_location_course_filtering {
             models::CourseFiltering::SnapToRoute => {
                 apply_snapped_course(snapped_user_location, current_step_geometry_index, line)
             }
+            models::CourseFiltering::SnapToRouteWithHeading { maximum_speed } => {
+                let is_slow = snapped_user_location
+                    .speed
+                    .is_none_or(|speed| speed.value < *maximum_speed);
+                match heading_course(heading, &snapped_user_location) {
+                    Some(course_over_ground) if is_slow => UserLocation {
+                        course_over_ground: Some(course_over_ground),
+                        ..snapped_user_location
+                    },
+                    _ => apply_snapped_course(
+                        snapped_user_location,
+                        current_step_geometry_index,
+                        line,
+                    ),
+                }
+            }
             models::CourseFiltering::Raw => snapped_user_location,
+        }
+    }
+
+    /// Appends a location to the map matching history, keeping only the configured number of fixes.
+    ///
+    /// The history is left empty when map matching is disabled.
+    fn updated_recent_locations(
+        &self,
+        recent_locations: &[UserLocation],
+        location: UserLocation,
+    ) -> Vec<UserLocation> {
+        let Some(config) = &self.config.map_matching else {
+            return Vec::new();
         };
 
-        (current_step_geometry_index, snapped_with_course)
+        let mut recent_locations = recent_locations.to_vec();
+        // The same location is passed again when recursing after a step advance.
+        if recent_locations.last() != Some(&location) {
+            recent_locations.push(location);
+        }
+        let excess = recent_locations
+            .len()
+            .saturating_sub(usize::from(config.history_size.max(1)));
+        recent_locations.drain(..excess);
+        recent_locations
     }
 
     /// Process waypoint advance
@@ -536,24 +1388,85 @@ impl JsNavigationController {
         serde_wasm_bindgen::to_value(&SerializableNavState::from(new_state))
             .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::step_advance::StepAdvanceCondition;
-    use super::*;
-    use crate::navigation_controller::step_advance::conditions::{
-        DistanceEntryAndExitCondition, DistanceToEndOfStepCondition,
-    };
-    use crate::navigation_controller::test_helpers::{
-        get_test_navigation_controller_config, nav_controller_insta_settings,
-    };
-    use crate::routing_adapters::osrm::models::OsrmWaypointProperties;
-    use crate::simulation::{
-        LocationBias, advance_location_simulation, location_simulation_from_route,
-    };
-    use crate::test_utils::{TestRoute, redact_properties};
-    use std::sync::Arc;
+    #[wasm_bindgen(js_name = replaceRoute)]
+    pub fn replace_route(&self, route: JsValue, state: JsValue) -> Result<JsValue, JsValue> {
+        let route: Route = serde_wasm_bindgen::from_value(route)?;
+        let state: SerializableNavState = serde_wasm_bindgen::from_value(state)?;
+        let new_state = self.0.replace_route(route, state.into());
+
+        serde_wasm_bindgen::to_value(&SerializableNavState::from(new_state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    pub fn pause(&self, state: JsValue) -> Result<JsValue, JsValue> {
+        let state: SerializableNavState = serde_wasm_bindgen::from_value(state)?;
+        let new_state = self.0.pause(state.into());
+
+        serde_wasm_bindgen::to_value(&SerializableNavState::from(new_state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    pub fn resume(&self, state: JsValue) -> Result<JsValue, JsValue> {
+        let state: SerializableNavState = serde_wasm_bindgen::from_value(state)?;
+        let new_state = self.0.resume(state.into());
+
+        serde_wasm_bindgen::to_value(&SerializableNavState::from(new_state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    pub fn cancel(&self, state: JsValue, reason: JsValue) -> Result<JsValue, JsValue> {
+        let state: SerializableNavState = serde_wasm_bindgen::from_value(state)?;
+        let reason: CancellationReason = serde_wasm_bindgen::from_value(reason)?;
+        let new_state = self.0.cancel(state.into(), reason);
+
+        serde_wasm_bindgen::to_value(&SerializableNavState::from(new_state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    /// Estimates the user's location at the given time (in milliseconds since the Unix epoch).
+    #[wasm_bindgen(js_name = estimateLocation)]
+    pub fn estimate_location(&self, timestamp: f64, state: JsValue) -> Result<JsValue, JsValue> {
+        let since_epoch = Duration::try_from_secs_f64(timestamp / 1000.0)
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))?;
+        let state: SerializableNavState = serde_wasm_bindgen::from_value(state)?;
+        let new_state = self
+            .0
+            .estimate_location(SystemTime::UNIX_EPOCH + since_epoch, state.into());
+
+        serde_wasm_bindgen::to_value(&SerializableNavState::from(new_state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+
+    /// Updates the device heading (ex: from a compass).
+    #[wasm_bindgen(js_name = updateHeading)]
+    pub fn update_heading(&self, heading: JsValue, state: JsValue) -> Result<JsValue, JsValue> {
+        let heading: Heading = serde_wasm_bindgen::from_value(heading)?;
+        let state: SerializableNavState = serde_wasm_bindgen::from_value(state)?;
+        let new_state = self.0.update_heading(heading, state.into());
+
+        serde_wasm_bindgen::to_value(&SerializableNavState::from(new_state))
+            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::step_advance::StepAdvanceCondition;
+    use super::*;
+    use crate::navigation_controller::step_advance::conditions::{
+        DistanceEntryAndExitCondition, DistanceToEndOfStepCondition,
+    };
+    use crate::navigation_controller::test_helpers::{
+        get_test_navigation_controller, get_test_navigation_controller_config,
+        nav_controller_insta_settings,
+    };
+    use crate::routing_adapters::osrm::models::OsrmWaypointProperties;
+    use crate::simulation::{
+        LocationBias, advance_location_simulation, location_simulation_from_route,
+    };
+    use crate::test_utils::{TestRoute, redact_properties};
+    use std::sync::Arc;
 
     fn test_full_route_state_snapshot(
         route: Route,
@@ -578,7 +1491,7 @@ mod tests {
                 controller.update_user_location(new_simulation_state.current_location, state);
 
             match new_state.trip_state() {
-                TripState::Idle { .. } => {}
+                TripState::Idle { .. } | TripState::Paused { .. } => {}
                 TripState::Navigating {
                     current_step_geometry_index,
                     ref remaining_steps,
@@ -764,6 +1677,15 @@ mod tests {
                 max_acceptable_deviation: 30.0,
             },
             snapped_location_course_filtering: CourseFiltering::Raw,
+            map_matching: None,
+            adaptive_eta: None,
+            dead_reckoning: None,
+            location_filter: None,
+            location_smoothing: None,
+            step_regression: None,
+            speeding: None,
+            maneuver_preview: None,
+            incidents: None,
             step_advance_condition: Arc::new(ManualStepCondition),
             arrival_step_advance_condition: Arc::new(ManualStepCondition),
         };
@@ -885,6 +1807,15 @@ mod tests {
                 max_acceptable_deviation: 30.0,
             },
             snapped_location_course_filtering: CourseFiltering::Raw,
+            map_matching: None,
+            adaptive_eta: None,
+            dead_reckoning: None,
+            location_filter: None,
+            location_smoothing: None,
+            step_regression: None,
+            speeding: None,
+            maneuver_preview: None,
+            incidents: None,
             step_advance_condition: Arc::new(ManualStepCondition),
             arrival_step_advance_condition: Arc::new(ManualStepCondition),
         };
@@ -955,6 +1886,15 @@ mod tests {
             waypoint_advance: WaypointAdvanceMode::WaypointWithinRange(100.0),
             route_deviation_tracking: RouteDeviationTracking::None,
             snapped_location_course_filtering: CourseFiltering::Raw,
+            map_matching: None,
+            adaptive_eta: None,
+            dead_reckoning: None,
+            location_filter: None,
+            location_smoothing: None,
+            step_regression: None,
+            speeding: None,
+            maneuver_preview: None,
+            incidents: None,
             step_advance_condition: Arc::clone(&pre_latched),
             arrival_step_advance_condition: Arc::clone(&pre_latched),
         };
@@ -983,4 +1923,1373 @@ mod tests {
             other => panic!("expected DistanceEntryExit, got {other:?}"),
         }
     }
+
+    #[test]
+    fn test_replace_route_preserves_trip_history() {
+        use crate::algorithms::snap_user_location_to_line;
+
+        let route = TestRoute::Valhalla.first_route();
+        let new_route = TestRoute::ValhallaExtended.first_route();
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+
+        let controller = create_navigator(
+            route,
+            get_test_navigation_controller_config(Arc::new(DistanceToEndOfStepCondition {
+                distance: 5,
+                minimum_horizontal_accuracy: 0,
+            })),
+            false,
+        );
+
+        let mut state = controller.get_initial_state(simulation_state.current_location);
+        for _ in 0..10 {
+            simulation_state = advance_location_simulation(&simulation_state);
+            state = controller.update_user_location(simulation_state.current_location, state);
+        }
+
+        let TripState::Navigating {
+            user_location,
+            summary: previous_summary,
+            ..
+        } = state.trip_state()
+        else {
+            panic!("Expected to be navigating");
+        };
+        assert!(previous_summary.distance_traveled > 0.0);
+        let step_advance_condition = state.step_advance_condition();
+
+        let replaced = controller.replace_route(new_route.clone(), state);
+        assert_eq!(controller.route().geometry, new_route.geometry);
+        // The step advance condition keeps its state
+        assert!(Arc::ptr_eq(
+            &replaced.step_advance_condition(),
+            &step_advance_condition
+        ));
+
+        match replaced.trip_state() {
+            TripState::Navigating {
+                snapped_user_location,
+                remaining_steps,
+                remaining_waypoints,
+                summary,
+                ..
+            } => {
+                assert_eq!(summary, previous_summary);
+                assert_eq!(remaining_steps, new_route.steps);
+                assert_eq!(remaining_waypoints, new_route.waypoints[1..]);
+                // The user is re-snapped to the first step of the new route.
+                assert_eq!(
+                    snapped_user_location.coordinates,
+                    snap_user_location_to_line(user_location, &new_route.steps[0].get_linestring())
+                        .coordinates
+                );
+            }
+            other => panic!("Expected Navigating, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_replace_route_reports_events() {
+        use crate::navigation_controller::events::NavigationEvent;
+        use crate::navigation_controller::step_advance::conditions::ManualStepCondition;
+        use crate::test_utils::make_user_location;
+
+        let route = TestRoute::Valhalla.first_route();
+        // Parsed again, so the instructions have new utterance IDs
+        let new_route = TestRoute::Valhalla.first_route();
+        let location = make_user_location(route.geometry[0].into(), 5.0);
+        let controller = create_navigator(
+            route,
+            get_test_navigation_controller_config(Arc::new(ManualStepCondition)),
+            false,
+        );
+
+        let state = controller.get_initial_state(location);
+        let state = controller.replace_route(new_route.clone(), state);
+        let spoken_instruction = new_route.steps[0].spoken_instructions[0].clone();
+        assert!(
+            state
+                .events()
+                .contains(&NavigationEvent::SpokenInstructionTriggered {
+                    instruction: spoken_instruction,
+                })
+        );
+
+        // Instructions announced before the reroute are not announced again
+        let state = controller.update_user_location(location, state);
+        assert!(
+            !state
+                .events()
+                .iter()
+                .any(|event| matches!(event, NavigationEvent::SpokenInstructionTriggered { .. }))
+        );
+    }
+
+    #[test]
+    fn test_pause_freezes_progress_until_resumed() {
+        let route = TestRoute::Valhalla.first_route();
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+
+        let controller = create_navigator(
+            route,
+            get_test_navigation_controller_config(Arc::new(DistanceToEndOfStepCondition {
+                distance: 5,
+                minimum_horizontal_accuracy: 0,
+            })),
+            false,
+        );
+
+        let mut state = controller.get_initial_state(simulation_state.current_location);
+        for _ in 0..5 {
+            simulation_state = advance_location_simulation(&simulation_state);
+            state = controller.update_user_location(simulation_state.current_location, state);
+        }
+        let TripState::Navigating {
+            remaining_steps: previous_steps,
+            progress: previous_progress,
+            summary: previous_summary,
+            ..
+        } = state.trip_state()
+        else {
+            panic!("Expected to be navigating");
+        };
+
+        state = controller.pause(state);
+        // Keep moving along the route while paused
+        for _ in 0..10 {
+            simulation_state = advance_location_simulation(&simulation_state);
+            state = controller.update_user_location(simulation_state.current_location, state);
+        }
+        // Advancing manually has no effect while paused
+        state = controller.advance_to_next_step(state);
+
+        let TripState::Paused {
+            user_location,
+            remaining_steps,
+            progress,
+            summary,
+            ..
+        } = state.trip_state()
+        else {
+            panic!("Expected to be paused");
+        };
+        assert_eq!(user_location, simulation_state.current_location);
+        assert_eq!(remaining_steps, previous_steps);
+        assert_eq!(progress, previous_progress);
+        assert_eq!(summary, previous_summary);
+        assert_eq!(state.trip_state().current_step(), None);
+        assert!(state.trip_state().deviation().is_none());
+
+        // Pausing again is a no-op
+        let paused_trip_state = state.trip_state();
+        state = controller.pause(state);
+        assert_eq!(state.trip_state(), paused_trip_state);
+
+        let resumed = controller.resume(state);
+        let TripState::Navigating {
+            progress, summary, ..
+        } = resumed.trip_state()
+        else {
+            panic!("Expected to be navigating");
+        };
+        assert_eq!(summary, previous_summary);
+        // Progress picks up from where the user resumed.
+        assert!(progress.distance_remaining < previous_progress.distance_remaining);
+    }
+
+    #[test]
+    fn test_pause_keeps_deviation_and_smoothing_state() {
+        use crate::deviation_detection::RouteDeviationState;
+        use crate::location_smoothing::LocationSmoothing;
+        use crate::models::ModeOfTravel;
+        use crate::navigation_controller::models::AdaptiveEtaConfig;
+
+        let route = TestRoute::Valhalla.first_route();
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+
+        let controller = get_test_navigation_controller(route, |config| {
+            config.adaptive_eta = Some(AdaptiveEtaConfig::default());
+            config.location_smoothing = Some(LocationSmoothing::DefaultFor {
+                mode_of_travel: ModeOfTravel::Driving,
+            });
+        });
+
+        let mut state = controller.get_initial_state(simulation_state.current_location);
+        for _ in 0..5 {
+            simulation_state = advance_location_simulation(&simulation_state);
+            state = controller.update_user_location(simulation_state.current_location, state);
+        }
+        let deviation_state = RouteDeviationState {
+            off_route_since: Some(simulation_state.current_location),
+            wrong_direction_since: None,
+        };
+        let state = state.with_deviation_state(deviation_state);
+        let smoothed_speed = state.smoothed_speed();
+        let location_smoothing_state = state.location_smoothing_state();
+        assert!(smoothed_speed.is_some());
+        assert!(location_smoothing_state.is_some());
+
+        let mut state = controller.pause(state);
+        // Location updates while paused leave the state untouched
+        for _ in 0..5 {
+            simulation_state = advance_location_simulation(&simulation_state);
+            state = controller.update_user_location(simulation_state.current_location, state);
+        }
+        assert_eq!(state.deviation_state(), deviation_state);
+        assert_eq!(state.smoothed_speed(), smoothed_speed);
+        assert_eq!(state.location_smoothing_state(), location_smoothing_state);
+
+        let resumed = controller.resume(state);
+        assert_eq!(resumed.deviation_state(), deviation_state);
+        assert_eq!(resumed.smoothed_speed(), smoothed_speed);
+        assert_eq!(resumed.location_smoothing_state(), location_smoothing_state);
+    }
+
+    #[test]
+    fn test_cancel_ends_trip_with_reason() {
+        let route = TestRoute::Valhalla.first_route();
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+
+        let controller = create_navigator(
+            route,
+            get_test_navigation_controller_config(Arc::new(DistanceToEndOfStepCondition {
+                distance: 5,
+                minimum_horizontal_accuracy: 0,
+            })),
+            false,
+        );
+
+        let mut state = controller.get_initial_state(simulation_state.current_location);
+        for _ in 0..5 {
+            simulation_state = advance_location_simulation(&simulation_state);
+            state = controller.update_user_location(simulation_state.current_location, state);
+        }
+        let TripState::Navigating {
+            summary: previous_summary,
+            ..
+        } = state.trip_state()
+        else {
+            panic!("Expected to be navigating");
+        };
+
+        let reason = CancellationReason::Other {
+            description: "Road closed".into(),
+        };
+        let canceled = controller.cancel(controller.pause(state), reason.clone());
+        let TripState::Complete {
+            user_location,
+            summary,
+            cancellation_reason,
+        } = canceled.trip_state()
+        else {
+            panic!("Expected the trip to be complete");
+        };
+        assert_eq!(user_location, simulation_state.current_location);
+        assert_eq!(cancellation_reason, Some(reason));
+        assert_eq!(
+            summary.distance_traveled,
+            previous_summary.distance_traveled
+        );
+        assert!(summary.ended_at.is_some());
+
+        // Canceling an ended trip is a no-op
+        let ended_trip_state = canceled.trip_state();
+        assert_eq!(
+            controller
+                .cancel(canceled, CancellationReason::UserCanceled)
+                .trip_state(),
+            ended_trip_state
+        );
+    }
+
+    #[test]
+    fn test_next_step_bearing_condition_completes_trip() {
+        use crate::navigation_controller::step_advance::conditions::{
+            AndAdvanceConditions, NextStepBearingCondition,
+        };
+
+        let route = TestRoute::Valhalla.first_route();
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+        let step_advance_condition: Arc<dyn StepAdvanceCondition> =
+            Arc::new(AndAdvanceConditions {
+                conditions: vec![
+                    Arc::new(DistanceToEndOfStepCondition {
+                        distance: 5,
+                        minimum_horizontal_accuracy: 0,
+                    }),
+                    Arc::new(NextStepBearingCondition {
+                        max_bearing_deviation: 45,
+                        minimum_course_accuracy: 10,
+                    }),
+                ],
+            });
+        let controller = NavigationController::new(
+            route,
+            NavigationControllerConfig {
+                arrival_step_advance_condition: Arc::clone(&step_advance_condition),
+                ..get_test_navigation_controller_config(step_advance_condition)
+            },
+        );
+
+        let mut state = controller.get_initial_state(simulation_state.current_location);
+        for _ in 0..10_000 {
+            if matches!(state.trip_state(), TripState::Complete { .. }) {
+                break;
+            }
+            simulation_state = advance_location_simulation(&simulation_state);
+            state = controller.update_user_location(simulation_state.current_location, state);
+        }
+        assert!(
+            matches!(state.trip_state(), TripState::Complete { .. }),
+            "Expected the trip to complete"
+        );
+    }
+
+    #[test]
+    fn test_next_step_bearing_condition_waits_for_turn() {
+        use crate::deviation_detection::RouteDeviationTracking;
+        use crate::models::CourseOverGround;
+        use crate::navigation_controller::step_advance::conditions::{
+            AndAdvanceConditions, NextStepBearingCondition,
+        };
+        use crate::navigation_controller::test_helpers::{
+            gen_route_from_steps, gen_route_step_with_coords,
+        };
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+        use std::time::Duration;
+
+        // Roughly 222m east, then a left turn to the north
+        let corner = coord!(x: 0.002, y: 0.0);
+        let destination = coord!(x: 0.002, y: 0.002);
+        let route = gen_route_from_steps(vec![
+            gen_route_step_with_coords(vec![coord!(x: 0.0, y: 0.0), corner]),
+            gen_route_step_with_coords(vec![corner, destination]),
+            gen_route_step_with_coords(vec![destination, destination]),
+        ]);
+
+        // One fix per second at 10m/s, with the course a GPS would report
+        let meters_per_degree = 111_195.0;
+        let turn_distance = corner.x * meters_per_degree;
+        let start_time = SystemTime::now();
+        let locations: Vec<_> = (0..30_u32)
+            .map(|second| {
+                let distance = 10.0 * f64::from(second);
+                let (coord, course) = if distance <= turn_distance {
+                    (coord!(x: distance / meters_per_degree, y: 0.0), 90.0)
+                } else {
+                    let distance_after_turn = (distance - turn_distance) / meters_per_degree;
+                    (coord!(x: corner.x, y: distance_after_turn), 0.0)
+                };
+                UserLocation {
+                    course_over_ground: Some(CourseOverGround::new(course, Some(5))),
+                    timestamp: start_time + Duration::from_secs(second.into()),
+                    ..make_user_location(coord, 5.0)
+                }
+            })
+            .collect();
+        let first_fix_after_turn = locations
+            .iter()
+            .position(|location| location.coordinates.lat > 0.0)
+            .expect("Expected the user to turn");
+
+        // Returns the index of the fix which advanced past the first step
+        let advancing_fix = |step_advance_condition: Arc<dyn StepAdvanceCondition>| {
+            let controller = get_test_navigation_controller(route.clone(), |config| {
+                config.route_deviation_tracking = RouteDeviationTracking::None;
+                config.step_advance_condition = step_advance_condition;
+            });
+            let mut state = controller.get_initial_state(locations[0]);
+            locations
+                .iter()
+                .skip(1)
+                .position(|location| {
+                    state = controller.update_user_location(*location, state.clone());
+                    match state.trip_state() {
+                        TripState::Navigating {
+                            remaining_steps, ..
+                        } => remaining_steps.len() < 3,
+                        _ => true,
+                    }
+                })
+                .map(|index| index + 1)
+        };
+        let distance_condition = DistanceToEndOfStepCondition {
+            distance: 20,
+            minimum_horizontal_accuracy: 10,
+        };
+
+        // Distance alone advances while the user is still approaching the junction
+        let distance_fix = advancing_fix(Arc::new(distance_condition))
+            .expect("Expected the distance condition to advance");
+        assert!(distance_fix < first_fix_after_turn);
+
+        // Confirming the bearing waits for the first fix after the turn
+        let bearing_fix = advancing_fix(Arc::new(AndAdvanceConditions {
+            conditions: vec![
+                Arc::new(distance_condition),
+                Arc::new(NextStepBearingCondition {
+                    max_bearing_deviation: 30,
+                    minimum_course_accuracy: 10,
+                }),
+            ],
+        }));
+        assert_eq!(bearing_fix, Some(first_fix_after_turn));
+    }
+
+    #[test]
+    fn test_navigation_events_over_full_route() {
+        use crate::navigation_controller::events::NavigationEvent;
+
+        let route = TestRoute::Valhalla.first_route();
+        let step_count = route.steps.len();
+        let all_utterance_ids: Vec<_> = route
+            .steps
+            .iter()
+            .flat_map(|step| &step.spoken_instructions)
+            .map(|instruction| instruction.utterance_id)
+            .collect();
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+
+        let controller = create_navigator(
+            route,
+            get_test_navigation_controller_config(Arc::new(DistanceToEndOfStepCondition {
+                distance: 5,
+                minimum_horizontal_accuracy: 0,
+            })),
+            false,
+        );
+
+        let mut state = controller.get_initial_state(simulation_state.current_location);
+        let mut events = state.events().to_vec();
+        loop {
+            simulation_state = advance_location_simulation(&simulation_state);
+            state = controller.update_user_location(simulation_state.current_location, state);
+            events.extend_from_slice(state.events());
+            if matches!(state.trip_state(), TripState::Complete { .. }) {
+                break;
+            }
+        }
+
+        let steps_advanced = events
+            .iter()
+            .filter(|event| matches!(event, NavigationEvent::StepAdvanced { .. }))
+            .count();
+        assert_eq!(steps_advanced, step_count - 1);
+        // Utterances are announced at most once, in order
+        // (the test config is strict about deviations, so a few are suppressed).
+        let announced: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                NavigationEvent::SpokenInstructionTriggered { instruction } => {
+                    Some(instruction.utterance_id)
+                }
+                _ => None,
+            })
+            .collect();
+        let expected: Vec<_> = all_utterance_ids
+            .into_iter()
+            .filter(|id| announced.contains(id))
+            .collect();
+        assert_eq!(announced, expected);
+        assert!(matches!(
+            events.last(),
+            Some(NavigationEvent::Arrived { .. })
+        ));
+
+        // Updates on a completed trip don't repeat the arrival
+        let state = controller.update_user_location(simulation_state.current_location, state);
+        assert_eq!(state.events(), []);
+    }
+
+    #[test]
+    fn test_spoken_instructions_announced_across_speed_run() {
+        use crate::deviation_detection::RouteDeviationTracking;
+        use crate::navigation_controller::events::NavigationEvent;
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+
+        let route = TestRoute::Valhalla.first_route();
+        let start = route.steps[0].geometry[0];
+        // The first few steps after the start are short, so they are all passed in one update.
+        let end_of_third_step = *route.steps[2].geometry.last().unwrap();
+        let end_of_third_step = make_user_location(
+            coord!(x: end_of_third_step.lng, y: end_of_third_step.lat),
+            0.0,
+        );
+
+        let controller = create_navigator(
+            route.clone(),
+            NavigationControllerConfig {
+                // Jumping ahead is not a deviation here
+                route_deviation_tracking: RouteDeviationTracking::None,
+                ..get_test_navigation_controller_config(Arc::new(DistanceToEndOfStepCondition {
+                    distance: 30,
+                    minimum_horizontal_accuracy: 0,
+                }))
+            },
+            false,
+        );
+
+        let state = controller
+            .get_initial_state(make_user_location(coord!(x: start.lng, y: start.lat), 0.0));
+        let mut events = state.events().to_vec();
+        let state = controller.update_user_location(end_of_third_step, state);
+        events.extend_from_slice(state.events());
+
+        let TripState::Navigating {
+            remaining_steps, ..
+        } = state.trip_state()
+        else {
+            panic!("Expected to be navigating");
+        };
+        let steps_passed = route.steps.len() - remaining_steps.len();
+        assert!(steps_passed >= 3);
+
+        // Every instruction on the first three steps is due by the time they are passed
+        // (the user snaps to the end of each), so all of them are announced, in order,
+        // rather than only the last one.
+        let expected: Vec<_> = route.steps[..3]
+            .iter()
+            .flat_map(|step| step.spoken_instructions.clone())
+            .collect();
+        let announced: Vec<_> = events
+            .into_iter()
+            .filter_map(|event| match event {
+                NavigationEvent::SpokenInstructionTriggered { instruction } => Some(instruction),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(announced[..expected.len()], expected);
+
+        // Nothing is repeated on the next update
+        let state = controller.update_user_location(end_of_third_step, state);
+        assert!(
+            !state
+                .events()
+                .iter()
+                .any(|event| matches!(event, NavigationEvent::SpokenInstructionTriggered { .. }))
+        );
+    }
+
+    #[test]
+    fn test_manual_advance_announces_instructions_due_on_passed_step() {
+        use crate::navigation_controller::events::NavigationEvent;
+        use crate::navigation_controller::step_advance::conditions::ManualStepCondition;
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+
+        let route = TestRoute::Valhalla.first_route();
+        let end_of_first_step = *route.steps[0].geometry.last().unwrap();
+        let end_of_first_step = make_user_location(
+            coord!(x: end_of_first_step.lng, y: end_of_first_step.lat),
+            0.0,
+        );
+
+        let controller = get_test_navigation_controller(route.clone(), |_| {});
+        // A state without any announcement history (ex: restored from a cached session),
+        // where every instruction on the first step is due.
+        let state = NavState::new(
+            controller.get_initial_state(end_of_first_step).trip_state(),
+            Arc::new(ManualStepCondition),
+        );
+
+        let state = controller.advance_to_next_step(state);
+        let announced: Vec<_> = state
+            .events()
+            .iter()
+            .filter_map(|event| match event {
+                NavigationEvent::SpokenInstructionTriggered { instruction } => Some(instruction),
+                _ => None,
+            })
+            .cloned()
+            .collect();
+        let expected = &route.steps[0].spoken_instructions;
+        assert_eq!(announced[..expected.len()], expected[..]);
+    }
+
+    #[test]
+    fn test_self_intersecting_map_matching() {
+        use crate::navigation_controller::models::MapMatchingConfig;
+        use geo::{Distance, Haversine, Point};
+
+        let route = TestRoute::ValhallaSelfIntersecting.first_route();
+        let route_distance: f64 = route.steps.iter().map(|step| step.distance).sum();
+        let mut simulation_state =
+            location_simulation_from_route(&route, Some(10.0), LocationBias::None)
+                .expect("Unable to create simulation");
+
+        let controller = create_navigator(
+            route,
+            NavigationControllerConfig {
+                map_matching: Some(MapMatchingConfig::default()),
+                ..get_test_navigation_controller_config(Arc::new(
+                    DistanceEntryAndExitCondition::exact(),
+                ))
+            },
+            false,
+        );
+
+        let mut state = controller.get_initial_state(simulation_state.current_location);
+        let mut distance_traveled = 0.0;
+        loop {
+            let previous_location = simulation_state.current_location;
+            simulation_state = advance_location_simulation(&simulation_state);
+            distance_traveled += Haversine.distance(
+                Point::from(previous_location),
+                Point::from(simulation_state.current_location),
+            );
+            state = controller.update_user_location(simulation_state.current_location, state);
+
+            match state.trip_state() {
+                TripState::Navigating {
+                    current_step_geometry_index,
+                    ref remaining_steps,
+                    ref progress,
+                    ref deviation,
+                    ..
+                } => {
+                    let index = current_step_geometry_index.expect("Expected a geometry index");
+                    assert!(index < remaining_steps[0].geometry.len() as u64);
+                    assert!(!deviation.is_completely_off_route());
+                    // Matched to the current pass through the loop, not the other one
+                    let distance_along_route = route_distance - progress.distance_remaining;
+                    assert!((distance_along_route - distance_traveled).abs() < 5.0);
+                    assert!(
+                        state.recent_locations().len()
+                            <= MapMatchingConfig::default().history_size.into()
+                    );
+                }
+                TripState::Complete { .. } => break,
+                TripState::Idle { .. } | TripState::Paused { .. } => {
+                    panic!("Unexpected idle or paused state")
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_adaptive_eta_reflects_crawling() {
+        use crate::models::Speed;
+        use crate::navigation_controller::models::AdaptiveEtaConfig;
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+
+        let route = TestRoute::Valhalla.first_route();
+        let start = route.steps[0].geometry[0];
+        let crawling = UserLocation {
+            speed: Some(Speed {
+                value: 0.5,
+                accuracy: None,
+            }),
+            ..make_user_location(coord!(x: start.lng, y: start.lat), 5.0)
+        };
+
+        let controller = get_test_navigation_controller(route, |config| {
+            config.adaptive_eta = Some(AdaptiveEtaConfig {
+                minimum_speed: 0.1,
+                ..AdaptiveEtaConfig::default()
+            });
+        });
+
+        let mut state = controller.get_initial_state(crawling);
+        let mut previous_adaptive_duration = 0.0;
+        for _ in 0..10 {
+            state = controller.update_user_location(crawling, state);
+            let TripState::Navigating { progress, .. } = state.trip_state() else {
+                panic!("Expected to be navigating");
+            };
+            let adaptive_duration = progress
+                .adaptive_duration_remaining
+                .expect("Expected an adaptive ETA");
+            // The smoothed speed keeps dropping towards the observed speed.
+            assert!(adaptive_duration > previous_adaptive_duration);
+            previous_adaptive_duration = adaptive_duration;
+        }
+
+        let TripState::Navigating { progress, .. } = state.trip_state() else {
+            panic!("Expected to be navigating");
+        };
+        assert!(progress.adaptive_duration_remaining.unwrap() > progress.duration_remaining);
+
+        // Disabled by default
+        let controller = get_test_navigation_controller(TestRoute::Valhalla.first_route(), |_| {});
+        let state = controller.get_initial_state(crawling);
+        let TripState::Navigating { progress, .. } = state.trip_state() else {
+            panic!("Expected to be navigating");
+        };
+        assert_eq!(progress.adaptive_duration_remaining, None);
+        assert_eq!(state.smoothed_speed(), None);
+    }
+
+    #[test]
+    fn test_location_filter_rejects_implausible_fixes() {
+        use crate::navigation_controller::events::NavigationEvent;
+        use crate::navigation_controller::models::{LocationFilterConfig, LocationRejectionReason};
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+        use std::time::Duration;
+
+        let route = TestRoute::Valhalla.first_route();
+        let start = route.steps[0].geometry[0];
+        let next = route.steps[0].geometry[1];
+        let fix = make_user_location(coord!(x: start.lng, y: start.lat), 5.0);
+        let after = |seconds: u64| fix.timestamp + Duration::from_secs(seconds);
+
+        let controller = get_test_navigation_controller(route, |config| {
+            config.location_filter = Some(LocationFilterConfig::default());
+        });
+        let state = controller.get_initial_state(fix);
+
+        let rejected = |location: UserLocation, reason: LocationRejectionReason| {
+            let new_state = controller.update_user_location(location, state.clone());
+            assert_eq!(new_state.trip_state(), state.trip_state());
+            let [
+                NavigationEvent::LocationRejected {
+                    reason: rejection_reason,
+                    ..
+                },
+            ] = new_state.events()
+            else {
+                panic!("Expected the location to be rejected");
+            };
+            assert_eq!(*rejection_reason, reason);
+        };
+
+        rejected(
+            make_user_location(coord!(x: f64::NAN, y: start.lat), 5.0),
+            LocationRejectionReason::InvalidCoordinates,
+        );
+        rejected(fix, LocationRejectionReason::Duplicate);
+        rejected(
+            UserLocation {
+                timestamp: fix.timestamp - Duration::from_secs(1),
+                ..make_user_location(coord!(x: next.lng, y: next.lat), 5.0)
+            },
+            LocationRejectionReason::OutOfOrder,
+        );
+        let teleport = UserLocation {
+            timestamp: after(1),
+            ..make_user_location(coord!(x: start.lng + 0.1, y: start.lat), 5.0)
+        };
+        let new_state = controller.update_user_location(teleport, state.clone());
+        assert_eq!(new_state.trip_state(), state.trip_state());
+        assert!(matches!(
+            new_state.events(),
+            [NavigationEvent::LocationRejected {
+                reason: LocationRejectionReason::ImplausibleSpeed { .. },
+                ..
+            }]
+        ));
+
+        // Plausible fixes are processed as usual.
+        let state = controller.update_user_location(
+            UserLocation {
+                timestamp: after(60),
+                ..make_user_location(coord!(x: next.lng, y: next.lat), 5.0)
+            },
+            state,
+        );
+        let TripState::Navigating { user_location, .. } = state.trip_state() else {
+            panic!("Expected to be navigating");
+        };
+        assert_eq!(user_location.timestamp, after(60));
+    }
+
+    #[test]
+    fn test_location_smoothing_keeps_raw_location() {
+        use crate::location_smoothing::LocationSmoothing;
+        use crate::models::ModeOfTravel;
+        use crate::test_utils::make_user_location;
+        use geo::{Distance, Haversine, Point, coord};
+        use std::time::Duration;
+
+        let route = TestRoute::Valhalla.first_route();
+        let start = route.steps[0].geometry[0];
+        let fix = make_user_location(coord!(x: start.lng, y: start.lat), 5.0);
+        let after = |seconds: u64| fix.timestamp + Duration::from_secs(seconds);
+        // After standing still for a while, a jump of ~20m within a second
+        let jittery_fix = UserLocation {
+            timestamp: after(10),
+            ..make_user_location(coord!(x: start.lng, y: start.lat + 0.0002), 5.0)
+        };
+
+        let distance_from_start =
+            |location: UserLocation| Haversine.distance(Point::from(fix), Point::from(location));
+
+        for replace_route in [false, true] {
+            let controller = get_test_navigation_controller(route.clone(), |config| {
+                config.location_smoothing = Some(LocationSmoothing::DefaultFor {
+                    mode_of_travel: ModeOfTravel::Walking,
+                });
+            });
+            let mut state = controller.get_initial_state(fix);
+            for seconds in 0..10 {
+                state = controller.update_user_location(
+                    UserLocation {
+                        timestamp: after(seconds),
+                        ..fix
+                    },
+                    state,
+                );
+            }
+            if replace_route {
+                // The filter state survives a reroute
+                let smoothing_state = state.location_smoothing_state();
+                state = controller.replace_route(route.clone(), state);
+                assert_eq!(state.location_smoothing_state(), smoothing_state);
+            }
+            let state = controller.update_user_location(jittery_fix, state);
+            assert!(state.location_smoothing_state().is_some());
+
+            let TripState::Navigating {
+                user_location,
+                raw_user_location,
+                ..
+            } = state.trip_state()
+            else {
+                panic!("Expected to be navigating");
+            };
+            assert_eq!(raw_user_location, Some(jittery_fix));
+            assert!(distance_from_start(user_location) < distance_from_start(jittery_fix) / 2.0);
+        }
+
+        // Disabled by default
+        let controller = get_test_navigation_controller(TestRoute::Valhalla.first_route(), |_| {});
+        let state = controller.get_initial_state(fix);
+        let state = controller.update_user_location(jittery_fix, state);
+        let TripState::Navigating {
+            user_location,
+            raw_user_location,
+            ..
+        } = state.trip_state()
+        else {
+            panic!("Expected to be navigating");
+        };
+        assert_eq!(user_location, jittery_fix);
+        assert_eq!(raw_user_location, None);
+    }
+
+    #[test]
+    fn test_step_regression_reactivates_earlier_step() {
+        use crate::deviation_detection::RouteDeviationTracking;
+        use crate::navigation_controller::events::NavigationEvent;
+        use crate::navigation_controller::models::StepRegressionConfig;
+        use crate::navigation_controller::test_helpers::{
+            gen_route_from_steps, gen_route_step_with_coords,
+        };
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+
+        // East, north, east, and a final arrival step
+        let steps = vec![
+            gen_route_step_with_coords(vec![coord!(x: 0.0, y: 0.0), coord!(x: 0.001, y: 0.0)]),
+            gen_route_step_with_coords(vec![coord!(x: 0.001, y: 0.0), coord!(x: 0.001, y: 0.001)]),
+            gen_route_step_with_coords(vec![
+                coord!(x: 0.001, y: 0.001),
+                coord!(x: 0.002, y: 0.001),
+            ]),
+            gen_route_step_with_coords(vec![
+                coord!(x: 0.002, y: 0.001),
+                coord!(x: 0.002, y: 0.001),
+            ]),
+        ];
+        let route = gen_route_from_steps(steps.clone());
+        let locations = [
+            // Passing the end of the first step
+            make_user_location(coord!(x: 0.000_95, y: 0.0), 5.0),
+            // Halfway along the second step
+            make_user_location(coord!(x: 0.001, y: 0.000_5), 5.0),
+            // Back on the first step
+            make_user_location(coord!(x: 0.000_4, y: 0.0), 5.0),
+        ];
+        let navigate = |config: NavigationControllerConfig| {
+            let controller = NavigationController::new(route.clone(), config);
+            locations.iter().fold(
+                controller.get_initial_state(make_user_location(coord!(x: 0.0, y: 0.0), 5.0)),
+                |state, location| controller.update_user_location(*location, state),
+            )
+        };
+        let config = NavigationControllerConfig {
+            route_deviation_tracking: RouteDeviationTracking::None,
+            ..get_test_navigation_controller_config(Arc::new(DistanceToEndOfStepCondition {
+                distance: 10,
+                minimum_horizontal_accuracy: 10,
+            }))
+        };
+
+        let state = navigate(NavigationControllerConfig {
+            step_regression: Some(StepRegressionConfig::default()),
+            ..config.clone()
+        });
+        let TripState::Navigating {
+            remaining_steps,
+            progress,
+            ..
+        } = state.trip_state()
+        else {
+            panic!("Expected to be navigating");
+        };
+        assert_eq!(remaining_steps, steps);
+        assert_eq!(state.completed_step_count(), 0);
+        // ~60% of the first step remains
+        assert!((progress.distance_to_next_maneuver - steps[0].distance * 0.6).abs() < 1.0);
+        assert_eq!(
+            state.events(),
+            [NavigationEvent::ReturnedToStep {
+                step: steps[0].clone()
+            }]
+        );
+
+        // Disabled by default
+        let state = navigate(config);
+        let TripState::Navigating {
+            remaining_steps, ..
+        } = state.trip_state()
+        else {
+            panic!("Expected to be navigating");
+        };
+        assert_eq!(remaining_steps, steps[1..]);
+        assert_eq!(state.completed_step_count(), 0);
+    }
+
+    #[test]
+    fn test_step_regression_keeps_tracking_state() {
+        use crate::deviation_detection::{RouteDeviationState, RouteDeviationTracking};
+        use crate::location_smoothing::{AxisEstimate, LocationSmoothingState};
+        use crate::models::Speed;
+        use crate::navigation_controller::models::{AdaptiveEtaConfig, StepRegressionConfig};
+        use crate::navigation_controller::test_helpers::{
+            gen_route_from_steps, gen_route_step_with_coords,
+        };
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+
+        let steps = vec![
+            gen_route_step_with_coords(vec![coord!(x: 0.0, y: 0.0), coord!(x: 0.001, y: 0.0)]),
+            gen_route_step_with_coords(vec![coord!(x: 0.001, y: 0.0), coord!(x: 0.001, y: 0.001)]),
+            gen_route_step_with_coords(vec![
+                coord!(x: 0.001, y: 0.001),
+                coord!(x: 0.001, y: 0.001),
+            ]),
+        ];
+        let controller = NavigationController::new(
+            gen_route_from_steps(steps.clone()),
+            NavigationControllerConfig {
+                route_deviation_tracking: RouteDeviationTracking::None,
+                step_regression: Some(StepRegressionConfig::default()),
+                adaptive_eta: Some(AdaptiveEtaConfig::default()),
+                ..get_test_navigation_controller_config(Arc::new(DistanceToEndOfStepCondition {
+                    distance: 10,
+                    minimum_horizontal_accuracy: 10,
+                }))
+            },
+        );
+        let state = [
+            make_user_location(coord!(x: 0.000_95, y: 0.0), 5.0),
+            make_user_location(coord!(x: 0.001, y: 0.000_5), 5.0),
+        ]
+        .into_iter()
+        .fold(
+            controller.get_initial_state(make_user_location(coord!(x: 0.0, y: 0.0), 5.0)),
+            |state, location| controller.update_user_location(location, state),
+        );
+        assert_eq!(state.completed_step_count(), 1);
+
+        let back_on_first_step = UserLocation {
+            speed: Some(Speed {
+                value: 10.0,
+                accuracy: None,
+            }),
+            ..make_user_location(coord!(x: 0.000_4, y: 0.0), 5.0)
+        };
+        let deviation_state = RouteDeviationState {
+            off_route_since: Some(back_on_first_step),
+            wrong_direction_since: None,
+        };
+        let axis_estimate = AxisEstimate {
+            velocity: 1.0,
+            position_variance: 2.0,
+            covariance: 0.0,
+            velocity_variance: 1.0,
+        };
+        let location_smoothing_state = LocationSmoothingState {
+            location: back_on_first_step,
+            east: axis_estimate,
+            north: axis_estimate,
+        };
+        let state = state
+            .with_deviation_state(deviation_state)
+            .with_location_smoothing_state(Some(location_smoothing_state));
+
+        let regressed_state = controller
+            .regress_to_earlier_step(back_on_first_step, &state)
+            .expect("Expected to return to the first step");
+        let TripState::Navigating {
+            remaining_steps,
+            progress,
+            ..
+        } = regressed_state.trip_state()
+        else {
+            panic!("Expected to be navigating");
+        };
+        assert_eq!(remaining_steps, steps);
+        assert_eq!(regressed_state.deviation_state(), deviation_state);
+        assert_eq!(
+            regressed_state.location_smoothing_state(),
+            Some(location_smoothing_state)
+        );
+        // The speed is smoothed over the restored step from scratch
+        assert_eq!(regressed_state.smoothed_speed(), Some(10.0));
+        assert!(progress.adaptive_duration_remaining.is_some());
+    }
+
+    #[test]
+    fn test_dead_reckoning_through_outage() {
+        use crate::models::Speed;
+        use crate::navigation_controller::models::DeadReckoningConfig;
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+        use std::time::Duration;
+
+        let route = TestRoute::Valhalla.first_route();
+        let start = route.steps[0].geometry[0];
+        let end_of_step = *route.steps[0].geometry.last().unwrap();
+        let fix = UserLocation {
+            speed: Some(Speed {
+                value: 10.0,
+                accuracy: None,
+            }),
+            ..make_user_location(coord!(x: start.lng, y: start.lat), 5.0)
+        };
+        let after = |seconds: u64| fix.timestamp + Duration::from_secs(seconds);
+        let distance_to_next_maneuver = |state: &NavState| match state.trip_state() {
+            TripState::Navigating { progress, .. } => progress.distance_to_next_maneuver,
+            _ => panic!("Expected to be navigating"),
+        };
+        let is_estimated = |state: &NavState| {
+            matches!(
+                state.trip_state(),
+                TripState::Navigating {
+                    location_estimated: true,
+                    ..
+                }
+            )
+        };
+
+        let controller = get_test_navigation_controller(route, |config| {
+            config.dead_reckoning = Some(DeadReckoningConfig::default());
+        });
+        let state = controller.get_initial_state(fix);
+        let initial_distance = distance_to_next_maneuver(&state);
+        assert!(!is_estimated(&state));
+
+        // A wildly inaccurate fix is replaced by an estimate along the route.
+        let state = controller.update_user_location(
+            UserLocation {
+                timestamp: after(2),
+                ..make_user_location(coord!(x: 0.0, y: 0.0), 500.0)
+            },
+            state,
+        );
+        assert!(is_estimated(&state));
+        assert!((initial_distance - distance_to_next_maneuver(&state) - 20.0).abs() < 0.5);
+
+        // Without any fixes, the estimate keeps moving.
+        let state = controller.estimate_location(after(4), state);
+        assert!(is_estimated(&state));
+        assert!((initial_distance - distance_to_next_maneuver(&state) - 40.0).abs() < 0.5);
+
+        // ... but never past the end of the current step.
+        let state = controller.estimate_location(after(50), state);
+        assert!(is_estimated(&state));
+        assert!(distance_to_next_maneuver(&state).abs() < 0.01);
+
+        // Estimates stop after the maximum duration.
+        let stale_state = controller.estimate_location(after(61), state.clone());
+        assert_eq!(stale_state.trip_state(), state.trip_state());
+        assert_eq!(stale_state.events(), []);
+
+        // A trustworthy fix ends dead reckoning.
+        let state = controller.update_user_location(
+            UserLocation {
+                timestamp: after(51),
+                ..make_user_location(coord!(x: end_of_step.lng, y: end_of_step.lat), 5.0)
+            },
+            state,
+        );
+        assert!(!is_estimated(&state));
+        assert_eq!(state.dead_reckoning_anchor(), None);
+
+        // Disabled by default
+        let controller = get_test_navigation_controller(TestRoute::Valhalla.first_route(), |_| {});
+        let state = controller.get_initial_state(fix);
+        let state = controller.estimate_location(after(2), state);
+        assert_eq!(distance_to_next_maneuver(&state), initial_distance);
+        assert!(!is_estimated(&state));
+    }
+
+    #[test]
+    fn test_update_heading_sets_course_at_low_speed() {
+        use crate::models::CourseOverGround;
+        use crate::navigation_controller::models::CourseFiltering;
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+        use std::time::Duration;
+
+        let route = TestRoute::Valhalla.first_route();
+        let start = route.steps[0].geometry[0];
+        let fix = make_user_location(coord!(x: start.lng, y: start.lat), 5.0);
+        let controller = get_test_navigation_controller(route.clone(), |config| {
+            config.snapped_location_course_filtering =
+                CourseFiltering::SnapToRouteWithHeading { maximum_speed: 2.0 };
+        });
+        let snapped_course = |state: &NavState| match state.trip_state() {
+            TripState::Navigating {
+                snapped_user_location,
+                ..
+            } => snapped_user_location
+                .course_over_ground
+                .map(|course| course.degrees),
+            _ => panic!("Expected to be navigating"),
+        };
+
+        // Without a heading, the route is used
+        let state = controller.get_initial_state(fix);
+        let route_bearing = snapped_course(&state).expect("Expected a snapped course");
+
+        let heading = Heading {
+            true_heading: (route_bearing + 180) % 360,
+            accuracy: 10,
+            timestamp: fix.timestamp,
+        };
+        let state = controller.update_heading(heading, state);
+        assert_eq!(snapped_course(&state), Some(heading.true_heading));
+        assert!(matches!(
+            state.trip_state(),
+            TripState::Navigating { heading: Some(h), .. } if h == heading
+        ));
+
+        // The heading is kept across location updates
+        let state = controller.update_user_location(
+            UserLocation {
+                timestamp: fix.timestamp + Duration::from_secs(1),
+                ..fix
+            },
+            state,
+        );
+        assert_eq!(snapped_course(&state), Some(heading.true_heading));
+
+        // ... and when the state is rebuilt on resuming or replacing the route
+        let state = controller.resume(controller.pause(state));
+        assert_eq!(snapped_course(&state), Some(heading.true_heading));
+        let state = controller.replace_route(route, state);
+        assert_eq!(snapped_course(&state), Some(heading.true_heading));
+
+        // When moving faster, the route wins
+        let state = controller.update_user_location(
+            UserLocation {
+                timestamp: fix.timestamp + Duration::from_secs(2),
+                speed: Some(Speed {
+                    value: 10.0,
+                    accuracy: None,
+                }),
+                course_over_ground: Some(CourseOverGround {
+                    degrees: heading.true_heading,
+                    accuracy: None,
+                }),
+                ..fix
+            },
+            state,
+        );
+        assert_eq!(snapped_course(&state), Some(route_bearing));
+    }
+
+    #[test]
+    fn test_speed_limit_and_speeding() {
+        use crate::models::{SegmentAnnotation, SegmentMaxSpeed, SegmentSpeedUnit};
+        use crate::navigation_controller::models::SpeedingConfig;
+        use crate::navigation_controller::test_helpers::{
+            gen_annotation_json, gen_route_from_steps, gen_route_step_with_coords,
+        };
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+
+        let limit = SegmentMaxSpeed::Known {
+            speed: 30.0,
+            unit: SegmentSpeedUnit::KilometersPerHour,
+        };
+        let next_limit = SegmentMaxSpeed::Known {
+            speed: 50.0,
+            unit: SegmentSpeedUnit::KilometersPerHour,
+        };
+        let mut step = gen_route_step_with_coords(vec![
+            coord!(x: 0.0, y: 0.0),
+            coord!(x: 0.001, y: 0.0),
+            coord!(x: 0.002, y: 0.0),
+            coord!(x: 0.003, y: 0.0),
+        ]);
+        step.annotations = Some(
+            [
+                Some(limit),
+                Some(SegmentMaxSpeed::Unknown),
+                Some(next_limit),
+            ]
+            .into_iter()
+            .map(|max_speed| {
+                gen_annotation_json(SegmentAnnotation {
+                    max_speed,
+                    ..SegmentAnnotation::default()
+                })
+            })
+            .collect(),
+        );
+        let route = gen_route_from_steps(vec![step]);
+        let location_with_speed = |value: f64| UserLocation {
+            speed: Some(Speed {
+                value,
+                accuracy: None,
+            }),
+            ..make_user_location(coord!(x: 0.0005, y: 0.0), 5.0)
+        };
+        let speed_limit_status = |speeding: Option<SpeedingConfig>, speed: f64| {
+            let controller =
+                get_test_navigation_controller(route.clone(), |config| config.speeding = speeding);
+            match controller
+                .get_initial_state(location_with_speed(speed))
+                .trip_state()
+            {
+                TripState::Navigating {
+                    speed_limit,
+                    next_speed_limit_change,
+                    is_speeding,
+                    ..
+                } => (speed_limit, next_speed_limit_change, is_speeding),
+                _ => panic!("Expected to be navigating"),
+            }
+        };
+
+        // 30 km/h is ~8.3 m/s
+        let (speed_limit, next_speed_limit_change, is_speeding) =
+            speed_limit_status(Some(SpeedingConfig::default()), 12.0);
+        assert_eq!(speed_limit, Some(limit));
+        assert!(is_speeding);
+        // The segment with an unknown limit is skipped
+        let change = next_speed_limit_change.expect("Expected a speed limit change");
+        assert_eq!(change.speed_limit, next_limit);
+        assert!((change.distance_to_change - 166.8).abs() < 0.1);
+
+        // Within the tolerance
+        assert!(!speed_limit_status(Some(SpeedingConfig::default()), 9.0).2);
+        // Disabled by default
+        assert!(!speed_limit_status(None, 12.0).2);
+    }
+
+    #[test]
+    fn test_maneuver_preview() {
+        use crate::models::ManeuverType;
+        use crate::navigation_controller::models::ManeuverPreviewConfig;
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+
+        let route = TestRoute::Valhalla.first_route();
+        let start = route.steps[0].geometry[0];
+        let location = make_user_location(coord!(x: start.lng, y: start.lat), 5.0);
+        let upcoming_maneuvers = |maneuver_preview| {
+            let controller = get_test_navigation_controller(route.clone(), |config| {
+                config.maneuver_preview = maneuver_preview;
+            });
+            match controller.get_initial_state(location).trip_state() {
+                TripState::Navigating {
+                    upcoming_maneuvers,
+                    progress,
+                    ..
+                } => (upcoming_maneuvers, progress),
+                _ => panic!("Expected to be navigating"),
+            }
+        };
+
+        let (maneuvers, progress) = upcoming_maneuvers(Some(ManeuverPreviewConfig {
+            maximum_maneuvers: u16::MAX,
+            ..ManeuverPreviewConfig::default()
+        }));
+        // Every step but the arrival step ends in a maneuver
+        assert_eq!(maneuvers.len(), route.steps.len() - 1);
+        assert_eq!(
+            maneuvers[0].distance_to_maneuver,
+            progress.distance_to_next_maneuver
+        );
+        assert_eq!(maneuvers[0].road_name, route.steps[1].road_name);
+        assert_eq!(
+            maneuvers.last().unwrap().maneuver_type,
+            Some(ManeuverType::Arrive)
+        );
+        assert!(
+            (maneuvers.last().unwrap().distance_to_maneuver - progress.distance_remaining).abs()
+                < 1e-6
+        );
+
+        // Disabled by default
+        assert_eq!(upcoming_maneuvers(None).0, []);
+    }
+
+    #[test]
+    fn test_upcoming_incident() {
+        use crate::models::Incident;
+        use crate::navigation_controller::models::IncidentsConfig;
+        use crate::navigation_controller::test_helpers::{
+            gen_route_from_steps, gen_route_step_with_coords,
+        };
+        use crate::test_utils::make_user_location;
+        use geo::coord;
+
+        let mut step = gen_route_step_with_coords(vec![
+            coord!(x: 0.0, y: 0.0),
+            coord!(x: 0.001, y: 0.0),
+            coord!(x: 0.002, y: 0.0),
+        ]);
+        step.incidents = vec![
+            serde_json::from_value::<Incident>(serde_json::json!({
+                "id": "construction",
+                "incidentType": "construction",
+                "lanesBlocked": [],
+                "geometryIndexStart": 1,
+                "affectedRoadNames": [],
+            }))
+            .unwrap(),
+        ];
+        let route = gen_route_from_steps(vec![step]);
+        let location = make_user_location(coord!(x: 0.0005, y: 0.0), 5.0);
+        let upcoming_incident = |incidents| {
+            let controller = get_test_navigation_controller(route.clone(), |config| {
+                config.incidents = incidents;
+            });
+            match controller.get_initial_state(location).trip_state() {
+                TripState::Navigating {
+                    upcoming_incident, ..
+                } => upcoming_incident,
+                _ => panic!("Expected to be navigating"),
+            }
+        };
+
+        let upcoming = upcoming_incident(Some(IncidentsConfig::default()))
+            .expect("Expected an upcoming incident");
+        assert_eq!(upcoming.incident.id, "construction");
+        assert!((upcoming.distance_to_incident - 55.6).abs() < 0.1);
+
+        // Disabled by default
+        assert_eq!(upcoming_incident(None), None);
+    }
 }
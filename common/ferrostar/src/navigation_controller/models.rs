@@ -1,10 +1,15 @@
 //! State and configuration data models.
 
+use super::events::{NavigationEvent, due_spoken_instructions, navigation_events};
 use super::step_advance::conditions::ManualStepCondition;
 use super::step_advance::{SerializableStepAdvanceCondition, StepAdvanceCondition};
 use crate::algorithms::distance_between_locations;
-use crate::deviation_detection::{RouteDeviation, RouteDeviationTracking};
-use crate::models::{RouteStep, SpokenInstruction, UserLocation, VisualInstruction, Waypoint};
+use crate::deviation_detection::{RouteDeviation, RouteDeviationState, RouteDeviationTracking};
+use crate::location_smoothing::{LocationSmoothing, LocationSmoothingState};
+use crate::models::{
+    Heading, Incident, ManeuverModifier, ManeuverType, RouteStep, SegmentAnnotation,
+    SegmentMaxSpeed, SpokenInstruction, UserLocation, VisualInstruction, Waypoint,
+};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
@@ -13,6 +18,7 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 #[cfg(feature = "wasm-bindgen")]
 use tsify::Tsify;
+use uuid::Uuid;
 
 /// The navigation state.
 ///
@@ -26,6 +32,42 @@ pub struct NavState {
     trip_state: TripState,
     // This has to be here because we actually do need to update the internal state that changes throughout navigation.
     step_advance_condition: Arc<dyn StepAdvanceCondition>,
+    /// The most recent raw location fixes (oldest first) used for map matching.
+    ///
+    /// This is only populated when [`NavigationControllerConfig::map_matching`] is set.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    recent_locations: Vec<UserLocation>,
+    /// State carried between location updates for deviation tracking with hysteresis.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    deviation_state: RouteDeviationState,
+    /// The exponentially smoothed speed (in meters per second) over the current step.
+    ///
+    /// This is only populated when [`NavigationControllerConfig::adaptive_eta`] is set.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    smoothed_speed: Option<f64>,
+    /// The events produced by the update that resulted in this state.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    events: Vec<NavigationEvent>,
+    /// The utterance IDs of all spoken instructions announced so far during the trip.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    announced_utterance_ids: Vec<Uuid>,
+    /// The last trustworthy location fix while the user's location is being estimated.
+    ///
+    /// This is only populated during dead reckoning
+    /// (see [`NavigationControllerConfig::dead_reckoning`]).
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    dead_reckoning_anchor: Option<UserLocation>,
+    /// The location smoothing filter state.
+    ///
+    /// This is only populated when [`NavigationControllerConfig::location_smoothing`] is set.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    location_smoothing_state: Option<LocationSmoothingState>,
+    /// The number of route steps the user has already completed.
+    ///
+    /// The completed steps are the first steps of the route being navigated.
+    /// This is only tracked when [`NavigationControllerConfig::step_regression`] is set.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    completed_step_count: u32,
 }
 
 impl NavState {
@@ -37,6 +79,14 @@ impl NavState {
         Self {
             trip_state,
             step_advance_condition,
+            recent_locations: Vec::new(),
+            deviation_state: RouteDeviationState::default(),
+            smoothed_speed: None,
+            events: Vec::new(),
+            announced_utterance_ids: Vec::new(),
+            dead_reckoning_anchor: None,
+            location_smoothing_state: None,
+            completed_step_count: 0,
         }
     }
 
@@ -45,22 +95,196 @@ impl NavState {
         Self {
             trip_state: TripState::Idle { user_location },
             step_advance_condition: Arc::new(ManualStepCondition {}), // No op condition.
+            recent_locations: Vec::new(),
+            deviation_state: RouteDeviationState::default(),
+            smoothed_speed: None,
+            events: Vec::new(),
+            announced_utterance_ids: Vec::new(),
+            dead_reckoning_anchor: None,
+            location_smoothing_state: None,
+            completed_step_count: 0,
         }
     }
 
     /// Creates a navigation state indicating the trip is complete (arrived at the destination but still tracking the user's location).
     ///
     /// The summary is retained as a snapshot (the caller should have this from the last known state).
-    pub fn complete(user_location: UserLocation, last_summary: TripSummary) -> Self {
+    pub fn complete(user_location: UserLocation, last_summary: &TripSummary) -> Self {
+        Self::ended(user_location, last_summary, None)
+    }
+
+    /// Creates a terminal state for a trip that was canceled before reaching the end of the route.
+    pub fn canceled(
+        user_location: UserLocation,
+        last_summary: &TripSummary,
+        reason: CancellationReason,
+    ) -> Self {
+        Self::ended(user_location, last_summary, Some(reason))
+    }
+
+    fn ended(
+        user_location: UserLocation,
+        last_summary: &TripSummary,
+        cancellation_reason: Option<CancellationReason>,
+    ) -> Self {
         Self {
             trip_state: TripState::Complete {
                 user_location,
                 summary: TripSummary {
                     ended_at: Some(Utc::now()),
-                    ..last_summary
+                    ..last_summary.clone()
                 },
+                cancellation_reason,
             },
             step_advance_condition: Arc::new(ManualStepCondition {}), // No op condition.
+            recent_locations: Vec::new(),
+            deviation_state: RouteDeviationState::default(),
+            smoothed_speed: None,
+            events: Vec::new(),
+            announced_utterance_ids: Vec::new(),
+            dead_reckoning_anchor: None,
+            location_smoothing_state: None,
+            completed_step_count: 0,
+        }
+    }
+
+    /// Returns a copy of this state with the given trip state.
+    ///
+    /// Everything else (ex: deviation tracking and location smoothing) is kept as is.
+    pub(crate) fn with_trip_state(self, trip_state: TripState) -> Self {
+        Self { trip_state, ..self }
+    }
+
+    /// Returns a copy of this state with the given recent location history.
+    pub(crate) fn with_recent_locations(self, recent_locations: Vec<UserLocation>) -> Self {
+        Self {
+            recent_locations,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this state with the given deviation tracking state.
+    pub(crate) fn with_deviation_state(self, deviation_state: RouteDeviationState) -> Self {
+        Self {
+            deviation_state,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this state with the given smoothed speed.
+    pub(crate) fn with_smoothed_speed(self, smoothed_speed: Option<f64>) -> Self {
+        Self {
+            smoothed_speed,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this state with the given dead reckoning anchor.
+    ///
+    /// The location of a navigating trip state is marked as estimated whenever an anchor is set.
+    pub(crate) fn with_dead_reckoning_anchor(
+        mut self,
+        dead_reckoning_anchor: Option<UserLocation>,
+    ) -> Self {
+        if let TripState::Navigating {
+            location_estimated, ..
+        } = &mut self.trip_state
+        {
+            *location_estimated = dead_reckoning_anchor.is_some();
+        }
+        Self {
+            dead_reckoning_anchor,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this state with the given location smoothing filter state.
+    pub(crate) fn with_location_smoothing_state(
+        self,
+        location_smoothing_state: Option<LocationSmoothingState>,
+    ) -> Self {
+        Self {
+            location_smoothing_state,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this state with the given number of completed steps.
+    pub(crate) fn with_completed_step_count(self, completed_step_count: u32) -> Self {
+        Self {
+            completed_step_count,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this state with the given raw (unsmoothed) user location.
+    ///
+    /// This only affects navigating trip states.
+    pub(crate) fn with_raw_user_location(mut self, location: Option<UserLocation>) -> Self {
+        if let TripState::Navigating {
+            raw_user_location, ..
+        } = &mut self.trip_state
+        {
+            *raw_user_location = location;
+        }
+        self
+    }
+
+    /// Returns a copy of this state with the given device heading and snapped user location.
+    ///
+    /// This only affects navigating and paused trip states.
+    pub(crate) fn with_heading(
+        mut self,
+        new_heading: Heading,
+        new_snapped_user_location: UserLocation,
+    ) -> Self {
+        match &mut self.trip_state {
+            TripState::Navigating {
+                heading,
+                snapped_user_location,
+                ..
+            } => {
+                *heading = Some(new_heading);
+                *snapped_user_location = new_snapped_user_location;
+            }
+            // A paused trip keeps the location it was paused at.
+            TripState::Paused { heading, .. } => *heading = Some(new_heading),
+            TripState::Idle { .. } | TripState::Complete { .. } => {}
+        }
+        self
+    }
+
+    /// Returns a copy of this state with the given events.
+    pub(crate) fn with_events(self, events: Vec<NavigationEvent>) -> Self {
+        Self { events, ..self }
+    }
+
+    /// Returns a copy of this state with the events that happened since the `previous` state.
+    ///
+    /// `passed_spoken_instructions` are the instructions which became due on steps
+    /// that were passed on the way to this state.
+    /// These, along with the instructions due in this state,
+    /// are announced unless `previous` already announced them.
+    pub(crate) fn with_events_since(
+        self,
+        previous: &NavState,
+        passed_spoken_instructions: Vec<SpokenInstruction>,
+    ) -> Self {
+        let mut announced_utterance_ids = previous.announced_utterance_ids.clone();
+        let mut events = navigation_events(&previous.trip_state, &self.trip_state);
+        for instruction in passed_spoken_instructions
+            .into_iter()
+            .chain(due_spoken_instructions(&self.trip_state))
+        {
+            if !announced_utterance_ids.contains(&instruction.utterance_id) {
+                announced_utterance_ids.push(instruction.utterance_id);
+                events.push(NavigationEvent::SpokenInstructionTriggered { instruction });
+            }
+        }
+        Self {
+            events,
+            announced_utterance_ids,
+            ..self
         }
     }
 
@@ -73,6 +297,42 @@ impl NavState {
     pub fn step_advance_condition(&self) -> Arc<dyn StepAdvanceCondition> {
         self.step_advance_condition.clone()
     }
+
+    #[inline]
+    pub(crate) fn recent_locations(&self) -> &[UserLocation] {
+        &self.recent_locations
+    }
+
+    #[inline]
+    pub(crate) fn deviation_state(&self) -> RouteDeviationState {
+        self.deviation_state
+    }
+
+    #[inline]
+    pub(crate) fn smoothed_speed(&self) -> Option<f64> {
+        self.smoothed_speed
+    }
+
+    #[inline]
+    pub(crate) fn dead_reckoning_anchor(&self) -> Option<UserLocation> {
+        self.dead_reckoning_anchor
+    }
+
+    #[inline]
+    pub(crate) fn location_smoothing_state(&self) -> Option<LocationSmoothingState> {
+        self.location_smoothing_state
+    }
+
+    #[inline]
+    pub(crate) fn completed_step_count(&self) -> u32 {
+        self.completed_step_count
+    }
+
+    /// The events produced by the update that resulted in this state.
+    #[inline]
+    pub fn events(&self) -> &[NavigationEvent] {
+        &self.events
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -84,6 +344,30 @@ pub struct SerializableNavState {
     pub(crate) trip_state: TripState,
     // This has to be here because we actually do need to update the internal state that changes throughout navigation.
     pub(crate) step_advance_condition: SerializableStepAdvanceCondition,
+    #[serde(default)]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub(crate) recent_locations: Vec<UserLocation>,
+    #[serde(default)]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub(crate) deviation_state: RouteDeviationState,
+    #[serde(default)]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub(crate) smoothed_speed: Option<f64>,
+    #[serde(default)]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub(crate) events: Vec<NavigationEvent>,
+    #[serde(default)]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub(crate) announced_utterance_ids: Vec<Uuid>,
+    #[serde(default)]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub(crate) dead_reckoning_anchor: Option<UserLocation>,
+    #[serde(default)]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub(crate) location_smoothing_state: Option<LocationSmoothingState>,
+    #[serde(default)]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub(crate) completed_step_count: u32,
 }
 
 impl From<SerializableNavState> for NavState {
@@ -91,6 +375,14 @@ impl From<SerializableNavState> for NavState {
         Self {
             trip_state: value.trip_state,
             step_advance_condition: value.step_advance_condition.into(),
+            recent_locations: value.recent_locations,
+            deviation_state: value.deviation_state,
+            smoothed_speed: value.smoothed_speed,
+            events: value.events,
+            announced_utterance_ids: value.announced_utterance_ids,
+            dead_reckoning_anchor: value.dead_reckoning_anchor,
+            location_smoothing_state: value.location_smoothing_state,
+            completed_step_count: value.completed_step_count,
         }
     }
 }
@@ -100,6 +392,14 @@ impl From<NavState> for SerializableNavState {
         Self {
             trip_state: value.trip_state,
             step_advance_condition: value.step_advance_condition.to_js(),
+            recent_locations: value.recent_locations,
+            deviation_state: value.deviation_state,
+            smoothed_speed: value.smoothed_speed,
+            events: value.events,
+            announced_utterance_ids: value.announced_utterance_ids,
+            dead_reckoning_anchor: value.dead_reckoning_anchor,
+            location_smoothing_state: value.location_smoothing_state,
+            completed_step_count: value.completed_step_count,
         }
     }
 }
@@ -122,6 +422,45 @@ pub struct TripProgress {
     /// The total duration remaining in the trip, in seconds.
     #[serde(alias = "duration_remaining")]
     pub duration_remaining: f64,
+    /// The total duration remaining in the trip, in seconds,
+    /// adjusted for the speed the user has recently been traveling at.
+    ///
+    /// This is only available when [`NavigationControllerConfig::adaptive_eta`] is set.
+    /// Unlike [`TripProgress::duration_remaining`], which relies solely on the planned durations of the route,
+    /// this reflects e.g. crawling through congestion the route did not anticipate.
+    #[serde(default, alias = "adaptive_duration_remaining")]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub adaptive_duration_remaining: Option<f64>,
+    /// The index of the route leg the user is currently on.
+    #[serde(default, alias = "current_leg_index")]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub current_leg_index: u32,
+    /// Progress towards the end of each remaining route leg, starting with the current one.
+    ///
+    /// Each leg ends at a (break) waypoint,
+    /// so this gives the distance and duration to each remaining stop.
+    /// The last entry covers the whole remaining trip.
+    #[serde(default, alias = "remaining_legs")]
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub remaining_legs: Vec<LegProgress>,
+}
+
+/// Progress towards the end of a single route leg.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct LegProgress {
+    /// The index of the leg within the route.
+    #[serde(alias = "leg_index")]
+    pub leg_index: u32,
+    /// The distance from the user to the end of the leg, in meters.
+    #[serde(alias = "distance_remaining")]
+    pub distance_remaining: f64,
+    /// The estimated duration until the user reaches the end of the leg, in seconds.
+    #[serde(alias = "duration_remaining")]
+    pub duration_remaining: f64,
 }
 
 /// Information pertaining to the user's full navigation trip. This includes
@@ -148,6 +487,20 @@ pub struct TripSummary {
     pub ended_at: Option<DateTime<Utc>>,
 }
 
+/// The reason a trip was canceled before reaching the end of the route.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum CancellationReason {
+    /// The user ended navigation.
+    UserCanceled,
+    /// Navigation was ended in favor of a new trip (ex: the user picked a different destination).
+    Superseded,
+    /// Navigation was ended for an application-specific reason.
+    Other { description: String },
+}
+
 impl TripSummary {
     pub(crate) fn update(
         &self,
@@ -211,7 +564,8 @@ pub enum TripState {
         /// The ordered list of steps that remain in the trip.
         ///
         /// The step at the front of the list is always the current step.
-        /// We currently assume that you cannot move backward to a previous step.
+        /// The user only moves back to a previous step
+        /// when [`NavigationControllerConfig::step_regression`] is set.
         #[serde(alias = "remaining_steps")]
         remaining_steps: Vec<RouteStep>,
         /// Remaining waypoints to visit on the route.
@@ -237,20 +591,110 @@ pub enum TripState {
         visual_instruction: Option<VisualInstruction>,
         /// The most recent spoken instruction that should be synthesized using TTS.
         ///
-        /// Note it is the responsibility of the platform layer to ensure that utterances are not synthesized multiple times. This property simply reports the current spoken instruction.
+        /// This property simply reports the current spoken instruction.
+        /// To synthesize each utterance exactly once (including ones that would otherwise be
+        /// skipped when several become due in a single update), use the
+        /// [`NavigationEvent::SpokenInstructionTriggered`] events of the [`NavState`] instead.
         #[serde(alias = "spoken_instruction")]
         spoken_instruction: Option<SpokenInstruction>,
         /// Annotation data at the current location.
         /// This is represented as a json formatted byte array to allow for flexible encoding of custom annotations.
         #[serde(alias = "annotation_json")]
         annotation_json: Option<String>,
+        /// Typed values of the common annotations at the current location.
+        ///
+        /// Unlike `annotation_json`, this does not include custom annotations.
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default, alias = "segment_annotation")]
+        segment_annotation: Option<SegmentAnnotation>,
+        /// The speed limit at the current location, if the route has speed limit annotations.
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default, alias = "speed_limit")]
+        speed_limit: Option<SegmentMaxSpeed>,
+        /// The next change of the speed limit along the route, if any.
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default, alias = "next_speed_limit_change")]
+        next_speed_limit_change: Option<SpeedLimitChange>,
+        /// Whether the user is traveling faster than the current speed limit.
+        ///
+        /// This is only ever set when [`NavigationControllerConfig::speeding`] is set.
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default, alias = "is_speeding")]
+        is_speeding: bool,
+        /// The next maneuvers along the route, starting with the one at the end of the current step.
+        ///
+        /// This is only populated when [`NavigationControllerConfig::maneuver_preview`] is set.
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default, alias = "upcoming_maneuvers")]
+        upcoming_maneuvers: Vec<UpcomingManeuver>,
+        /// The incident the user is in, or else the nearest one ahead along the route.
+        ///
+        /// Incidents which are not expected to be in effect when the user reaches them
+        /// (based on their start and end times) are ignored.
+        /// This is only populated when [`NavigationControllerConfig::incidents`] is set.
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default, alias = "upcoming_incident")]
+        upcoming_incident: Option<UpcomingIncident>,
+        /// Whether the user's location is an estimate rather than a location fix.
+        ///
+        /// This is the case while dead reckoning through a GPS outage (ex: in a tunnel);
+        /// see [`NavigationControllerConfig::dead_reckoning`].
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default, alias = "location_estimated")]
+        location_estimated: bool,
+        /// The location fix as reported, before smoothing.
+        ///
+        /// This is only populated when [`NavigationControllerConfig::location_smoothing`] is set,
+        /// in which case `user_location` is the smoothed location.
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default, alias = "raw_user_location")]
+        raw_user_location: Option<UserLocation>,
+        /// The latest heading of the device (ex: from a compass),
+        /// as provided via [`Navigator::update_heading`](super::Navigator::update_heading).
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default)]
+        heading: Option<Heading>,
+    },
+    /// Guidance is paused (ex: at a rest stop), and progress along the route is frozen.
+    ///
+    /// While paused, location updates only update the user's raw location.
+    /// No distance is added to the trip summary, no instructions are issued,
+    /// and neither route deviation nor step advancement are checked until the trip is resumed.
+    #[serde(rename_all = "camelCase")]
+    Paused {
+        /// The user's raw location.
+        #[serde(alias = "user_location")]
+        user_location: UserLocation,
+        /// The user's snapped location at the time the trip was paused.
+        #[serde(alias = "snapped_user_location")]
+        snapped_user_location: UserLocation,
+        /// The ordered list of steps that remain in the trip.
+        #[serde(alias = "remaining_steps")]
+        remaining_steps: Vec<RouteStep>,
+        /// Remaining waypoints to visit on the route.
+        #[serde(alias = "remaining_waypoints")]
+        remaining_waypoints: Vec<Waypoint>,
+        /// The trip progress at the time the trip was paused.
+        progress: TripProgress,
+        /// Information pertaining to the user's full navigation trip.
+        summary: TripSummary,
+        /// The latest device heading (ex: from a compass), if any.
+        ///
+        /// This is restored when the trip is resumed.
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default)]
+        heading: Option<Heading>,
     },
-    /// The navigation controller has reached the end of the trip.
+    /// The trip has ended, either by reaching the end of the route or by being canceled.
     Complete {
         user_location: UserLocation,
         /// Information pertaining to the user's full navigation trip. This includes
         /// simple stats like total duration, and distance.
         summary: TripSummary,
+        /// Why the trip was canceled, or `None` if the user reached the end of the route.
+        #[cfg_attr(feature = "uniffi", uniffi(default))]
+        #[serde(default)]
+        cancellation_reason: Option<CancellationReason>,
     },
 }
 
@@ -313,6 +757,353 @@ pub enum CourseFiltering {
 
     /// Use the raw course as reported by the location provider with no processing.
     Raw,
+
+    /// Like [`CourseFiltering::SnapToRoute`], but uses the device heading
+    /// (see [`Navigator::update_heading`](super::Navigator::update_heading)) at low speed.
+    ///
+    /// When moving slowly, neither the reported course nor the route reveal
+    /// which way the user is facing (ex: at the start of a trip, or when walking).
+    #[serde(rename_all = "camelCase")]
+    SnapToRouteWithHeading {
+        /// Below this speed (in meters per second), or when the speed is unknown,
+        /// a recent device heading is used instead of the route's bearing.
+        #[serde(alias = "maximum_speed")]
+        maximum_speed: f64,
+    },
+}
+
+/// Configures hidden Markov model (HMM) map matching of user locations to the route.
+///
+/// By default, each location is snapped independently to the closest point on the current step.
+/// This is cheap, but can jump to the wrong branch when the route passes near itself
+/// (self-intersections, parallel frontage roads, cloverleaf interchanges, etc.).
+///
+/// With map matching enabled, the navigation controller instead considers the most recent location fixes,
+/// along with their course and speed,
+/// and picks the most likely position along the remaining route near the current step.
+/// See [`crate::map_matching`] for details.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct MapMatchingConfig {
+    /// The number of recent location fixes (including the current one) to consider.
+    #[serde(alias = "history_size")]
+    pub history_size: u16,
+    /// Route positions farther than this many meters from a location fix are not considered as matches.
+    #[serde(alias = "search_radius")]
+    pub search_radius: f64,
+    /// The minimum standard deviation (in meters) assumed for location measurements.
+    ///
+    /// The reported horizontal accuracy is used when it is larger than this.
+    #[serde(alias = "minimum_measurement_sigma")]
+    pub minimum_measurement_sigma: f64,
+    /// How strongly (in meters) the distance traveled along the route may differ
+    /// from the distance traveled between fixes.
+    ///
+    /// Smaller values penalize jumps along the route (ex: to another branch) more heavily.
+    #[serde(alias = "transition_beta")]
+    pub transition_beta: f64,
+}
+
+impl Default for MapMatchingConfig {
+    fn default() -> Self {
+        Self {
+            history_size: 5,
+            search_radius: 50.0,
+            minimum_measurement_sigma: 5.0,
+            transition_beta: 10.0,
+        }
+    }
+}
+
+/// Configures the adaptive ETA reported in [`TripProgress::adaptive_duration_remaining`].
+///
+/// The user's speed is exponentially smoothed over the current step,
+/// starting from the speed planned for the step.
+/// The time to the next maneuver is then derived from the smoothed speed,
+/// while the planned durations are used for all later steps.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct AdaptiveEtaConfig {
+    /// The weight (between 0 and 1) given to each new speed observation.
+    ///
+    /// Larger values react faster to changes in speed, but are noisier.
+    #[serde(alias = "smoothing_factor")]
+    pub smoothing_factor: f64,
+    /// The minimum speed (in meters per second) assumed when estimating the time remaining.
+    ///
+    /// This keeps the estimate finite while the user is stopped (ex: at a traffic light).
+    #[serde(alias = "minimum_speed")]
+    pub minimum_speed: f64,
+}
+
+impl Default for AdaptiveEtaConfig {
+    fn default() -> Self {
+        Self {
+            smoothing_factor: 0.2,
+            minimum_speed: 1.0,
+        }
+    }
+}
+
+/// Configures dead reckoning along the route during GPS outages (ex: in tunnels).
+///
+/// When location fixes are too inaccurate to be trusted,
+/// or stop arriving altogether (see [`Navigator::estimate_location`](super::Navigator::estimate_location)),
+/// the navigation controller instead advances the user along the route geometry.
+/// The speed is taken from the last trustworthy fix,
+/// falling back to the speed annotations (or the planned speed) of the current step.
+///
+/// Estimates never run past the end of the current step,
+/// so navigation continues smoothly from the next trustworthy fix.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct DeadReckoningConfig {
+    /// Location fixes with a horizontal accuracy worse than this (in meters) are replaced by an estimate.
+    #[serde(alias = "maximum_horizontal_accuracy")]
+    pub maximum_horizontal_accuracy: f64,
+    /// The longest time (in seconds) since the last trustworthy fix that the location is estimated for.
+    ///
+    /// After this, location fixes are used as reported again,
+    /// as the estimate is increasingly likely to be wrong.
+    #[serde(alias = "maximum_duration")]
+    pub maximum_duration: f64,
+}
+
+impl Default for DeadReckoningConfig {
+    fn default() -> Self {
+        Self {
+            maximum_horizontal_accuracy: 50.0,
+            maximum_duration: 60.0,
+        }
+    }
+}
+
+/// Configures the sanitation of location fixes before they are used for navigation.
+///
+/// Fixes which are rejected leave the navigation state unchanged,
+/// and are reported via [`NavigationEvent::LocationRejected`].
+/// Fixes with invalid coordinates, and fixes which are older than (or as old as)
+/// the last accepted one are always rejected.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct LocationFilterConfig {
+    /// The fastest plausible speed (in meters per second) between consecutive fixes.
+    ///
+    /// The horizontal accuracy of both fixes is taken into account,
+    /// so that jitter while moving slowly is not mistaken for a jump.
+    #[serde(alias = "maximum_speed")]
+    pub maximum_speed: f64,
+}
+
+impl Default for LocationFilterConfig {
+    fn default() -> Self {
+        Self {
+            // Roughly 250 km/h
+            maximum_speed: 70.0,
+        }
+    }
+}
+
+/// The reason a location fix was rejected (see [`LocationFilterConfig`]).
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub enum LocationRejectionReason {
+    /// The coordinates are not finite, or out of range.
+    InvalidCoordinates,
+    /// The fix has the same timestamp as the last accepted one.
+    Duplicate,
+    /// The fix is older than the last accepted one.
+    OutOfOrder,
+    /// Reaching the fix from the last accepted one would require moving implausibly fast.
+    ImplausibleSpeed {
+        /// The speed (in meters per second) implied by the two fixes.
+        implied_speed: f64,
+    },
+}
+
+/// Configures moving back to an earlier step when the user is clearly back on it
+/// (ex: after driving around a parking lot, or GPS drift).
+///
+/// The most recently completed step within range is reactivated as the current step,
+/// and instructions and progress are derived from it again.
+/// Waypoints which have already been reached are not restored.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct StepRegressionConfig {
+    /// The user must be within this distance (in meters) of an earlier step's line.
+    #[serde(alias = "maximum_distance_from_step")]
+    pub maximum_distance_from_step: f64,
+    /// The user must be farther than this distance (in meters) from the current step's line.
+    ///
+    /// This keeps GPS noise around the start of the current step
+    /// from flipping back and forth between steps.
+    #[serde(alias = "minimum_distance_from_current_step")]
+    pub minimum_distance_from_current_step: f64,
+}
+
+impl Default for StepRegressionConfig {
+    fn default() -> Self {
+        Self {
+            maximum_distance_from_step: 10.0,
+            minimum_distance_from_current_step: 30.0,
+        }
+    }
+}
+
+/// A change of the speed limit ahead of the user.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct SpeedLimitChange {
+    /// The speed limit after the change.
+    #[serde(alias = "speed_limit")]
+    pub speed_limit: SegmentMaxSpeed,
+    /// The distance (in meters) along the route from the user to where the new limit applies.
+    #[serde(alias = "distance_to_change")]
+    pub distance_to_change: f64,
+}
+
+/// A maneuver ahead of the user (see `upcoming_maneuvers` in [`TripState::Navigating`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct UpcomingManeuver {
+    /// The broad class of the maneuver, if known.
+    #[serde(alias = "maneuver_type")]
+    pub maneuver_type: Option<ManeuverType>,
+    /// Additional detail about the maneuver, if known.
+    #[serde(alias = "maneuver_modifier")]
+    pub maneuver_modifier: Option<ManeuverModifier>,
+    /// The name of the road after the maneuver.
+    #[serde(alias = "road_name")]
+    pub road_name: Option<String>,
+    /// The distance (in meters) along the route from the user's snapped location to the maneuver.
+    #[serde(alias = "distance_to_maneuver")]
+    pub distance_to_maneuver: f64,
+    /// The estimated travel time (in seconds) from the user's snapped location to the maneuver.
+    #[serde(alias = "duration_to_maneuver")]
+    pub duration_to_maneuver: f64,
+    /// Whether this maneuver closely follows the previous one
+    /// (see [`ManeuverPreviewConfig::closely_spaced_distance`]).
+    ///
+    /// This is never set for the first upcoming maneuver.
+    #[serde(alias = "is_closely_spaced")]
+    pub is_closely_spaced: bool,
+}
+
+/// Configures the preview of upcoming maneuvers (see `upcoming_maneuvers` in [`TripState::Navigating`]).
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct ManeuverPreviewConfig {
+    /// The maximum number of upcoming maneuvers to include.
+    #[serde(alias = "maximum_maneuvers")]
+    pub maximum_maneuvers: u16,
+    /// Maneuvers at most this distance (in meters) after the previous one are flagged as closely spaced
+    /// (ex: so that the UI can show "then turn left" alongside the current instruction).
+    #[serde(alias = "closely_spaced_distance")]
+    pub closely_spaced_distance: f64,
+}
+
+impl Default for ManeuverPreviewConfig {
+    fn default() -> Self {
+        Self {
+            maximum_maneuvers: 3,
+            closely_spaced_distance: 50.0,
+        }
+    }
+}
+
+/// An incident along the route (see `upcoming_incident` in [`TripState::Navigating`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct UpcomingIncident {
+    /// The incident.
+    pub incident: Incident,
+    /// The distance (in meters) along the route from the user's snapped location
+    /// to the start of the incident.
+    ///
+    /// This is zero when the user is inside the incident.
+    #[serde(alias = "distance_to_incident")]
+    pub distance_to_incident: f64,
+    /// The estimated travel time (in seconds) from the user's snapped location
+    /// to the start of the incident.
+    #[serde(alias = "duration_to_incident")]
+    pub duration_to_incident: f64,
+    /// Whether the user is currently between the start and end of the incident.
+    ///
+    /// Only incidents on the current step can be this one,
+    /// as route steps only carry the incidents which lie entirely within them.
+    #[serde(alias = "is_inside")]
+    pub is_inside: bool,
+}
+
+/// Configures the reporting of incidents along the route (see `upcoming_incident` in [`TripState::Navigating`]).
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct IncidentsConfig {
+    /// Incidents farther than this distance (in meters) along the route are not reported.
+    #[serde(alias = "maximum_distance")]
+    pub maximum_distance: f64,
+}
+
+impl Default for IncidentsConfig {
+    fn default() -> Self {
+        Self {
+            maximum_distance: 10_000.0,
+        }
+    }
+}
+
+/// Configures flagging users who travel faster than the speed limit
+/// (see `is_speeding` in [`TripState::Navigating`]).
+///
+/// Users are never flagged when either their speed or the speed limit is unknown.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "wasm-bindgen", derive(Tsify))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "wasm-bindgen", tsify(into_wasm_abi, from_wasm_abi))]
+pub struct SpeedingConfig {
+    /// How much (in meters per second) the user's speed may exceed the speed limit
+    /// before they are flagged.
+    pub tolerance: f64,
+}
+
+impl Default for SpeedingConfig {
+    fn default() -> Self {
+        // Roughly 5 km/h, which absorbs speedometer and GPS error.
+        Self { tolerance: 1.4 }
+    }
 }
 
 /// Controls when a waypoint should be marked as complete.
@@ -369,6 +1160,35 @@ pub struct NavigationControllerConfig {
     pub route_deviation_tracking: RouteDeviationTracking,
     /// Configures how the heading component of the snapped location is reported in [`TripState`].
     pub snapped_location_course_filtering: CourseFiltering,
+    /// Enables map matching of user locations to the route.
+    ///
+    /// When [`None`], each location is snapped to the closest point on the current step.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub map_matching: Option<MapMatchingConfig>,
+    /// Enables the adaptive ETA in [`TripProgress::adaptive_duration_remaining`].
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub adaptive_eta: Option<AdaptiveEtaConfig>,
+    /// Enables dead reckoning along the route during GPS outages.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub dead_reckoning: Option<DeadReckoningConfig>,
+    /// Enables the rejection of invalid, out-of-order, and implausible location fixes.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub location_filter: Option<LocationFilterConfig>,
+    /// Enables smoothing of location fixes before they are snapped to the route.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub location_smoothing: Option<LocationSmoothing>,
+    /// Enables moving back to an earlier step when the user is clearly back on it.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub step_regression: Option<StepRegressionConfig>,
+    /// Enables flagging users who travel faster than the speed limit.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub speeding: Option<SpeedingConfig>,
+    /// Enables the preview of upcoming maneuvers.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub maneuver_preview: Option<ManeuverPreviewConfig>,
+    /// Enables the reporting of incidents along the route.
+    #[cfg_attr(feature = "uniffi", uniffi(default))]
+    pub incidents: Option<IncidentsConfig>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -398,6 +1218,35 @@ pub struct SerializableNavigationControllerConfig {
     /// Configures how the heading component of the snapped location is reported in [`TripState`].
     #[serde(alias = "snapped_location_course_filtering")]
     pub snapped_location_course_filtering: CourseFiltering,
+    /// Enables map matching of user locations to the route.
+    ///
+    /// When [`None`], each location is snapped to the closest point on the current step.
+    #[serde(default, alias = "map_matching")]
+    pub map_matching: Option<MapMatchingConfig>,
+    /// Enables the adaptive ETA in [`TripProgress::adaptive_duration_remaining`].
+    #[serde(default, alias = "adaptive_eta")]
+    pub adaptive_eta: Option<AdaptiveEtaConfig>,
+    /// Enables dead reckoning along the route during GPS outages.
+    #[serde(default, alias = "dead_reckoning")]
+    pub dead_reckoning: Option<DeadReckoningConfig>,
+    /// Enables the rejection of invalid, out-of-order, and implausible location fixes.
+    #[serde(default, alias = "location_filter")]
+    pub location_filter: Option<LocationFilterConfig>,
+    /// Enables smoothing of location fixes before they are snapped to the route.
+    #[serde(default, alias = "location_smoothing")]
+    pub location_smoothing: Option<LocationSmoothing>,
+    /// Enables moving back to an earlier step when the user is clearly back on it.
+    #[serde(default, alias = "step_regression")]
+    pub step_regression: Option<StepRegressionConfig>,
+    /// Enables flagging users who travel faster than the speed limit.
+    #[serde(default)]
+    pub speeding: Option<SpeedingConfig>,
+    /// Enables the preview of upcoming maneuvers.
+    #[serde(default, alias = "maneuver_preview")]
+    pub maneuver_preview: Option<ManeuverPreviewConfig>,
+    /// Enables the reporting of incidents along the route.
+    #[serde(default)]
+    pub incidents: Option<IncidentsConfig>,
 }
 
 impl From<SerializableNavigationControllerConfig> for NavigationControllerConfig {
@@ -408,6 +1257,15 @@ impl From<SerializableNavigationControllerConfig> for NavigationControllerConfig
             arrival_step_advance_condition: js_config.arrival_step_advance_condition.into(),
             route_deviation_tracking: js_config.route_deviation_tracking,
             snapped_location_course_filtering: js_config.snapped_location_course_filtering,
+            map_matching: js_config.map_matching,
+            adaptive_eta: js_config.adaptive_eta,
+            dead_reckoning: js_config.dead_reckoning,
+            location_filter: js_config.location_filter,
+            location_smoothing: js_config.location_smoothing,
+            step_regression: js_config.step_regression,
+            speeding: js_config.speeding,
+            maneuver_preview: js_config.maneuver_preview,
+            incidents: js_config.incidents,
         }
     }
 }
@@ -420,6 +1278,15 @@ impl From<NavigationControllerConfig> for SerializableNavigationControllerConfig
             arrival_step_advance_condition: config.arrival_step_advance_condition.to_js(),
             route_deviation_tracking: config.route_deviation_tracking,
             snapped_location_course_filtering: config.snapped_location_course_filtering,
+            map_matching: config.map_matching,
+            adaptive_eta: config.adaptive_eta,
+            dead_reckoning: config.dead_reckoning,
+            location_filter: config.location_filter,
+            location_smoothing: config.location_smoothing,
+            step_regression: config.step_regression,
+            speeding: config.speeding,
+            maneuver_preview: config.maneuver_preview,
+            incidents: config.incidents,
         }
     }
 }
@@ -3,9 +3,11 @@ use std::sync::Arc;
 use super::{StepAdvanceCondition, StepAdvanceConditionSerializable, StepAdvanceResult};
 use crate::{
     algorithms::{
-        deviation_from_line, get_linestring, is_within_threshold_to_end_of_linestring,
-        snap_user_location_to_line,
+        deviation_from_line, get_bearing_to_next_point, get_linestring,
+        is_within_threshold_to_end_of_linestring, snap_user_location_to_line,
     },
+    deviation_detection::course_difference,
+    models::UserLocation,
     navigation_controller::models::TripState,
 };
 use geo::Point;
@@ -116,6 +118,79 @@ impl StepAdvanceConditionSerializable for DistanceToEndOfStepCondition {
     }
 }
 
+/// Automatically advances when the user is expected to reach the end of the step within a given time.
+///
+/// The distance threshold scales with the user's reported speed,
+/// so it is wider at highway speeds and tighter on foot.
+/// It is clamped between `minimum_distance` and `maximum_distance`,
+/// and falls back to `minimum_distance` when the location has no speed.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
+pub struct SpeedScaledDistanceToEndOfStepCondition {
+    /// The time to the end of the step, in seconds, at which to advance.
+    pub seconds_to_end_of_step: f64,
+    /// The minimum distance threshold, in meters.
+    pub minimum_distance: u16,
+    /// The maximum distance threshold, in meters.
+    pub maximum_distance: u16,
+    /// The minimum required horizontal accuracy of the user location, in meters.
+    /// Values larger than this cannot trigger a step advance.
+    pub minimum_horizontal_accuracy: u16,
+}
+
+impl StepAdvanceCondition for SpeedScaledDistanceToEndOfStepCondition {
+    fn should_advance_step(&self, trip_state: TripState) -> StepAdvanceResult {
+        let distance = trip_state
+            .user_location()
+            .map_or(self.minimum_distance, |location| self.distance(location));
+
+        let should_advance = DistanceToEndOfStepCondition {
+            distance,
+            minimum_horizontal_accuracy: self.minimum_horizontal_accuracy,
+        }
+        .should_advance_inner(&trip_state)
+        .is_some_and(|result| result.should_advance);
+
+        if should_advance {
+            StepAdvanceResult::advance_to_new_instance(self)
+        } else {
+            StepAdvanceResult::continue_with_state(self.new_instance())
+        }
+    }
+
+    fn new_instance(&self) -> Arc<dyn StepAdvanceCondition> {
+        Arc::new(*self)
+    }
+}
+
+impl SpeedScaledDistanceToEndOfStepCondition {
+    /// The distance threshold for the location's speed, in meters.
+    fn distance(&self, user_location: UserLocation) -> u16 {
+        let minimum_distance = f64::from(self.minimum_distance);
+        let maximum_distance = f64::from(self.maximum_distance).max(minimum_distance);
+
+        user_location
+            .speed
+            .map(|speed| speed.value * self.seconds_to_end_of_step)
+            .filter(|distance| distance.is_finite())
+            .map_or(self.minimum_distance, |distance| {
+                // The clamped distance always fits in a u16
+                distance.clamp(minimum_distance, maximum_distance).round() as u16
+            })
+    }
+}
+
+impl StepAdvanceConditionSerializable for SpeedScaledDistanceToEndOfStepCondition {
+    fn to_js(&self) -> SerializableStepAdvanceCondition {
+        SerializableStepAdvanceCondition::SpeedScaledDistanceToEndOfStep {
+            seconds_to_end_of_step: self.seconds_to_end_of_step,
+            minimum_distance: self.minimum_distance,
+            maximum_distance: self.maximum_distance,
+            minimum_horizontal_accuracy: self.minimum_horizontal_accuracy,
+        }
+    }
+}
+
 /// Controls when a deviation-aware step-advance condition is allowed to evaluate,
 /// based on the user's current
 /// [`RouteDeviation`](crate::deviation_detection::RouteDeviation) status.
@@ -220,6 +295,84 @@ impl StepAdvanceConditionSerializable for DistanceFromStepCondition {
     }
 }
 
+/// Advances once the user's course aligns with the initial bearing of the next step.
+///
+/// This confirms that the user has actually taken the maneuver,
+/// which avoids premature advances at complex junctions where the user is close to
+/// the maneuver point but has not turned yet.
+/// On its own, this advances as soon as the user is heading the right way,
+/// so it is intended to be composed with a distance-based condition
+/// using [`AndAdvanceConditions`].
+///
+/// If there is no next step (i.e. on the last step of the route),
+/// or the next step has no bearing (e.g. a zero-length arrival step),
+/// there is nothing to confirm and the condition advances.
+/// This lets the trip complete when composed with [`AndAdvanceConditions`].
+///
+/// This always checks the course of the raw user location.
+/// The snapped location is snapped to the current step,
+/// so its course cannot confirm a turn onto the next step.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
+pub struct NextStepBearingCondition {
+    /// The maximum acceptable difference between the course and the next step's bearing, in degrees.
+    pub max_bearing_deviation: u16,
+    /// Courses less accurate than this (in degrees) cannot trigger a step advance.
+    pub minimum_course_accuracy: u16,
+}
+
+impl StepAdvanceCondition for NextStepBearingCondition {
+    fn should_advance_step(&self, trip_state: TripState) -> StepAdvanceResult {
+        if self.is_bearing_confirmed(&trip_state) {
+            StepAdvanceResult::advance_to_new_instance(self)
+        } else {
+            StepAdvanceResult::continue_with_state(self.new_instance())
+        }
+    }
+
+    fn new_instance(&self) -> Arc<dyn StepAdvanceCondition> {
+        Arc::new(*self)
+    }
+}
+
+impl NextStepBearingCondition {
+    fn is_bearing_confirmed(self, trip_state: &TripState) -> bool {
+        let Some(next_step) = trip_state.next_step() else {
+            return true;
+        };
+        let Some(next_step_bearing) = next_step
+            .geometry
+            .windows(2)
+            .position(|segment| segment[0] != segment[1])
+            .and_then(|index| get_bearing_to_next_point(index, &next_step.get_linestring()))
+        else {
+            return true;
+        };
+
+        trip_state
+            .user_location()
+            .and_then(|location| location.course_over_ground)
+            .filter(|course| {
+                course
+                    .accuracy
+                    .is_none_or(|accuracy| accuracy <= self.minimum_course_accuracy)
+            })
+            .is_some_and(|course| {
+                course_difference(course.degrees, next_step_bearing.degrees)
+                    <= self.max_bearing_deviation
+            })
+    }
+}
+
+impl StepAdvanceConditionSerializable for NextStepBearingCondition {
+    fn to_js(&self) -> SerializableStepAdvanceCondition {
+        SerializableStepAdvanceCondition::NextStepBearing {
+            max_bearing_deviation: self.max_bearing_deviation,
+            minimum_course_accuracy: self.minimum_course_accuracy,
+        }
+    }
+}
+
 /// Advance if any of the conditions are met (OR).
 ///
 /// This is ideal for short circuit type advance conditions.
@@ -702,6 +855,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_speed_scaled_distance_to_end_of_step_advance() {
+        use crate::models::Speed;
+
+        let condition = SpeedScaledDistanceToEndOfStepCondition {
+            seconds_to_end_of_step: 5.0,
+            minimum_distance: 10,
+            maximum_distance: 50,
+            minimum_horizontal_accuracy: 10,
+        };
+        // ~33 meters from the end of the step
+        let location_at_speed = |speed: Option<f64>| UserLocation {
+            speed: speed.map(|value| Speed {
+                value,
+                accuracy: None,
+            }),
+            ..make_user_location(coord!(x: 0.0007, y: 0.0), 5.0)
+        };
+        let should_advance = |condition: &SpeedScaledDistanceToEndOfStepCondition,
+                              speed: Option<f64>| {
+            let trip_state = get_navigating_trip_state(
+                location_at_speed(speed),
+                vec![STRAIGHT_LINE_SHORT_ROUTE_STEP.clone()],
+                vec![],
+                RouteDeviation::NoDeviation,
+            );
+            condition.should_advance_step(trip_state).should_advance
+        };
+
+        // Without a speed, and on foot, the minimum distance applies
+        assert!(!should_advance(&condition, None));
+        assert!(!should_advance(&condition, Some(1.4)));
+        // 5 seconds at highway speed is clamped to the maximum distance
+        assert!(should_advance(&condition, Some(30.0)));
+        assert!(!should_advance(
+            &SpeedScaledDistanceToEndOfStepCondition {
+                maximum_distance: 20,
+                ..condition
+            },
+            Some(30.0)
+        ));
+
+        // Round trips through the serializable representation
+        let json = serde_json::to_string(&condition.to_js()).unwrap();
+        let condition: Arc<dyn StepAdvanceCondition> =
+            serde_json::from_str::<SerializableStepAdvanceCondition>(&json)
+                .unwrap()
+                .into();
+        assert!(
+            condition
+                .should_advance_step(get_navigating_trip_state(
+                    location_at_speed(Some(30.0)),
+                    vec![STRAIGHT_LINE_SHORT_ROUTE_STEP.clone()],
+                    vec![],
+                    RouteDeviation::NoDeviation,
+                ))
+                .should_advance
+        );
+    }
+
     #[test]
     fn test_distance_from_step_advance_with_deviation() {
         // Create a location that's far from the route (500+ meters north)
@@ -927,6 +1140,135 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_next_step_bearing_condition_in_and_composite_advance() {
+        use crate::models::CourseOverGround;
+
+        let next_step = gen_route_step_with_coords(vec![
+            coord!(x: 0.001, y: 0.0),
+            coord!(x: 0.001, y: 0.001), // North
+        ]);
+        let bearing_condition = NextStepBearingCondition {
+            max_bearing_deviation: 30,
+            minimum_course_accuracy: 20,
+        };
+        let and_condition = AndAdvanceConditions {
+            conditions: vec![
+                Arc::new(DistanceToEndOfStepCondition {
+                    minimum_horizontal_accuracy: 10,
+                    distance: 20,
+                }),
+                Arc::new(bearing_condition),
+            ],
+        };
+        let trip_state_with_course = |location: UserLocation, degrees: u16, accuracy: u16| {
+            get_navigating_trip_state(
+                UserLocation {
+                    course_over_ground: Some(CourseOverGround::new(
+                        f64::from(degrees),
+                        Some(accuracy),
+                    )),
+                    ..location
+                },
+                vec![STRAIGHT_LINE_SHORT_ROUTE_STEP.clone(), next_step.clone()],
+                vec![],
+                RouteDeviation::NoDeviation,
+            )
+        };
+
+        // Close to the maneuver, but still heading east
+        let result = and_condition.should_advance_step(trip_state_with_course(
+            *LOCATION_NEAR_END_OF_STEP,
+            90,
+            5,
+        ));
+        assert!(
+            !result.should_advance,
+            "Should not advance before the user has turned"
+        );
+
+        // Turned north, but with an inaccurate course
+        let result = and_condition.should_advance_step(trip_state_with_course(
+            *LOCATION_NEAR_END_OF_STEP,
+            10,
+            45,
+        ));
+        assert!(
+            !result.should_advance,
+            "Should not advance with an inaccurate course"
+        );
+
+        // Turned north, but still far from the maneuver
+        let result = and_condition.should_advance_step(trip_state_with_course(
+            *LOCATION_NEAR_START_OF_STEP,
+            10,
+            5,
+        ));
+        assert!(
+            !result.should_advance,
+            "Should not advance when far from the end of the step"
+        );
+
+        let result = and_condition.should_advance_step(trip_state_with_course(
+            *LOCATION_NEAR_END_OF_STEP,
+            10,
+            5,
+        ));
+        assert!(
+            result.should_advance,
+            "Should advance once the user has turned onto the next step"
+        );
+    }
+
+    #[test]
+    fn test_next_step_bearing_condition_without_a_maneuver_to_confirm() {
+        let bearing_condition = NextStepBearingCondition {
+            max_bearing_deviation: 30,
+            minimum_course_accuracy: 20,
+        };
+
+        // A zero-length next step has no bearing to confirm
+        let trip_state = get_navigating_trip_state(
+            *LOCATION_NEAR_END_OF_STEP,
+            vec![
+                STRAIGHT_LINE_SHORT_ROUTE_STEP.clone(),
+                gen_route_step_with_coords(vec![
+                    coord!(x: 0.001, y: 0.0),
+                    coord!(x: 0.001, y: 0.0),
+                ]),
+            ],
+            vec![],
+            RouteDeviation::NoDeviation,
+        );
+        assert!(
+            bearing_condition
+                .should_advance_step(trip_state)
+                .should_advance,
+            "Should advance when the next step has no bearing"
+        );
+
+        // Neither does the last step, so the trip can complete
+        let trip_state = get_navigating_trip_state(
+            *LOCATION_NEAR_END_OF_STEP,
+            vec![STRAIGHT_LINE_SHORT_ROUTE_STEP.clone()],
+            vec![],
+            RouteDeviation::NoDeviation,
+        );
+        let and_condition = AndAdvanceConditions {
+            conditions: vec![
+                Arc::new(DistanceToEndOfStepCondition {
+                    minimum_horizontal_accuracy: 10,
+                    distance: 20,
+                }),
+                Arc::new(bearing_condition),
+            ],
+        };
+        assert!(
+            and_condition.should_advance_step(trip_state).should_advance,
+            "Should advance on the last step"
+        );
+    }
+
     // Stateful Conditions
 
     #[test]
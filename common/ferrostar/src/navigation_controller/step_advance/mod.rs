@@ -4,7 +4,8 @@ use crate::navigation_controller::{
     step_advance::conditions::{
         AndAdvanceConditions, DeviationCalculationPolicy, DistanceEntryAndExitCondition,
         DistanceEntryAndSnappedExitCondition, DistanceFromStepCondition,
-        DistanceToEndOfStepCondition, ManualStepCondition, OrAdvanceConditions,
+        DistanceToEndOfStepCondition, ManualStepCondition, NextStepBearingCondition,
+        OrAdvanceConditions, SpeedScaledDistanceToEndOfStepCondition,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -112,6 +113,17 @@ pub enum SerializableStepAdvanceCondition {
         minimum_horizontal_accuracy: u16,
     },
     #[serde(rename_all = "camelCase")]
+    SpeedScaledDistanceToEndOfStep {
+        #[serde(alias = "seconds_to_end_of_step")]
+        seconds_to_end_of_step: f64,
+        #[serde(alias = "minimum_distance")]
+        minimum_distance: u16,
+        #[serde(alias = "maximum_distance")]
+        maximum_distance: u16,
+        #[serde(alias = "minimum_horizontal_accuracy")]
+        minimum_horizontal_accuracy: u16,
+    },
+    #[serde(rename_all = "camelCase")]
     DistanceFromStep {
         distance: u16,
         #[serde(alias = "minimum_horizontal_accuracy")]
@@ -120,6 +132,13 @@ pub enum SerializableStepAdvanceCondition {
         calculation_policy: DeviationCalculationPolicy,
     },
     #[serde(rename_all = "camelCase")]
+    NextStepBearing {
+        #[serde(alias = "max_bearing_deviation")]
+        max_bearing_deviation: u16,
+        #[serde(alias = "minimum_course_accuracy")]
+        minimum_course_accuracy: u16,
+    },
+    #[serde(rename_all = "camelCase")]
     DistanceEntryExit {
         #[serde(alias = "distance_to_end_of_step")]
         distance_to_end_of_step: u16,
@@ -162,6 +181,17 @@ impl From<SerializableStepAdvanceCondition> for Arc<dyn StepAdvanceCondition> {
                 distance,
                 minimum_horizontal_accuracy,
             }),
+            SerializableStepAdvanceCondition::SpeedScaledDistanceToEndOfStep {
+                seconds_to_end_of_step,
+                minimum_distance,
+                maximum_distance,
+                minimum_horizontal_accuracy,
+            } => Arc::new(SpeedScaledDistanceToEndOfStepCondition {
+                seconds_to_end_of_step,
+                minimum_distance,
+                maximum_distance,
+                minimum_horizontal_accuracy,
+            }),
             SerializableStepAdvanceCondition::DistanceFromStep {
                 distance,
                 minimum_horizontal_accuracy,
@@ -171,6 +201,13 @@ impl From<SerializableStepAdvanceCondition> for Arc<dyn StepAdvanceCondition> {
                 minimum_horizontal_accuracy,
                 calculation_policy,
             }),
+            SerializableStepAdvanceCondition::NextStepBearing {
+                max_bearing_deviation,
+                minimum_course_accuracy,
+            } => Arc::new(NextStepBearingCondition {
+                max_bearing_deviation,
+                minimum_course_accuracy,
+            }),
             SerializableStepAdvanceCondition::DistanceEntryExit {
                 minimum_horizontal_accuracy,
                 distance_to_end_of_step,
@@ -234,6 +271,28 @@ pub fn step_advance_distance_to_end_of_step(
     })
 }
 
+/// Convenience function for creating a [`SpeedScaledDistanceToEndOfStepCondition`].
+///
+/// This advances to the next step when the user is within `seconds_to_end_of_step` seconds
+/// of the last point in the current route step at their current speed.
+/// The distance is clamped between `minimum_distance` and `maximum_distance` meters.
+/// Does not advance unless the reported location accuracy is `minimum_horizontal_accuracy` meters or better.
+#[cfg(feature = "uniffi")]
+#[uniffi::export]
+pub fn step_advance_speed_scaled_distance_to_end_of_step(
+    seconds_to_end_of_step: f64,
+    minimum_distance: u16,
+    maximum_distance: u16,
+    minimum_horizontal_accuracy: u16,
+) -> Arc<dyn StepAdvanceCondition> {
+    Arc::new(SpeedScaledDistanceToEndOfStepCondition {
+        seconds_to_end_of_step,
+        minimum_distance,
+        maximum_distance,
+        minimum_horizontal_accuracy,
+    })
+}
+
 /// Convenience function for creating a [`DistanceFromStepCondition`].
 ///
 /// This advances to the next step when the user is at least `distance` meters away from any point
@@ -257,6 +316,26 @@ pub fn step_advance_distance_from_step(
     })
 }
 
+/// Convenience function for creating a [`NextStepBearingCondition`].
+///
+/// This advances to the next step once the user's course is within `max_bearing_deviation` degrees
+/// of the initial bearing of the next step.
+/// Courses less accurate than `minimum_course_accuracy` degrees are ignored.
+///
+/// Combine this with a distance-based condition using [`step_advance_and`]
+/// to confirm that the user has taken the maneuver.
+#[cfg(feature = "uniffi")]
+#[uniffi::export]
+pub fn step_advance_next_step_bearing(
+    max_bearing_deviation: u16,
+    minimum_course_accuracy: u16,
+) -> Arc<dyn StepAdvanceCondition> {
+    Arc::new(NextStepBearingCondition {
+        max_bearing_deviation,
+        minimum_course_accuracy,
+    })
+}
+
 /// Convenience function for creating an [`OrAdvanceConditions`].
 ///
 /// This composes multiple conditions together and advances to the next step if ANY of them trigger.
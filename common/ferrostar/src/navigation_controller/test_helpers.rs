@@ -3,19 +3,24 @@ use std::sync::Arc;
 use crate::deviation_detection::RouteDeviation;
 use crate::deviation_detection::RouteDeviationTracking;
 use crate::models::{
-    BoundingBox, GeographicCoordinate, Route, RouteStep, UserLocation, Waypoint, WaypointKind,
+    BoundingBox, GeographicCoordinate, Route, RouteStep, SegmentAnnotation, SegmentMaxSpeed,
+    UserLocation, Waypoint, WaypointKind,
 };
+use crate::navigation_controller::NavigationController;
 use crate::navigation_controller::models::{
     CourseFiltering, NavigationControllerConfig, TripProgress, TripState, TripSummary,
     WaypointAdvanceMode,
 };
 use crate::navigation_controller::step_advance::StepAdvanceCondition;
-use crate::navigation_controller::step_advance::conditions::DistanceToEndOfStepCondition;
+use crate::navigation_controller::step_advance::conditions::{
+    DistanceToEndOfStepCondition, ManualStepCondition,
+};
 #[cfg(feature = "alloc")]
 use alloc::string::ToString;
 use chrono::{DateTime, Utc};
 use geo::{BoundingRect, Coord, Distance, Haversine, LineString, Point, point};
 use insta::{Settings, dynamic_redaction};
+use serde_json::json;
 
 pub fn get_test_navigation_controller_config(
     step_advance_condition: Arc<dyn StepAdvanceCondition>,
@@ -30,6 +35,15 @@ pub fn get_test_navigation_controller_config(
             max_acceptable_deviation: 0.0,
         },
         snapped_location_course_filtering: CourseFiltering::Raw,
+        map_matching: None,
+        adaptive_eta: None,
+        dead_reckoning: None,
+        location_filter: None,
+        location_smoothing: None,
+        step_regression: None,
+        speeding: None,
+        maneuver_preview: None,
+        incidents: None,
         step_advance_condition,
         arrival_step_advance_condition: Arc::new(DistanceToEndOfStepCondition {
             distance: 5,
@@ -38,6 +52,17 @@ pub fn get_test_navigation_controller_config(
     }
 }
 
+/// Creates a controller for `route` using the [test configuration](get_test_navigation_controller_config)
+/// with manual step advance, as adjusted by `configure`.
+pub fn get_test_navigation_controller(
+    route: Route,
+    configure: impl FnOnce(&mut NavigationControllerConfig),
+) -> NavigationController {
+    let mut config = get_test_navigation_controller_config(Arc::new(ManualStepCondition));
+    configure(&mut config);
+    NavigationController::new(route, config)
+}
+
 pub fn get_test_step_advance_condition(distance: u16) -> Arc<dyn StepAdvanceCondition> {
     Arc::new(DistanceToEndOfStepCondition {
         distance,
@@ -76,9 +101,28 @@ pub fn gen_dummy_route_step(
         incidents: vec![],
         driving_side: None,
         roundabout_exit_number: None,
+        leg_index: 0,
     }
 }
 
+/// Encodes a segment annotation the way an OSRM-style route response would
+/// (see [`RouteStep::annotations`]).
+pub fn gen_annotation_json(annotation: SegmentAnnotation) -> String {
+    let max_speed = annotation.max_speed.map(|max_speed| match max_speed {
+        SegmentMaxSpeed::NoLimit => json!({ "none": true }),
+        SegmentMaxSpeed::Unknown => json!({ "unknown": true }),
+        SegmentMaxSpeed::Known { speed, unit } => json!({ "speed": speed, "unit": unit }),
+    });
+    json!({
+        "distance": annotation.distance,
+        "duration": annotation.duration,
+        "speed": annotation.speed,
+        "maxspeed": max_speed,
+        "congestion_numeric": annotation.congestion.map(|congestion| congestion.value),
+    })
+    .to_string()
+}
+
 /// Creates a RouteStep with a list of coordinates.
 ///
 /// This is useful for testing specific scenarios where you need more control over the
@@ -124,6 +168,7 @@ pub fn gen_route_step_with_coords(coordinates: Vec<Coord>) -> RouteStep {
         incidents: vec![],
         driving_side: None,
         roundabout_exit_number: None,
+        leg_index: 0,
     }
 }
 
@@ -279,6 +324,9 @@ pub fn get_navigating_trip_state(
             distance_to_next_maneuver: 100.0,
             distance_remaining: 1000.0,
             duration_remaining: 600.0,
+            adaptive_duration_remaining: None,
+            current_leg_index: 0,
+            remaining_legs: vec![],
         },
         deviation,
         summary: TripSummary {
@@ -290,5 +338,14 @@ pub fn get_navigating_trip_state(
         visual_instruction: None,
         spoken_instruction: None,
         annotation_json: None,
+        segment_annotation: None,
+        speed_limit: None,
+        next_speed_limit_change: None,
+        is_speeding: false,
+        upcoming_maneuvers: vec![],
+        upcoming_incident: None,
+        location_estimated: false,
+        raw_user_location: None,
+        heading: None,
     }
 }
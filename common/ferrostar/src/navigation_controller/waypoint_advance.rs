@@ -99,7 +99,9 @@ impl WaypointAdvanceChecker {
                     }
                 }
             }
-            TripState::Complete { .. } | TripState::Idle { .. } => WaypointAdvanceResult::Unchanged,
+            TripState::Complete { .. } | TripState::Idle { .. } | TripState::Paused { .. } => {
+                WaypointAdvanceResult::Unchanged
+            }
         }
     }
 }
@@ -6,8 +6,8 @@ use chrono::{Duration, Utc};
 
 #[cfg(feature = "uniffi")]
 use crate::{
-    models::{Route, UserLocation},
-    navigation_controller::models::NavState,
+    models::{Heading, Route, UserLocation},
+    navigation_controller::{events::NavigationEvent, models::NavState},
     navigation_session::{
         NavigationObserver,
         caching::models::{NavigationCachingConfig, NavigationSessionSnapshot},
@@ -90,20 +90,11 @@ impl NavigationObserver for NavigationSessionCache {
         #[allow(unused_variables)] location: UserLocation,
         state: NavState,
     ) {
-        let should_cache = self
-            .current_record
-            .lock()
-            .ok()
-            .and_then(|record| {
-                record.as_ref().map(|record| {
-                    let elapsed = Utc::now() - record.saved_at;
-                    let interval = Duration::seconds(self.config.cache_interval_seconds);
-                    elapsed > interval
-                })
-            })
-            .unwrap_or(false);
+        self.handle_update(state, self.cache_interval_elapsed());
+    }
 
-        self.handle_update(state, should_cache);
+    fn on_heading_update(&self, #[allow(unused_variables)] heading: Heading, state: NavState) {
+        self.handle_update(state, self.cache_interval_elapsed());
     }
 
     fn on_advance_to_next_step(&self, state: NavState) {
@@ -121,10 +112,56 @@ impl NavigationObserver for NavigationSessionCache {
         // We don't need to cache the snapshot here because it will be cached immediately after
         // when on_get_initial_state is called. See `NavigationSession` for observer sequences.
     }
+
+    fn on_route_replaced(&self, route: Route, state: NavState) {
+        if let Ok(mut record) = self.current_record.lock() {
+            *record = Some(NavigationSessionSnapshot {
+                saved_at: Utc::now(),
+                route,
+                trip_state: None,
+            });
+        }
+        self.handle_update(state, true);
+    }
+
+    fn on_pause(&self, state: NavState) {
+        self.handle_update(state, true);
+    }
+
+    fn on_resume(&self, state: NavState) {
+        self.handle_update(state, true);
+    }
+
+    fn on_cancel(&self, #[allow(unused_variables)] state: NavState) {
+        // A canceled trip can't be resumed, so there is nothing left to cache.
+        if let Ok(mut record) = self.current_record.lock() {
+            *record = None;
+        }
+        self.cache.delete();
+    }
+
+    fn on_navigation_events(&self, #[allow(unused_variables)] events: Vec<NavigationEvent>) {
+        // The cached snapshot only needs the resulting state.
+    }
 }
 
 #[cfg(feature = "uniffi")]
 impl NavigationSessionCache {
+    /// Whether the cache interval has passed since the record was last saved.
+    fn cache_interval_elapsed(&self) -> bool {
+        self.current_record
+            .lock()
+            .ok()
+            .and_then(|record| {
+                record.as_ref().map(|record| {
+                    let elapsed = Utc::now() - record.saved_at;
+                    let interval = Duration::seconds(self.config.cache_interval_seconds);
+                    elapsed > interval
+                })
+            })
+            .unwrap_or(false)
+    }
+
     fn handle_update(&self, state: NavState, should_cache: bool) {
         if !should_cache {
             return;
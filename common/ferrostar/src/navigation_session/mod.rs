@@ -1,26 +1,41 @@
 use std::sync::Arc;
 
 use crate::{
-    models::{Route, UserLocation},
+    models::{Heading, Route, UserLocation},
     navigation_controller::{
         NavigationController, Navigator,
-        models::{NavState, NavigationControllerConfig},
+        events::NavigationEvent,
+        models::{CancellationReason, NavState, NavigationControllerConfig, TripState},
     },
 };
+#[cfg(all(feature = "std", not(feature = "web-time")))]
+use std::time::SystemTime;
+#[cfg(feature = "web-time")]
+use web_time::SystemTime;
 
 #[cfg(test)]
 pub(crate) mod test_helpers;
 
 pub mod caching;
 pub mod recording;
+pub mod rerouting;
 pub mod specialized;
 
 #[cfg_attr(feature = "uniffi", uniffi::export(with_foreign))]
 pub trait NavigationObserver: Send + Sync {
     fn on_get_initial_state(&self, state: NavState);
     fn on_user_location_update(&self, location: UserLocation, state: NavState);
+    fn on_heading_update(&self, heading: Heading, state: NavState);
     fn on_advance_to_next_step(&self, state: NavState);
     fn on_route_available(&self, route: Route);
+    fn on_route_replaced(&self, route: Route, state: NavState);
+    fn on_pause(&self, state: NavState);
+    fn on_resume(&self, state: NavState);
+    fn on_cancel(&self, state: NavState);
+    /// Called with the events produced by a navigation state update (if there were any).
+    ///
+    /// This is called after the observer method for the update itself.
+    fn on_navigation_events(&self, events: Vec<NavigationEvent>);
 }
 
 #[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
@@ -55,6 +70,7 @@ impl Navigator for NavigationSession {
             observer.on_route_available(self.route());
             observer.on_get_initial_state(state.clone());
         }
+        self.notify_navigation_events(&state);
         state
     }
 
@@ -63,6 +79,7 @@ impl Navigator for NavigationSession {
         for observer in &self.observers {
             observer.on_advance_to_next_step(state.clone());
         }
+        self.notify_navigation_events(&state);
         state
     }
 
@@ -71,8 +88,83 @@ impl Navigator for NavigationSession {
         for observer in &self.observers {
             observer.on_user_location_update(location, state.clone());
         }
+        self.notify_navigation_events(&state);
         state
     }
+
+    fn replace_route(&self, route: Route, state: NavState) -> NavState {
+        let state = self.controller.replace_route(route.clone(), state);
+        for observer in &self.observers {
+            observer.on_route_replaced(route.clone(), state.clone());
+        }
+        self.notify_navigation_events(&state);
+        state
+    }
+
+    fn pause(&self, state: NavState) -> NavState {
+        let state = self.controller.pause(state);
+        for observer in &self.observers {
+            observer.on_pause(state.clone());
+        }
+        self.notify_navigation_events(&state);
+        state
+    }
+
+    fn resume(&self, state: NavState) -> NavState {
+        let state = self.controller.resume(state);
+        for observer in &self.observers {
+            observer.on_resume(state.clone());
+        }
+        self.notify_navigation_events(&state);
+        state
+    }
+
+    fn cancel(&self, state: NavState, reason: CancellationReason) -> NavState {
+        let state = self.controller.cancel(state, reason);
+        for observer in &self.observers {
+            observer.on_cancel(state.clone());
+        }
+        self.notify_navigation_events(&state);
+        state
+    }
+
+    fn estimate_location(&self, timestamp: SystemTime, state: NavState) -> NavState {
+        let state = self.controller.estimate_location(timestamp, state);
+        // Observers see the estimate like any other location update.
+        if let TripState::Navigating {
+            user_location,
+            location_estimated: true,
+            ..
+        } = state.trip_state()
+        {
+            for observer in &self.observers {
+                observer.on_user_location_update(user_location, state.clone());
+            }
+        }
+        self.notify_navigation_events(&state);
+        state
+    }
+
+    fn update_heading(&self, heading: Heading, state: NavState) -> NavState {
+        let state = self.controller.update_heading(heading, state);
+        for observer in &self.observers {
+            observer.on_heading_update(heading, state.clone());
+        }
+        self.notify_navigation_events(&state);
+        state
+    }
+}
+
+impl NavigationSession {
+    /// Notifies observers of the events produced by the update that resulted in `state`.
+    fn notify_navigation_events(&self, state: &NavState) {
+        if state.events().is_empty() {
+            return;
+        }
+        for observer in &self.observers {
+            observer.on_navigation_events(state.events().to_vec());
+        }
+    }
 }
 
 /// Creates a new navigation session for the given route and configuration.
@@ -1,6 +1,9 @@
 use crate::{
-    models::{Route, UserLocation},
-    navigation_controller::models::{NavState, NavigationControllerConfig},
+    models::{Heading, Route, UserLocation},
+    navigation_controller::{
+        events::NavigationEvent,
+        models::{NavState, NavigationControllerConfig},
+    },
     navigation_session::{
         NavigationObserver,
         recording::models::{
@@ -64,6 +67,18 @@ impl NavigationObserver for NavigationRecorder {
         }
     }
 
+    fn on_heading_update(
+        &self,
+        // The heading is captured in the NavState
+        #[allow(unused_variables)] heading: Heading,
+        state: NavState,
+    ) {
+        let event = NavigationRecordingEvent::state_update(state.into());
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event);
+        }
+    }
+
     fn on_advance_to_next_step(&self, state: NavState) {
         let event = NavigationRecordingEvent::state_update(state.into());
         if let Ok(mut events) = self.events.lock() {
@@ -74,15 +89,53 @@ impl NavigationObserver for NavigationRecorder {
     fn on_route_available(&self, #[allow(unused_variables)] route: Route) {
         // TODO: We could capture the route on the recording if desired.
     }
+
+    fn on_route_replaced(&self, route: Route, state: NavState) {
+        let route_event = NavigationRecordingEvent::route_update(route);
+        let state_event = NavigationRecordingEvent::state_update(state.into());
+        if let Ok(mut events) = self.events.lock() {
+            events.push(route_event);
+            events.push(state_event);
+        }
+    }
+
+    fn on_pause(&self, state: NavState) {
+        let event = NavigationRecordingEvent::state_update(state.into());
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event);
+        }
+    }
+
+    fn on_resume(&self, state: NavState) {
+        let event = NavigationRecordingEvent::state_update(state.into());
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event);
+        }
+    }
+
+    fn on_cancel(&self, state: NavState) {
+        let event = NavigationRecordingEvent::state_update(state.into());
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event);
+        }
+    }
+
+    fn on_navigation_events(&self, #[allow(unused_variables)] events: Vec<NavigationEvent>) {
+        // Events can be derived from the recorded state updates on replay.
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use geo::coord;
     use std::sync::Arc;
 
+    use crate::models::Heading;
     use crate::routing_adapters::osrm::models::OsrmWaypointProperties;
-    use crate::test_utils::{TestRoute, redact_properties};
+    use crate::test_utils::{TestRoute, make_user_location, redact_properties};
     use crate::{
+        navigation_controller::Navigator,
+        navigation_controller::models::{CancellationReason, TripState},
         navigation_controller::{
             NavigationController,
             test_helpers::{
@@ -90,7 +143,9 @@ mod tests {
                 nav_controller_insta_settings,
             },
         },
-        navigation_session::recording::models::{NavigationRecording, RecordingError},
+        navigation_session::recording::models::{
+            NavigationRecording, NavigationRecordingEventData, RecordingError,
+        },
         navigation_session::{
             NavigationSession, recording::NavigationRecorder,
             test_helpers::test_full_route_state_snapshot,
@@ -200,4 +255,105 @@ mod tests {
                 .contains("system time exceeds epoch milliseconds")
         );
     }
+
+    #[test]
+    fn test_recording_route_replacement() {
+        let route = TestRoute::Valhalla.first_route();
+        let new_route = TestRoute::ValhallaExtended.first_route();
+        let config = get_test_navigation_controller_config(get_test_step_advance_condition(0));
+        let recorder = Arc::new(NavigationRecorder::new(route.clone(), config.clone()));
+        let session = NavigationSession::new(
+            Arc::new(NavigationController::new(route.clone(), config)),
+            vec![recorder.clone()],
+        );
+
+        let start = route.geometry[0];
+        let state =
+            session.get_initial_state(make_user_location(coord!(x: start.lng, y: start.lat), 5.0));
+        let _ = session.replace_route(new_route.clone(), state);
+
+        let events = recorder.get_events();
+        assert_eq!(events.len(), 3);
+        match &events[1].event_data {
+            NavigationRecordingEventData::RouteUpdate { route } => {
+                assert_eq!(route.geometry, new_route.geometry);
+            }
+            other @ NavigationRecordingEventData::StateUpdate { .. } => {
+                panic!("Expected RouteUpdate, got {other:?}")
+            }
+        }
+        assert!(matches!(
+            events[2].event_data,
+            NavigationRecordingEventData::StateUpdate { .. }
+        ));
+    }
+
+    #[test]
+    fn test_recording_heading_update() {
+        let route = TestRoute::Valhalla.first_route();
+        let config = get_test_navigation_controller_config(get_test_step_advance_condition(0));
+        let recorder = Arc::new(NavigationRecorder::new(route.clone(), config.clone()));
+        let session = NavigationSession::new(
+            Arc::new(NavigationController::new(route.clone(), config)),
+            vec![recorder.clone()],
+        );
+
+        let start = route.geometry[0];
+        let location = make_user_location(coord!(x: start.lng, y: start.lat), 5.0);
+        let heading = Heading {
+            true_heading: 90,
+            accuracy: 10,
+            timestamp: location.timestamp,
+        };
+        let state = session.get_initial_state(location);
+        let _ = session.update_heading(heading, state);
+
+        let events = recorder.get_events();
+        assert_eq!(events.len(), 2);
+        match &events[1].event_data {
+            NavigationRecordingEventData::StateUpdate {
+                trip_state:
+                    TripState::Navigating {
+                        heading: recorded_heading,
+                        ..
+                    },
+                ..
+            } => assert_eq!(recorded_heading, &Some(heading)),
+            other => panic!("Expected a navigating StateUpdate, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_recording_cancellation() {
+        let route = TestRoute::Valhalla.first_route();
+        let config = get_test_navigation_controller_config(get_test_step_advance_condition(0));
+        let recorder = Arc::new(NavigationRecorder::new(route.clone(), config.clone()));
+        let session = NavigationSession::new(
+            Arc::new(NavigationController::new(route.clone(), config)),
+            vec![recorder.clone()],
+        );
+
+        let start = route.geometry[0];
+        let state =
+            session.get_initial_state(make_user_location(coord!(x: start.lng, y: start.lat), 5.0));
+        let _ = session.cancel(state, CancellationReason::UserCanceled);
+
+        let events = recorder.get_events();
+        assert_eq!(events.len(), 2);
+        match &events[1].event_data {
+            NavigationRecordingEventData::StateUpdate {
+                trip_state:
+                    TripState::Complete {
+                        summary,
+                        cancellation_reason,
+                        ..
+                    },
+                ..
+            } => {
+                assert!(summary.ended_at.is_some());
+                assert_eq!(cancellation_reason, &Some(CancellationReason::UserCanceled));
+            }
+            other => panic!("Expected a canceled StateUpdate, got {other:?}"),
+        }
+    }
 }
@@ -121,6 +121,11 @@ impl NavigationRecordingEvent {
         })
     }
 
+    /// Create a [`NavigationRecordingEventData::RouteUpdate`] event from a new route.
+    pub fn route_update(route: Route) -> Self {
+        Self::new(NavigationRecordingEventData::RouteUpdate { route })
+    }
+
     pub fn timestamp(&self) -> i64 {
         self.timestamp
     }
@@ -137,7 +142,9 @@ pub enum NavigationRecordingEventData {
         trip_state: TripState,
         step_advance_condition: SerializableStepAdvanceCondition,
     },
-    // TODO: Figure out how to record re-routes.
+    /// The route was replaced mid-trip (e.g. after a reroute).
+    ///
+    /// This is followed by a state update with the state on the new route.
     RouteUpdate {
         /// Updated route.
         route: Route,
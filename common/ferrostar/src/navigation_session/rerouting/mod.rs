@@ -0,0 +1,371 @@
+//! Automatic rerouting on top of the navigation state machine.
+//!
+//! The [`NavigationController`] only *detects* that the user has left the route
+//! (see [`crate::deviation_detection`]).
+//! The [`RerouteController`] implements the rest of the loop that every platform otherwise needs:
+//! waiting until the user has been off the route for a while,
+//! building a route request from the remaining waypoints,
+//! fetching and parsing the response,
+//! and producing a new navigation state which keeps the trip history.
+//!
+//! The network call itself stays on the platform side via [`RouteFetcher`].
+
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    navigation_controller::{
+        NavigationController, Navigator,
+        models::{NavState, NavigationControllerConfig, TripState},
+    },
+    routing_adapters::{RouteAdapter, RouteRequest},
+};
+use models::{RerouteConfig, RerouteError, RerouteStatus, RerouteTracking};
+
+#[cfg(all(feature = "std", not(feature = "web-time")))]
+use std::time::SystemTime;
+
+#[cfg(feature = "web-time")]
+use web_time::SystemTime;
+
+pub mod models;
+
+/// Performs the network request for a reroute.
+///
+/// Implementations execute the [`RouteRequest`] (typically over HTTP)
+/// and return the raw response body,
+/// which is then parsed by the [`RouteAdapter`] of the [`RerouteController`].
+#[cfg_attr(feature = "uniffi", uniffi::export(with_foreign))]
+pub trait RouteFetcher: Send + Sync {
+    fn fetch_route(&self, request: RouteRequest) -> Result<Vec<u8>, RerouteError>;
+}
+
+/// Decides when to reroute, and builds the navigation state for the new route.
+///
+/// Feed every state produced by your [`Navigator`] into [`RerouteController::check_for_reroute`].
+/// Once the user has been completely off the route (or traveling the wrong direction along it)
+/// for [`RerouteConfig::debounce_seconds`]
+/// (and at least [`RerouteConfig::minimum_interval_seconds`] have passed since the last attempt),
+/// the controller requests a route to the remaining waypoints
+/// and returns a state positioned on the new route
+/// (see [`Navigator::replace_route`] for what is carried over).
+#[cfg_attr(feature = "uniffi", derive(uniffi::Object))]
+pub struct RerouteController {
+    config: RerouteConfig,
+    navigation_config: NavigationControllerConfig,
+    route_adapter: Arc<RouteAdapter>,
+    route_fetcher: Arc<dyn RouteFetcher>,
+    tracking: Mutex<RerouteTracking>,
+}
+
+#[cfg_attr(feature = "uniffi", uniffi::export)]
+impl RerouteController {
+    /// Creates a reroute controller.
+    ///
+    /// The `navigation_config` is used to compute the navigation state on new routes,
+    /// and should be the same one that your [`Navigator`] uses.
+    #[cfg_attr(feature = "uniffi", uniffi::constructor)]
+    pub fn new(
+        config: RerouteConfig,
+        navigation_config: NavigationControllerConfig,
+        route_adapter: Arc<RouteAdapter>,
+        route_fetcher: Arc<dyn RouteFetcher>,
+    ) -> Self {
+        Self {
+            config,
+            navigation_config,
+            route_adapter,
+            route_fetcher,
+            tracking: Mutex::new(RerouteTracking::default()),
+        }
+    }
+
+    /// Checks whether a reroute is due for the given state, and performs it if so.
+    ///
+    /// A failed attempt still counts towards the minimum interval between reroutes.
+    pub fn check_for_reroute(&self, state: &NavState) -> Result<RerouteStatus, RerouteError> {
+        let TripState::Navigating {
+            user_location,
+            ref remaining_waypoints,
+            deviation,
+            ..
+        } = state.trip_state()
+        else {
+            self.reset();
+            return Ok(RerouteStatus::NotNeeded);
+        };
+
+        if !deviation.is_completely_off_route() && !deviation.is_wrong_direction() {
+            self.reset();
+            return Ok(RerouteStatus::NotNeeded);
+        }
+
+        if !self.should_attempt(user_location.timestamp) {
+            return Ok(RerouteStatus::Pending);
+        }
+
+        let request = self
+            .route_adapter
+            .generate_request(user_location, remaining_waypoints.clone())?;
+        let response = self.route_fetcher.fetch_route(request)?;
+        let route = self
+            .route_adapter
+            .parse_response(response)?
+            .into_iter()
+            .next()
+            .ok_or(RerouteError::NoRoutes)?;
+
+        let state = NavigationController::new(route.clone(), self.navigation_config.clone())
+            .replace_route(route.clone(), state.clone());
+        Ok(RerouteStatus::Rerouted { route, state })
+    }
+
+    /// Clears the debounce state (e.g. after the platform switched routes by other means).
+    pub fn reset(&self) {
+        if let Ok(mut tracking) = self.tracking.lock() {
+            *tracking = RerouteTracking::default();
+        }
+    }
+}
+
+impl RerouteController {
+    /// Records an off-route observation and decides whether a reroute should be attempted now.
+    fn should_attempt(&self, timestamp: SystemTime) -> bool {
+        let Ok(mut tracking) = self.tracking.lock() else {
+            return false;
+        };
+
+        let off_route_since = *tracking.off_route_since.get_or_insert(timestamp);
+        let elapsed_seconds = |since: SystemTime| {
+            timestamp
+                .duration_since(since)
+                .unwrap_or_default()
+                .as_secs()
+        };
+
+        let debounced = elapsed_seconds(off_route_since) >= self.config.debounce_seconds;
+        let interval_elapsed = tracking.last_attempt_at.is_none_or(|last_attempt_at| {
+            elapsed_seconds(last_attempt_at) >= self.config.minimum_interval_seconds
+        });
+
+        if debounced && interval_elapsed {
+            tracking.last_attempt_at = Some(timestamp);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deviation_detection::{DeviationKind, RouteDeviation};
+    use crate::navigation_controller::test_helpers::{
+        get_navigating_trip_state, get_test_navigation_controller_config,
+        get_test_step_advance_condition,
+    };
+    use crate::routing_adapters::osrm::OsrmResponseParser;
+    use crate::routing_adapters::valhalla::ValhallaHttpRequestGenerator;
+    use crate::test_utils::{TestRoute, make_user_location};
+    use geo::coord;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Serves a canned Valhalla response and counts how often it was asked to.
+    struct StubRouteFetcher {
+        calls: AtomicUsize,
+    }
+
+    impl RouteFetcher for StubRouteFetcher {
+        fn fetch_route(&self, _request: RouteRequest) -> Result<Vec<u8>, RerouteError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(TestRoute::Valhalla.file_content().as_bytes().to_vec())
+        }
+    }
+
+    fn reroute_controller(config: RerouteConfig) -> (RerouteController, Arc<StubRouteFetcher>) {
+        let fetcher = Arc::new(StubRouteFetcher {
+            calls: AtomicUsize::new(0),
+        });
+        let route_adapter = Arc::new(RouteAdapter::new(
+            Arc::new(ValhallaHttpRequestGenerator::new(
+                "https://localhost/route",
+                "auto",
+                serde_json::Map::new(),
+            )),
+            Arc::new(OsrmResponseParser::new(6)),
+        ));
+        let controller = RerouteController::new(
+            config,
+            get_test_navigation_controller_config(get_test_step_advance_condition(5)),
+            route_adapter,
+            fetcher.clone(),
+        );
+        (controller, fetcher)
+    }
+
+    fn off_route_state(seconds: u64, distance_traveled: f64) -> NavState {
+        let route = TestRoute::Valhalla.first_route();
+        let start = route.geometry[0];
+        let mut location = make_user_location(coord!(x: start.lng, y: start.lat), 5.0);
+        location.timestamp = SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+
+        let mut trip_state = get_navigating_trip_state(
+            location,
+            route.steps.clone(),
+            route.waypoints[1..].to_vec(),
+            RouteDeviation::Deviation {
+                kind: DeviationKind::CompletelyOffRoute {
+                    deviation_from_route_line: 100.0,
+                },
+            },
+        );
+        if let TripState::Navigating { summary, .. } = &mut trip_state {
+            summary.distance_traveled = distance_traveled;
+        }
+
+        NavState::new(trip_state, get_test_step_advance_condition(5))
+    }
+
+    #[test]
+    fn test_not_needed_while_on_route() {
+        let (controller, fetcher) = reroute_controller(RerouteConfig::default());
+        let route = TestRoute::Valhalla.first_route();
+        let start = route.geometry[0];
+        let state = NavigationController::new(
+            route,
+            get_test_navigation_controller_config(get_test_step_advance_condition(5)),
+        )
+        .get_initial_state(make_user_location(coord!(x: start.lng, y: start.lat), 0.0));
+
+        assert!(matches!(
+            controller.check_for_reroute(&state),
+            Ok(RerouteStatus::NotNeeded)
+        ));
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_debounce_and_minimum_interval() {
+        let (controller, fetcher) = reroute_controller(RerouteConfig {
+            debounce_seconds: 3,
+            minimum_interval_seconds: 10,
+        });
+
+        // The first off-route fix starts the debounce window.
+        assert!(matches!(
+            controller.check_for_reroute(&off_route_state(100, 0.0)),
+            Ok(RerouteStatus::Pending)
+        ));
+        assert!(matches!(
+            controller.check_for_reroute(&off_route_state(102, 0.0)),
+            Ok(RerouteStatus::Pending)
+        ));
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 0);
+
+        // Still off route after the debounce period.
+        assert!(matches!(
+            controller.check_for_reroute(&off_route_state(103, 0.0)),
+            Ok(RerouteStatus::Rerouted { .. })
+        ));
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 1);
+
+        // The new route (hypothetically) did not fix things, but we have to wait.
+        assert!(matches!(
+            controller.check_for_reroute(&off_route_state(110, 0.0)),
+            Ok(RerouteStatus::Pending)
+        ));
+        assert!(matches!(
+            controller.check_for_reroute(&off_route_state(113, 0.0)),
+            Ok(RerouteStatus::Rerouted { .. })
+        ));
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_returning_to_route_resets_debounce() {
+        let (controller, fetcher) = reroute_controller(RerouteConfig {
+            debounce_seconds: 3,
+            minimum_interval_seconds: 0,
+        });
+
+        assert!(matches!(
+            controller.check_for_reroute(&off_route_state(100, 0.0)),
+            Ok(RerouteStatus::Pending)
+        ));
+        assert!(matches!(
+            controller.check_for_reroute(&NavState::idle(None)),
+            Ok(RerouteStatus::NotNeeded)
+        ));
+        // The off-route streak starts over.
+        assert!(matches!(
+            controller.check_for_reroute(&off_route_state(103, 0.0)),
+            Ok(RerouteStatus::Pending)
+        ));
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_rerouted_state_preserves_summary() {
+        let (controller, _) = reroute_controller(RerouteConfig {
+            debounce_seconds: 0,
+            minimum_interval_seconds: 0,
+        });
+
+        let previous_state = off_route_state(100, 1234.0);
+        let TripState::Navigating {
+            summary: previous_summary,
+            ..
+        } = previous_state.trip_state()
+        else {
+            unreachable!()
+        };
+
+        let Ok(RerouteStatus::Rerouted { route, state }) =
+            controller.check_for_reroute(&previous_state)
+        else {
+            panic!("Expected a reroute");
+        };
+
+        match state.trip_state() {
+            TripState::Navigating {
+                summary,
+                remaining_steps,
+                deviation,
+                ..
+            } => {
+                assert_eq!(summary, previous_summary);
+                assert_eq!(remaining_steps, route.steps);
+                assert_eq!(deviation, RouteDeviation::NoDeviation);
+            }
+            other => panic!("Expected Navigating, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_no_routes_is_an_error() {
+        struct EmptyRouteFetcher;
+
+        impl RouteFetcher for EmptyRouteFetcher {
+            fn fetch_route(&self, _request: RouteRequest) -> Result<Vec<u8>, RerouteError> {
+                Ok(br#"{"code": "Ok", "routes": [], "waypoints": []}"#.to_vec())
+            }
+        }
+
+        let (reference, _) = reroute_controller(RerouteConfig::default());
+        let controller = RerouteController::new(
+            RerouteConfig {
+                debounce_seconds: 0,
+                minimum_interval_seconds: 0,
+            },
+            reference.navigation_config.clone(),
+            reference.route_adapter.clone(),
+            Arc::new(EmptyRouteFetcher),
+        );
+
+        assert!(matches!(
+            controller.check_for_reroute(&off_route_state(100, 0.0)),
+            Err(RerouteError::NoRoutes)
+        ));
+    }
+}
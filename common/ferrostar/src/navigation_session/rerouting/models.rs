@@ -0,0 +1,107 @@
+use crate::{
+    models::Route,
+    navigation_controller::models::NavState,
+    routing_adapters::error::{ParsingError, RoutingRequestGenerationError},
+};
+
+#[cfg(all(feature = "std", not(feature = "web-time")))]
+use std::time::SystemTime;
+
+#[cfg(feature = "web-time")]
+use web_time::SystemTime;
+
+/// Configures when the [`RerouteController`](super::RerouteController) decides to fetch a new route.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct RerouteConfig {
+    /// How long (in seconds) the user must be continuously off the route
+    /// before a new route is requested.
+    ///
+    /// This smooths over a single noisy location fix triggering a reroute.
+    pub debounce_seconds: u64,
+    /// The minimum time (in seconds) between two consecutive reroute attempts.
+    ///
+    /// This applies regardless of whether the previous attempt succeeded,
+    /// so a failing routing backend is not hammered with requests.
+    pub minimum_interval_seconds: u64,
+}
+
+impl Default for RerouteConfig {
+    fn default() -> Self {
+        Self {
+            debounce_seconds: 3,
+            minimum_interval_seconds: 10,
+        }
+    }
+}
+
+/// The outcome of a [`RerouteController`](super::RerouteController) check.
+#[derive(Clone)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[allow(clippy::large_enum_variant)]
+pub enum RerouteStatus {
+    /// The user is following the route (or not navigating); nothing to do.
+    NotNeeded,
+    /// The user is off the route, but the debounce period or minimum interval has not elapsed yet.
+    Pending,
+    /// A new route was fetched.
+    ///
+    /// The state is positioned on the new route,
+    /// and carries over the [`TripSummary`](crate::navigation_controller::models::TripSummary)
+    /// from the state which triggered the reroute.
+    /// Your navigator is still on the old route; switch it over with
+    /// [`Navigator::replace_route`](crate::navigation_controller::Navigator::replace_route)
+    /// (which produces an equivalent state), or create a new one for `route`.
+    Rerouted { route: Route, state: NavState },
+}
+
+/// An error raised while rerouting.
+#[derive(Debug, thiserror::Error)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Error))]
+pub enum RerouteError {
+    #[error("Failed to generate a route request: {error}.")]
+    RequestGenerationError { error: String },
+    #[error("Failed to fetch a route: {error}.")]
+    FetchError { error: String },
+    #[error("Failed to parse the route response: {error}.")]
+    ParsingError { error: String },
+    #[error("The routing backend did not return any routes.")]
+    NoRoutes,
+    #[error("An unknown error fetching a route was raised in foreign code.")]
+    UnknownFetchError,
+}
+
+#[cfg(feature = "uniffi")]
+impl From<uniffi::UnexpectedUniFFICallbackError> for RerouteError {
+    fn from(_: uniffi::UnexpectedUniFFICallbackError) -> RerouteError {
+        RerouteError::UnknownFetchError
+    }
+}
+
+impl From<RoutingRequestGenerationError> for RerouteError {
+    fn from(e: RoutingRequestGenerationError) -> Self {
+        RerouteError::RequestGenerationError {
+            error: e.to_string(),
+        }
+    }
+}
+
+impl From<ParsingError> for RerouteError {
+    fn from(e: ParsingError) -> Self {
+        RerouteError::ParsingError {
+            error: e.to_string(),
+        }
+    }
+}
+
+/// Internal bookkeeping for debouncing reroutes.
+///
+/// Times are taken from location timestamps rather than the wall clock,
+/// so that recordings and simulations replay deterministically.
+#[derive(Debug, Default, Copy, Clone)]
+pub(crate) struct RerouteTracking {
+    /// The timestamp of the first location in the current off-route streak.
+    pub off_route_since: Option<SystemTime>,
+    /// The timestamp of the location that triggered the last reroute attempt.
+    pub last_attempt_at: Option<SystemTime>,
+}
@@ -24,7 +24,7 @@ pub(crate) fn test_full_route_state_snapshot(
         let new_state = session.update_user_location(new_simulation_state.current_location, state);
 
         match new_state.trip_state() {
-            TripState::Idle { .. } => {}
+            TripState::Idle { .. } | TripState::Paused { .. } => {}
             TripState::Navigating {
                 current_step_geometry_index,
                 ref remaining_steps,
@@ -139,7 +139,8 @@ impl Route {
             let steps = route
                 .legs
                 .iter()
-                .flat_map(|leg| {
+                .zip(0..)
+                .flat_map(|(leg, leg_index)| {
                     // Converts all single value annotation vectors into a single vector with a value object.
                     let annotations = leg
                         .annotation
@@ -215,6 +216,7 @@ impl Route {
                             step_geometry,
                             annotation_slice,
                             relevant_incidents_slice,
+                            leg_index,
                         ))
                     })
                 })
@@ -250,6 +252,7 @@ impl RouteStep {
         geometry: Vec<GeographicCoordinate>,
         annotations: Option<Vec<AnyAnnotationValue>>,
         incidents: Vec<Incident>,
+        leg_index: u32,
     ) -> Self {
         let visual_instructions = value
             .banner_instructions
@@ -349,6 +352,7 @@ impl RouteStep {
             incidents,
             driving_side,
             roundabout_exit_number: value.maneuver.exit,
+            leg_index,
         }
     }
 }
@@ -418,6 +422,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_valhalla_preserving_leg_indices() {
+        let route = TestRoute::ValhallaWithRoundabouts.first_route();
+
+        let leg_indices: Vec<_> = route.steps.iter().map(|step| step.leg_index).collect();
+        assert_eq!(leg_indices, [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1]);
+    }
+
     #[test]
     fn parse_valhalla_asserting_sub_maneuvers() {
         let routes = TestRoute::ValhallaExtended.parse();
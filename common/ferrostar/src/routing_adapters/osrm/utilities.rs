@@ -59,7 +59,7 @@ impl From<&MapboxOsrmIncident> for Incident {
             end_time: incident.end_time,
             impact: incident.impact,
             lanes_blocked: incident.lanes_blocked.clone(),
-            congestion: incident.congestion.clone(),
+            congestion: incident.congestion,
             closed: incident.closed,
             geometry_index_start: incident.geometry_index_start,
             geometry_index_end: incident.geometry_index_end,
@@ -56,6 +56,15 @@ fn same_location_results_in_identical_state() {
             }),
             route_deviation_tracking: RouteDeviationTracking::None,
             snapped_location_course_filtering: CourseFiltering::Raw,
+            map_matching: None,
+            adaptive_eta: None,
+            dead_reckoning: None,
+            location_filter: None,
+            location_smoothing: None,
+            step_regression: None,
+            speeding: None,
+            maneuver_preview: None,
+            incidents: None,
         },
         false,
     );
@@ -102,6 +111,15 @@ fn simple_route_state_machine_manual_advance() {
             arrival_step_advance_condition: Arc::new(ManualStepCondition),
             route_deviation_tracking: RouteDeviationTracking::None,
             snapped_location_course_filtering: CourseFiltering::Raw,
+            map_matching: None,
+            adaptive_eta: None,
+            dead_reckoning: None,
+            location_filter: None,
+            location_smoothing: None,
+            step_regression: None,
+            speeding: None,
+            maneuver_preview: None,
+            incidents: None,
         },
         false,
     );
@@ -184,6 +202,15 @@ fn simple_route_state_machine_advances_with_location_change() {
             }),
             route_deviation_tracking: RouteDeviationTracking::None,
             snapped_location_course_filtering: CourseFiltering::Raw,
+            map_matching: None,
+            adaptive_eta: None,
+            dead_reckoning: None,
+            location_filter: None,
+            location_smoothing: None,
+            step_regression: None,
+            speeding: None,
+            maneuver_preview: None,
+            incidents: None,
         },
         false,
     );